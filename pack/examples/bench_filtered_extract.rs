@@ -0,0 +1,197 @@
+//! Benchmark: filtered extraction over a "hostile" pack whose entry table
+//! order doesn't track its data layout, the way a community pack built by a
+//! different tool (or patched in place) might look. Compares reading the
+//! matched entries in table order against sorting them by `data_offset`
+//! first, using total absolute seek distance as a stand-in for the cost of
+//! a slow medium (spinning disk, network share) where forward-only reads
+//! are far cheaper than jumping around.
+//!
+//! Run with `cargo run --release -p pack --example bench_filtered_extract`.
+
+use std::cell::Cell;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::rc::Rc;
+use std::time::Instant;
+
+use pack::compress;
+use pack::nullstring::NullString;
+use pack::pack::{Entry, Header, Origin, Pack, MAGIC, VERSION};
+
+const ENTRY_COUNT: usize = 400;
+
+fn main() {
+    let bytes = build_hostile_pack();
+
+    let seek_stats = Rc::new(SeekStats::default());
+    let reader = InstrumentedReader::new(Cursor::new(bytes), seek_stats.clone());
+    let mut pack = Pack::from_reader(reader, Origin::Memory).expect("synthetic pack parses");
+
+    // Simulate a `--filter` that matches roughly a third of the entries,
+    // scattered through the table.
+    let matched: Vec<Entry> = pack
+        .entries
+        .iter()
+        .filter(|e| e.path.to_string_lossy().ends_with("7.dat") || e.path.to_string_lossy().ends_with("3.dat"))
+        .cloned()
+        .collect();
+    println!("{} of {} entries matched", matched.len(), pack.entries.len());
+
+    let table_order = matched.clone();
+    let (table_time, table_seek_distance) = run_pass(&mut pack, &seek_stats, &table_order);
+    println!(
+        "table order:  {:>8.2?}  total seek distance {} bytes",
+        table_time, table_seek_distance
+    );
+
+    let mut offset_order = matched;
+    offset_order.sort_by_key(|e| e.data_offset);
+    let (offset_time, offset_seek_distance) = run_pass(&mut pack, &seek_stats, &offset_order);
+    println!(
+        "offset order: {:>8.2?}  total seek distance {} bytes",
+        offset_time, offset_seek_distance
+    );
+
+    println!(
+        "reordering cut total seek distance by {:.1}x",
+        table_seek_distance as f64 / offset_seek_distance.max(1) as f64
+    );
+}
+
+fn run_pass(pack: &mut Pack<InstrumentedReader<Cursor<Vec<u8>>>>, stats: &SeekStats, order: &[Entry]) -> (std::time::Duration, u64) {
+    stats.reset();
+    let start = Instant::now();
+    for entry in order {
+        pack.read_entry_bytes(entry).expect("entry decompresses");
+    }
+    (start.elapsed(), stats.total_distance())
+}
+
+/// Build a pack where the file table is in ascending name order but the
+/// data section is written in descending order, so table order and
+/// `data_offset` order point in opposite directions.
+fn build_hostile_pack() -> Vec<u8> {
+    let names: Vec<String> = (0..ENTRY_COUNT).map(|i| format!("unit/{i:04}.dat")).collect();
+
+    struct Prepared {
+        name: String,
+        chunk: Vec<u8>,
+        size_on_disk: u32,
+    }
+    let prepared: Vec<Prepared> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let data = pseudo_random_bytes(i, 500 + (i % 2500));
+            Prepared {
+                name: name.clone(),
+                chunk: compress::compress(&data),
+                size_on_disk: data.len() as u32,
+            }
+        })
+        .collect();
+
+    // Table order: ascending. Data order: descending.
+    let data_section_offset = Header::SIZE
+        + prepared
+            .iter()
+            .map(|p| p.name.len() + 1 + 1 + 4 + 4 + 8 + 4 + 4)
+            .sum::<usize>();
+
+    let mut data_offsets = vec![0u64; prepared.len()];
+    let mut cursor = data_section_offset as u64;
+    let mut data_section = Vec::new();
+    for i in (0..prepared.len()).rev() {
+        data_offsets[i] = cursor;
+        cursor += prepared[i].chunk.len() as u64;
+        data_section.extend_from_slice(&prepared[i].chunk);
+    }
+
+    let mut file_section = Vec::new();
+    for (i, p) in prepared.iter().enumerate() {
+        NullString::write(&mut file_section, &p.name).unwrap();
+        file_section.push(1); // compressed
+        file_section.extend_from_slice(&p.size_on_disk.to_le_bytes());
+        file_section.extend_from_slice(&(p.chunk.len() as u32).to_le_bytes());
+        file_section.extend_from_slice(&data_offsets[i].to_le_bytes());
+        file_section.extend_from_slice(&1u32.to_le_bytes()); // chunk_count
+        file_section.extend_from_slice(&(p.chunk.len() as u32).to_le_bytes());
+    }
+
+    let header = Header {
+        magic: MAGIC,
+        version: VERSION,
+        entry_count: prepared.len() as u32,
+        file_section_size: file_section.len() as u32,
+        data_section_offset: data_section_offset as u32,
+    };
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&header.magic);
+    bytes.extend_from_slice(&header.version.to_le_bytes());
+    bytes.extend_from_slice(&header.entry_count.to_le_bytes());
+    bytes.extend_from_slice(&header.file_section_size.to_le_bytes());
+    bytes.extend_from_slice(&header.data_section_offset.to_le_bytes());
+    bytes.extend_from_slice(&file_section);
+    bytes.extend_from_slice(&data_section);
+    bytes
+}
+
+fn pseudo_random_bytes(seed: usize, len: usize) -> Vec<u8> {
+    let mut state = (seed as u64).wrapping_mul(2685821657736338717).wrapping_add(1);
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state & 0xff) as u8
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct SeekStats {
+    last: Cell<u64>,
+    total: Cell<u64>,
+}
+
+impl SeekStats {
+    fn record(&self, new_pos: u64) {
+        let last = self.last.get();
+        self.total.set(self.total.get() + last.abs_diff(new_pos));
+        self.last.set(new_pos);
+    }
+
+    fn reset(&self) {
+        self.last.set(0);
+        self.total.set(0);
+    }
+
+    fn total_distance(&self) -> u64 {
+        self.total.get()
+    }
+}
+
+struct InstrumentedReader<R> {
+    inner: R,
+    stats: Rc<SeekStats>,
+}
+
+impl<R> InstrumentedReader<R> {
+    fn new(inner: R, stats: Rc<SeekStats>) -> Self {
+        InstrumentedReader { inner, stats }
+    }
+}
+
+impl<R: Read> Read for InstrumentedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<R: Seek> Seek for InstrumentedReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let new_pos = self.inner.seek(pos)?;
+        self.stats.record(new_pos);
+        Ok(new_pos)
+    }
+}
@@ -0,0 +1,392 @@
+//! Interactive two-pane TUI browser for `.pack` archives.
+
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::Terminal;
+
+use crate::error::{io_err, Result};
+use crate::pack::{sanitize_entry_path, Pack};
+
+pub struct BrowseArgs {
+    pub pack: PathBuf,
+    pub dest: PathBuf,
+}
+
+#[derive(PartialEq, Eq)]
+enum Pane {
+    Dirs,
+    Entries,
+}
+
+struct App {
+    pack: Pack<File>,
+    dirs: Vec<String>,
+    pane: Pane,
+    dir_state: ListState,
+    entry_state: ListState,
+    filter: String,
+    filtering: bool,
+    marked: BTreeSet<usize>,
+    preview: Option<String>,
+    status: String,
+}
+
+impl App {
+    /// Build the directory tree once, up front, from the pack's entry
+    /// paths. This is the only pass over all entries that isn't driven by
+    /// user input, so browsing stays responsive on packs with tens of
+    /// thousands of entries.
+    fn new(pack: Pack<File>) -> Self {
+        let mut dirs: BTreeSet<String> = BTreeSet::new();
+        dirs.insert(String::new());
+        for entry in &pack.entries {
+            let mut dir = parent_dir(&entry.path);
+            loop {
+                dirs.insert(dir.clone());
+                if dir.is_empty() {
+                    break;
+                }
+                dir = match dir.rsplit_once('/') {
+                    Some((parent, _)) => parent.to_string(),
+                    None => String::new(),
+                };
+            }
+        }
+
+        let mut dir_state = ListState::default();
+        dir_state.select(Some(0));
+        let mut entry_state = ListState::default();
+        entry_state.select(Some(0));
+
+        App {
+            pack,
+            dirs: dirs.into_iter().collect(),
+            pane: Pane::Dirs,
+            dir_state,
+            entry_state,
+            filter: String::new(),
+            filtering: false,
+            marked: BTreeSet::new(),
+            preview: None,
+            status: String::new(),
+        }
+    }
+
+    fn current_dir(&self) -> &str {
+        self.dirs
+            .get(self.dir_state.selected().unwrap_or(0))
+            .map(String::as_str)
+            .unwrap_or("")
+    }
+
+    fn visible_entries(&self) -> Vec<usize> {
+        let dir = self.current_dir();
+        let needle = self.filter.to_lowercase();
+        self.pack
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| {
+                parent_dir(&e.path) == dir
+                    && (needle.is_empty() || e.path.to_string_lossy().to_lowercase().contains(&needle))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        match self.pane {
+            Pane::Dirs => {
+                let len = self.dirs.len();
+                move_list_state(&mut self.dir_state, len, delta);
+                self.entry_state.select(Some(0));
+            }
+            Pane::Entries => {
+                let len = self.visible_entries().len();
+                move_list_state(&mut self.entry_state, len, delta);
+            }
+        }
+    }
+
+    fn selected_entry(&self) -> Option<usize> {
+        let visible = self.visible_entries();
+        self.entry_state.selected().and_then(|i| visible.get(i).copied())
+    }
+
+    fn toggle_mark(&mut self) {
+        if let Some(index) = self.selected_entry() {
+            if !self.marked.remove(&index) {
+                self.marked.insert(index);
+            }
+        }
+    }
+
+    fn toggle_preview(&mut self) {
+        if self.preview.is_some() {
+            self.preview = None;
+            return;
+        }
+        if let Some(index) = self.selected_entry() {
+            let entry = self.pack.entries[index].clone();
+            match self.pack.read_entry_bytes(&entry) {
+                Ok(bytes) => {
+                    let text = String::from_utf8_lossy(&bytes[..bytes.len().min(4096)]).into_owned();
+                    self.preview = Some(text);
+                }
+                Err(e) => self.status = format!("preview failed: {e}"),
+            }
+        }
+    }
+
+    fn extract_marked(&mut self, dest: &std::path::Path) {
+        if self.marked.is_empty() {
+            self.status = "nothing marked".to_string();
+            return;
+        }
+        let mut extracted = 0;
+        let mut rejected = 0;
+        let marked: Vec<usize> = self.marked.iter().copied().collect();
+        for index in marked {
+            let entry = self.pack.entries[index].clone();
+            let Ok(relative) = sanitize_entry_path(&entry.path) else {
+                rejected += 1;
+                continue;
+            };
+            let target = dest.join(relative);
+            if self.pack.extract_entry(&entry, &target).is_ok() {
+                extracted += 1;
+            }
+        }
+        self.status = match rejected {
+            0 => format!("extracted {extracted} entries to {}", dest.display()),
+            rejected => format!("extracted {extracted} entries to {} ({rejected} rejected)", dest.display()),
+        };
+        self.marked.clear();
+    }
+}
+
+fn parent_dir(path: &std::path::Path) -> String {
+    path.parent()
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn move_list_state(state: &mut ListState, len: usize, delta: i32) {
+    if len == 0 {
+        state.select(None);
+        return;
+    }
+    let current = state.selected().unwrap_or(0) as i32;
+    let next = (current + delta).clamp(0, len as i32 - 1);
+    state.select(Some(next as usize));
+}
+
+pub fn run(args: &BrowseArgs) -> Result<()> {
+    let pack = Pack::open(&args.pack)?;
+    let mut app = App::new(pack);
+
+    install_panic_restore_hook();
+
+    enable_raw_mode().map_err(|e| io_err(&args.pack, e))?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen).map_err(|e| io_err(&args.pack, e))?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend).map_err(|e| io_err(&args.pack, e))?;
+
+    let outcome = event_loop(&mut terminal, &mut app, &args.dest);
+
+    disable_raw_mode().ok();
+    let _ = execute!(terminal.backend_mut(), LeaveAlternateScreen);
+    let _ = terminal.show_cursor();
+
+    outcome.map_err(|e| io_err(&args.pack, e))
+}
+
+fn install_panic_restore_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        default_hook(info);
+    }));
+}
+
+fn event_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    dest: &std::path::Path,
+) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        if app.filtering {
+            match key.code {
+                KeyCode::Enter | KeyCode::Esc => app.filtering = false,
+                KeyCode::Backspace => {
+                    app.filter.pop();
+                }
+                KeyCode::Char(c) => app.filter.push(c),
+                _ => {}
+            }
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Tab => {
+                app.pane = match app.pane {
+                    Pane::Dirs => Pane::Entries,
+                    Pane::Entries => Pane::Dirs,
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => app.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => app.move_selection(-1),
+            KeyCode::Char('/') => {
+                app.filtering = true;
+                app.filter.clear();
+            }
+            KeyCode::Char('m') => app.toggle_mark(),
+            KeyCode::Char('p') | KeyCode::Enter => app.toggle_preview(),
+            KeyCode::Char('x') => app.extract_marked(dest),
+            _ => {}
+        }
+    }
+}
+
+fn draw(frame: &mut ratatui::Frame, app: &App) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(frame.area());
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(rows[0]);
+
+    let help = "Tab: switch pane  j/k: move  /: filter  m: mark  p/Enter: preview  x: extract  q: quit";
+    let status_line = if app.status.is_empty() {
+        help.to_string()
+    } else {
+        format!("{help}  |  {}", app.status)
+    };
+    frame.render_widget(Paragraph::new(status_line), rows[1]);
+
+    let dir_items: Vec<ListItem> = app
+        .dirs
+        .iter()
+        .map(|d| ListItem::new(if d.is_empty() { "/".to_string() } else { d.clone() }))
+        .collect();
+    let dirs_list = List::new(dir_items)
+        .block(Block::default().borders(Borders::ALL).title("Directories"))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(dirs_list, columns[0], &mut app.dir_state.clone());
+
+    let visible = app.visible_entries();
+    let entry_items: Vec<ListItem> = visible
+        .iter()
+        .map(|&i| {
+            let entry = &app.pack.entries[i];
+            let mark = if app.marked.contains(&i) { "*" } else { " " };
+            ListItem::new(format!(
+                "{mark} {} ({} bytes)",
+                entry.path.display(),
+                entry.size_on_disk
+            ))
+        })
+        .collect();
+    let title = if app.filtering {
+        format!("Entries (filter: {}_)", app.filter)
+    } else if app.filter.is_empty() {
+        "Entries".to_string()
+    } else {
+        format!("Entries (filter: {})", app.filter)
+    };
+    let entries_list = List::new(entry_items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(entries_list, columns[1], &mut app.entry_state.clone());
+
+    if let Some(preview) = &app.preview {
+        let area = centered_rect(70, 70, frame.area());
+        let block = Block::default().borders(Borders::ALL).title("Preview");
+        frame.render_widget(Paragraph::new(preview.as_str()).block(block), area);
+    }
+}
+
+fn centered_rect(pct_x: u16, pct_y: u16, area: ratatui::layout::Rect) -> ratatui::layout::Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - pct_y) / 2),
+            Constraint::Percentage(pct_y),
+            Constraint::Percentage((100 - pct_y) / 2),
+        ])
+        .split(area);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - pct_x) / 2),
+            Constraint::Percentage(pct_x),
+            Constraint::Percentage((100 - pct_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{self, prepare_entry};
+
+    /// `extract_marked` is the browser's equivalent of `extract`/`extract-all`'s
+    /// write loop -- a marked entry with a traversal path must be rejected the
+    /// same way, instead of escaping `dest` because a human picked it in the
+    /// TUI rather than a filter.
+    #[test]
+    fn extract_marked_skips_a_traversal_entry_without_escaping_dest() {
+        let dir = std::env::temp_dir().join("pack_browse_traversal_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let pack_path = dir.join("malicious.pack");
+        writer::write_pack(
+            &pack_path,
+            &[
+                prepare_entry("safe.txt".to_string(), b"fine"),
+                prepare_entry("../escaped_parent.txt".to_string(), b"parent traversal"),
+            ],
+        )
+        .unwrap();
+
+        let pack = Pack::open(&pack_path).unwrap();
+        let mut app = App::new(pack);
+        app.marked.insert(0);
+        app.marked.insert(1);
+
+        let dest = dir.join("out");
+        app.extract_marked(&dest);
+
+        assert_eq!(std::fs::read(dest.join("safe.txt")).unwrap(), b"fine");
+        assert!(!dir.join("escaped_parent.txt").exists());
+        assert!(app.status.contains("1 rejected"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use super::{parse_cas, Result};
+
+pub struct InfoArgs {
+    pub file: PathBuf,
+}
+
+pub fn info(args: &InfoArgs) -> Result<()> {
+    let cas = parse_cas(&args.file)?;
+    println!("version:     {}", cas.version);
+    println!("frame_count: {}", cas.frame_count);
+    println!("bone_count:  {}", cas.bones.len());
+    println!("duration:    {:.3}s", cas.duration);
+    for bone in &cas.bones {
+        println!("  {} ({} keyframes)", bone.name, bone.keyframes.len());
+    }
+    Ok(())
+}
+
+pub struct DumpArgs {
+    pub file: PathBuf,
+    pub format: DumpFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DumpFormat {
+    Json,
+}
+
+pub fn dump(args: &DumpArgs) -> Result<()> {
+    let cas = parse_cas(&args.file)?;
+    match args.format {
+        DumpFormat::Json => {
+            let json = serde_json::to_string_pretty(&cas).expect("CasFile is always serializable");
+            println!("{json}");
+        }
+    }
+    Ok(())
+}
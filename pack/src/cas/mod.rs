@@ -0,0 +1,175 @@
+//! Parser for `.cas` animation files (skeletal animation tracks used by
+//! both battle units and campaign-map strat models).
+
+pub mod commands;
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::reader::PosReader;
+
+pub const MAGIC: [u8; 4] = *b"CAS1";
+
+#[derive(thiserror::Error, Debug)]
+pub enum CasError {
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{path}: not a .cas file (expected magic {expected:?}, found {found:?})")]
+    BadMagic {
+        path: PathBuf,
+        expected: [u8; 4],
+        found: [u8; 4],
+    },
+
+    #[error("{path}: malformed data at offset 0x{offset:x}: {message}")]
+    Malformed {
+        path: PathBuf,
+        offset: u64,
+        message: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, CasError>;
+
+#[derive(Debug, Serialize)]
+pub struct Keyframe {
+    pub position: [f32; 3],
+    pub rotation: [f32; 4],
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoneTrack {
+    pub name: String,
+    pub keyframes: Vec<Keyframe>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CasFile {
+    pub version: u32,
+    pub frame_count: u32,
+    pub duration: f32,
+    pub bones: Vec<BoneTrack>,
+}
+
+/// Parse a `.cas` file's header and keyframe data. Every read is checked;
+/// truncated or garbage input produces a [`CasError`] naming the byte
+/// offset where parsing stopped, never a panic.
+pub fn parse_cas(path: &Path) -> Result<CasFile> {
+    let file = File::open(path).map_err(|e| io_err(path, e))?;
+    let mut reader = PosReader::new(file);
+
+    let mut magic = [0u8; 4];
+    reader
+        .read_exact(&mut magic)
+        .map_err(|e| header_err(path, &reader, "truncated magic", e))?;
+    if magic != MAGIC {
+        return Err(CasError::BadMagic {
+            path: path.to_path_buf(),
+            expected: MAGIC,
+            found: magic,
+        });
+    }
+
+    let version = reader
+        .read_u32()
+        .map_err(|e| header_err(path, &reader, "truncated version", e))?;
+    let bone_count = reader
+        .read_u32()
+        .map_err(|e| header_err(path, &reader, "truncated bone count", e))?;
+    let frame_count = reader
+        .read_u32()
+        .map_err(|e| header_err(path, &reader, "truncated frame count", e))?;
+    let duration = reader
+        .read_f32()
+        .map_err(|e| header_err(path, &reader, "truncated duration", e))?;
+
+    let mut bones = Vec::with_capacity(bone_count as usize);
+    for _ in 0..bone_count {
+        let name = reader
+            .read_null_string()
+            .map_err(|e| header_err(path, &reader, "truncated bone name", e))?;
+
+        let mut keyframes = Vec::with_capacity(frame_count as usize);
+        for frame in 0..frame_count {
+            let position = read_vec3(&mut reader, path, &name, frame)?;
+            let rotation = read_quat(&mut reader, path, &name, frame)?;
+            keyframes.push(Keyframe { position, rotation });
+        }
+
+        bones.push(BoneTrack { name, keyframes });
+    }
+
+    Ok(CasFile {
+        version,
+        frame_count,
+        duration,
+        bones,
+    })
+}
+
+fn read_vec3(
+    reader: &mut PosReader<File>,
+    path: &Path,
+    bone: &str,
+    frame: u32,
+) -> Result<[f32; 3]> {
+    let offset = reader.position();
+    let mut v = [0.0f32; 3];
+    for slot in &mut v {
+        *slot = reader.read_f32().map_err(|_| CasError::Malformed {
+            path: path.to_path_buf(),
+            offset,
+            message: format!("truncated position for bone {bone:?} frame {frame}"),
+        })?;
+    }
+    Ok(v)
+}
+
+fn read_quat(
+    reader: &mut PosReader<File>,
+    path: &Path,
+    bone: &str,
+    frame: u32,
+) -> Result<[f32; 4]> {
+    let offset = reader.position();
+    let mut v = [0.0f32; 4];
+    for slot in &mut v {
+        *slot = reader.read_f32().map_err(|_| CasError::Malformed {
+            path: path.to_path_buf(),
+            offset,
+            message: format!("truncated rotation for bone {bone:?} frame {frame}"),
+        })?;
+    }
+    Ok(v)
+}
+
+fn io_err(path: &Path, source: std::io::Error) -> CasError {
+    CasError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn header_err(
+    path: &Path,
+    reader: &PosReader<File>,
+    message: &str,
+    source: std::io::Error,
+) -> CasError {
+    if source.kind() == std::io::ErrorKind::UnexpectedEof {
+        CasError::Malformed {
+            path: path.to_path_buf(),
+            offset: reader.position(),
+            message: message.to_string(),
+        }
+    } else {
+        io_err(path, source)
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::delta::{Manifest, MANIFEST_PATH};
+use crate::error::Result;
+use crate::fileset;
+use crate::writer::{self, PreparedEntry};
+
+pub struct ApplyDeltaArgs {
+    pub base: PathBuf,
+    pub patch: PathBuf,
+    pub output: PathBuf,
+}
+
+/// Reconstruct a full pack from a base pack and a delta produced by
+/// `pack delta`: start from the base, drop the manifest's deletions, then
+/// overlay every non-manifest entry from the patch.
+pub fn run(args: &ApplyDeltaArgs) -> Result<()> {
+    let base = fileset::load(&args.base)?;
+    let patch = fileset::load(&args.patch)?;
+
+    let mut manifest = Manifest::default();
+    let mut files: HashMap<String, Vec<u8>> = base.into_iter().collect();
+
+    for (path, data) in patch {
+        if path == MANIFEST_PATH {
+            manifest = serde_json::from_slice(&data).unwrap_or_default();
+            continue;
+        }
+        files.insert(path, data);
+    }
+
+    for path in &manifest.deletions {
+        files.remove(path);
+    }
+
+    let mut prepared: Vec<PreparedEntry> = files
+        .into_iter()
+        .map(|(path, data)| writer::prepare_entry(path, &data))
+        .collect();
+    prepared.sort_by(|a, b| a.internal_path.cmp(&b.internal_path));
+
+    let count = prepared.len();
+    writer::write_pack(&args.output, &prepared)?;
+    println!("Wrote {count} entries to {}", args.output.display());
+    Ok(())
+}
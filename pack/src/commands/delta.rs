@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::fileset;
+use crate::hash::hash_bytes;
+use crate::writer::{self, PreparedEntry};
+
+/// Internal path used for the manifest entry that records deletions. Chosen
+/// to sort before ordinary content paths and to be obviously not a real
+/// game asset.
+pub const MANIFEST_PATH: &str = ".m2tw-delta-manifest.json";
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct Manifest {
+    pub deletions: Vec<String>,
+}
+
+pub struct DeltaArgs {
+    pub base: PathBuf,
+    pub updated: PathBuf,
+    pub output: PathBuf,
+}
+
+/// Compare `base` against `updated` by content hash and write a pack
+/// containing only new/modified entries plus a manifest listing deletions.
+pub fn run(args: &DeltaArgs) -> Result<()> {
+    let base = fileset::load(&args.base)?;
+    let updated = fileset::load(&args.updated)?;
+
+    let base_hashes: HashMap<&str, [u8; 32]> = base
+        .iter()
+        .map(|(path, data)| (path.as_str(), hash_bytes(data)))
+        .collect();
+    let updated_paths: std::collections::HashSet<&str> =
+        updated.iter().map(|(path, _)| path.as_str()).collect();
+
+    let mut prepared: Vec<PreparedEntry> = Vec::new();
+    for (path, data) in &updated {
+        let changed = match base_hashes.get(path.as_str()) {
+            Some(base_hash) => *base_hash != hash_bytes(data),
+            None => true,
+        };
+        if changed {
+            prepared.push(writer::prepare_entry(path.clone(), data));
+        }
+    }
+
+    let deletions: Vec<String> = base
+        .iter()
+        .map(|(path, _)| path.clone())
+        .filter(|path| !updated_paths.contains(path.as_str()))
+        .collect();
+
+    let manifest_json =
+        serde_json::to_vec(&Manifest { deletions }).expect("Manifest is always serializable");
+    prepared.push(writer::prepare_entry(MANIFEST_PATH.to_string(), &manifest_json));
+    prepared.sort_by(|a, b| a.internal_path.cmp(&b.internal_path));
+
+    let changed_count = prepared.len() - 1;
+    writer::write_pack(&args.output, &prepared)?;
+    println!(
+        "Wrote delta with {changed_count} changed entries to {}",
+        args.output.display()
+    );
+    Ok(())
+}
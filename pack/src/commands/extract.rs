@@ -0,0 +1,1064 @@
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use indicatif::{ProgressBar, ProgressStyle};
+use rayon::prelude::*;
+
+use crate::encoding::LegacyEncoding;
+use crate::error::{io_err, PackError, Result};
+use crate::filter;
+use crate::pack::{sanitize_entry_path, Pack};
+use crate::stats::StatsCollector;
+
+pub struct ExtractArgs {
+    pub pack: PathBuf,
+    pub dest: PathBuf,
+    /// Glob patterns, at least one of which an entry must match to be
+    /// extracted. Repeatable and/or comma-separated; empty matches
+    /// everything.
+    pub filters: Vec<String>,
+    /// Glob patterns excluded after `filters` is applied: an entry matching
+    /// one of these is skipped even if it also matches a `--filter`.
+    /// Repeatable and/or comma-separated.
+    pub excludes: Vec<String>,
+    /// Regex matched against the forward-slash-normalized entry path,
+    /// instead of `--filter`. Rejected if `--filter` is also given.
+    pub regex: Option<String>,
+    /// Fold ASCII case in both patterns and entry paths before matching, so
+    /// `--filter data/ui/**` also matches `Data/UI/button.tga`.
+    pub ignore_case: bool,
+    /// Skip sorting matched entries by `data_offset` before reading them.
+    /// The sort is what turns filtered extraction into a forward sweep over
+    /// the data section instead of seeking around in table order; only
+    /// worth disabling to compare against the old behavior.
+    pub no_reorder: bool,
+    /// Number of worker threads to extract entries with, or 0 to use the
+    /// number of available CPUs (rayon's default). Each worker opens its
+    /// own handle onto `pack` and reads only the entries handed to it, since
+    /// every entry's data offset and chunk list is independent of the rest.
+    pub jobs: usize,
+    /// Map `pack` into memory once and decompress chunks straight out of
+    /// the mapping instead of seeking and reading each one into a fresh
+    /// buffer. Falls back to the buffered reader automatically if mapping
+    /// the pack fails (e.g. on some network filesystems).
+    pub use_mmap: bool,
+    /// Write the throughput/timing summary as JSON to this file, in
+    /// addition to printing it, so extraction performance can be tracked
+    /// across tool versions in CI.
+    pub stats_json: Option<PathBuf>,
+    /// Legacy codepage to try for entry names that aren't valid UTF-8, or
+    /// `None` to go straight to lossy replacement. Defaults to cp1252.
+    pub legacy_encoding: Option<LegacyEncoding>,
+    /// Fail with a non-zero exit instead of just warning when `filter`
+    /// matched no entries.
+    pub strict_filters: bool,
+    /// Don't show a progress bar, even if stderr is a terminal.
+    pub no_progress: bool,
+    /// What to do when an entry's destination file already exists.
+    pub overwrite: OverwritePolicy,
+    /// Abort the whole run instead of just skipping an entry whose path
+    /// tries to escape `dest` (a `..` component, a leading separator, or a
+    /// drive prefix).
+    pub strict_paths: bool,
+    /// Write every selected entry directly into `dest` using only its file
+    /// name, dropping the directory structure entirely.
+    pub flatten: bool,
+    /// What to do when `--flatten` would write two different entries to the
+    /// same file name.
+    pub on_collision: OnCollision,
+}
+
+/// What to do when an entry's destination file already exists. Checked
+/// before the file is opened for writing, so `--no-clobber` never
+/// truncates a target it's about to reject.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverwritePolicy {
+    /// Overwrite the existing file. The default, for backwards
+    /// compatibility with versions that always overwrote.
+    #[default]
+    Overwrite,
+    /// Leave the existing file untouched and log that it was skipped.
+    SkipExisting,
+    /// Abort the run with an error naming the first conflicting path.
+    NoClobber,
+}
+
+/// What to do when `--flatten` would write two different entries to the
+/// same file name. Resolved up front, before any file is written, so `Fail`
+/// can list every collision at once instead of aborting on the first one.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnCollision {
+    /// Append `_2`, `_3`, ... before the extension until the name is free.
+    #[default]
+    Suffix,
+    /// Abort the run with a list of every colliding group of entries.
+    Fail,
+}
+
+pub fn run(args: &ExtractArgs) -> Result<()> {
+    let matcher =
+        filter::EntryMatcher::new(&args.filters, &args.excludes, args.regex.as_deref(), args.ignore_case)?;
+    let pack = Pack::open_with_encoding(&args.pack, args.legacy_encoding)?;
+    let mut matched: Vec<_> = pack.entries.iter().filter(|entry| matcher.matches(&entry.path)).cloned().collect();
+    matcher.check_matched(matched.len(), pack.entries.iter().map(|e| e.path.as_path()), args.strict_filters)?;
+    if !args.no_reorder {
+        matched.sort_by_key(|entry| entry.data_offset);
+    }
+
+    let targets = resolve_targets(&matched, args)?;
+
+    let progress = new_progress_bar(matched.iter().map(|e| u64::from(e.size_on_disk)).sum(), args.no_progress);
+
+    for entry in &matched {
+        if entry.decode_kind.is_affected() {
+            progress.suspend(|| {
+                eprintln!(
+                    "warning: {} decoded via {} from raw bytes {}",
+                    entry.path.display(),
+                    entry.decode_kind,
+                    crate::encoding::to_hex(&entry.raw_name)
+                );
+            });
+        }
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.jobs)
+        .build()
+        .map_err(|e| PackError::ThreadPool(e.to_string()))?;
+
+    let started = Instant::now();
+    let per_entry: Vec<_> = pool.install(|| {
+        matched
+            .par_iter()
+            .zip(targets.par_iter())
+            .map(|(entry, target)| -> Result<_> {
+                let relative = match target {
+                    TargetResolution::Write(relative) => relative,
+                    TargetResolution::Rejected(reason) => {
+                        let entry_name = entry.path.display().to_string();
+                        progress.suspend(|| {
+                            eprintln!("warning: refusing to extract {entry_name:?}: {reason}");
+                        });
+                        return Ok(EntryOutcome::Rejected);
+                    }
+                };
+                let dest = args.dest.join(relative);
+
+                if dest.exists() {
+                    match args.overwrite {
+                        OverwritePolicy::Overwrite => {}
+                        OverwritePolicy::SkipExisting => {
+                            progress.suspend(|| eprintln!("skipping (already exists): {}", dest.display()));
+                            progress.inc(u64::from(entry.size_on_disk));
+                            return Ok(EntryOutcome::Skipped);
+                        }
+                        OverwritePolicy::NoClobber => {
+                            return Err(PackError::ExtractTargetExists { dest });
+                        }
+                    }
+                }
+
+                let (bytes, read_time, decompress_time) = if args.use_mmap {
+                    crate::pack::read_entry_bytes_mmap(&args.pack, entry)?
+                } else {
+                    crate::pack::read_entry_bytes_from_path(&args.pack, entry)?
+                };
+
+                let write_start = Instant::now();
+                if let Some(parent) = dest.parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| io_err(parent, e))?;
+                }
+                std::fs::write(&dest, &bytes).map_err(|e| io_err(&dest, e))?;
+                let write_time = write_start.elapsed();
+
+                progress.set_message(entry.path.display().to_string());
+                progress.inc(bytes.len() as u64);
+
+                Ok(EntryOutcome::Written {
+                    size_in_pack: entry.size_in_pack,
+                    bytes_len: bytes.len() as u64,
+                    read_time,
+                    decompress_time,
+                    write_time,
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+    progress.finish_and_clear();
+
+    let mut stats = StatsCollector::default();
+    let mut skipped = 0usize;
+    let mut rejected = 0usize;
+    for outcome in per_entry {
+        match outcome {
+            EntryOutcome::Written { size_in_pack, bytes_len, read_time, decompress_time, write_time } => {
+                stats.record_entry(u64::from(size_in_pack), bytes_len, read_time, decompress_time, write_time);
+            }
+            EntryOutcome::Skipped => skipped += 1,
+            EntryOutcome::Rejected => rejected += 1,
+        }
+    }
+    let written = matched.len() - skipped - rejected;
+    let report = stats.finish(started.elapsed());
+
+    match (skipped, rejected) {
+        (0, 0) => println!("Extracted {written} entries to {}", args.dest.display()),
+        (skipped, 0) => println!("Extracted {written} entries to {} ({skipped} skipped)", args.dest.display()),
+        (0, rejected) => println!("Extracted {written} entries to {} ({rejected} rejected)", args.dest.display()),
+        (skipped, rejected) => println!(
+            "Extracted {written} entries to {} ({skipped} skipped, {rejected} rejected)",
+            args.dest.display()
+        ),
+    }
+    report.print_summary();
+    if let Some(stats_path) = &args.stats_json {
+        report.write_json(stats_path)?;
+    }
+    Ok(())
+}
+
+/// What happened to one matched entry, folded back into the run summary
+/// after the parallel extraction loop completes.
+enum EntryOutcome {
+    Written {
+        size_in_pack: u32,
+        bytes_len: u64,
+        read_time: std::time::Duration,
+        decompress_time: std::time::Duration,
+        write_time: std::time::Duration,
+    },
+    Skipped,
+    Rejected,
+}
+
+/// Where one matched entry should be written, decided up front (before the
+/// parallel extraction loop starts) so `--flatten` collisions can be
+/// resolved against the whole batch instead of racing per-worker.
+enum TargetResolution {
+    Write(PathBuf),
+    Rejected(String),
+}
+
+/// Resolves every matched entry's destination path relative to `dest`,
+/// sanitizing it first and then, if `args.flatten` is set, collapsing it
+/// down to just its file name. Returns before any file is touched, so
+/// `--strict-paths` and `--on-collision=fail` can abort the whole run
+/// instead of failing partway through.
+fn resolve_targets(matched: &[crate::pack::Entry], args: &ExtractArgs) -> Result<Vec<TargetResolution>> {
+    let mut sanitized = Vec::with_capacity(matched.len());
+    for entry in matched {
+        match sanitize_entry_path(&entry.path) {
+            Ok(relative) => {
+                let target = if args.flatten {
+                    relative.file_name().map(PathBuf::from).unwrap_or(relative)
+                } else {
+                    relative
+                };
+                sanitized.push(Ok((target, entry.path.display().to_string())));
+            }
+            Err(reason) => {
+                if args.strict_paths {
+                    return Err(PackError::UnsafeEntryPath { entry: entry.path.display().to_string(), reason });
+                }
+                sanitized.push(Err(reason));
+            }
+        }
+    }
+
+    if args.flatten && args.on_collision == OnCollision::Fail {
+        check_flatten_collisions(&sanitized)?;
+    }
+
+    let mut used = std::collections::HashSet::new();
+    Ok(sanitized
+        .into_iter()
+        .map(|entry| match entry {
+            Ok((target, _)) if args.flatten => TargetResolution::Write(dedupe_by_suffix(&target, &mut used)),
+            Ok((target, _)) => TargetResolution::Write(target),
+            Err(reason) => TargetResolution::Rejected(reason),
+        })
+        .collect())
+}
+
+/// Makes `name` unique against everything already in `used` by appending
+/// `_2`, `_3`, ... before the extension, and records whatever it returns.
+fn dedupe_by_suffix(name: &std::path::Path, used: &mut std::collections::HashSet<PathBuf>) -> PathBuf {
+    if used.insert(name.to_path_buf()) {
+        return name.to_path_buf();
+    }
+    let stem = name.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+    let ext = name.extension().map(|e| e.to_string_lossy().into_owned());
+    let mut n = 2;
+    loop {
+        let candidate = match &ext {
+            Some(ext) => PathBuf::from(format!("{stem}_{n}.{ext}")),
+            None => PathBuf::from(format!("{stem}_{n}")),
+        };
+        if used.insert(candidate.clone()) {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+/// Fails with a message listing every group of entries that `--flatten`
+/// would collapse onto the same file name, so `--on-collision=fail` reports
+/// the whole picture instead of just the first collision found.
+fn check_flatten_collisions(sanitized: &[std::result::Result<(PathBuf, String), String>]) -> Result<()> {
+    let mut groups: std::collections::HashMap<&PathBuf, Vec<&str>> = std::collections::HashMap::new();
+    for (target, original) in sanitized.iter().flatten() {
+        groups.entry(target).or_default().push(original);
+    }
+    let mut colliding: Vec<_> = groups.into_iter().filter(|(_, originals)| originals.len() > 1).collect();
+    if colliding.is_empty() {
+        return Ok(());
+    }
+    colliding.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut message = String::from("--flatten: these entries would collide on the same file name:\n");
+    for (target, originals) in colliding {
+        message.push_str(&format!("  {}: {}\n", target.display(), originals.join(", ")));
+    }
+    Err(PackError::InvalidExtract(message.trim_end().to_string()))
+}
+
+/// A byte-driven progress bar on stderr showing the current entry,
+/// throughput, and ETA, or a hidden no-op bar if progress reporting is
+/// disabled or stderr isn't a terminal to draw one on.
+fn new_progress_bar(total_bytes: u64, no_progress: bool) -> ProgressBar {
+    if no_progress || !std::io::stderr().is_terminal() {
+        return ProgressBar::hidden();
+    }
+    let bar = ProgressBar::new(total_bytes);
+    bar.set_style(
+        ProgressStyle::with_template(
+            "{elapsed_precise} [{bar:30.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec}, eta {eta}) {wide_msg}",
+        )
+        .expect("progress bar template is valid")
+        .progress_chars("=> "),
+    );
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::create::{self, CreateArgs};
+    use crate::writer::{self, prepare_entry};
+
+    fn build_test_pack(dir: &std::path::Path) -> PathBuf {
+        let source = dir.join("source");
+        std::fs::create_dir_all(source.join("data/text")).unwrap();
+        std::fs::create_dir_all(source.join("data/ui")).unwrap();
+        std::fs::write(source.join("data/text/menu.txt"), b"menu strings").unwrap();
+        std::fs::write(source.join("data/ui/button.txt"), b"button art").unwrap();
+        std::fs::write(source.join("data/sounds.txt"), b"sound bank").unwrap();
+
+        let pack_path = dir.join("test.pack");
+        create::run(&CreateArgs { source, output: pack_path.clone(), from_manifest: None }).unwrap();
+        pack_path
+    }
+
+    /// Two `--filter` patterns whose globs both match `data/text/menu.txt`
+    /// (one via `data/text/**`, the other naming the file directly) should
+    /// still only extract it once.
+    #[test]
+    fn overlapping_filters_do_not_extract_an_entry_twice() {
+        let dir = std::env::temp_dir().join("pack_extract_overlap_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: vec![
+                "data/text/**".to_string(),
+                "data/text/menu.txt".to_string(),
+                "data/ui/**".to_string(),
+            ],
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert_eq!(std::fs::read(dest.join("data/text/menu.txt")).unwrap(), b"menu strings");
+        assert_eq!(std::fs::read(dest.join("data/ui/button.txt")).unwrap(), b"button art");
+        assert!(!dest.join("data/sounds.txt").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// A single `--filter` value with a comma should behave the same as
+    /// passing it as two separate `--filter` flags.
+    #[test]
+    fn comma_separated_filter_behaves_like_two_repeated_flags() {
+        let dir = std::env::temp_dir().join("pack_extract_comma_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: vec!["data/text/**,data/ui/**".to_string()],
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert!(dest.join("data/text/menu.txt").exists());
+        assert!(dest.join("data/ui/button.txt").exists());
+        assert!(!dest.join("data/sounds.txt").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--exclude` is applied after `--filter`: a nested path under an
+    /// excluded subtree stays out even though it matches the broad include.
+    #[test]
+    fn exclude_wins_over_a_broader_include_on_nested_paths() {
+        let dir = std::env::temp_dir().join("pack_extract_exclude_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("source");
+        std::fs::create_dir_all(source.join("data/sounds/sfx")).unwrap();
+        std::fs::create_dir_all(source.join("data/ui")).unwrap();
+        std::fs::write(source.join("data/sounds/sfx/hit.wav"), b"hit").unwrap();
+        std::fs::write(source.join("data/ui/button.txt"), b"button art").unwrap();
+        let pack_path = dir.join("test.pack");
+        create::run(&CreateArgs { source, output: pack_path.clone(), from_manifest: None }).unwrap();
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: vec!["data/**".to_string()],
+            excludes: vec!["data/sounds/**".to_string()],
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert!(dest.join("data/ui/button.txt").exists());
+        assert!(!dest.join("data/sounds/sfx/hit.wav").exists());
+        assert!(!dest.join("data/sounds").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--ignore-case` matches a lowercase filter against mixed-case entries
+    /// on disk, the way packs mixing `Data/` and `data/` need.
+    #[test]
+    fn ignore_case_matches_mixed_case_entries() {
+        let dir = std::env::temp_dir().join("pack_extract_ignore_case_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("source");
+        std::fs::create_dir_all(source.join("Data/UI")).unwrap();
+        std::fs::write(source.join("Data/UI/Button.TGA"), b"button art").unwrap();
+        let pack_path = dir.join("test.pack");
+        create::run(&CreateArgs { source, output: pack_path.clone(), from_manifest: None }).unwrap();
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: vec!["data/ui/*.tga".to_string()],
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: true,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert!(dest.join("Data/UI/Button.TGA").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--regex` extracts entries matching the pattern against the entry
+    /// path, without needing `--filter` at all.
+    #[test]
+    fn regex_extracts_entries_matching_the_pattern() {
+        let dir = std::env::temp_dir().join("pack_extract_regex_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: Some(r"^data/(text|ui)/".to_string()),
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert!(dest.join("data/text/menu.txt").exists());
+        assert!(dest.join("data/ui/button.txt").exists());
+        assert!(!dest.join("data/sounds.txt").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Combining `--filter` and `--regex` is rejected before any pack is
+    /// opened -- the source pack in this test doesn't even exist.
+    #[test]
+    fn regex_combined_with_filter_is_rejected() {
+        let err = run(&ExtractArgs {
+            pack: PathBuf::from("/nonexistent/does-not-exist.pack"),
+            dest: PathBuf::from("/nonexistent/out"),
+            filters: vec!["data/**".to_string()],
+            excludes: Vec::new(),
+            regex: Some(r"\.txt$".to_string()),
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("--regex"));
+    }
+
+    /// Extracting with several worker threads must still reassemble every
+    /// entry byte-for-byte, including one that spans multiple chunks -- the
+    /// case most likely to break if per-entry state leaked between workers.
+    #[test]
+    fn parallel_extraction_matches_single_threaded_output() {
+        let dir = std::env::temp_dir().join("pack_extract_parallel_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("source");
+        std::fs::create_dir_all(source.join("data")).unwrap();
+        let big: Vec<u8> = (0..(crate::compress::CHUNK_SIZE * 3 + 50)).map(|i| (i % 251) as u8).collect();
+        for i in 0..8 {
+            std::fs::write(source.join(format!("data/file{i}.bin")), &big).unwrap();
+        }
+        let pack_path = dir.join("test.pack");
+        create::run(&CreateArgs { source, output: pack_path.clone(), from_manifest: None }).unwrap();
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 4,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        for i in 0..8 {
+            assert_eq!(std::fs::read(dest.join(format!("data/file{i}.bin"))).unwrap(), big);
+        }
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--mmap` must extract every entry byte-for-byte the same as the
+    /// buffered reader, including one that spans multiple chunks.
+    #[test]
+    fn mmap_extraction_matches_buffered_reader_output() {
+        let dir = std::env::temp_dir().join("pack_extract_mmap_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("source");
+        std::fs::create_dir_all(source.join("data")).unwrap();
+        let big: Vec<u8> = (0..(crate::compress::CHUNK_SIZE * 3 + 50)).map(|i| (i % 251) as u8).collect();
+        for i in 0..4 {
+            std::fs::write(source.join(format!("data/file{i}.bin")), &big).unwrap();
+        }
+        let pack_path = dir.join("test.pack");
+        create::run(&CreateArgs { source, output: pack_path.clone(), from_manifest: None }).unwrap();
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 4,
+            use_mmap: true,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        for i in 0..4 {
+            assert_eq!(std::fs::read(dest.join(format!("data/file{i}.bin"))).unwrap(), big);
+        }
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// The default policy overwrites a file already at the destination.
+    #[test]
+    fn overwrite_policy_default_replaces_existing_files() {
+        let dir = std::env::temp_dir().join("pack_extract_overwrite_default_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+        let dest = dir.join("out");
+        std::fs::create_dir_all(dest.join("data/text")).unwrap();
+        std::fs::write(dest.join("data/text/menu.txt"), b"stale").unwrap();
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert_eq!(std::fs::read(dest.join("data/text/menu.txt")).unwrap(), b"menu strings");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--skip-existing` leaves a file already at the destination untouched.
+    #[test]
+    fn overwrite_policy_skip_existing_leaves_existing_files_untouched() {
+        let dir = std::env::temp_dir().join("pack_extract_skip_existing_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+        let dest = dir.join("out");
+        std::fs::create_dir_all(dest.join("data/text")).unwrap();
+        std::fs::write(dest.join("data/text/menu.txt"), b"stale").unwrap();
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::SkipExisting,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert_eq!(std::fs::read(dest.join("data/text/menu.txt")).unwrap(), b"stale");
+        assert_eq!(std::fs::read(dest.join("data/ui/button.txt")).unwrap(), b"button art");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--no-clobber` aborts the run with an error naming the conflicting
+    /// path, instead of touching it.
+    #[test]
+    fn overwrite_policy_no_clobber_aborts_naming_the_conflict() {
+        let dir = std::env::temp_dir().join("pack_extract_no_clobber_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+        let dest = dir.join("out");
+        std::fs::create_dir_all(dest.join("data/text")).unwrap();
+        std::fs::write(dest.join("data/text/menu.txt"), b"stale").unwrap();
+
+        let err = run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::NoClobber,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("data/text/menu.txt"));
+        assert_eq!(std::fs::read(dest.join("data/text/menu.txt")).unwrap(), b"stale");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    fn build_malicious_pack(dir: &std::path::Path) -> PathBuf {
+        let prepared = vec![
+            prepare_entry("safe.txt".to_string(), b"fine"),
+            prepare_entry("../escaped_parent.txt".to_string(), b"parent traversal"),
+            prepare_entry("data/../../escaped_nested.txt".to_string(), b"nested traversal"),
+            prepare_entry("/absolute.txt".to_string(), b"leading separator"),
+            prepare_entry(r"..\..\windows\system32\evil.dll".to_string(), b"backslash traversal"),
+        ];
+        let pack_path = dir.join("malicious.pack");
+        writer::write_pack(&pack_path, &prepared).unwrap();
+        pack_path
+    }
+
+    /// By default, a malicious entry is skipped with a warning but doesn't
+    /// take down the rest of the extraction, and nothing escapes `dest`.
+    #[test]
+    fn malicious_entries_are_skipped_without_escaping_dest() {
+        let dir = std::env::temp_dir().join("pack_extract_traversal_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_malicious_pack(&dir);
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert_eq!(std::fs::read(dest.join("safe.txt")).unwrap(), b"fine");
+        assert!(!dir.join("escaped_parent.txt").exists());
+        assert!(!dir.join("escaped_nested.txt").exists());
+        assert!(!PathBuf::from("/absolute.txt").exists());
+        assert!(!dir.join("windows/system32/evil.dll").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--strict-paths` turns a malicious entry into a hard error for the
+    /// whole run instead of a per-entry warning.
+    #[test]
+    fn strict_paths_aborts_the_run_on_a_malicious_entry() {
+        let dir = std::env::temp_dir().join("pack_extract_traversal_strict_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_malicious_pack(&dir);
+        let dest = dir.join("out");
+
+        let err = run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: true,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("unsafe path"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Windows-authored entries use `\` as a separator; on Linux/macOS that's
+    /// just an ordinary filename character to the OS, so extraction has to
+    /// split on it itself. A `--filter` using `/` should still match, and the
+    /// result on disk should be a real directory tree, not a single file
+    /// literally named with backslashes in it.
+    #[test]
+    fn backslash_separated_entries_extract_into_a_directory_tree() {
+        let dir = std::env::temp_dir().join("pack_extract_backslash_separators_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let prepared = vec![
+            prepare_entry(r"data\ui\button.tga".to_string(), b"button pixels"),
+            prepare_entry(r"data\sounds\hit.wav".to_string(), b"thwack"),
+        ];
+        let pack_path = dir.join("windows_style.pack");
+        writer::write_pack(&pack_path, &prepared).unwrap();
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: vec!["data/ui/**".to_string()],
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: false,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert_eq!(std::fs::read(dest.join("data").join("ui").join("button.tga")).unwrap(), b"button pixels");
+        assert!(!dest.join(r"data\ui\button.tga").exists());
+        assert!(!dest.join("data").join("sounds").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--flatten` writes every matched entry straight into `dest` using
+    /// only its file name, dropping the directory tree entirely.
+    #[test]
+    fn flatten_writes_entries_by_file_name_only() {
+        let dir = std::env::temp_dir().join("pack_extract_flatten_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let prepared = vec![
+            prepare_entry("data/ui/button.tga".to_string(), b"button pixels"),
+            prepare_entry("data/icons/gear.tga".to_string(), b"gear pixels"),
+        ];
+        let pack_path = dir.join("test.pack");
+        writer::write_pack(&pack_path, &prepared).unwrap();
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: vec!["**/*.tga".to_string()],
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: true,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert_eq!(std::fs::read(dest.join("button.tga")).unwrap(), b"button pixels");
+        assert_eq!(std::fs::read(dest.join("gear.tga")).unwrap(), b"gear pixels");
+        assert!(!dest.join("data").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// The default `--on-collision=suffix` numbers colliding basenames
+    /// instead of one silently overwriting the other.
+    #[test]
+    fn flatten_suffix_numbers_colliding_basenames() {
+        let dir = std::env::temp_dir().join("pack_extract_flatten_suffix_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let prepared = vec![
+            prepare_entry("data/ui/button.tga".to_string(), b"first"),
+            prepare_entry("data/icons/button.tga".to_string(), b"second"),
+            prepare_entry("data/menu/button.tga".to_string(), b"third"),
+        ];
+        let pack_path = dir.join("test.pack");
+        writer::write_pack(&pack_path, &prepared).unwrap();
+        let dest = dir.join("out");
+
+        run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: true,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: true,
+            on_collision: OnCollision::Suffix,
+        })
+        .unwrap();
+
+        assert_eq!(std::fs::read(dest.join("button.tga")).unwrap(), b"first");
+        assert_eq!(std::fs::read(dest.join("button_2.tga")).unwrap(), b"second");
+        assert_eq!(std::fs::read(dest.join("button_3.tga")).unwrap(), b"third");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// `--on-collision=fail` aborts before writing anything and lists every
+    /// colliding group of entries.
+    #[test]
+    fn flatten_fail_aborts_and_lists_the_collision() {
+        let dir = std::env::temp_dir().join("pack_extract_flatten_fail_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let prepared = vec![
+            prepare_entry("data/ui/button.tga".to_string(), b"first"),
+            prepare_entry("data/icons/button.tga".to_string(), b"second"),
+        ];
+        let pack_path = dir.join("test.pack");
+        writer::write_pack(&pack_path, &prepared).unwrap();
+        let dest = dir.join("out");
+
+        let err = run(&ExtractArgs {
+            pack: pack_path,
+            dest: dest.clone(),
+            filters: Vec::new(),
+            excludes: Vec::new(),
+            regex: None,
+            ignore_case: false,
+            no_reorder: false,
+            jobs: 1,
+            use_mmap: false,
+            stats_json: None,
+            legacy_encoding: None,
+            strict_filters: false,
+            no_progress: true,
+            overwrite: OverwritePolicy::Overwrite,
+            strict_paths: false,
+            flatten: true,
+            on_collision: OnCollision::Fail,
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("button.tga"));
+        assert!(err.to_string().contains("data/ui/button.tga"));
+        assert!(err.to_string().contains("data/icons/button.tga"));
+        assert!(!dest.exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::error::Result;
+use crate::filter;
+use crate::loadorder;
+use std::fs::File;
+
+use crate::pack::{sanitize_entry_path, Entry, Pack};
+
+pub struct ExtractAllArgs {
+    pub game_dir: PathBuf,
+    pub dest: PathBuf,
+    pub filter: Option<String>,
+}
+
+/// Extract every `*.pack` under `game_dir`, applying override resolution so
+/// that each internal path is written exactly once, from the pack that
+/// wins according to the engine's load order.
+pub fn run(args: &ExtractAllArgs) -> Result<()> {
+    let mut names: Vec<String> = std::fs::read_dir(&args.game_dir)
+        .map_err(|e| crate::error::io_err(&args.game_dir, e))?
+        .filter_map(std::result::Result::ok)
+        .map(|dirent| dirent.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "pack"))
+        .filter_map(|path| path.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .collect();
+    loadorder::sort_by_load_order(&mut names);
+
+    let mut packs: Vec<Pack<File>> = Vec::new();
+    let mut failed: Vec<String> = Vec::new();
+    for name in &names {
+        match Pack::open(&args.game_dir.join(name)) {
+            Ok(pack) => packs.push(pack),
+            Err(e) => failed.push(format!("{name}: {e}")),
+        }
+    }
+
+    let patterns: Vec<String> = args.filter.clone().into_iter().collect();
+
+    // Later packs in load order override earlier ones for the same path.
+    let mut winners: HashMap<String, (usize, Entry)> = HashMap::new();
+    for (pack_index, pack) in packs.iter().enumerate() {
+        for entry in &pack.entries {
+            if !filter::matches(&patterns, &entry.path, false) {
+                continue;
+            }
+            let key = entry.path.to_string_lossy().into_owned();
+            winners.insert(key, (pack_index, entry.clone()));
+        }
+    }
+
+    let mut extracted = 0usize;
+    let mut rejected = 0usize;
+    for (pack_index, entry) in winners.into_values() {
+        let relative = match sanitize_entry_path(&entry.path) {
+            Ok(relative) => relative,
+            Err(reason) => {
+                let entry_name = entry.path.display().to_string();
+                eprintln!("warning: refusing to extract {entry_name:?}: {reason}");
+                rejected += 1;
+                continue;
+            }
+        };
+        let dest = args.dest.join(relative);
+        packs[pack_index].extract_entry(&entry, &dest)?;
+        extracted += 1;
+    }
+
+    match rejected {
+        0 => println!("Extracted {extracted} entries from {} packs to {}", packs.len(), args.dest.display()),
+        rejected => println!(
+            "Extracted {extracted} entries from {} packs to {} ({rejected} rejected)",
+            packs.len(),
+            args.dest.display()
+        ),
+    }
+    if !failed.is_empty() {
+        println!("Failed to parse {} pack(s):", failed.len());
+        for reason in &failed {
+            println!("  {reason}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{self, prepare_entry};
+
+    #[test]
+    fn extracts_every_pack_under_game_dir() {
+        let dir = std::env::temp_dir().join("pack_extract_all_basic_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+
+        writer::write_pack(&dir.join("data.pack"), &[prepare_entry("data/text/menu.txt".to_string(), b"menu strings")]).unwrap();
+
+        let dest = dir.join("out");
+        run(&ExtractAllArgs { game_dir: dir.clone(), dest: dest.clone(), filter: None }).unwrap();
+
+        assert_eq!(std::fs::read(dest.join("data/text/menu.txt")).unwrap(), b"menu strings");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// This is `extract-all`'s whole purpose: unattended extraction of
+    /// every pack under a game directory, with no human reviewing each
+    /// entry's path first -- exactly the case a hostile entry from a
+    /// downloaded mod pack needs to be rejected in, the same as `extract`.
+    #[test]
+    fn malicious_entries_are_skipped_without_escaping_dest() {
+        let dir = std::env::temp_dir().join("pack_extract_all_traversal_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+
+        writer::write_pack(
+            &dir.join("data.pack"),
+            &[
+                prepare_entry("safe.txt".to_string(), b"fine"),
+                prepare_entry("../escaped_parent.txt".to_string(), b"parent traversal"),
+                prepare_entry(r"..\..\windows\system32\evil.dll".to_string(), b"backslash traversal"),
+            ],
+        )
+        .unwrap();
+
+        let dest = dir.join("out");
+        run(&ExtractAllArgs { game_dir: dir.clone(), dest: dest.clone(), filter: None }).unwrap();
+
+        assert_eq!(std::fs::read(dest.join("safe.txt")).unwrap(), b"fine");
+        assert!(!dir.join("escaped_parent.txt").exists());
+        assert!(!dir.parent().unwrap().join("escaped_parent.txt").exists());
+        assert!(!dest.join("../escaped_parent.txt").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
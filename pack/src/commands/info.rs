@@ -0,0 +1,296 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::error::{io_err, Result};
+use crate::pack::{Header, Pack, MAGIC};
+
+pub struct InfoArgs {
+    pub packs: Vec<PathBuf>,
+    pub debug_structure: bool,
+    pub format: InfoFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InfoFormat {
+    Text,
+    Json,
+}
+
+/// Pack-level statistics, as opposed to `list`'s per-entry view: useful for
+/// spotting where a mod repack's bloat comes from at a glance, without
+/// diffing entry-by-entry against a vanilla pack.
+#[derive(Serialize)]
+struct PackStats {
+    pack: String,
+    version: u32,
+    entries: usize,
+    chunks: usize,
+    header_size: usize,
+    file_table_size: u32,
+    total_size_on_disk: u64,
+    total_size_in_pack: u64,
+    /// `total_size_in_pack / total_size_on_disk`, i.e. how much of the
+    /// original size the packed data takes up. `None` when the pack has no
+    /// entries, since there's nothing to divide by.
+    compression_ratio: Option<f64>,
+}
+
+fn scan_pack(path: &Path) -> Result<PackStats> {
+    let pack = Pack::open(path)?;
+    let chunks: usize = pack.entries.iter().map(|e| e.chunk_sizes.len()).sum();
+    let total_size_on_disk: u64 = pack.entries.iter().map(|e| u64::from(e.size_on_disk)).sum();
+    let total_size_in_pack: u64 = pack.entries.iter().map(|e| u64::from(e.size_in_pack)).sum();
+    Ok(PackStats {
+        pack: pack.name.clone(),
+        version: pack.header.version,
+        entries: pack.entries.len(),
+        chunks,
+        header_size: Header::SIZE,
+        file_table_size: pack.header.file_section_size,
+        total_size_on_disk,
+        total_size_in_pack,
+        compression_ratio: (total_size_on_disk > 0).then(|| total_size_in_pack as f64 / total_size_on_disk as f64),
+    })
+}
+
+pub fn run(args: &InfoArgs) -> Result<()> {
+    if args.debug_structure {
+        for path in &args.packs {
+            debug_structure(path)?;
+        }
+        return Ok(());
+    }
+
+    let stats: Vec<PackStats> = args.packs.iter().map(|path| scan_pack(path)).collect::<Result<_>>()?;
+    match args.format {
+        InfoFormat::Text => {
+            for stat in &stats {
+                println!("{} ({} entries)", stat.pack, stat.entries);
+                println!("  version:            {}", stat.version);
+                println!("  chunks:             {}", stat.chunks);
+                println!("  header size:        {} bytes", stat.header_size);
+                println!("  file table size:    {} bytes", stat.file_table_size);
+                println!("  total size on disk: {} bytes", stat.total_size_on_disk);
+                println!("  total size in pack: {} bytes", stat.total_size_in_pack);
+                match stat.compression_ratio {
+                    Some(ratio) => println!("  compression ratio:  {:.1}%", ratio * 100.0),
+                    None => println!("  compression ratio:  n/a (no entries)"),
+                }
+            }
+        }
+        InfoFormat::Json => {
+            let json = serde_json::to_string_pretty(&stats).expect("pack stats are always serializable");
+            println!("{json}");
+        }
+    }
+    Ok(())
+}
+
+/// Annotated, fault-tolerant walk of a pack's header and file record
+/// section, for reporting corrupt packs upstream without needing a
+/// hex editor. Every read here is bounds-checked; nothing panics.
+fn debug_structure(path: &Path) -> Result<()> {
+    let data = std::fs::read(path).map_err(|e| io_err(path, e))?;
+    println!("file: {} ({} bytes)", path.display(), data.len());
+    println!();
+
+    let mut cursor = ByteCursor::new(&data);
+
+    println!("header:");
+    let magic = match cursor.read_bytes(4) {
+        Some(bytes) => bytes,
+        None => {
+            report_stop(&data, cursor.pos, "unexpected EOF reading magic");
+            return Ok(());
+        }
+    };
+    println!(
+        "  magic              @{:#06x}: {}  {:?}  (expected {:?})",
+        0,
+        hex(magic),
+        String::from_utf8_lossy(magic),
+        String::from_utf8_lossy(&MAGIC),
+    );
+
+    let version = match cursor.read_u32() {
+        Some(v) => v,
+        None => {
+            report_stop(&data, cursor.pos, "unexpected EOF reading version");
+            return Ok(());
+        }
+    };
+    println!("  version             @{:#06x}: = {version}", cursor.pos - 4);
+
+    let entry_count = match cursor.read_u32() {
+        Some(v) => v,
+        None => {
+            report_stop(&data, cursor.pos, "unexpected EOF reading entry_count");
+            return Ok(());
+        }
+    };
+    println!("  entry_count         @{:#06x}: = {entry_count}", cursor.pos - 4);
+
+    let file_section_size = match cursor.read_u32() {
+        Some(v) => v,
+        None => {
+            report_stop(&data, cursor.pos, "unexpected EOF reading file_section_size");
+            return Ok(());
+        }
+    };
+    println!(
+        "  file_section_size   @{:#06x}: = {file_section_size}",
+        cursor.pos - 4
+    );
+
+    let data_section_offset = match cursor.read_u32() {
+        Some(v) => v,
+        None => {
+            report_stop(&data, cursor.pos, "unexpected EOF reading data_section_offset");
+            return Ok(());
+        }
+    };
+    println!(
+        "  data_section_offset @{:#06x}: = {data_section_offset}",
+        cursor.pos - 4
+    );
+
+    let record_section_start = cursor.pos;
+    let record_section_end = record_section_start + file_section_size as usize;
+    println!();
+    println!(
+        "file record section: declared [{:#06x}, {:#06x}) ({file_section_size} bytes), file is {} bytes",
+        record_section_start,
+        record_section_end,
+        data.len()
+    );
+    if record_section_end > data.len() {
+        println!(
+            "  note: declared end is {} bytes past the end of the file",
+            record_section_end - data.len()
+        );
+    }
+
+    let mut entries: Vec<(usize, String)> = Vec::new();
+    for index in 0..entry_count as usize {
+        match read_entry_summary(&mut cursor) {
+            Some(entry) => entries.push(entry),
+            None => {
+                report_stop(
+                    &data,
+                    cursor.pos,
+                    &format!("unexpected EOF reading entry #{index}"),
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    println!();
+    println!("entries ({} parsed):", entries.len());
+    let show_head_tail = 3;
+    for (index, (offset, summary)) in entries.iter().enumerate() {
+        let is_head = index < show_head_tail;
+        let is_tail = index >= entries.len().saturating_sub(show_head_tail);
+        if is_head || is_tail {
+            println!("  #{index} @{offset:#06x}: {summary}");
+        } else if index == show_head_tail {
+            println!("  ... {} entries omitted ...", entries.len() - 2 * show_head_tail);
+        }
+    }
+
+    println!();
+    println!("parsing stopped at {:#06x}: reached declared entry_count cleanly", cursor.pos);
+    if cursor.pos != record_section_end {
+        println!(
+            "  note: parser ended at {:#06x} but file_section_size implies {:#06x} ({} byte discrepancy)",
+            cursor.pos,
+            record_section_end,
+            cursor.pos as i64 - record_section_end as i64
+        );
+    }
+    Ok(())
+}
+
+/// Read one entry, returning `(start_offset, human summary)` or `None` on
+/// truncation partway through.
+fn read_entry_summary(cursor: &mut ByteCursor) -> Option<(usize, String)> {
+    let start = cursor.pos;
+    let (name_bytes, _) = cursor.read_null_string()?;
+    let compressed = cursor.read_bytes(1)?[0] != 0;
+    let size_on_disk = cursor.read_u32()?;
+    let size_in_pack = cursor.read_u32()?;
+    let data_offset = cursor.read_u64()?;
+    let chunk_count = cursor.read_u32()?;
+    let mut chunk_sizes = Vec::with_capacity(chunk_count.min(4096) as usize);
+    for _ in 0..chunk_count {
+        chunk_sizes.push(cursor.read_u32()?);
+    }
+    let path = String::from_utf8_lossy(&name_bytes).into_owned();
+    let chunk_preview: Vec<String> = chunk_sizes.iter().take(3).map(|c| c.to_string()).collect();
+    Some((
+        start,
+        format!(
+            "path={path:?} compressed={compressed} size_on_disk={size_on_disk} size_in_pack={size_in_pack} \
+             data_offset={data_offset:#x} chunks={chunk_count} [{}{}]",
+            chunk_preview.join(","),
+            if chunk_sizes.len() > 3 { ",..." } else { "" }
+        ),
+    ))
+}
+
+fn report_stop(data: &[u8], offset: usize, reason: &str) {
+    println!();
+    println!("parsing stopped at {offset:#06x}: {reason}");
+    println!("hex window around {offset:#06x}:");
+    let window_start = offset.saturating_sub(16);
+    let window_end = (offset + 16).min(data.len());
+    let window = &data[window_start..window_end];
+    println!("  {:#06x}: {}", window_start, hex(window));
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect::<Vec<_>>().join(" ")
+}
+
+/// Bounds-checked byte reader used only by the debug-structure dump: every
+/// method returns `None` on truncation instead of panicking, and leaves
+/// `pos` unchanged on failure so callers can report exactly where reading
+/// stopped.
+struct ByteCursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn read_bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(n)?;
+        let slice = self.data.get(self.pos..end)?;
+        self.pos = end;
+        Some(slice)
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        let bytes = self.read_bytes(4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    fn read_u64(&mut self) -> Option<u64> {
+        let bytes = self.read_bytes(8)?;
+        Some(u64::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    /// Reads bytes up to and including a null terminator, returning the
+    /// bytes before it. `None` if no terminator is found before EOF.
+    fn read_null_string(&mut self) -> Option<(Vec<u8>, usize)> {
+        let start = self.pos;
+        let nul_offset = self.data[self.pos..].iter().position(|&b| b == 0)?;
+        let bytes = self.data[self.pos..self.pos + nul_offset].to_vec();
+        self.pos += nul_offset + 1;
+        Some((bytes, start))
+    }
+}
@@ -0,0 +1,320 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::encoding::LegacyEncoding;
+use crate::error::Result;
+use crate::filter;
+use crate::pack::{Entry, Pack};
+
+pub struct ListArgs {
+    pub packs: Vec<PathBuf>,
+    /// Glob patterns, at least one of which an entry must match to be
+    /// listed. Repeatable and/or comma-separated, same as `extract`; empty
+    /// matches everything.
+    pub filters: Vec<String>,
+    /// Glob patterns excluded after `filters` is applied: an entry matching
+    /// one of these is left out even if it also matches a `--filter`.
+    /// Repeatable and/or comma-separated.
+    pub excludes: Vec<String>,
+    /// Regex matched against the forward-slash-normalized entry path,
+    /// instead of `--filter`. Rejected if `--filter` is also given.
+    pub regex: Option<String>,
+    /// Fold ASCII case in both patterns and entry paths before matching, so
+    /// `--filter data/ui/**` also matches `Data/UI/button.tga`.
+    pub ignore_case: bool,
+    /// Fail with a non-zero exit instead of just warning when `filter`
+    /// matched no entries.
+    pub strict_filters: bool,
+    pub format: ListFormat,
+    /// Legacy codepage to try for entry names that aren't valid UTF-8, or
+    /// `None` to go straight to lossy replacement. Defaults to cp1252.
+    pub legacy_encoding: Option<LegacyEncoding>,
+    /// Print packed/unpacked size, compression ratio, and chunk count per
+    /// entry instead of just the path. Only affects `--format text`.
+    pub long: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Serialize)]
+struct EntryRecord {
+    pack: String,
+    /// Position in the pack's file table, independent of `--filter` --
+    /// stable so a JSON consumer can refer back to "entry #N" the same way
+    /// `pack info --debug-structure` does.
+    index: usize,
+    path: String,
+    size_on_disk: u32,
+    size_in_pack: u32,
+    data_offset: u64,
+    chunk_count: usize,
+    compressed: bool,
+    /// How the path was decoded from its raw bytes: "utf-8", a legacy
+    /// codepage name, or "lossy replacement". Present so tooling consuming
+    /// `--format json` can flag entries whose name might not be exact.
+    decode: String,
+    /// The entry name's raw bytes as hex, present only when `decode` isn't
+    /// "utf-8" so an affected entry's original bytes aren't lost to decoding.
+    raw_name_hex: Option<String>,
+}
+
+pub fn run(args: &ListArgs) -> Result<()> {
+    let matcher =
+        filter::EntryMatcher::new(&args.filters, &args.excludes, args.regex.as_deref(), args.ignore_case)?;
+    let packs: Vec<Pack<std::fs::File>> =
+        args.packs.iter().map(|path| Pack::open_with_encoding(path, args.legacy_encoding)).collect::<Result<_>>()?;
+
+    // Filter matches are judged across every pack in the batch, not
+    // pack-by-pack: `pack list --filter x patch.pack localized.pack` should
+    // only warn/fail if `x` is nowhere in the whole set.
+    let total_matched: usize =
+        packs.iter().map(|pack| pack.entries.iter().filter(|entry| matcher.matches(&entry.path)).count()).sum();
+    let all_paths = packs.iter().flat_map(|pack| pack.entries.iter().map(|e| e.path.as_path()));
+    matcher.check_matched(total_matched, all_paths, args.strict_filters)?;
+
+    let mut records = Vec::new();
+    for pack in &packs {
+        let matched: Vec<(usize, _)> =
+            pack.entries.iter().enumerate().filter(|(_, entry)| matcher.matches(&entry.path)).collect();
+
+        for (_, entry) in &matched {
+            if entry.decode_kind.is_affected() {
+                eprintln!(
+                    "warning: {} decoded via {} from raw bytes {}",
+                    entry.path.display(),
+                    entry.decode_kind,
+                    crate::encoding::to_hex(&entry.raw_name)
+                );
+            }
+        }
+
+        if args.format == ListFormat::Text {
+            if args.long {
+                print_long(&pack.name, pack.header.entry_count, &matched);
+            } else {
+                println!("{} ({} entries)", pack.name, pack.header.entry_count);
+                for (_, entry) in &matched {
+                    println!("{}", entry.path.display());
+                }
+            }
+        } else {
+            records.extend(to_records(&pack.name, &matched));
+        }
+    }
+
+    match args.format {
+        ListFormat::Text => {}
+        ListFormat::Json => {
+            let json = serde_json::to_string_pretty(&records).expect("entry records are always serializable");
+            println!("{json}");
+        }
+        ListFormat::Csv => print!("{}", to_csv(&records)),
+    }
+    Ok(())
+}
+
+/// `-l/--long` output: one row per entry with packed size, unpacked size,
+/// compression ratio, chunk count, and whether it's stored compressed at
+/// all, columns aligned to the widest value in this pack. Ends with a
+/// totals row, so a bloated pack's biggest offenders (and how much of it is
+/// even worth compressing) are visible at a glance.
+fn print_long(pack_name: &str, entry_count: u32, matched: &[(usize, &Entry)]) {
+    println!("{pack_name} ({entry_count} entries)");
+    if matched.is_empty() {
+        return;
+    }
+
+    let ratio_of = |packed: u64, unpacked: u64| -> String {
+        if unpacked > 0 {
+            format!("{:.1}%", packed as f64 / unpacked as f64 * 100.0)
+        } else {
+            "n/a".to_string()
+        }
+    };
+
+    struct Row {
+        packed: String,
+        unpacked: String,
+        ratio: String,
+        chunks: String,
+        compressed: &'static str,
+        path: String,
+    }
+    let rows: Vec<Row> = matched
+        .iter()
+        .map(|(_, entry)| Row {
+            packed: entry.size_in_pack.to_string(),
+            unpacked: entry.size_on_disk.to_string(),
+            ratio: ratio_of(u64::from(entry.size_in_pack), u64::from(entry.size_on_disk)),
+            chunks: entry.chunk_sizes.len().to_string(),
+            compressed: if entry.compressed { "yes" } else { "no" },
+            path: entry.path.display().to_string(),
+        })
+        .collect();
+
+    let packed_w = rows.iter().map(|r| r.packed.len()).max().unwrap_or(0).max("packed".len());
+    let unpacked_w = rows.iter().map(|r| r.unpacked.len()).max().unwrap_or(0).max("unpacked".len());
+    let ratio_w = rows.iter().map(|r| r.ratio.len()).max().unwrap_or(0).max("ratio".len());
+    let chunks_w = rows.iter().map(|r| r.chunks.len()).max().unwrap_or(0).max("chunks".len());
+    let compressed_w = "compressed".len();
+
+    println!(
+        "  {:>packed_w$}  {:>unpacked_w$}  {:>ratio_w$}  {:>chunks_w$}  {:<compressed_w$}  path",
+        "packed", "unpacked", "ratio", "chunks", "compressed"
+    );
+    for row in &rows {
+        println!(
+            "  {:>packed_w$}  {:>unpacked_w$}  {:>ratio_w$}  {:>chunks_w$}  {:<compressed_w$}  {}",
+            row.packed, row.unpacked, row.ratio, row.chunks, row.compressed, row.path
+        );
+    }
+
+    let total_packed: u64 = matched.iter().map(|(_, e)| u64::from(e.size_in_pack)).sum();
+    let total_unpacked: u64 = matched.iter().map(|(_, e)| u64::from(e.size_on_disk)).sum();
+    let total_chunks: usize = matched.iter().map(|(_, e)| e.chunk_sizes.len()).sum();
+    println!(
+        "  {:>packed_w$}  {:>unpacked_w$}  {:>ratio_w$}  {:>chunks_w$}  {:<compressed_w$}  {} entries total",
+        total_packed,
+        total_unpacked,
+        ratio_of(total_packed, total_unpacked),
+        total_chunks,
+        "",
+        rows.len()
+    );
+}
+
+fn to_records(pack_name: &str, matched: &[(usize, &Entry)]) -> Vec<EntryRecord> {
+    matched
+        .iter()
+        .map(|(index, entry)| EntryRecord {
+            pack: pack_name.to_string(),
+            index: *index,
+            path: entry.path.display().to_string(),
+            size_on_disk: entry.size_on_disk,
+            size_in_pack: entry.size_in_pack,
+            data_offset: entry.data_offset,
+            chunk_count: entry.chunk_sizes.len(),
+            compressed: entry.compressed,
+            decode: entry.decode_kind.to_string(),
+            raw_name_hex: entry.decode_kind.is_affected().then(|| crate::encoding::to_hex(&entry.raw_name)),
+        })
+        .collect()
+}
+
+/// Renders records as CSV: a header row, then one row per entry with the
+/// columns an Excel-side audit actually needs (pack, index, path, packed
+/// size, unpacked size, offset) -- the rest of [`EntryRecord`]'s fields are
+/// left to `--format json` for anything scripted. Fields are quoted per
+/// RFC 4180 whenever they contain a comma, quote, or newline, since mod
+/// paths routinely contain both spaces and (less often) commas.
+fn to_csv(records: &[EntryRecord]) -> String {
+    let mut out = String::new();
+    out.push_str("pack,index,path,size_in_pack,size_on_disk,data_offset\n");
+    for record in records {
+        out.push_str(&csv_field(&record.pack));
+        out.push(',');
+        out.push_str(&record.index.to_string());
+        out.push(',');
+        out.push_str(&csv_field(&record.path));
+        out.push(',');
+        out.push_str(&record.size_in_pack.to_string());
+        out.push(',');
+        out.push_str(&record.size_on_disk.to_string());
+        out.push(',');
+        out.push_str(&record.data_offset.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+/// Quotes a single CSV field if it contains a comma, double quote, or
+/// newline, doubling any embedded double quotes.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{self, prepare_entry};
+
+    /// Builds a two-entry pack (one path with a space, one with non-ASCII
+    /// bytes) and checks the JSON records round-trip through serde_json
+    /// with the right values, including for paths that would need escaping.
+    #[test]
+    fn json_records_round_trip_through_serde_json() {
+        let entries = [
+            prepare_entry("data/text with space.txt".to_string(), b"hello"),
+            prepare_entry("data/\u{00e9}\u{00e8}.txt".to_string(), b"unicode path"),
+        ];
+        let path = std::env::temp_dir().join("pack_list_json_test.pack");
+        writer::write_pack(&path, &entries).unwrap();
+        let pack = Pack::open(&path).unwrap();
+
+        let matched: Vec<(usize, &Entry)> = pack.entries.iter().enumerate().collect();
+        let records = to_records(&pack.name, &matched);
+        let json = serde_json::to_string_pretty(&records).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let array = parsed.as_array().unwrap();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array[0]["path"], "data/text with space.txt");
+        assert_eq!(array[0]["index"], 0);
+        assert_eq!(array[0]["chunk_count"], 1);
+        assert_eq!(array[0]["size_on_disk"], 5);
+        assert_eq!(array[1]["path"], "data/\u{00e9}\u{00e8}.txt");
+        assert_eq!(array[1]["data_offset"], pack.entries[1].data_offset);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    /// A path with a comma and one with an embedded quote must come out
+    /// quoted, with the embedded quote doubled, so the row still parses as
+    /// a single field when opened in a spreadsheet.
+    #[test]
+    fn csv_quotes_fields_with_commas_and_quotes() {
+        let records = vec![
+            EntryRecord {
+                pack: "mod, v2.pack".to_string(),
+                index: 0,
+                path: "data/units, extra.txt".to_string(),
+                size_on_disk: 10,
+                size_in_pack: 8,
+                data_offset: 20,
+                chunk_count: 1,
+                compressed: true,
+                decode: "utf-8".to_string(),
+                raw_name_hex: None,
+            },
+            EntryRecord {
+                pack: "base.pack".to_string(),
+                index: 1,
+                path: "data/say \"hi\".txt".to_string(),
+                size_on_disk: 5,
+                size_in_pack: 5,
+                data_offset: 30,
+                chunk_count: 1,
+                compressed: false,
+                decode: "utf-8".to_string(),
+                raw_name_hex: None,
+            },
+        ];
+
+        let csv = to_csv(&records);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), "pack,index,path,size_in_pack,size_on_disk,data_offset");
+        assert_eq!(lines.next().unwrap(), "\"mod, v2.pack\",0,\"data/units, extra.txt\",8,10,20");
+        assert_eq!(lines.next().unwrap(), "base.pack,1,\"data/say \"\"hi\"\".txt\",5,5,30");
+        assert!(lines.next().is_none());
+    }
+}
@@ -0,0 +1,11 @@
+pub mod add;
+pub mod apply_delta;
+pub mod create;
+pub mod delta;
+pub mod extract;
+pub mod extract_all;
+pub mod info;
+pub mod list;
+pub mod remove;
+pub mod rename;
+pub mod verify;
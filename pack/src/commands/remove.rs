@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+use crate::error::Result;
+use crate::filter;
+use crate::pack::Pack;
+use crate::writer::{self, PreparedEntry};
+
+pub struct RemoveArgs {
+    pub pack: PathBuf,
+    pub filter: String,
+    pub dry_run: bool,
+}
+
+/// Strip every entry matching `--filter` out of a pack. Chunks aren't
+/// shared between entries in this format, so "removing chunks" is just
+/// dropping the removed entries' own chunks and rewriting the file record
+/// section so every later entry's `data_offset` shifts down to close the
+/// gap they leave. Reuses the same raw-chunk-copy + full atomic rewrite
+/// [`crate::commands::add`] uses, so the surviving entries stay
+/// contiguous for `verify --deep`. `--dry-run` reports what would be
+/// removed without touching the file.
+pub fn run(args: &RemoveArgs) -> Result<()> {
+    let patterns = vec![args.filter.clone()];
+    let mut pack = Pack::open(&args.pack)?;
+
+    let all_paths: Vec<PathBuf> = pack.entries.iter().map(|e| e.path.clone()).collect();
+    let matched_count = pack.entries.iter().filter(|e| filter::matches(&patterns, &e.path, false)).count();
+    filter::check_matched(&patterns, matched_count, all_paths.iter().map(PathBuf::as_path), false)?;
+
+    let mut removed_entries = 0usize;
+    let mut removed_bytes = 0u64;
+    let mut kept = Vec::with_capacity(pack.entries.len() - matched_count);
+    for entry in pack.entries.clone() {
+        if filter::matches(&patterns, &entry.path, false) {
+            removed_entries += 1;
+            removed_bytes += u64::from(entry.size_in_pack);
+            continue;
+        }
+        let chunks = pack.read_entry_raw_chunks(&entry)?;
+        kept.push(PreparedEntry {
+            internal_path: entry.path.to_string_lossy().into_owned(),
+            chunks,
+            compressed: entry.compressed,
+            size_on_disk: entry.size_on_disk,
+        });
+    }
+
+    if args.dry_run {
+        println!(
+            "Would remove {removed_entries} entr{} ({removed_bytes} bytes) from {} (dry run: nothing written)",
+            if removed_entries == 1 { "y" } else { "ies" },
+            args.pack.display()
+        );
+        return Ok(());
+    }
+
+    writer::write_pack_atomic(&args.pack, &kept)?;
+    println!(
+        "Removed {removed_entries} entr{} ({removed_bytes} bytes) from {}",
+        if removed_entries == 1 { "y" } else { "ies" },
+        args.pack.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::create::{self, CreateArgs};
+
+    fn build_test_pack(dir: &std::path::Path) -> PathBuf {
+        let source = dir.join("source");
+        std::fs::create_dir_all(source.join("data/ui/debug")).unwrap();
+        std::fs::write(source.join("data/ui/debug/overlay.txt"), b"debug overlay").unwrap();
+        std::fs::write(source.join("data/ui/debug/grid.txt"), b"debug grid").unwrap();
+        std::fs::write(source.join("data/units.txt"), b"unit1").unwrap();
+
+        let pack_path = dir.join("test.pack");
+        create::run(&CreateArgs { source, output: pack_path.clone(), from_manifest: None }).unwrap();
+        pack_path
+    }
+
+    #[test]
+    fn remove_drops_matching_entries_and_shifts_remaining_offsets() {
+        let dir = std::env::temp_dir().join("pack_remove_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+
+        run(&RemoveArgs { pack: pack_path.clone(), filter: "data/ui/debug/**".to_string(), dry_run: false }).unwrap();
+
+        let mut pack = Pack::open(&pack_path).unwrap();
+        assert_eq!(pack.entries.len(), 1);
+        assert_eq!(pack.entries[0].path.to_string_lossy(), "data/units.txt");
+        assert_eq!(pack.entries[0].data_offset, u64::from(pack.header.data_section_offset));
+        let entry = pack.entries[0].clone();
+        assert_eq!(pack.read_entry_bytes(&entry).unwrap(), b"unit1");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn remove_dry_run_leaves_the_pack_untouched() {
+        let dir = std::env::temp_dir().join("pack_remove_dry_run_test");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack_path = build_test_pack(&dir);
+        let before = std::fs::read(&pack_path).unwrap();
+
+        run(&RemoveArgs { pack: pack_path.clone(), filter: "data/ui/debug/**".to_string(), dry_run: true }).unwrap();
+
+        assert_eq!(std::fs::read(&pack_path).unwrap(), before);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::error::{io_err, PackError, Result};
+use crate::pack::{Entry, Pack};
+use crate::writer;
+
+pub struct RenameArgs {
+    pub pack: PathBuf,
+    pub old_path: Option<String>,
+    pub new_path: Option<String>,
+    pub from_csv: Option<PathBuf>,
+}
+
+/// Change one or more entries' internal paths without touching their data:
+/// rebuilds the file record section (shifting every `data_offset` if the
+/// section's size changes) but copies the data section verbatim. A single
+/// rename is `pack rename <PACK> <OLD> <NEW>`; many at once is
+/// `pack rename <PACK> --from-csv renames.csv`, one `old,new` pair per line.
+pub fn run(args: &RenameArgs) -> Result<()> {
+    let renames = match &args.from_csv {
+        Some(csv_path) => read_csv(csv_path)?,
+        None => {
+            let old = args.old_path.clone().ok_or_else(|| {
+                PackError::InvalidRename("OLD_PATH is required unless --from-csv is given".to_string())
+            })?;
+            let new = args.new_path.clone().ok_or_else(|| {
+                PackError::InvalidRename("NEW_PATH is required unless --from-csv is given".to_string())
+            })?;
+            vec![(old, new)]
+        }
+    };
+    if renames.is_empty() {
+        return Err(PackError::InvalidRename("no renames given".to_string()));
+    }
+
+    let pack = Pack::open(&args.pack)?;
+
+    let mut paths: HashSet<String> = pack.entries.iter().map(entry_key).collect();
+    for (old, new) in &renames {
+        if !paths.remove(old.as_str()) {
+            return Err(PackError::RenameSourceNotFound {
+                path: args.pack.clone(),
+                old: old.clone(),
+            });
+        }
+        if !paths.insert(new.clone()) {
+            return Err(PackError::RenameTargetExists {
+                path: args.pack.clone(),
+                new: new.clone(),
+            });
+        }
+    }
+
+    let rename_map: HashMap<&str, &str> = renames.iter().map(|(o, n)| (o.as_str(), n.as_str())).collect();
+    let mut entries: Vec<Entry> = pack.entries.clone();
+    for entry in &mut entries {
+        if let Some(new) = rename_map.get(entry_key(entry).as_str()) {
+            entry.path = PathBuf::from(*new);
+        }
+    }
+
+    writer::rewrite_metadata(&args.pack, pack.header.data_section_offset, &entries)?;
+    println!(
+        "Renamed {} entr{} in {}",
+        renames.len(),
+        if renames.len() == 1 { "y" } else { "ies" },
+        args.pack.display()
+    );
+    Ok(())
+}
+
+fn entry_key(entry: &Entry) -> String {
+    entry.path.to_string_lossy().into_owned()
+}
+
+fn read_csv(path: &Path) -> Result<Vec<(String, String)>> {
+    let text = std::fs::read_to_string(path).map_err(|e| io_err(path, e))?;
+    let mut renames = Vec::new();
+    for (line_no, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (old, new) = line.split_once(',').ok_or_else(|| {
+            PackError::InvalidRename(format!("{}:{}: expected \"old,new\"", path.display(), line_no + 1))
+        })?;
+        renames.push((old.trim().to_string(), new.trim().to_string()));
+    }
+    Ok(renames)
+}
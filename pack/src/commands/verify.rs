@@ -0,0 +1,226 @@
+use std::io::Seek;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::error::{PackError, Result};
+use crate::pack::{Entry, Pack};
+use crate::stats::StatsCollector;
+
+pub struct VerifyArgs {
+    pub packs: Vec<PathBuf>,
+    /// Cross-check redundant metadata (chunk/size bookkeeping, data offsets,
+    /// overlap between entries) instead of just decompressing.
+    pub deep: bool,
+    pub format: VerifyFormat,
+    /// Write the throughput/timing summary as JSON to this file, in
+    /// addition to printing it.
+    pub stats_json: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum VerifyFormat {
+    Text,
+    Json,
+}
+
+/// Verify every pack in `args.packs` in turn -- a bad file doesn't stop the
+/// rest from being checked, so a batch like `pack verify *.pack` gives the
+/// full damage report in one run. Returns [`PackError::VerificationFailed`]
+/// if any pack failed, so the process exits non-zero without needing the
+/// caller to inspect printed output.
+pub fn run(args: &VerifyArgs) -> Result<()> {
+    let mut any_failed = false;
+    for pack_path in &args.packs {
+        let passed = match Pack::open(pack_path) {
+            Ok(pack) => {
+                if args.deep {
+                    run_deep(pack, args)?
+                } else {
+                    run_shallow(pack, args)?
+                }
+            }
+            Err(e) => {
+                println!("{}: {e}", pack_path.display());
+                false
+            }
+        };
+        any_failed |= !passed;
+    }
+    if any_failed {
+        return Err(PackError::VerificationFailed);
+    }
+    Ok(())
+}
+
+/// Decompress every entry and report every failure, if any -- it keeps
+/// going rather than stopping at the first bad entry. This is a shallow
+/// check: it only proves that every chunk decompresses without error, not
+/// that the pack's internal bookkeeping is self-consistent. Returns
+/// whether the pack passed.
+fn run_shallow(mut pack: Pack<std::fs::File>, args: &VerifyArgs) -> Result<bool> {
+    let entries = pack.entries.clone();
+    let mut failures = 0usize;
+    let started = Instant::now();
+    let mut stats = StatsCollector::default();
+    for entry in &entries {
+        match pack.read_entry_bytes_timed(entry) {
+            Ok((bytes, read_time, decompress_time)) => {
+                stats.record_entry(
+                    u64::from(entry.size_in_pack),
+                    bytes.len() as u64,
+                    read_time,
+                    decompress_time,
+                    Duration::ZERO,
+                );
+            }
+            Err(e) => {
+                eprintln!("{}: {}: {e}", pack.name, entry.path.display());
+                failures += 1;
+            }
+        }
+    }
+    let report = stats.finish(started.elapsed());
+
+    println!("{}: {} entries", pack.name, entries.len());
+    if failures == 0 {
+        println!("{} entries OK", entries.len());
+    } else {
+        println!("{failures} of {} entries failed to decompress", entries.len());
+    }
+    report.print_summary();
+    if let Some(stats_path) = &args.stats_json {
+        report.write_json(stats_path)?;
+    }
+    Ok(failures == 0)
+}
+
+#[derive(Serialize)]
+struct Violation {
+    invariant: String,
+    expected: String,
+    actual: String,
+}
+
+#[derive(Serialize)]
+struct EntryReport {
+    path: String,
+    violations: Vec<Violation>,
+}
+
+#[derive(Serialize)]
+struct DeepReport {
+    pack: String,
+    entries: Vec<EntryReport>,
+}
+
+/// Cross-check every redundant piece of metadata an entry carries, on top
+/// of the shallow decompression check: that its chunk sizes sum to
+/// `size_in_pack`, that decompressing it actually produces `size_on_disk`
+/// bytes, that its `data_offset` lands exactly where the previous entries'
+/// data would end, and that its data doesn't run past the end of the file.
+/// Community packs are sometimes subtly malformed in one of these ways yet
+/// still load fine in game, so violations are reported rather than treated
+/// as fatal.
+fn run_deep(mut pack: Pack<std::fs::File>, args: &VerifyArgs) -> Result<bool> {
+    let entries = pack.entries.clone();
+    let file_size = pack.total_len()?;
+    let mut expected_offset = u64::from(pack.header.data_section_offset);
+
+    let mut reports = Vec::new();
+    for entry in &entries {
+        let violations = check_entry(&mut pack, entry, expected_offset, file_size);
+        expected_offset += entry.chunk_sizes.iter().map(|&s| u64::from(s)).sum::<u64>();
+        if !violations.is_empty() {
+            reports.push(EntryReport {
+                path: entry.path.display().to_string(),
+                violations,
+            });
+        }
+    }
+
+    let passed = reports.is_empty();
+    match args.format {
+        VerifyFormat::Json => {
+            let report = DeepReport {
+                pack: pack.name.clone(),
+                entries: reports,
+            };
+            let json = serde_json::to_string_pretty(&report).expect("DeepReport is always serializable");
+            println!("{json}");
+        }
+        VerifyFormat::Text => {
+            println!("{}: {} entries", pack.name, entries.len());
+            if reports.is_empty() {
+                println!("{} entries OK", entries.len());
+            } else {
+                for report in &reports {
+                    println!("{}:", report.path);
+                    for violation in &report.violations {
+                        println!(
+                            "  {}: expected {}, got {}",
+                            violation.invariant, violation.expected, violation.actual
+                        );
+                    }
+                }
+                println!("{} of {} entries violated an invariant", reports.len(), entries.len());
+            }
+        }
+    }
+    Ok(passed)
+}
+
+fn check_entry<R: std::io::Read + Seek>(
+    pack: &mut Pack<R>,
+    entry: &Entry,
+    expected_offset: u64,
+    file_size: u64,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    let chunk_total: u64 = entry.chunk_sizes.iter().map(|&s| u64::from(s)).sum();
+    if chunk_total != u64::from(entry.size_in_pack) {
+        violations.push(Violation {
+            invariant: "sum(chunk_sizes) == size_in_pack".to_string(),
+            expected: entry.size_in_pack.to_string(),
+            actual: chunk_total.to_string(),
+        });
+    }
+
+    if entry.data_offset != expected_offset {
+        violations.push(Violation {
+            invariant: "data_offset follows the previous entry's data".to_string(),
+            expected: expected_offset.to_string(),
+            actual: entry.data_offset.to_string(),
+        });
+    }
+
+    let data_end = entry.data_offset + chunk_total;
+    if data_end > file_size {
+        violations.push(Violation {
+            invariant: "entry data does not run past end of file".to_string(),
+            expected: format!("<= {file_size}"),
+            actual: data_end.to_string(),
+        });
+    }
+
+    match pack.read_entry_bytes(entry) {
+        Ok(bytes) => {
+            if bytes.len() as u64 != u64::from(entry.size_on_disk) {
+                violations.push(Violation {
+                    invariant: "decompressed length == size_on_disk".to_string(),
+                    expected: entry.size_on_disk.to_string(),
+                    actual: bytes.len().to_string(),
+                });
+            }
+        }
+        Err(e) => violations.push(Violation {
+            invariant: "entry decompresses without error".to_string(),
+            expected: "no error".to_string(),
+            actual: e.to_string(),
+        }),
+    }
+
+    violations
+}
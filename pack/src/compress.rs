@@ -0,0 +1,15 @@
+//! Thin wrapper around the LZO1X variant used to compress pack chunks.
+
+/// Maximum size, in bytes, of a single (uncompressed) chunk. Entries larger
+/// than this are split across multiple independently compressed chunks so
+/// that extraction can seek to an arbitrary offset without decompressing the
+/// whole entry.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    lzokay_native::compress(data).expect("in-memory compression cannot fail")
+}
+
+pub fn decompress(data: &[u8], expected_len: usize) -> Result<Vec<u8>, String> {
+    lzokay_native::decompress_all(data, Some(expected_len)).map_err(|e| format!("{e:?}"))
+}
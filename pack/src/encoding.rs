@@ -0,0 +1,104 @@
+//! Decoding entry names that aren't valid UTF-8. Some community packs are
+//! built with tools that write paths in a legacy single-byte codepage
+//! instead of UTF-8; naively decoding them with `String::from_utf8` panics.
+//! [`decode`] tries UTF-8 first, falls back to a configured legacy codepage,
+//! and only resorts to lossy replacement if both fail, so an entry name can
+//! always be turned into a `String` without panicking.
+
+use std::fmt;
+
+use encoding_rs::Encoding;
+
+/// A legacy single-byte codepage to try when an entry name isn't valid
+/// UTF-8, as selected by `--legacy-encoding`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegacyEncoding {
+    Cp1252,
+    Cp1250,
+}
+
+impl LegacyEncoding {
+    fn encoding(self) -> &'static Encoding {
+        match self {
+            LegacyEncoding::Cp1252 => encoding_rs::WINDOWS_1252,
+            LegacyEncoding::Cp1250 => encoding_rs::WINDOWS_1250,
+        }
+    }
+}
+
+impl std::str::FromStr for LegacyEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "cp1252" | "windows-1252" => Ok(LegacyEncoding::Cp1252),
+            "cp1250" | "windows-1250" => Ok(LegacyEncoding::Cp1250),
+            other => Err(format!("unsupported legacy encoding {other:?} (expected \"cp1252\" or \"cp1250\")")),
+        }
+    }
+}
+
+impl fmt::Display for LegacyEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LegacyEncoding::Cp1252 => write!(f, "cp1252"),
+            LegacyEncoding::Cp1250 => write!(f, "cp1250"),
+        }
+    }
+}
+
+/// How an entry name's raw bytes ended up as a `String`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeKind {
+    /// The raw bytes were already valid UTF-8.
+    Utf8,
+    /// The raw bytes were decoded as the given legacy codepage.
+    Legacy(LegacyEncoding),
+    /// Neither UTF-8 nor the legacy codepage (or none was configured)
+    /// worked; invalid sequences were replaced with U+FFFD.
+    Lossy,
+}
+
+impl DecodeKind {
+    /// Whether this name round-trips exactly, i.e. wasn't itself already a
+    /// clean decode.
+    pub fn is_affected(self) -> bool {
+        !matches!(self, DecodeKind::Utf8)
+    }
+}
+
+impl fmt::Display for DecodeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeKind::Utf8 => write!(f, "utf-8"),
+            DecodeKind::Legacy(enc) => write!(f, "{enc}"),
+            DecodeKind::Lossy => write!(f, "lossy replacement"),
+        }
+    }
+}
+
+pub struct DecodedName {
+    pub text: String,
+    pub kind: DecodeKind,
+}
+
+/// Decode raw entry-name bytes, trying UTF-8, then `legacy` if given, then
+/// falling back to lossy UTF-8 replacement. Never panics.
+pub fn decode(raw: &[u8], legacy: Option<LegacyEncoding>) -> DecodedName {
+    if let Ok(text) = std::str::from_utf8(raw) {
+        return DecodedName { text: text.to_string(), kind: DecodeKind::Utf8 };
+    }
+    if let Some(legacy) = legacy {
+        let (text, _, had_errors) = legacy.encoding().decode(raw);
+        if !had_errors {
+            return DecodedName { text: text.into_owned(), kind: DecodeKind::Legacy(legacy) };
+        }
+    }
+    DecodedName { text: String::from_utf8_lossy(raw).into_owned(), kind: DecodeKind::Lossy }
+}
+
+/// Render bytes as lowercase hex, for reporting the raw form of a name that
+/// needed a fallback decode.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect::<Vec<_>>().join(" ")
+}
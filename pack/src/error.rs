@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+/// Errors produced while reading or writing `.pack` archives.
+#[derive(thiserror::Error, Debug)]
+pub enum PackError {
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{path}: not a pack file (expected magic {expected:?}, found {found:?})")]
+    BadMagic {
+        path: PathBuf,
+        expected: [u8; 4],
+        found: [u8; 4],
+    },
+
+    #[error("{path}: unsupported pack version {version}")]
+    UnsupportedVersion { path: PathBuf, version: u32 },
+
+    #[error(
+        "{path}: file record section is truncated (header claims {expected} bytes, only {actual} available)"
+    )]
+    TruncatedFileSection {
+        path: PathBuf,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("{path}: corrupt entry #{index} ({reason})")]
+    CorruptEntry {
+        path: PathBuf,
+        index: usize,
+        reason: String,
+    },
+
+    #[error("{path}: failed to decompress {entry:?} (chunk {chunk}): {reason}")]
+    Decompress {
+        path: PathBuf,
+        entry: String,
+        chunk: usize,
+        reason: String,
+    },
+
+    #[error(transparent)]
+    Manifest(#[from] crate::manifest::ManifestError),
+
+    #[error("{path}: no entry named {old:?}")]
+    RenameSourceNotFound { path: PathBuf, old: String },
+
+    #[error("{path}: an entry already exists at {new:?}")]
+    RenameTargetExists { path: PathBuf, new: String },
+
+    #[error("{0}")]
+    InvalidRename(String),
+
+    #[error("{path}: an entry already exists at {target:?} (use --replace to overwrite it)")]
+    AddTargetExists { path: PathBuf, target: String },
+
+    #[error("{0}")]
+    InvalidAdd(String),
+
+    #[error("filter(s) {patterns:?} matched no entries")]
+    NoFilterMatches { patterns: Vec<String> },
+
+    #[error("{0}")]
+    InvalidFilter(String),
+
+    #[error("failed to build extraction thread pool: {0}")]
+    ThreadPool(String),
+
+    #[error("{dest}: already exists (use --skip-existing or --force to overwrite)")]
+    ExtractTargetExists { dest: PathBuf },
+
+    #[error("{entry:?}: unsafe path ({reason}), refusing to extract it")]
+    UnsafeEntryPath { entry: String, reason: String },
+
+    #[error("{0}")]
+    InvalidExtract(String),
+
+    #[error("one or more packs failed verification")]
+    VerificationFailed,
+}
+
+pub type Result<T> = std::result::Result<T, PackError>;
+
+pub(crate) fn io_err(path: &std::path::Path, source: std::io::Error) -> PackError {
+    PackError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
@@ -0,0 +1,44 @@
+//! Load a named set of (internal path, contents) pairs from either a plain
+//! directory or an existing `.pack` file, so commands like `delta` can
+//! accept either as their "updated" side.
+
+use std::path::Path;
+
+use walkdir::WalkDir;
+
+use crate::commands::create::relative_slashed;
+use crate::error::{io_err, Result};
+use crate::pack::Pack;
+
+pub fn load(path: &Path) -> Result<Vec<(String, Vec<u8>)>> {
+    if path.is_dir() {
+        load_dir(path)
+    } else {
+        load_pack(path)
+    }
+}
+
+fn load_dir(dir: &Path) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut out = Vec::new();
+    for dirent in WalkDir::new(dir).into_iter().filter_map(std::result::Result::ok) {
+        if !dirent.file_type().is_file() {
+            continue;
+        }
+        let path = dirent.path();
+        let internal_path = relative_slashed(dir, path);
+        let data = std::fs::read(path).map_err(|e| io_err(path, e))?;
+        out.push((internal_path, data));
+    }
+    Ok(out)
+}
+
+fn load_pack(path: &Path) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut pack = Pack::open(path)?;
+    let entries = pack.entries.clone();
+    let mut out = Vec::with_capacity(entries.len());
+    for entry in &entries {
+        let data = pack.read_entry_bytes(entry)?;
+        out.push((entry.path.to_string_lossy().into_owned(), data));
+    }
+    Ok(out)
+}
@@ -0,0 +1,240 @@
+use std::path::Path;
+
+use glob::{MatchOptions, Pattern};
+use regex::Regex;
+
+use crate::error::{PackError, Result};
+use crate::suggest;
+
+/// Splits every `--filter` value on commas (so `--filter a,b` behaves like
+/// passing `--filter a --filter b`) and drops empty fragments left by stray
+/// commas or whitespace. Called once per command invocation; the expanded
+/// list is then reused for every entry via [`matches`].
+pub fn expand_patterns(filters: &[String]) -> Vec<String> {
+    filters.iter().flat_map(|f| f.split(',')).map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect()
+}
+
+/// Whether an entry path matches any of `patterns` (already expanded via
+/// [`expand_patterns`]). An empty list matches everything. With
+/// `ignore_case`, ASCII letters in both the pattern and the path are folded
+/// before comparing, so `Data/*.TGA` matches `data/unit.tga` -- packs mix
+/// case in both directory and extension freely, since the game itself
+/// doesn't care. The entry path is matched in its forward-slash-normalized
+/// form, so a pattern like `data/ui/**` matches a Windows-authored entry
+/// (`data\ui\button.tga`) the same way on every platform.
+pub fn matches(patterns: &[String], entry_path: &Path, ignore_case: bool) -> bool {
+    if patterns.is_empty() {
+        return true;
+    }
+    let options = MatchOptions { case_sensitive: !ignore_case, ..MatchOptions::new() };
+    let path = entry_path.to_string_lossy().replace('\\', "/");
+    patterns.iter().any(|pattern| match Pattern::new(pattern) {
+        Ok(p) => p.matches_with(&path, options),
+        Err(_) => false,
+    })
+}
+
+/// Whether an entry path should be processed once both `--filter` and
+/// `--exclude` are taken into account: it must match an include pattern
+/// (or there are none) and must not match any exclude pattern. Excludes
+/// win over includes, so a path named by both is left out.
+pub fn matches_with_excludes(
+    includes: &[String],
+    excludes: &[String],
+    entry_path: &Path,
+    ignore_case: bool,
+) -> bool {
+    if !matches(includes, entry_path, ignore_case) {
+        return false;
+    }
+    excludes.is_empty() || !matches(excludes, entry_path, ignore_case)
+}
+
+/// Warn (or, with `strict`, fail) when `patterns` was non-empty but matched
+/// none of `all_paths`, so a typo'd glob doesn't silently produce an empty
+/// extraction. Up to five "did you mean" candidates are included in the
+/// warning, built from the first pattern. Does nothing when no filter was
+/// given or it matched something.
+pub fn check_matched<'a>(
+    patterns: &[String],
+    matched_count: usize,
+    all_paths: impl Iterator<Item = &'a Path>,
+    strict: bool,
+) -> Result<()> {
+    if patterns.is_empty() || matched_count > 0 {
+        return Ok(());
+    }
+    report_no_matches(patterns, all_paths, strict)
+}
+
+fn report_no_matches<'a>(
+    patterns: &[String],
+    all_paths: impl Iterator<Item = &'a Path>,
+    strict: bool,
+) -> Result<()> {
+    let suggestions = suggest::suggest(&patterns[0], all_paths, 5);
+    if suggestions.is_empty() {
+        eprintln!("warning: filter {patterns:?} matched no entries");
+    } else {
+        eprintln!("warning: filter {patterns:?} matched no entries, did you mean:");
+        for suggestion in &suggestions {
+            eprintln!("  {suggestion}");
+        }
+    }
+
+    if strict {
+        return Err(PackError::NoFilterMatches { patterns: patterns.to_vec() });
+    }
+    Ok(())
+}
+
+/// A single reusable per-entry match decision, built once per command
+/// invocation from either `--filter`/`--exclude` globs or a `--regex`, then
+/// applied in the extraction/listing loop. Kept as its own type (rather than
+/// inlined in `cmd_extract`) so the match logic -- including the `--regex`
+/// vs `--filter` conflict check -- can be unit tested without building a
+/// pack.
+#[derive(Debug)]
+pub enum EntryMatcher {
+    Globs {
+        includes: Vec<String>,
+        excludes: Vec<String>,
+        ignore_case: bool,
+    },
+    Regex(Regex),
+}
+
+impl EntryMatcher {
+    /// Builds a matcher from a command's `--filter`/`--exclude`/`--regex`
+    /// options, failing fast -- before any pack is opened -- if `--regex`
+    /// is combined with `--filter`, or if the regex itself doesn't compile.
+    pub fn new(filters: &[String], excludes: &[String], regex: Option<&str>, ignore_case: bool) -> Result<Self> {
+        if let Some(pattern) = regex {
+            if !filters.is_empty() {
+                return Err(PackError::InvalidFilter("--regex cannot be combined with --filter".to_string()));
+            }
+            let re = Regex::new(pattern)
+                .map_err(|e| PackError::InvalidFilter(format!("invalid --regex {pattern:?}: {e}")))?;
+            return Ok(EntryMatcher::Regex(re));
+        }
+        Ok(EntryMatcher::Globs { includes: expand_patterns(filters), excludes: expand_patterns(excludes), ignore_case })
+    }
+
+    /// Whether `entry_path` should be processed under this matcher. Regex
+    /// patterns are matched against the forward-slash-normalized path, so
+    /// the same pattern behaves the same regardless of platform separators.
+    pub fn matches(&self, entry_path: &Path) -> bool {
+        match self {
+            EntryMatcher::Globs { includes, excludes, ignore_case } => {
+                matches_with_excludes(includes, excludes, entry_path, *ignore_case)
+            }
+            EntryMatcher::Regex(re) => {
+                re.is_match(&entry_path.to_string_lossy().replace('\\', "/"))
+            }
+        }
+    }
+
+    /// Same job as the free [`check_matched`], for a matcher that may be
+    /// glob- or regex-based. An unrestricted matcher (no `--filter` and no
+    /// `--regex`) never warns, however few entries matched.
+    pub fn check_matched<'a>(
+        &self,
+        matched_count: usize,
+        all_paths: impl Iterator<Item = &'a Path>,
+        strict: bool,
+    ) -> Result<()> {
+        let is_unrestricted = matches!(self, EntryMatcher::Globs { includes, .. } if includes.is_empty());
+        if is_unrestricted || matched_count > 0 {
+            return Ok(());
+        }
+        let patterns = match self {
+            EntryMatcher::Globs { includes, .. } => includes.clone(),
+            EntryMatcher::Regex(re) => vec![re.as_str().to_string()],
+        };
+        report_no_matches(&patterns, all_paths, strict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_patterns_splits_on_commas_and_trims_whitespace() {
+        let filters = vec!["data/text/**".to_string(), " data/ui/**, data/menu/** ".to_string()];
+        assert_eq!(expand_patterns(&filters), vec!["data/text/**", "data/ui/**", "data/menu/**"]);
+    }
+
+    #[test]
+    fn matches_is_true_if_any_pattern_matches() {
+        let patterns = vec!["data/text/**".to_string(), "data/ui/**".to_string()];
+        assert!(matches(&patterns, Path::new("data/ui/button.tga"), false));
+        assert!(!matches(&patterns, Path::new("data/sounds/hit.wav"), false));
+    }
+
+    #[test]
+    fn matches_with_no_patterns_matches_everything() {
+        assert!(matches(&[], Path::new("anything.txt"), false));
+    }
+
+    #[test]
+    fn excludes_win_over_includes_on_nested_paths() {
+        let includes = vec!["data/**".to_string()];
+        let excludes = vec!["data/sounds/**".to_string()];
+        assert!(matches_with_excludes(&includes, &excludes, Path::new("data/ui/button.tga"), false));
+        assert!(!matches_with_excludes(&includes, &excludes, Path::new("data/sounds/hit.wav"), false));
+        assert!(!matches_with_excludes(&includes, &excludes, Path::new("data/sounds/sfx/hit.wav"), false));
+    }
+
+    #[test]
+    fn no_excludes_behaves_like_matches() {
+        let includes = vec!["data/**".to_string()];
+        assert!(matches_with_excludes(&includes, &[], Path::new("data/sounds/hit.wav"), false));
+    }
+
+    #[test]
+    fn case_sensitive_by_default_rejects_mismatched_case() {
+        let patterns = vec!["data/UI/*.tga".to_string()];
+        assert!(!matches(&patterns, Path::new("data/ui/button.tga"), false));
+        assert!(matches(&patterns, Path::new("data/UI/button.tga"), false));
+    }
+
+    #[test]
+    fn backslash_separated_entries_match_forward_slash_patterns() {
+        let patterns = vec!["data/ui/**".to_string()];
+        assert!(matches(&patterns, Path::new(r"data\ui\button.tga"), false));
+        assert!(!matches(&patterns, Path::new(r"data\sounds\hit.wav"), false));
+    }
+
+    #[test]
+    fn ignore_case_matches_regardless_of_pattern_or_path_case() {
+        let patterns = vec!["Data/UI/*.TGA".to_string()];
+        assert!(matches(&patterns, Path::new("data/ui/button.tga"), true));
+        assert!(matches(&patterns, Path::new("DATA/UI/BUTTON.TGA"), true));
+    }
+
+    #[test]
+    fn regex_and_filter_together_is_rejected_before_matching_anything() {
+        let err = EntryMatcher::new(&["data/**".to_string()], &[], Some(r"\.tga$"), false).unwrap_err();
+        assert!(err.to_string().contains("--regex"));
+    }
+
+    #[test]
+    fn invalid_regex_is_rejected_up_front() {
+        let err = EntryMatcher::new(&[], &[], Some("("), false).unwrap_err();
+        assert!(matches!(err, PackError::InvalidFilter(_)));
+    }
+
+    #[test]
+    fn regex_matches_texture_files_but_not_normal_maps() {
+        let matcher = EntryMatcher::new(&[], &[], Some(r"^data/.*(?<!_normal)\.texture$"), false);
+        // The `regex` crate has no lookaround support, so an exclusion like
+        // this that globs can't express either has to fail to compile
+        // rather than silently mismatch.
+        assert!(matcher.is_err());
+
+        let matcher = EntryMatcher::new(&[], &[], Some(r"^data/units/[a-z]+\.texture$"), false).unwrap();
+        assert!(matcher.matches(Path::new("data/units/knight.texture")));
+        assert!(!matcher.matches(Path::new("data/units/knight_normal.texture")));
+    }
+}
@@ -0,0 +1,7 @@
+//! Content hashing shared by the delta/dedupe commands.
+
+pub type ContentHash = [u8; 32];
+
+pub fn hash_bytes(data: &[u8]) -> ContentHash {
+    *blake3::hash(data).as_bytes()
+}
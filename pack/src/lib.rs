@@ -0,0 +1,17 @@
+pub mod browse;
+pub mod cas;
+pub mod commands;
+pub mod compress;
+pub mod encoding;
+pub mod error;
+pub mod filter;
+pub mod fileset;
+pub mod hash;
+pub mod loadorder;
+pub mod manifest;
+pub mod nullstring;
+pub mod pack;
+pub mod reader;
+pub mod stats;
+pub mod suggest;
+pub mod writer;
@@ -0,0 +1,25 @@
+//! The engine's fixed pack load order, used by `extract-all` to resolve
+//! which pack "wins" when the same internal path appears in more than one.
+//!
+//! Later entries in this list override earlier ones, matching the engine's
+//! own behaviour: the base data loads first, then localization, then
+//! patches. Packs not named here are treated as mods and applied last, in
+//! alphabetical order, so a mod always overrides the base game.
+pub const KNOWN_ORDER: &[&str] = &[
+    "data.pack",
+    "localized.pack",
+    "patch.pack",
+    "patch_2.pack",
+];
+
+/// Sort `names` (bare file names, e.g. `"data.pack"`) into engine load
+/// order: known packs in `KNOWN_ORDER`, then everything else alphabetically.
+pub fn sort_by_load_order(names: &mut [String]) {
+    names.sort_by_key(|name| {
+        let rank = KNOWN_ORDER
+            .iter()
+            .position(|known| known.eq_ignore_ascii_case(name))
+            .unwrap_or(KNOWN_ORDER.len());
+        (rank, name.to_ascii_lowercase())
+    });
+}
@@ -0,0 +1,443 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use pack::cas;
+use pack::commands::{add, apply_delta, create, delta, extract, extract_all, info, list, remove, rename, verify};
+use pack::encoding::LegacyEncoding;
+use pack::error::PackError;
+
+#[derive(Parser)]
+#[command(name = "pack", about = "Inspect and manipulate M2TW .pack archives")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List entries in one or more packs
+    List {
+        #[arg(required = true)]
+        packs: Vec<PathBuf>,
+        /// Only list entries matching this glob. Repeatable, and/or
+        /// comma-separated, to match any of several patterns
+        #[arg(long)]
+        filter: Vec<String>,
+        /// Exclude entries matching this glob, applied after `--filter`: an
+        /// entry is listed if it matches a `--filter` (or none was given)
+        /// and no `--exclude`. Repeatable, and/or comma-separated
+        #[arg(long)]
+        exclude: Vec<String>,
+        /// Only list entries whose path matches this regex, as an
+        /// alternative to `--filter`. Cannot be combined with `--filter`
+        #[arg(long)]
+        regex: Option<String>,
+        /// Fold ASCII case in `--filter`/`--exclude` patterns and entry
+        /// paths before matching, since packs mix `Data/`/`data/` and
+        /// `.TGA`/`.tga` freely
+        #[arg(long)]
+        ignore_case: bool,
+        #[arg(long, default_value = "text")]
+        format: String,
+        /// Legacy codepage to fall back to for entry names that aren't
+        /// valid UTF-8, or "off" to go straight to lossy replacement
+        #[arg(long, default_value = "cp1252")]
+        legacy_encoding: String,
+        /// Exit non-zero if `--filter` matched no entries
+        #[arg(long)]
+        strict_filters: bool,
+        /// Show packed/unpacked size, compression ratio, and chunk count per
+        /// entry, with a totals line at the end
+        #[arg(short = 'l', long)]
+        long: bool,
+    },
+    /// Extract entries from a pack to a destination directory
+    Extract {
+        pack: PathBuf,
+        dest: PathBuf,
+        /// Only extract entries matching this glob. Repeatable, and/or
+        /// comma-separated, to match any of several patterns
+        #[arg(long)]
+        filter: Vec<String>,
+        /// Exclude entries matching this glob, applied after `--filter`: an
+        /// entry is extracted if it matches a `--filter` (or none was given)
+        /// and no `--exclude`. Repeatable, and/or comma-separated
+        #[arg(long)]
+        exclude: Vec<String>,
+        /// Only extract entries whose path matches this regex, as an
+        /// alternative to `--filter`. Cannot be combined with `--filter`
+        #[arg(long)]
+        regex: Option<String>,
+        /// Fold ASCII case in `--filter`/`--exclude` patterns and entry
+        /// paths before matching, since packs mix `Data/`/`data/` and
+        /// `.TGA`/`.tga` freely
+        #[arg(long)]
+        ignore_case: bool,
+        /// Extract in table order instead of sorting matched entries by
+        /// data_offset first
+        #[arg(long)]
+        no_reorder: bool,
+        /// Number of worker threads to extract with, or 0 for the number of
+        /// available CPUs
+        #[arg(long, default_value_t = 0)]
+        jobs: usize,
+        /// Map the pack into memory once and decompress chunks straight out
+        /// of the mapping instead of seeking and reading each one into a
+        /// fresh buffer. Falls back to the buffered reader automatically if
+        /// mapping fails
+        #[arg(long)]
+        mmap: bool,
+        /// Write the read/decompress/write throughput summary as JSON to
+        /// this file
+        #[arg(long)]
+        stats_json: Option<PathBuf>,
+        /// Legacy codepage to fall back to for entry names that aren't
+        /// valid UTF-8, or "off" to go straight to lossy replacement
+        #[arg(long, default_value = "cp1252")]
+        legacy_encoding: String,
+        /// Exit non-zero if `--filter` matched no entries
+        #[arg(long)]
+        strict_filters: bool,
+        /// Don't show a progress bar. Implied automatically when stderr
+        /// isn't a terminal
+        #[arg(long)]
+        no_progress: bool,
+        /// Leave existing files at the destination untouched instead of
+        /// overwriting them, logging each one skipped
+        #[arg(long, conflicts_with_all = ["no_clobber", "force"])]
+        skip_existing: bool,
+        /// Abort with an error naming the first destination file that
+        /// already exists, instead of overwriting it
+        #[arg(long, conflicts_with_all = ["skip_existing", "force"])]
+        no_clobber: bool,
+        /// Overwrite existing files at the destination. This is the
+        /// default; the flag exists to say so explicitly in scripts
+        #[arg(long, conflicts_with_all = ["skip_existing", "no_clobber"])]
+        force: bool,
+        /// Abort the whole run instead of just skipping (with a warning)
+        /// an entry whose path tries to escape the destination directory
+        #[arg(long)]
+        strict_paths: bool,
+        /// Write every selected entry directly into DEST using only its
+        /// file name, instead of recreating the pack's directory structure
+        #[arg(long)]
+        flatten: bool,
+        /// What to do when --flatten would write two entries to the same
+        /// file name: "suffix" (default) numbers the later ones, "fail"
+        /// aborts and lists every collision
+        #[arg(long, default_value = "suffix", requires = "flatten")]
+        on_collision: String,
+    },
+    /// Decompress every entry in one or more packs to check for corruption
+    Verify {
+        #[arg(required = true)]
+        packs: Vec<PathBuf>,
+        /// Cross-check chunk/size bookkeeping, data offsets, and overlap
+        /// between entries instead of just decompressing
+        #[arg(long)]
+        deep: bool,
+        #[arg(long, default_value = "text")]
+        format: String,
+        /// Write the read/decompress throughput summary as JSON to this file
+        #[arg(long)]
+        stats_json: Option<PathBuf>,
+    },
+    /// Print pack-level statistics: entry/chunk counts, section sizes,
+    /// total packed/unpacked size, and compression ratio
+    Info {
+        #[arg(required = true)]
+        packs: Vec<PathBuf>,
+        /// Dump an annotated, fault-tolerant walk of the raw structure
+        /// instead, for diagnosing packs that fail to parse
+        #[arg(long)]
+        debug_structure: bool,
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Create a pack from the contents of a directory
+    Create {
+        source: PathBuf,
+        #[arg(long)]
+        output: PathBuf,
+        /// TOML manifest overriding per-file compression, internal path, and
+        /// ordering priority
+        #[arg(long = "from-manifest")]
+        from_manifest: Option<PathBuf>,
+    },
+    /// Add or replace entries in an existing pack without rebuilding it
+    Add {
+        pack: PathBuf,
+        /// Files on disk to add, one per `--as`
+        files: Vec<PathBuf>,
+        /// Internal path for the file at the same position in FILES
+        #[arg(long = "as", required = true)]
+        as_paths: Vec<String>,
+        /// Overwrite an entry already at the target path instead of failing
+        #[arg(long)]
+        replace: bool,
+    },
+    /// Delete entries matching a filter from a pack
+    Remove {
+        pack: PathBuf,
+        #[arg(long)]
+        filter: String,
+        /// Report what would be removed without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Inspect .cas animation/model files
+    Cas {
+        #[command(subcommand)]
+        action: CasCommand,
+    },
+    /// Interactively browse a pack's contents
+    Browse {
+        pack: PathBuf,
+        /// Destination directory for entries marked with `m` and extracted with `x`
+        #[arg(long, default_value = "extracted")]
+        dest: PathBuf,
+    },
+    /// Create a pack containing only entries changed from a base pack
+    Delta {
+        #[arg(long)]
+        base: PathBuf,
+        #[arg(long)]
+        updated: PathBuf,
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Reconstruct a full pack from a base pack and a delta patch
+    ApplyDelta {
+        #[arg(long)]
+        base: PathBuf,
+        #[arg(long)]
+        patch: PathBuf,
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Change an entry's internal path in place, without touching its data
+    Rename {
+        pack: PathBuf,
+        old_path: Option<String>,
+        new_path: Option<String>,
+        /// Apply many renames from a CSV file of `old,new` pairs, one per
+        /// line, instead of OLD_PATH/NEW_PATH
+        #[arg(long = "from-csv")]
+        from_csv: Option<PathBuf>,
+    },
+    /// Extract every pack in a game data directory, applying engine
+    /// load-order override resolution
+    ExtractAll {
+        #[arg(long = "game-dir")]
+        game_dir: PathBuf,
+        #[arg(long)]
+        dest: PathBuf,
+        #[arg(long)]
+        filter: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum CasCommand {
+    /// Print the header and per-bone track summary
+    Info { file: PathBuf },
+    /// Export the full keyframe data
+    Dump {
+        file: PathBuf,
+        #[arg(long, default_value = "json")]
+        format: String,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result: Result<(), String> = match cli.command {
+        Command::List { packs, filter, exclude, regex, ignore_case, format, legacy_encoding, strict_filters, long } => {
+            let format = match format.as_str() {
+                "text" => list::ListFormat::Text,
+                "json" => list::ListFormat::Json,
+                "csv" => list::ListFormat::Csv,
+                other => {
+                    eprintln!("error: unsupported list format {other:?} (expected \"text\", \"json\", or \"csv\")");
+                    std::process::exit(1);
+                }
+            };
+            let legacy_encoding = parse_legacy_encoding(&legacy_encoding);
+            exit_on_no_filter_matches(list::run(&list::ListArgs {
+                packs,
+                filters: filter,
+                excludes: exclude,
+                regex,
+                ignore_case,
+                strict_filters,
+                format,
+                legacy_encoding,
+                long,
+            }))
+        }
+        Command::Extract {
+            pack,
+            dest,
+            filter,
+            exclude,
+            regex,
+            ignore_case,
+            no_reorder,
+            jobs,
+            mmap,
+            stats_json,
+            legacy_encoding,
+            strict_filters,
+            no_progress,
+            skip_existing,
+            no_clobber,
+            force: _,
+            strict_paths,
+            flatten,
+            on_collision,
+        } => {
+            let legacy_encoding = parse_legacy_encoding(&legacy_encoding);
+            let overwrite = if skip_existing {
+                extract::OverwritePolicy::SkipExisting
+            } else if no_clobber {
+                extract::OverwritePolicy::NoClobber
+            } else {
+                extract::OverwritePolicy::Overwrite
+            };
+            let on_collision = parse_on_collision(&on_collision);
+            exit_on_no_filter_matches(extract::run(&extract::ExtractArgs {
+                pack,
+                dest,
+                filters: filter,
+                excludes: exclude,
+                regex,
+                ignore_case,
+                no_reorder,
+                jobs,
+                use_mmap: mmap,
+                stats_json,
+                legacy_encoding,
+                strict_filters,
+                no_progress,
+                overwrite,
+                strict_paths,
+                flatten,
+                on_collision,
+            }))
+        }
+        Command::Verify { packs, deep, format, stats_json } => {
+            let format = match format.as_str() {
+                "text" => verify::VerifyFormat::Text,
+                "json" => verify::VerifyFormat::Json,
+                other => {
+                    eprintln!("error: unsupported verify format {other:?} (expected \"text\" or \"json\")");
+                    std::process::exit(1);
+                }
+            };
+            verify::run(&verify::VerifyArgs { packs, deep, format, stats_json }).map_err(|e| e.to_string())
+        }
+        Command::Info { packs, debug_structure, format } => {
+            let format = match format.as_str() {
+                "text" => info::InfoFormat::Text,
+                "json" => info::InfoFormat::Json,
+                other => {
+                    eprintln!("error: unsupported info format {other:?} (expected \"text\" or \"json\")");
+                    std::process::exit(1);
+                }
+            };
+            info::run(&info::InfoArgs { packs, debug_structure, format }).map_err(|e| e.to_string())
+        }
+        Command::Create { source, output, from_manifest } => {
+            create::run(&create::CreateArgs { source, output, from_manifest })
+                .map_err(|e| e.to_string())
+        }
+        Command::Add { pack, files, as_paths, replace } => {
+            add::run(&add::AddArgs { pack, files, as_paths, replace }).map_err(|e| e.to_string())
+        }
+        Command::Remove { pack, filter, dry_run } => {
+            remove::run(&remove::RemoveArgs { pack, filter, dry_run }).map_err(|e| e.to_string())
+        }
+        Command::Cas { action } => run_cas(action).map_err(|e| e.to_string()),
+        Command::Browse { pack, dest } => {
+            pack::browse::run(&pack::browse::BrowseArgs { pack, dest }).map_err(|e| e.to_string())
+        }
+        Command::Delta { base, updated, output } => {
+            delta::run(&delta::DeltaArgs { base, updated, output }).map_err(|e| e.to_string())
+        }
+        Command::ApplyDelta { base, patch, output } => {
+            apply_delta::run(&apply_delta::ApplyDeltaArgs { base, patch, output })
+                .map_err(|e| e.to_string())
+        }
+        Command::Rename { pack, old_path, new_path, from_csv } => {
+            rename::run(&rename::RenameArgs { pack, old_path, new_path, from_csv }).map_err(|e| e.to_string())
+        }
+        Command::ExtractAll { game_dir, dest, filter } => {
+            extract_all::run(&extract_all::ExtractAllArgs { game_dir, dest, filter })
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+/// A `--filter` matching nothing is a distinct outcome from any other
+/// failure: scripts doing `pack list --filter x *.pack || echo "not found"`
+/// need to tell "the file isn't in there" apart from "the pack is corrupt"
+/// by exit code, so this exits `3` instead of falling through to the
+/// generic error path's `1`.
+fn exit_on_no_filter_matches(result: pack::error::Result<()>) -> Result<(), String> {
+    match result {
+        // filter::check_matched already printed the "did you mean" warning
+        // for this; nothing left to say beyond picking the exit code.
+        Err(PackError::NoFilterMatches { .. }) => std::process::exit(3),
+        other => other.map_err(|e| e.to_string()),
+    }
+}
+
+/// Parses a `--legacy-encoding` value. `"off"` disables the legacy fallback
+/// (non-UTF-8 names go straight to lossy replacement); anything else must
+/// name a supported codepage.
+fn parse_legacy_encoding(value: &str) -> Option<LegacyEncoding> {
+    if value.eq_ignore_ascii_case("off") {
+        return None;
+    }
+    match value.parse() {
+        Ok(encoding) => Some(encoding),
+        Err(e) => {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parses an `--on-collision` value.
+fn parse_on_collision(value: &str) -> extract::OnCollision {
+    match value {
+        "suffix" => extract::OnCollision::Suffix,
+        "fail" => extract::OnCollision::Fail,
+        other => {
+            eprintln!("error: invalid --on-collision {other:?} (expected \"suffix\" or \"fail\")");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run_cas(action: CasCommand) -> cas::Result<()> {
+    match action {
+        CasCommand::Info { file } => cas::commands::info(&cas::commands::InfoArgs { file }),
+        CasCommand::Dump { file, format } => {
+            let format = match format.as_str() {
+                "json" => cas::commands::DumpFormat::Json,
+                other => {
+                    eprintln!("error: unsupported dump format {other:?} (expected \"json\")");
+                    std::process::exit(1);
+                }
+            };
+            cas::commands::dump(&cas::commands::DumpArgs { file, format })
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! `--from-manifest` build manifests for `pack create`: a TOML file listing
+//! per-file or per-glob overrides (compression, an explicit internal path,
+//! ordering priority) layered on top of a source directory. Files not
+//! matched by any rule keep the usual defaults.
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use walkdir::WalkDir;
+
+use crate::commands::create::relative_slashed;
+
+/// Errors validating or parsing a build manifest.
+#[derive(thiserror::Error, Debug)]
+pub enum ManifestError {
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{path}:{line}: {message}")]
+    Parse {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+
+    #[error("{path}:{line}: source file not found: {source_glob:?}")]
+    MissingSource {
+        path: PathBuf,
+        line: usize,
+        source_glob: String,
+    },
+
+    #[error(
+        "{path}: duplicate internal path {internal_path:?} (rules at line {first_line} and line {second_line})"
+    )]
+    DuplicatePath {
+        path: PathBuf,
+        internal_path: String,
+        first_line: usize,
+        second_line: usize,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, ManifestError>;
+
+#[derive(Deserialize, Default)]
+struct RawManifest {
+    #[serde(default)]
+    entry: Vec<toml::Spanned<RawEntry>>,
+}
+
+#[derive(Deserialize)]
+struct RawEntry {
+    /// Disk path or glob, relative to the source directory, using `/`.
+    source: String,
+    /// Explicit internal path; defaults to `source`'s resolved disk path.
+    path: Option<String>,
+    #[serde(default = "default_compress")]
+    compress: bool,
+    #[serde(default)]
+    priority: i64,
+}
+
+fn default_compress() -> bool {
+    true
+}
+
+/// A single file resolved from a manifest (or the directory defaults for
+/// files no rule matched).
+pub struct ResolvedEntry {
+    pub disk_path: PathBuf,
+    pub internal_path: String,
+    pub compress: bool,
+    pub priority: i64,
+}
+
+/// Load `manifest_path`, apply its rules to the files under `source_dir`,
+/// and return the fully resolved entry list in build order.
+pub fn resolve(manifest_path: &Path, source_dir: &Path) -> Result<Vec<ResolvedEntry>> {
+    let text = std::fs::read_to_string(manifest_path).map_err(|e| ManifestError::Io {
+        path: manifest_path.to_path_buf(),
+        source: e,
+    })?;
+    let raw: RawManifest = toml::from_str(&text).map_err(|e| ManifestError::Parse {
+        path: manifest_path.to_path_buf(),
+        line: e.span().map(|span| line_of(&text, span.start)).unwrap_or(1),
+        message: e.message().to_string(),
+    })?;
+
+    let disk_files = walk_source_dir(source_dir).map_err(|e| ManifestError::Io {
+        path: source_dir.to_path_buf(),
+        source: e,
+    })?;
+
+    let mut assigned: HashMap<PathBuf, (ResolvedEntry, usize)> = HashMap::new();
+    let mut matched: HashSet<PathBuf> = HashSet::new();
+
+    for rule in &raw.entry {
+        let line = line_of(&text, rule.span().start);
+        let rule = rule.get_ref();
+        let is_glob = rule.source.contains(['*', '?', '[', ']']);
+        let pattern = glob::Pattern::new(&rule.source).map_err(|e| ManifestError::Parse {
+            path: manifest_path.to_path_buf(),
+            line,
+            message: format!("invalid glob {:?}: {e}", rule.source),
+        })?;
+
+        let mut any = false;
+        for (disk_path, default_internal) in &disk_files {
+            if !pattern.matches(default_internal) {
+                continue;
+            }
+            any = true;
+            let internal_path = rule.path.clone().unwrap_or_else(|| default_internal.clone());
+            let entry = ResolvedEntry {
+                disk_path: disk_path.clone(),
+                internal_path,
+                compress: rule.compress,
+                priority: rule.priority,
+            };
+            assigned.insert(disk_path.clone(), (entry, line));
+            matched.insert(disk_path.clone());
+        }
+
+        if !any && !is_glob {
+            return Err(ManifestError::MissingSource {
+                path: manifest_path.to_path_buf(),
+                line,
+                source_glob: rule.source.clone(),
+            });
+        }
+    }
+
+    for (disk_path, default_internal) in &disk_files {
+        if matched.contains(disk_path) {
+            continue;
+        }
+        let entry = ResolvedEntry {
+            disk_path: disk_path.clone(),
+            internal_path: default_internal.clone(),
+            compress: true,
+            priority: 0,
+        };
+        assigned.insert(disk_path.clone(), (entry, 0));
+    }
+
+    let mut by_internal_path: HashMap<String, usize> = HashMap::new();
+    for (entry, line) in assigned.values() {
+        if let Some(&first_line) = by_internal_path.get(&entry.internal_path) {
+            return Err(ManifestError::DuplicatePath {
+                path: manifest_path.to_path_buf(),
+                internal_path: entry.internal_path.clone(),
+                first_line,
+                second_line: *line,
+            });
+        }
+        by_internal_path.insert(entry.internal_path.clone(), *line);
+    }
+
+    let mut resolved: Vec<ResolvedEntry> = assigned.into_values().map(|(entry, _)| entry).collect();
+    resolved.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.internal_path.cmp(&b.internal_path)));
+    Ok(resolved)
+}
+
+fn walk_source_dir(source_dir: &Path) -> std::io::Result<Vec<(PathBuf, String)>> {
+    let mut out = Vec::new();
+    for dirent in WalkDir::new(source_dir).into_iter().filter_map(std::result::Result::ok) {
+        if !dirent.file_type().is_file() {
+            continue;
+        }
+        let path = dirent.path().to_path_buf();
+        let internal_path = relative_slashed(source_dir, &path);
+        out.push((path, internal_path));
+    }
+    Ok(out)
+}
+
+fn line_of(text: &str, byte_offset: usize) -> usize {
+    text[..byte_offset.min(text.len())].matches('\n').count() + 1
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+use std::io::{self, Read};
+
+/// A null-terminated byte string, as used for entry paths in the pack file
+/// record section.
+pub struct NullString(pub Vec<u8>);
+
+impl NullString {
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        let mut byte = [0u8; 1];
+        loop {
+            reader.read_exact(&mut byte)?;
+            if byte[0] == 0 {
+                break;
+            }
+            bytes.push(byte[0]);
+        }
+        Ok(NullString(bytes))
+    }
+
+    pub fn write<W: std::io::Write>(writer: &mut W, s: &str) -> io::Result<()> {
+        writer.write_all(s.as_bytes())?;
+        writer.write_all(&[0])
+    }
+}
+
+impl fmt::Display for NullString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.0))
+    }
+}
@@ -0,0 +1,55 @@
+use std::io::{self, Read, Seek};
+
+/// A thin wrapper around a `Read + Seek` source that tracks the current
+/// stream position, so callers can report exactly where a malformed file
+/// stopped making sense instead of a bare `UnexpectedEof`.
+pub struct PosReader<R> {
+    inner: R,
+    pos: u64,
+}
+
+impl<R: Read + Seek> PosReader<R> {
+    pub fn new(inner: R) -> Self {
+        PosReader { inner, pos: 0 }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    pub fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.inner.read_exact(buf)?;
+        self.pos += buf.len() as u64;
+        Ok(())
+    }
+
+    pub fn read_u8(&mut self) -> io::Result<u8> {
+        let mut buf = [0u8; 1];
+        self.read_exact(&mut buf)?;
+        Ok(buf[0])
+    }
+
+    pub fn read_u32(&mut self) -> io::Result<u32> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    pub fn read_f32(&mut self) -> io::Result<f32> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf)?;
+        Ok(f32::from_le_bytes(buf))
+    }
+
+    pub fn read_null_string(&mut self) -> io::Result<String> {
+        let mut bytes = Vec::new();
+        loop {
+            let b = self.read_u8()?;
+            if b == 0 {
+                break;
+            }
+            bytes.push(b);
+        }
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
@@ -0,0 +1,103 @@
+//! Coarse throughput/timing accounting shared by `extract` and `verify`, so
+//! both can report the same read/decompress/write breakdown and MB/s
+//! figures, and dump them as `--stats-json` for tracking across versions.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::error::{io_err, Result};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+#[derive(Default)]
+pub struct StatsCollector {
+    files: usize,
+    compressed_bytes: u64,
+    decompressed_bytes: u64,
+    read_time: Duration,
+    decompress_time: Duration,
+    write_time: Duration,
+    peak_mb_per_s: f64,
+}
+
+impl StatsCollector {
+    pub fn record_entry(
+        &mut self,
+        compressed_bytes: u64,
+        decompressed_bytes: u64,
+        read_time: Duration,
+        decompress_time: Duration,
+        write_time: Duration,
+    ) {
+        self.files += 1;
+        self.compressed_bytes += compressed_bytes;
+        self.decompressed_bytes += decompressed_bytes;
+        self.read_time += read_time;
+        self.decompress_time += decompress_time;
+        self.write_time += write_time;
+
+        let entry_time = (read_time + decompress_time + write_time).as_secs_f64();
+        if entry_time > 0.0 {
+            let mb_per_s = decompressed_bytes as f64 / MB / entry_time;
+            self.peak_mb_per_s = self.peak_mb_per_s.max(mb_per_s);
+        }
+    }
+
+    pub fn finish(self, elapsed: Duration) -> Report {
+        let elapsed_secs = elapsed.as_secs_f64();
+        let avg_mb_per_s = if elapsed_secs > 0.0 {
+            self.decompressed_bytes as f64 / MB / elapsed_secs
+        } else {
+            0.0
+        };
+        Report {
+            files: self.files,
+            compressed_bytes: self.compressed_bytes,
+            decompressed_bytes: self.decompressed_bytes,
+            elapsed_secs,
+            read_secs: self.read_time.as_secs_f64(),
+            decompress_secs: self.decompress_time.as_secs_f64(),
+            write_secs: self.write_time.as_secs_f64(),
+            avg_mb_per_s,
+            peak_mb_per_s: self.peak_mb_per_s,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Report {
+    pub files: usize,
+    pub compressed_bytes: u64,
+    pub decompressed_bytes: u64,
+    pub elapsed_secs: f64,
+    pub read_secs: f64,
+    pub decompress_secs: f64,
+    pub write_secs: f64,
+    pub avg_mb_per_s: f64,
+    pub peak_mb_per_s: f64,
+}
+
+impl Report {
+    pub fn print_summary(&self) {
+        println!(
+            "{} files, {:.2} MB compressed read, {:.2} MB decompressed, {:.2}s total ({:.1} MB/s avg, {:.1} MB/s peak)",
+            self.files,
+            self.compressed_bytes as f64 / MB,
+            self.decompressed_bytes as f64 / MB,
+            self.elapsed_secs,
+            self.avg_mb_per_s,
+            self.peak_mb_per_s,
+        );
+        println!(
+            "  read {:.2}s, decompress {:.2}s, write {:.2}s",
+            self.read_secs, self.decompress_secs, self.write_secs
+        );
+    }
+
+    pub fn write_json(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("Report is always serializable");
+        std::fs::write(path, json).map_err(|e| io_err(path, e))
+    }
+}
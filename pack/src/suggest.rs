@@ -0,0 +1,53 @@
+//! "Did you mean" suggestions for a `--filter` glob that matched nothing, so
+//! a typo doesn't silently produce an empty extraction.
+
+use std::path::Path;
+
+/// Suggest up to `limit` entry paths that might be what `pattern` meant to
+/// match: paths containing `pattern`'s literal characters (case-
+/// insensitively) first, then the closest remaining paths by edit distance.
+pub fn suggest<'a>(pattern: &str, paths: impl Iterator<Item = &'a Path>, limit: usize) -> Vec<String> {
+    let paths: Vec<String> = paths.map(|p| p.to_string_lossy().into_owned()).collect();
+    let needle = strip_glob_metacharacters(pattern).to_lowercase();
+
+    let mut suggestions: Vec<String> = if needle.is_empty() {
+        Vec::new()
+    } else {
+        paths.iter().filter(|p| p.to_lowercase().contains(&needle)).take(limit).cloned().collect()
+    };
+
+    if suggestions.len() < limit {
+        let mut by_distance: Vec<(usize, &String)> =
+            paths.iter().filter(|p| !suggestions.contains(p)).map(|p| (edit_distance(pattern, p), p)).collect();
+        by_distance.sort_by_key(|(distance, _)| *distance);
+        for (_, path) in by_distance {
+            if suggestions.len() >= limit {
+                break;
+            }
+            suggestions.push(path.clone());
+        }
+    }
+    suggestions
+}
+
+fn strip_glob_metacharacters(pattern: &str) -> String {
+    pattern.chars().filter(|c| !matches!(c, '*' | '?' | '[' | ']')).collect()
+}
+
+/// Classic Levenshtein distance. Only used to rank a handful of "did you
+/// mean" candidates, so the textbook O(n*m) DP table is plenty.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let temp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] { prev } else { 1 + prev.min(row[j]).min(row[j - 1]) };
+            prev = temp;
+        }
+    }
+    row[b.len()]
+}
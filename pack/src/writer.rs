@@ -0,0 +1,158 @@
+//! Shared pack-writing primitives used by `create`, `delta`, and
+//! `apply-delta`: turn raw file contents into a compressed, chunked
+//! [`PreparedEntry`] and serialize a set of them to a `.pack` file.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::compress::{self, CHUNK_SIZE};
+use crate::error::{io_err, Result};
+use crate::nullstring::NullString;
+use crate::pack::{Entry, Header, MAGIC, VERSION};
+
+pub struct PreparedEntry {
+    pub internal_path: String,
+    pub chunks: Vec<Vec<u8>>,
+    pub compressed: bool,
+    pub size_on_disk: u32,
+}
+
+pub fn prepare_entry(internal_path: String, data: &[u8]) -> PreparedEntry {
+    prepare_entry_with_compression(internal_path, data, true)
+}
+
+/// Like [`prepare_entry`], but lets the caller store `data` uncompressed —
+/// useful for already-compressed formats (textures, audio) that a second
+/// pass of LZO would only slow down.
+pub fn prepare_entry_with_compression(
+    internal_path: String,
+    data: &[u8],
+    compress: bool,
+) -> PreparedEntry {
+    let chunks = if compress {
+        data.chunks(CHUNK_SIZE).map(compress::compress).collect()
+    } else {
+        data.chunks(CHUNK_SIZE).map(|c| c.to_vec()).collect()
+    };
+    PreparedEntry {
+        internal_path,
+        chunks,
+        compressed: compress,
+        size_on_disk: data.len() as u32,
+    }
+}
+
+pub fn write_pack(output: &Path, entries: &[PreparedEntry]) -> Result<()> {
+    let file_section_size: usize = entries
+        .iter()
+        .map(|e| e.internal_path.len() + 1 + 1 + 4 + 4 + 8 + 4 + e.chunks.len() * 4)
+        .sum();
+    let data_section_offset = Header::SIZE + file_section_size;
+
+    let mut file_section = Vec::new();
+    let mut data_section = Vec::new();
+    let mut data_cursor = data_section_offset as u64;
+
+    for entry in entries {
+        NullString::write(&mut file_section, &entry.internal_path).map_err(|e| io_err(output, e))?;
+        file_section.push(entry.compressed as u8);
+
+        let size_in_pack: u32 = entry.chunks.iter().map(|c| c.len() as u32).sum();
+        file_section.extend_from_slice(&entry.size_on_disk.to_le_bytes());
+        file_section.extend_from_slice(&size_in_pack.to_le_bytes());
+        file_section.extend_from_slice(&data_cursor.to_le_bytes());
+        file_section.extend_from_slice(&(entry.chunks.len() as u32).to_le_bytes());
+        for chunk in &entry.chunks {
+            file_section.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+            data_section.extend_from_slice(chunk);
+            data_cursor += chunk.len() as u64;
+        }
+    }
+
+    let header = Header {
+        magic: MAGIC,
+        version: VERSION,
+        entry_count: entries.len() as u32,
+        file_section_size: file_section.len() as u32,
+        data_section_offset: data_section_offset as u32,
+    };
+
+    let mut file = File::create(output).map_err(|e| io_err(output, e))?;
+    file.write_all(&header.magic).map_err(|e| io_err(output, e))?;
+    file.write_all(&header.version.to_le_bytes()).map_err(|e| io_err(output, e))?;
+    file.write_all(&header.entry_count.to_le_bytes()).map_err(|e| io_err(output, e))?;
+    file.write_all(&header.file_section_size.to_le_bytes()).map_err(|e| io_err(output, e))?;
+    file.write_all(&header.data_section_offset.to_le_bytes()).map_err(|e| io_err(output, e))?;
+    file.write_all(&file_section).map_err(|e| io_err(output, e))?;
+    file.write_all(&data_section).map_err(|e| io_err(output, e))?;
+    Ok(())
+}
+
+/// Rewrite only a pack's header and file record section in place, leaving
+/// the data section untouched: used by `rename` (and any future
+/// metadata-only edit) to avoid a full extract + repack. The data section
+/// is copied verbatim and shifted by however many bytes the file record
+/// section grew or shrank by. Written atomically via a temp file in the
+/// same directory, then renamed over `path`.
+pub fn rewrite_metadata(path: &Path, old_data_section_offset: u32, entries: &[Entry]) -> Result<()> {
+    let mut file = File::open(path).map_err(|e| io_err(path, e))?;
+    file.seek(SeekFrom::Start(u64::from(old_data_section_offset)))
+        .map_err(|e| io_err(path, e))?;
+    let mut data_section = Vec::new();
+    file.read_to_end(&mut data_section).map_err(|e| io_err(path, e))?;
+    drop(file);
+
+    let file_section_size: usize = entries
+        .iter()
+        .map(|e| e.path.to_string_lossy().len() + 1 + 1 + 4 + 4 + 8 + 4 + e.chunk_sizes.len() * 4)
+        .sum();
+    let new_data_section_offset = Header::SIZE + file_section_size;
+    let offset_delta = new_data_section_offset as i64 - i64::from(old_data_section_offset);
+
+    let mut file_section = Vec::with_capacity(file_section_size);
+    for entry in entries {
+        NullString::write(&mut file_section, &entry.path.to_string_lossy())
+            .expect("writing to an in-memory buffer cannot fail");
+        file_section.push(entry.compressed as u8);
+        file_section.extend_from_slice(&entry.size_on_disk.to_le_bytes());
+        file_section.extend_from_slice(&entry.size_in_pack.to_le_bytes());
+        let new_offset = (entry.data_offset as i64 + offset_delta) as u64;
+        file_section.extend_from_slice(&new_offset.to_le_bytes());
+        file_section.extend_from_slice(&(entry.chunk_sizes.len() as u32).to_le_bytes());
+        for size in &entry.chunk_sizes {
+            file_section.extend_from_slice(&size.to_le_bytes());
+        }
+    }
+
+    let header = Header {
+        magic: MAGIC,
+        version: VERSION,
+        entry_count: entries.len() as u32,
+        file_section_size: file_section.len() as u32,
+        data_section_offset: new_data_section_offset as u32,
+    };
+
+    let tmp_path = path.with_extension("pack.tmp");
+    let mut tmp = File::create(&tmp_path).map_err(|e| io_err(&tmp_path, e))?;
+    tmp.write_all(&header.magic).map_err(|e| io_err(&tmp_path, e))?;
+    tmp.write_all(&header.version.to_le_bytes()).map_err(|e| io_err(&tmp_path, e))?;
+    tmp.write_all(&header.entry_count.to_le_bytes()).map_err(|e| io_err(&tmp_path, e))?;
+    tmp.write_all(&header.file_section_size.to_le_bytes()).map_err(|e| io_err(&tmp_path, e))?;
+    tmp.write_all(&header.data_section_offset.to_le_bytes()).map_err(|e| io_err(&tmp_path, e))?;
+    tmp.write_all(&file_section).map_err(|e| io_err(&tmp_path, e))?;
+    tmp.write_all(&data_section).map_err(|e| io_err(&tmp_path, e))?;
+    drop(tmp);
+
+    std::fs::rename(&tmp_path, path).map_err(|e| io_err(path, e))
+}
+
+/// Write `entries` to `path` atomically, via a temp file in the same
+/// directory renamed over `path` once it's fully written: used by `add` so
+/// pushing in a couple of files can't leave a half-written pack behind if
+/// it's interrupted. Otherwise identical to [`write_pack`].
+pub fn write_pack_atomic(path: &Path, entries: &[PreparedEntry]) -> Result<()> {
+    let tmp_path = path.with_extension("pack.tmp");
+    write_pack(&tmp_path, entries)?;
+    std::fs::rename(&tmp_path, path).map_err(|e| io_err(path, e))
+}
@@ -0,0 +1,217 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::text::{parse_descr_lines, strip_keyword, DescrLine, ReadOptions};
+use crate::trigger::{self, Trigger};
+use crate::win_conditions::Reference;
+
+/// An `Ancillary` record from `export_descr_ancillaries.txt`: its type, the
+/// portrait/icon image it points at, whether it can be passed to another
+/// character on death, and the ancillaries it can't coexist with.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Ancillary {
+    /// 0-based position among successfully parsed ancillaries, in file order.
+    pub id: usize,
+    /// 1-based line the `Ancillary` header started on.
+    pub line_number: usize,
+    pub name: String,
+    pub kind: Option<String>,
+    pub transferable: bool,
+    pub image: Option<String>,
+    /// 1-based line the `Image` line started on, for pointing `validate`
+    /// findings at the right place when the image doesn't exist.
+    pub image_line: Option<usize>,
+    /// `Effect ATTRIBUTE VALUE` lines, kept verbatim (e.g. `"Command 1"") --
+    /// same convention as `traits::TraitLevel::effects`.
+    pub effects: Vec<String>,
+    pub excluded_ancillaries: Vec<Reference>,
+}
+
+/// A recoverable problem hit while parsing `export_descr_ancillaries.txt`,
+/// same recovery strategy as [`crate::traits::TraitProblem`]: the offending
+/// line is skipped and logged here instead of failing the whole file.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AncillaryProblem {
+    pub line_number: usize,
+    pub message: String,
+}
+
+/// The parsed contents of `export_descr_ancillaries.txt`: every `Ancillary`
+/// and `Trigger` record that parsed cleanly, plus a log of anything that
+/// didn't (see [`AncillaryProblem`]).
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct AncillariesFile {
+    pub ancillaries: Vec<Ancillary>,
+    pub triggers: Vec<Trigger>,
+    pub problems: Vec<AncillaryProblem>,
+}
+
+impl AncillariesFile {
+    pub fn load(_path: &Path, text: &str) -> Result<AncillariesFile> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        Ok(parse(&lines))
+    }
+}
+
+enum Current {
+    Ancillary(Ancillary),
+    Trigger(Trigger),
+}
+
+fn parse(lines: &[DescrLine]) -> AncillariesFile {
+    let mut file = AncillariesFile::default();
+    let mut current: Option<Current> = None;
+
+    for line in lines {
+        let text = line.text.as_str();
+
+        if let Some(rest) = strip_keyword(text, "Ancillary") {
+            finish(&mut current, &mut file);
+            current = Some(Current::Ancillary(Ancillary {
+                id: 0,
+                line_number: line.line_number,
+                name: rest.trim().to_string(),
+                kind: None,
+                transferable: false,
+                image: None,
+                image_line: None,
+                effects: Vec::new(),
+                excluded_ancillaries: Vec::new(),
+            }));
+            continue;
+        }
+        if let Some(trigger) = trigger::try_start_trigger(text, line.line_number) {
+            finish(&mut current, &mut file);
+            current = Some(Current::Trigger(trigger));
+            continue;
+        }
+
+        match &mut current {
+            Some(Current::Ancillary(a)) => {
+                if let Some(rest) = strip_keyword(text, "Type") {
+                    a.kind = Some(rest.trim().to_string());
+                } else if text.trim() == "Transferable" {
+                    a.transferable = true;
+                } else if text.trim() == "NotTransferable" {
+                    a.transferable = false;
+                } else if let Some(rest) = strip_keyword(text, "Image") {
+                    a.image = Some(rest.trim().to_string());
+                    a.image_line = Some(line.line_number);
+                } else if let Some(rest) = strip_keyword(text, "Effect") {
+                    a.effects.push(rest.trim().to_string());
+                } else if let Some(rest) = strip_keyword(text, "ExcludedAncillaries") {
+                    a.excluded_ancillaries = split_list(rest, line.line_number);
+                } else {
+                    file.problems.push(AncillaryProblem {
+                        line_number: line.line_number,
+                        message: format!("unrecognized line {text:?} in ancillary {:?}", a.name),
+                    });
+                }
+            }
+            Some(Current::Trigger(trigger)) => {
+                if let Err(message) = trigger::parse_trigger_line(trigger, text, line.line_number) {
+                    file.problems.push(AncillaryProblem { line_number: line.line_number, message });
+                }
+            }
+            None => {
+                file.problems.push(AncillaryProblem {
+                    line_number: line.line_number,
+                    message: format!("line {text:?} appears before any `Ancillary` or `Trigger`"),
+                });
+            }
+        }
+    }
+    finish(&mut current, &mut file);
+
+    for (id, a) in file.ancillaries.iter_mut().enumerate() {
+        a.id = id;
+    }
+    for (id, trigger) in file.triggers.iter_mut().enumerate() {
+        trigger.id = id;
+    }
+
+    file
+}
+
+fn finish(current: &mut Option<Current>, file: &mut AncillariesFile) {
+    match current.take() {
+        Some(Current::Ancillary(a)) => file.ancillaries.push(a),
+        Some(Current::Trigger(trigger)) => file.triggers.push(trigger),
+        None => {}
+    }
+}
+
+fn split_list(rest: &str, line_number: usize) -> Vec<Reference> {
+    rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(|name| Reference { line_number, name: name.to_string() }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn parse_text(text: &str) -> AncillariesFile {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("export_descr_ancillaries_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        parse(&lines)
+    }
+
+    #[test]
+    fn parses_ancillary_and_trigger() {
+        let file = parse_text(
+            r#"
+Ancillary bodyguard
+Type personal
+Transferable
+Image ancillaries/bodyguard.tga
+Effect Command 1
+ExcludedAncillaries turncoat, coward
+
+Trigger GetBodyguardTrigger
+WhenToTest CharacterTurnEnd
+Condition Attribute Loyalty > 5
+Affects bodyguard 0 Chance 25
+"#,
+        );
+
+        assert_eq!(file.problems, Vec::<AncillaryProblem>::new(), "{:?}", file.problems);
+        assert_eq!(file.ancillaries.len(), 1);
+        let a = &file.ancillaries[0];
+        assert_eq!(a.name, "bodyguard");
+        assert_eq!(a.kind.as_deref(), Some("personal"));
+        assert!(a.transferable);
+        assert_eq!(a.image.as_deref(), Some("ancillaries/bodyguard.tga"));
+        assert_eq!(a.effects, vec!["Command 1"]);
+        assert_eq!(a.excluded_ancillaries.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(), vec!["turncoat", "coward"]);
+
+        assert_eq!(file.triggers.len(), 1);
+        assert_eq!(file.triggers[0].affects[0].target, "bodyguard");
+    }
+
+    #[test]
+    fn missing_blank_line_between_ancillaries_still_splits_them() {
+        let file = parse_text("Ancillary First\nType personal\nAncillary Second\nType personal\n");
+        assert_eq!(file.problems, Vec::<AncillaryProblem>::new(), "{:?}", file.problems);
+        assert_eq!(file.ancillaries.len(), 2);
+        assert_eq!(file.ancillaries[0].name, "First");
+        assert_eq!(file.ancillaries[1].name, "Second");
+    }
+
+    #[test]
+    fn malformed_line_becomes_a_problem_instead_of_failing_the_whole_file() {
+        let file = parse_text("Ancillary First\nType personal\nNotARealKeyword\nEffect Command 1\n");
+        assert_eq!(file.ancillaries.len(), 1);
+        assert_eq!(file.ancillaries[0].effects, vec!["Command 1"]);
+        assert_eq!(file.problems.len(), 1);
+        assert!(file.problems[0].message.contains("unrecognized line"), "{:?}", file.problems[0]);
+    }
+}
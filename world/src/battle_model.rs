@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A `model`-prefixed mesh entry inside a battle model record (`model`,
+/// `model_flexi`, `model_flexi_weapon`, ...), kept as its own entry instead
+/// of folded into `extra` so [`crate::validate::validate`] can check every
+/// one of them against the data directory. The per-LOD distance that
+/// follows the path on `model_flexi` lines isn't kept -- nothing in this
+/// codebase needs it yet.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct ModelPath {
+    /// 1-based line this entry was declared on.
+    pub line_number: usize,
+    pub key: String,
+    pub path: String,
+}
+
+/// A `texture faction[, faction...], path` line, naming which factions a
+/// texture applies to so a missing file can be reported as "affects
+/// england, france" rather than just a bare path.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct FactionTexture {
+    /// 1-based line this entry was declared on.
+    pub line_number: usize,
+    pub factions: Vec<String>,
+    pub path: String,
+}
+
+/// A soldier model record from `descr_model_battle.txt`, referenced by name
+/// from an `export_descr_unit.txt` unit's `soldier` line (see
+/// [`crate::unit::Unit::soldier_model`]). Records are separated by blank
+/// lines, each starting with a `type NAME` line, same layout as
+/// [`crate::projectile::Projectile`].
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct BattleModel {
+    /// 0-based position in the file, stable for a given file so other
+    /// loaded data (and external tooling) can reference a battle model by
+    /// id instead of by name.
+    pub id: usize,
+    /// 1-based line the `type NAME` line started on.
+    pub line_number: usize,
+    pub name: String,
+    /// Entries from `skeleton` lines, kept verbatim. Not cross-checked
+    /// against the skeletons archive -- this codebase has no parser for
+    /// that format yet.
+    pub skeletons: Vec<String>,
+    pub model_paths: Vec<ModelPath>,
+    pub textures: Vec<FactionTexture>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+    /// The line each recognized or `extra` key was set from, keyed by field
+    /// name, same convention as [`crate::faction::Faction::field_lines`].
+    pub field_lines: BTreeMap<String, usize>,
+}
+
+impl BattleModel {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<BattleModel>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut models = Vec::new();
+        for block in split_blocks(&lines) {
+            models.push(parse_record(path, &block)?);
+        }
+
+        for (id, model) in models.iter_mut().enumerate() {
+            model.id = id;
+        }
+
+        Ok(models)
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank lines, same
+/// as [`crate::unit::split_blocks`].
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<BattleModel> {
+    let name_line = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let name = name_line
+        .text
+        .strip_prefix("type ")
+        .map(str::trim)
+        .ok_or_else(|| err(name_line, format!("expected a `type` line, found {:?}", name_line.text)))?
+        .to_string();
+
+    let mut skeletons = Vec::new();
+    let mut model_paths = Vec::new();
+    let mut textures = Vec::new();
+    let mut extra = BTreeMap::new();
+    let mut field_lines = BTreeMap::new();
+
+    for line in &block[1..] {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let rest = line.text[key.len()..].trim().to_string();
+        field_lines.insert(key.to_string(), line.line_number);
+
+        match key {
+            "skeleton" => skeletons.extend(rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)),
+            "texture" => {
+                let mut fields: Vec<&str> = rest.split(',').map(str::trim).collect();
+                let texture_path = fields.pop().ok_or_else(|| err(line, "expected a `texture` line to end with a path".to_string()))?;
+                if texture_path.is_empty() {
+                    return Err(err(line, "expected a `texture` line to end with a path".to_string()));
+                }
+                textures.push(FactionTexture {
+                    line_number: line.line_number,
+                    factions: fields.into_iter().map(str::to_string).collect(),
+                    path: texture_path.to_string(),
+                });
+            }
+            _ if key.starts_with("model") => {
+                let model_path = rest.split(',').next().unwrap_or("").trim().to_string();
+                model_paths.push(ModelPath { line_number: line.line_number, key: key.to_string(), path: model_path });
+            }
+            _ => {
+                extra.insert(key.to_string(), rest);
+            }
+        }
+    }
+
+    Ok(BattleModel { id: 0, line_number: name_line.line_number, name, skeletons, model_paths, textures, extra, field_lines })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn models_from(text: &str) -> Vec<BattleModel> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_model_battle_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut models = Vec::new();
+        for block in split_blocks(&lines) {
+            models.push(parse_record(&path, &block).unwrap());
+        }
+        models
+    }
+
+    #[test]
+    fn parses_name_skeletons_models_and_textures() {
+        let models = models_from(
+            r#"
+type early_byzantine_infantry
+skeleton fs_spearman, fs_fastspearman
+indiv_range 40
+model_flexi data/models_unit/unit_infantry_high.cas, 30
+model_flexi data/models_unit/unit_infantry_low.cas, 200
+texture byzantium, data/models_unit/textures/byzantine_infantry.tga
+texture england, france, data/models_unit/textures/western_infantry.tga
+
+type spearmen_militia
+skeleton fs_spearman
+"#,
+        );
+        assert_eq!(models.len(), 2);
+
+        let byzantine = &models[0];
+        assert_eq!(byzantine.name, "early_byzantine_infantry");
+        assert_eq!(byzantine.skeletons, vec!["fs_spearman", "fs_fastspearman"]);
+        assert_eq!(byzantine.model_paths.len(), 2);
+        assert_eq!(byzantine.model_paths[0].key, "model_flexi");
+        assert_eq!(byzantine.model_paths[0].path, "data/models_unit/unit_infantry_high.cas");
+        assert_eq!(byzantine.textures.len(), 2);
+        assert_eq!(byzantine.textures[0].factions, vec!["byzantium"]);
+        assert_eq!(byzantine.textures[0].path, "data/models_unit/textures/byzantine_infantry.tga");
+        assert_eq!(byzantine.textures[1].factions, vec!["england", "france"]);
+        assert_eq!(byzantine.extra.get("indiv_range"), Some(&"40".to_string()));
+
+        let militia = &models[1];
+        assert_eq!(militia.name, "spearmen_militia");
+        assert!(militia.model_paths.is_empty());
+        assert!(militia.textures.is_empty());
+    }
+
+    #[test]
+    fn missing_type_line_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_model_battle_test_{id}.txt"));
+        std::fs::write(&path, "skeleton fs_spearman\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("expected a `type` line"), "{err}");
+    }
+
+    #[test]
+    fn texture_line_without_a_path_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_model_battle_test_{id}.txt"));
+        std::fs::write(&path, "type arrow\ntexture\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("expected a `texture` line"), "{err}");
+    }
+}
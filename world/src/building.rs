@@ -0,0 +1,455 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A building chain from `export_descr_buildings.txt`: a name and an
+/// ordered list of upgrade levels (e.g. `hovel` -> `village` -> `town`).
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Building {
+    pub name: String,
+    /// 1-based line the `building` keyword started on.
+    pub line_number: usize,
+    pub levels: Vec<BuildingLevel>,
+    /// Keyed lines directly inside the `building { ... }` block that aren't
+    /// a level (e.g. SS's `plugin`/`tags` lines).
+    pub extra: BTreeMap<String, String>,
+}
+
+/// One upgrade level of a `Building`.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct BuildingLevel {
+    pub name: String,
+    /// 1-based line the level's header (`name [requires ...]`) started on.
+    pub line_number: usize,
+    /// The raw `requires` expression gating this level's header, if any
+    /// (e.g. `factions { all }`). Not parsed further: the expression
+    /// grammar (`and`/`or`, event counters, ...) is out of scope here.
+    pub requires: Option<String>,
+    pub construction: Option<u32>,
+    pub cost: Option<u32>,
+    pub capabilities: Vec<Capability>,
+    pub recruitment: Vec<RecruitmentEntry>,
+    /// Keyed lines inside the level block that aren't `construction`,
+    /// `cost`, `capability`, or `recruit_pool` (e.g. `material`,
+    /// `settlement_min`).
+    pub extra: BTreeMap<String, String>,
+}
+
+/// One line inside a level's `capability { ... }` block.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Capability {
+    pub line_number: usize,
+    pub kind: String,
+    pub value: String,
+    pub requires: Option<String>,
+}
+
+/// One `recruit_pool` line inside a level block.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct RecruitmentEntry {
+    pub line_number: usize,
+    pub unit: String,
+    /// The raw pool parameters (spawn value, replenishment rate, ...),
+    /// exactly as written, since their meaning depends on the unit and
+    /// isn't needed for EDB<->EDU cross-checks.
+    pub params: String,
+    pub requires: Option<String>,
+}
+
+impl Building {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Building>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(path, &lines)
+    }
+}
+
+fn parse(path: &Path, lines: &[&DescrLine]) -> Result<Vec<Building>> {
+    let mut buildings = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].text.trim() == "building" {
+            let building_line = lines[i].line_number;
+            let (open, close) = find_block(path, lines, i)?;
+            buildings.push(parse_building(path, building_line, &lines[open + 1..close])?);
+            i = close + 1;
+        } else {
+            i += 1;
+        }
+    }
+    Ok(buildings)
+}
+
+fn parse_building(path: &Path, line_number: usize, body: &[&DescrLine]) -> Result<Building> {
+    let mut name = None;
+    let mut level_names = Vec::new();
+    let mut levels = Vec::new();
+    let mut extra = BTreeMap::new();
+
+    let mut i = 0;
+    while i < body.len() {
+        let line = body[i];
+        let text = line.text.as_str();
+
+        if let Some(rest) = text.strip_prefix("levels ") {
+            level_names = rest.split_whitespace().map(str::to_string).collect();
+            i += 1;
+            continue;
+        }
+
+        let mut parts = text.split_whitespace();
+        let Some(first) = parts.next() else {
+            i += 1;
+            continue;
+        };
+
+        if level_names.iter().any(|n| n == first) {
+            if name.is_none() {
+                name = Some(first.to_string());
+            }
+            let rest = text[first.len()..].trim();
+            let (_, requires) = split_requires(rest);
+            let (open, close) = find_block(path, body, i)?;
+            levels.push(parse_level(path, first.to_string(), line.line_number, requires, &body[open + 1..close])?);
+            i = close + 1;
+            continue;
+        }
+
+        // A stray keyed line directly in the building block (SS's `plugin`
+        // and `tags` lines, or anything else this loader doesn't know
+        // about yet).
+        let value = parts.collect::<Vec<_>>().join(" ");
+        extra.insert(first.to_string(), value);
+        i += 1;
+    }
+
+    let name = name.unwrap_or_else(|| level_names.first().cloned().unwrap_or_default());
+    if name.is_empty() {
+        return Err(ParseError {
+            file: path.to_path_buf(),
+            line_number,
+            line_text: String::new(),
+            message: "building block has no levels to name it after".to_string(),
+        }
+        .into());
+    }
+
+    Ok(Building { name, line_number, levels, extra })
+}
+
+fn parse_level(path: &Path, name: String, line_number: usize, requires: Option<String>, body: &[&DescrLine]) -> Result<BuildingLevel> {
+    let mut construction = None;
+    let mut cost = None;
+    let mut capabilities = Vec::new();
+    let mut recruitment = Vec::new();
+    let mut extra = BTreeMap::new();
+
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut i = 0;
+    while i < body.len() {
+        let line = body[i];
+        let text = line.text.as_str();
+
+        if text.trim() == "capability" {
+            let (open, close) = find_block(path, body, i)?;
+            for inner in &body[open + 1..close] {
+                capabilities.push(parse_capability(inner));
+            }
+            i = close + 1;
+            continue;
+        }
+
+        if let Some(rest) = text.strip_prefix("recruit_pool") {
+            recruitment.push(parse_recruitment(path, line, rest.trim())?);
+            i += 1;
+            continue;
+        }
+
+        let mut parts = text.split_whitespace();
+        let Some(key) = parts.next() else {
+            i += 1;
+            continue;
+        };
+        let value = parts.collect::<Vec<_>>().join(" ");
+
+        match key {
+            "construction" => construction = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid construction time")))?),
+            "cost" => cost = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid cost")))?),
+            _ => {
+                extra.insert(key.to_string(), value);
+            }
+        }
+        i += 1;
+    }
+
+    Ok(BuildingLevel { name, line_number, requires, construction, cost, capabilities, recruitment, extra })
+}
+
+fn parse_capability(line: &DescrLine) -> Capability {
+    let (value, requires) = split_requires(&line.text);
+    let mut parts = value.splitn(2, char::is_whitespace);
+    let kind = parts.next().unwrap_or_default().to_string();
+    let value = parts.next().unwrap_or_default().trim().to_string();
+    Capability { line_number: line.line_number, kind, value, requires }
+}
+
+fn parse_recruitment(path: &Path, line: &DescrLine, rest: &str) -> Result<RecruitmentEntry> {
+    let (pool, requires) = split_requires(rest);
+    let pool = pool.trim();
+    let mut quotes = pool.match_indices('"');
+    let (Some((start, _)), Some((end, _))) = (quotes.next(), quotes.next()) else {
+        return Err(ParseError {
+            file: path.to_path_buf(),
+            line_number: line.line_number,
+            line_text: line.text.clone(),
+            message: "recruit_pool is missing a quoted unit name".to_string(),
+        }
+        .into());
+    };
+    let unit = pool[start + 1..end].to_string();
+    let params = pool[end + 1..].trim().to_string();
+    Ok(RecruitmentEntry { line_number: line.line_number, unit, params, requires })
+}
+
+/// Splits `text` into its value and an optional trailing `requires`
+/// expression. Handles both `KIND VALUE requires EXPR` and a bare
+/// `requires EXPR` (the level-header form, where there's no value before
+/// it). `pub(crate)` so [`crate::edb`] can reuse the same split when
+/// rewriting a level header's `requires` clause.
+pub(crate) fn split_requires(text: &str) -> (String, Option<String>) {
+    let trimmed = text.trim();
+    if let Some(rest) = trimmed.strip_prefix("requires ") {
+        return (String::new(), Some(rest.trim().to_string()));
+    }
+    match trimmed.find(" requires ") {
+        Some(idx) => (trimmed[..idx].trim().to_string(), Some(trimmed[idx + " requires ".len()..].trim().to_string())),
+        None => (trimmed.to_string(), None),
+    }
+}
+
+/// Finds the `{ ... }` block starting at or after `lines[start]`, returning
+/// the index of the line holding the (first) unmatched opening brace and
+/// the index of the line holding its matching closing brace. Braces that
+/// balance within a single line (e.g. `requires factions { all }`) don't
+/// count as the block's own delimiters, so a `requires` expression on a
+/// level's header line doesn't get mistaken for that level's body.
+fn find_block(path: &Path, lines: &[&DescrLine], start: usize) -> Result<(usize, usize)> {
+    let mut open_at = start;
+    loop {
+        if open_at >= lines.len() {
+            return Err(ParseError {
+                file: path.to_path_buf(),
+                line_number: lines[start].line_number,
+                line_text: lines[start].text.clone(),
+                message: "block is missing its opening `{`".to_string(),
+            }
+            .into());
+        }
+        let opens = lines[open_at].text.matches('{').count();
+        let closes = lines[open_at].text.matches('}').count();
+        if opens > closes {
+            break;
+        }
+        open_at += 1;
+    }
+
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(open_at) {
+        let opens = line.text.matches('{').count() as i32;
+        let closes = line.text.matches('}').count() as i32;
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((open_at, idx));
+        }
+    }
+
+    Err(ParseError {
+        file: path.to_path_buf(),
+        line_number: lines[open_at].line_number,
+        line_text: lines[open_at].text.clone(),
+        message: "block is missing its closing `}`".to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn buildings_from(text: &str) -> Vec<Building> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("export_descr_buildings_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(Path::new("export_descr_buildings.txt"), &lines).unwrap()
+    }
+
+    fn parse_err(text: &str) -> String {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("export_descr_buildings_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(Path::new("export_descr_buildings.txt"), &lines).unwrap_err().to_string()
+    }
+
+    const VANILLA_EXCERPT: &str = r#"
+building
+{
+    levels tavern town_watch
+    tavern  requires factions { all }
+    {
+        capability
+        {
+            law_bonus 1
+            crime -1
+        }
+        material wood
+        construction 1
+        cost 600
+        settlement_min village
+    }
+    town_watch requires factions { all }
+    {
+        capability
+        {
+            law_bonus 2
+        }
+        recruit_pool "Sergeant Spearmen" 1 0.05 2 1 requires factions { northern_european, }
+        material wood
+        construction 2
+        cost 1300
+        settlement_min town
+    }
+}
+"#;
+
+    #[test]
+    fn vanilla_excerpt_produces_two_levels() {
+        let buildings = buildings_from(VANILLA_EXCERPT);
+        assert_eq!(buildings.len(), 1);
+        let building = &buildings[0];
+        assert_eq!(building.name, "tavern");
+        assert!(building.extra.is_empty());
+        assert_eq!(building.levels.len(), 2);
+
+        let tavern = &building.levels[0];
+        assert_eq!(tavern.name, "tavern");
+        assert_eq!(tavern.requires.as_deref(), Some("factions { all }"));
+        assert_eq!(tavern.construction, Some(1));
+        assert_eq!(tavern.cost, Some(600));
+        assert_eq!(tavern.extra.get("material"), Some(&"wood".to_string()));
+        assert_eq!(tavern.extra.get("settlement_min"), Some(&"village".to_string()));
+        assert_eq!(tavern.capabilities.len(), 2);
+        assert_eq!(tavern.capabilities[0].kind, "law_bonus");
+        assert_eq!(tavern.capabilities[0].value, "1");
+        assert_eq!(tavern.capabilities[0].requires, None);
+        assert_eq!(tavern.capabilities[1].kind, "crime");
+        assert_eq!(tavern.capabilities[1].value, "-1");
+        assert!(tavern.recruitment.is_empty());
+
+        let town_watch = &building.levels[1];
+        assert_eq!(town_watch.name, "town_watch");
+        assert_eq!(town_watch.recruitment.len(), 1);
+        let recruit = &town_watch.recruitment[0];
+        assert_eq!(recruit.unit, "Sergeant Spearmen");
+        assert_eq!(recruit.params, "1 0.05 2 1");
+        assert_eq!(recruit.requires.as_deref(), Some("factions { northern_european, }"));
+    }
+
+    const SS_EXCERPT: &str = r#"
+building
+{
+    plugin barracks_1
+    tags custom_tag
+    levels barracks_1 barracks_2
+    barracks_1 requires factions { all }, not event_counter is_playable_start_pos 1  ; SS-only gate
+    {
+        capability
+        {
+            law_bonus 1
+        }
+        material wood
+        construction 1
+        cost 300
+        settlement_min village
+    }
+    barracks_2 requires factions { all }
+    {
+        capability
+        {
+            law_bonus 2 requires factions { scotland, }
+        }
+        recruit_pool "Militia Sergeants" 1 0.07 3 1 requires factions { northern_european, }
+        recruit_pool "Peasants" 1 0.05 1 1
+        material wood
+        construction 2
+        cost 800
+        settlement_min town
+    }
+}
+"#;
+
+    #[test]
+    fn ss_excerpt_handles_plugin_tags_noise_and_multi_clause_requires() {
+        let buildings = buildings_from(SS_EXCERPT);
+        assert_eq!(buildings.len(), 1);
+        let building = &buildings[0];
+        assert_eq!(building.name, "barracks_1");
+        assert_eq!(building.extra.get("plugin"), Some(&"barracks_1".to_string()));
+        assert_eq!(building.extra.get("tags"), Some(&"custom_tag".to_string()));
+        assert_eq!(building.levels.len(), 2);
+
+        let barracks_1 = &building.levels[0];
+        assert_eq!(barracks_1.requires.as_deref(), Some("factions { all }, not event_counter is_playable_start_pos 1"));
+
+        let barracks_2 = &building.levels[1];
+        assert_eq!(barracks_2.capabilities.len(), 1);
+        assert_eq!(barracks_2.capabilities[0].kind, "law_bonus");
+        assert_eq!(barracks_2.capabilities[0].value, "2");
+        assert_eq!(barracks_2.capabilities[0].requires.as_deref(), Some("factions { scotland, }"));
+        assert_eq!(barracks_2.recruitment.len(), 2);
+        assert_eq!(barracks_2.recruitment[0].unit, "Militia Sergeants");
+        assert_eq!(barracks_2.recruitment[0].requires.as_deref(), Some("factions { northern_european, }"));
+        assert_eq!(barracks_2.recruitment[1].unit, "Peasants");
+        assert_eq!(barracks_2.recruitment[1].requires, None);
+    }
+
+    #[test]
+    fn multiple_buildings_in_one_file_are_all_parsed() {
+        let text = format!("{VANILLA_EXCERPT}\n{SS_EXCERPT}");
+        let buildings = buildings_from(&text);
+        assert_eq!(buildings.len(), 2);
+        assert_eq!(buildings[0].name, "tavern");
+        assert_eq!(buildings[1].name, "barracks_1");
+    }
+
+    #[test]
+    fn missing_closing_brace_is_rejected() {
+        let text = r#"
+building
+{
+    levels tavern
+    tavern requires factions { all }
+    {
+        cost 600
+"#;
+        let message = parse_err(text);
+        assert!(message.contains("missing its closing `}`"), "{message}");
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::error::{io_err, Result};
+use crate::heightmap::HeightMap;
+use crate::localization::LOCALIZATION_FILES;
+use crate::world::{World, CAMPAIGN_DATA_GROUPS, CAMPAIGN_HEADER_GROUPS, OPTIONAL_ASSET_NAMES, OPTIONAL_FILE_NAMES};
+
+/// Bumped whenever `World`'s shape changes in a way that would make an old
+/// cache file deserialize into something wrong instead of failing cleanly,
+/// or whenever the fingerprint's own shape changes (as when the candidate
+/// universe it covers grows or its keying scheme changes).
+const CACHE_VERSION: u32 = 2;
+
+const CACHE_FILE_NAME: &str = ".world-cache.bin";
+
+/// Cheap stand-in for a source file's content: not a hash, since reading
+/// every file to hash it would defeat the point of caching, just enough to
+/// notice "this file was touched since the cache was written".
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FileStamp {
+    len: u64,
+    modified: u64,
+}
+
+#[derive(Serialize)]
+struct CacheFileOut<'a> {
+    version: u32,
+    fingerprint: &'a BTreeMap<String, Option<FileStamp>>,
+    world: &'a World,
+}
+
+#[derive(Deserialize)]
+struct CacheFileIn {
+    version: u32,
+    fingerprint: BTreeMap<String, Option<FileStamp>>,
+    world: World,
+}
+
+/// Loads a `World`, transparently caching the parsed result next to
+/// `config.data_dir` so a repeated load of an unchanged mod skips straight
+/// past the text parsing. A missing, corrupt, wrong-version, or stale cache
+/// is just a cache miss, not an error -- this always falls back to a plain
+/// `World::load` rather than surfacing anything to the caller.
+///
+/// Opt-in via `--cache`, since a stale cache silently serving the wrong data
+/// is worse than always reparsing for the modding workflow (edit mod files,
+/// rerun) this tool mostly exists for. Also skipped entirely if
+/// `config.packs` is set, since pack-sourced files have synthetic
+/// `origin:relative` paths with no real filesystem mtime to fingerprint.
+pub fn load(config: &Config) -> Result<World> {
+    if !config.cache || config.packs.is_some() {
+        return World::load(config);
+    }
+
+    let cache_path = cache_path(config);
+    if let Some(mut world) = try_read(&cache_path, config) {
+        // `HeightMap.heights` is `#[serde(skip)]` to keep JSON dumps small,
+        // so a cache hit deserializes it empty. Reloading it is cheap
+        // binary I/O, not the text parsing the cache exists to avoid.
+        refresh_heightmap(config, &mut world)?;
+        return Ok(world);
+    }
+
+    let world = World::load(config)?;
+    write(&cache_path, config, &world);
+    Ok(world)
+}
+
+/// Deletes the cache file for `world cache clear`. An already-absent file
+/// counts as success, since either way there's no cache left afterward.
+pub fn clear(config: &Config) -> Result<()> {
+    let path = cache_path(config);
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(io_err(&path, e)),
+    }
+}
+
+fn cache_path(config: &Config) -> PathBuf {
+    config.data_dir.join(CACHE_FILE_NAME)
+}
+
+/// Reads and validates a cache file, returning `None` for anything that
+/// means "just reload normally": absent, corrupt, wrong version, or a
+/// current fingerprint that doesn't match the one the cache was written
+/// with.
+fn try_read(path: &Path, config: &Config) -> Option<World> {
+    let bytes = fs::read(path).ok()?;
+    let cache: CacheFileIn = bincode::deserialize(&bytes).ok()?;
+    if cache.version != CACHE_VERSION {
+        return None;
+    }
+    if compute_fingerprint(config) != cache.fingerprint {
+        return None;
+    }
+    Some(cache.world)
+}
+
+/// Writes a cache file fingerprinting the full candidate universe
+/// `World::load` could possibly have read from, per `compute_fingerprint`.
+/// Failure to write (e.g. a read-only data directory) is silently ignored
+/// -- caching is an optimization, not something a load should fail over.
+fn write(path: &Path, config: &Config, world: &World) {
+    let fingerprint = compute_fingerprint(config);
+    let cache = CacheFileOut { version: CACHE_VERSION, fingerprint: &fingerprint, world };
+    if let Ok(bytes) = bincode::serialize(&cache) {
+        let _ = fs::write(path, bytes);
+    }
+}
+
+/// Fingerprints every file name `World::load` might read, at every
+/// directory it could come from -- not just whichever file actually won the
+/// override race last time. This is what lets a cache hit notice a new mod
+/// override file appearing at a higher-priority location, or a previously
+/// absent optional file (`campaign_script.txt`, `descr_missions.txt`, ...)
+/// being created: both change a candidate's stamp from `None` to `Some(..)`
+/// (or vice versa) even though `world.sources` from the last load never
+/// mentioned that path. Keyed by `"mod:{name}"`/`"base:{name}"`/
+/// `"locale:{name}"` so the same file name checked at different override
+/// locations doesn't collide.
+///
+/// `config.packs` is never included here -- `load` skips caching entirely
+/// whenever packs are in play, so there's nothing to fingerprint for them.
+fn compute_fingerprint(config: &Config) -> BTreeMap<String, Option<FileStamp>> {
+    let mut fingerprint = BTreeMap::new();
+
+    for group in CAMPAIGN_HEADER_GROUPS.iter().chain(CAMPAIGN_DATA_GROUPS) {
+        for &name in *group {
+            add_override_candidates(config, name, &mut fingerprint);
+        }
+    }
+    for &name in OPTIONAL_FILE_NAMES {
+        add_override_candidates(config, name, &mut fingerprint);
+    }
+    for &name in OPTIONAL_ASSET_NAMES {
+        add_override_candidates(config, name, &mut fingerprint);
+    }
+    let locale_dir = config.locale_dir.as_deref().unwrap_or(&config.data_dir);
+    let text_dir = locale_dir.join("text");
+    for &name in LOCALIZATION_FILES {
+        fingerprint.insert(format!("locale:{name}"), fingerprint_path(&text_dir.join(name)));
+    }
+
+    fingerprint
+}
+
+/// Fingerprints `name` at every directory [`Config::resolve`] and
+/// [`Config::read_data`] would check it at (`mod_dir`, then `data_dir`),
+/// covering both kinds of override lookup `World::load` uses -- neither
+/// consults anything beyond those two directories for fingerprinting
+/// purposes, since `packs` is excluded from caching altogether.
+fn add_override_candidates(config: &Config, name: &str, out: &mut BTreeMap<String, Option<FileStamp>>) {
+    if let Some(mod_dir) = &config.mod_dir {
+        out.insert(format!("mod:{name}"), fingerprint_path(&mod_dir.join(name)));
+    }
+    out.insert(format!("base:{name}"), fingerprint_path(&config.data_dir.join(name)));
+}
+
+fn fingerprint_path(path: &Path) -> Option<FileStamp> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(FileStamp { len: metadata.len(), modified })
+}
+
+/// Reloads the heightmap directly from disk, bypassing the cache's
+/// (necessarily empty) deserialized field.
+fn refresh_heightmap(config: &Config, world: &mut World) -> Result<()> {
+    let hgt = config.resolve(Path::new("map_heights.hgt"));
+    let tga = config.resolve(Path::new("map_heights.tga"));
+    world.heightmap = HeightMap::try_load(&hgt.path, &tga.path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_fixture(dir: &Path) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join("descr_sm_factions.txt"), "faction england\n").unwrap();
+        fs::write(dir.join("descr_regions.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_strat.txt"), "\n").unwrap();
+        fs::write(dir.join("export_descr_unit.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_cultures.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_religions.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_terrain.txt"), "\n").unwrap();
+        fs::write(dir.join("export_descr_buildings.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_walls.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_names.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_mercenaries.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_rebel_factions.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_win_conditions.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_events.txt"), "\n").unwrap();
+        fs::write(dir.join("export_descr_character_traits.txt"), "\n").unwrap();
+        fs::write(dir.join("export_descr_ancillaries.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_projectile.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_mount.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_model_battle.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_sm_resources.txt"), "\n").unwrap();
+        fs::write(dir.join("descr_campaign_db.xml"), "\n").unwrap();
+
+        let text_dir = dir.join("text");
+        fs::create_dir_all(&text_dir).unwrap();
+        fs::write(text_dir.join("expanded.txt"), "\n").unwrap();
+        fs::write(text_dir.join("export_units.txt"), "\n").unwrap();
+        fs::write(text_dir.join("export_buildings.txt"), "\n").unwrap();
+        fs::write(text_dir.join("imperial_campaign_regions_and_settlement_names.txt"), "\n").unwrap();
+    }
+
+    fn base_config(data_dir: PathBuf, mod_dir: Option<PathBuf>) -> Config {
+        Config { data_dir, mod_dir, locale_dir: None, packs: None, cache: true, debug_timing: false }
+    }
+
+    #[test]
+    fn fingerprint_is_stable_when_nothing_changes() {
+        let dir = std::env::temp_dir().join("world_cache_stable_test");
+        fs::remove_dir_all(&dir).ok();
+        write_fixture(&dir);
+
+        let config = base_config(dir.clone(), None);
+        let a = compute_fingerprint(&config);
+        let b = compute_fingerprint(&config);
+        assert_eq!(a, b);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn fingerprint_changes_when_a_higher_priority_mod_override_appears() {
+        let dir = std::env::temp_dir().join("world_cache_new_override_test");
+        fs::remove_dir_all(&dir).ok();
+        let base = dir.join("data");
+        let mod_dir = dir.join("mod_data");
+        write_fixture(&base);
+
+        let config = base_config(base.clone(), Some(mod_dir.clone()));
+        let before = compute_fingerprint(&config);
+
+        fs::create_dir_all(&mod_dir).unwrap();
+        fs::write(mod_dir.join("descr_sm_factions.txt"), "faction golden_horde\n").unwrap();
+        let after = compute_fingerprint(&config);
+
+        assert_ne!(before, after, "a new mod override file must change the fingerprint even though the base file it shadows is untouched");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn fingerprint_changes_when_an_optional_file_is_newly_created() {
+        let dir = std::env::temp_dir().join("world_cache_new_optional_test");
+        fs::remove_dir_all(&dir).ok();
+        write_fixture(&dir);
+
+        let config = base_config(dir.clone(), None);
+        let before = compute_fingerprint(&config);
+
+        fs::write(dir.join("campaign_script.txt"), "spawn_army faction egypt region Egypt\n").unwrap();
+        let after = compute_fingerprint(&config);
+
+        assert_ne!(before, after, "a newly created optional file must change the fingerprint even though it had no `sources` entry to fingerprint before");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn fingerprint_changes_when_a_localization_file_is_edited() {
+        let dir = std::env::temp_dir().join("world_cache_locale_edit_test");
+        fs::remove_dir_all(&dir).ok();
+        write_fixture(&dir);
+
+        let config = base_config(dir.clone(), None);
+        let before = compute_fingerprint(&config);
+
+        // `LocalizationFile::load` reads these out of a `text/` subdirectory,
+        // not `data_dir` itself -- fingerprinting the wrong path would leave
+        // this edit invisible to cache invalidation.
+        fs::write(dir.join("text").join("expanded.txt"), "{FACTION_EGYPT}Egypt\n").unwrap();
+        let after = compute_fingerprint(&config);
+
+        assert_ne!(before, after, "editing a file under text/ must change the fingerprint");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, strip_keyword, DescrLine, ReadOptions};
+
+/// The campaign-wide header of `descr_strat.txt`: the campaign's name, its
+/// playable/unlockable/nonplayable faction lists, and its date range. Mod
+/// files reorder these freely, so they're picked out by keyword rather than
+/// by position.
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct CampaignSettings {
+    pub campaign_name: Option<String>,
+    pub playable_factions: Vec<String>,
+    pub unlockable_factions: Vec<String>,
+    pub nonplayable_factions: Vec<String>,
+    /// Kept as the file's raw text (e.g. `"1200 winter"`); there's no
+    /// established date type in this codebase to parse it into.
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub timescale: Option<u32>,
+    /// Line number of the `playable`/`unlockable`/`nonplayable` line itself,
+    /// keyed by that keyword, so `validate` can point at the exact list a
+    /// bad faction name came from.
+    pub list_lines: BTreeMap<String, usize>,
+}
+
+impl CampaignSettings {
+    /// The calendar year `start_date` begins in (e.g. `1200` from
+    /// `"1200 winter"`), for comparing against `descr_events.txt` dates.
+    /// `None` if there's no start date or its year isn't parseable.
+    pub fn start_year(&self) -> Option<u32> {
+        self.start_date.as_deref()?.split_whitespace().next()?.parse().ok()
+    }
+
+    /// The calendar year `end_date` falls in, same convention as
+    /// [`CampaignSettings::start_year`].
+    pub fn end_year(&self) -> Option<u32> {
+        self.end_date.as_deref()?.split_whitespace().next()?.parse().ok()
+    }
+
+    pub fn load(path: &Path, text: &str) -> Result<CampaignSettings> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+            ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+        };
+
+        let mut settings = CampaignSettings::default();
+        for line in &lines {
+            if let Some(rest) = strip_keyword(&line.text, "campaign_name") {
+                settings.campaign_name = Some(rest.trim().to_string());
+            } else if let Some(rest) = strip_keyword(&line.text, "playable") {
+                settings.playable_factions = split_names(rest);
+                settings.list_lines.insert("playable".to_string(), line.line_number);
+            } else if let Some(rest) = strip_keyword(&line.text, "unlockable") {
+                settings.unlockable_factions = split_names(rest);
+                settings.list_lines.insert("unlockable".to_string(), line.line_number);
+            } else if let Some(rest) = strip_keyword(&line.text, "nonplayable") {
+                settings.nonplayable_factions = split_names(rest);
+                settings.list_lines.insert("nonplayable".to_string(), line.line_number);
+            } else if let Some(rest) = strip_keyword(&line.text, "start_date") {
+                settings.start_date = Some(rest.trim().to_string());
+            } else if let Some(rest) = strip_keyword(&line.text, "end_date") {
+                settings.end_date = Some(rest.trim().to_string());
+            } else if let Some(rest) = strip_keyword(&line.text, "timescale") {
+                let value = rest.trim();
+                settings.timescale =
+                    Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid timescale")))?);
+            }
+        }
+
+        Ok(settings)
+    }
+}
+
+/// A faction's economic and AI settings from its `descr_strat.txt` section:
+/// `denari`, `denari_kings_purse`, `ai_label`, and `superfaction`. Parsed by
+/// keyword rather than position, same as [`CampaignSettings`].
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct FactionStart {
+    /// 0-based position in `descr_strat.txt`, in file order.
+    pub id: usize,
+    /// 1-based line the `faction` header started on.
+    pub line_number: usize,
+    pub faction: String,
+    pub denari: Option<u32>,
+    pub denari_kings_purse: Option<u32>,
+    pub ai_label: Option<String>,
+    pub superfaction: Option<String>,
+}
+
+impl FactionStart {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<FactionStart>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+            ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+        };
+
+        let mut starts: Vec<FactionStart> = Vec::new();
+        for line in &lines {
+            if let Some(rest) = strip_keyword(&line.text, "faction") {
+                let name = rest.split(',').next().unwrap_or(rest).trim().to_string();
+                starts.push(FactionStart {
+                    id: 0,
+                    line_number: line.line_number,
+                    faction: name,
+                    denari: None,
+                    denari_kings_purse: None,
+                    ai_label: None,
+                    superfaction: None,
+                });
+                continue;
+            }
+
+            let Some(current) = starts.last_mut() else { continue };
+            if let Some(rest) = strip_keyword(&line.text, "denari_kings_purse") {
+                let value = rest.trim();
+                current.denari_kings_purse =
+                    Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid denari_kings_purse")))?);
+            } else if let Some(rest) = strip_keyword(&line.text, "denari") {
+                let value = rest.trim();
+                current.denari = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid denari amount")))?);
+            } else if let Some(rest) = strip_keyword(&line.text, "ai_label") {
+                current.ai_label = Some(rest.trim().to_string());
+            } else if let Some(rest) = strip_keyword(&line.text, "superfaction") {
+                current.superfaction = Some(rest.trim().to_string());
+            }
+        }
+
+        for (id, start) in starts.iter_mut().enumerate() {
+            start.id = id;
+        }
+
+        Ok(starts)
+    }
+}
+
+fn split_names(rest: &str) -> Vec<String> {
+    rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect()
+}
+
+/// A starting diplomatic stance between two factions.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stance {
+    Peace,
+    War,
+    Alliance,
+}
+
+/// One declared relationship between `faction_a` and `faction_b`, order not
+/// significant -- the engine treats a pair's stance the same regardless of
+/// which side it was declared from.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct DiplomaticStance {
+    /// 1-based line the declaration started on (the `faction_relationships`
+    /// entry itself, or the `relationship` block's opening line).
+    pub line_number: usize,
+    pub faction_a: String,
+    pub faction_b: String,
+    pub stance: Stance,
+}
+
+/// The starting diplomatic stances from `descr_strat.txt`'s
+/// `faction_relationships`/`core_attitudes` section (older `FACTION_A
+/// FACTION_B STANCE` lines) and/or its `relationship { ... }` blocks (the
+/// form Kingdoms-based mods use instead). A pair with no declaration at all
+/// is implicitly at peace.
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct DiplomacyMatrix {
+    pub stances: Vec<DiplomaticStance>,
+}
+
+impl DiplomacyMatrix {
+    pub fn load(path: &Path, text: &str) -> Result<DiplomacyMatrix> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+            ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+        };
+
+        let mut stances = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = &lines[i];
+            let first_token = line.text.split_whitespace().next();
+
+            if first_token == Some("faction_relationships") || first_token == Some("core_attitudes") {
+                let (inner, consumed) = capture_brace_block(path, &lines, i)?;
+                for entry in inner {
+                    let tokens: Vec<&str> = entry.text.split_whitespace().collect();
+                    let [faction_a, faction_b, stance] = tokens[..] else {
+                        return Err(err(entry, format!("expected \"FACTION_A FACTION_B STANCE\", found {:?}", entry.text)));
+                    };
+                    stances.push(DiplomaticStance {
+                        line_number: entry.line_number,
+                        faction_a: faction_a.to_string(),
+                        faction_b: faction_b.to_string(),
+                        stance: parse_stance(path, entry, stance)?,
+                    });
+                }
+                i += consumed;
+                continue;
+            }
+
+            if first_token == Some("relationship") {
+                let (inner, consumed) = capture_brace_block(path, &lines, i)?;
+                let mut faction_a = None;
+                let mut faction_b = None;
+                let mut stance = None;
+                for entry in &inner {
+                    if let Some(rest) = strip_keyword(&entry.text, "faction_1") {
+                        faction_a = Some(rest.trim().to_string());
+                    } else if let Some(rest) = strip_keyword(&entry.text, "faction_2") {
+                        faction_b = Some(rest.trim().to_string());
+                    } else if let Some(rest) = strip_keyword(&entry.text, "stance") {
+                        stance = Some(parse_stance(path, entry, rest.trim())?);
+                    }
+                }
+                let (Some(faction_a), Some(faction_b), Some(stance)) = (faction_a, faction_b, stance) else {
+                    return Err(err(line, "relationship block needs faction_1, faction_2, and stance".to_string()));
+                };
+                stances.push(DiplomaticStance { line_number: line.line_number, faction_a, faction_b, stance });
+                i += consumed;
+                continue;
+            }
+
+            i += 1;
+        }
+
+        Ok(DiplomacyMatrix { stances })
+    }
+
+    /// The declared stance between `a` and `b`, checked in either
+    /// declaration order since a pair only ever needs to be declared once.
+    /// `None` if the pair was never declared -- callers that want the
+    /// engine's implicit default should treat that as [`Stance::Peace`].
+    pub fn stance_between(&self, a: &str, b: &str) -> Option<Stance> {
+        self.stances
+            .iter()
+            .find(|s| (s.faction_a.eq_ignore_ascii_case(a) && s.faction_b.eq_ignore_ascii_case(b)) || (s.faction_a.eq_ignore_ascii_case(b) && s.faction_b.eq_ignore_ascii_case(a)))
+            .map(|s| s.stance)
+    }
+}
+
+fn parse_stance(path: &Path, line: &DescrLine, value: &str) -> Result<Stance> {
+    match value.to_lowercase().as_str() {
+        "peace" | "neutral" => Ok(Stance::Peace),
+        "war" | "at_war" => Ok(Stance::War),
+        "alliance" | "allied" | "ally" => Ok(Stance::Alliance),
+        other => {
+            Err(ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message: format!("{other:?} is not a recognized diplomatic stance") }.into())
+        }
+    }
+}
+
+/// Consumes a `{ ... }` block starting at or after `lines[start]` (the
+/// opening brace may trail the keyword or appear on a later line), and
+/// returns its non-blank depth-1 inner lines plus the number of lines
+/// consumed (from `start` through the closing `}`, inclusive). Assumes a
+/// flat block with no further nesting, same as [`crate::character`]'s copy
+/// of this helper.
+fn capture_brace_block<'a>(path: &Path, lines: &'a [DescrLine], start: usize) -> Result<(Vec<&'a DescrLine>, usize)> {
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut open_at = start;
+    while !lines[open_at].text.contains('{') {
+        open_at += 1;
+        if open_at >= lines.len() {
+            return Err(err(&lines[start], "block is missing its opening `{`".to_string()));
+        }
+    }
+
+    let mut depth = 0i32;
+    let mut inner = Vec::new();
+    for (offset, line) in lines[open_at..].iter().enumerate() {
+        let idx = open_at + offset;
+        let mut text = line.text.as_str();
+        if idx == open_at {
+            text = &text[text.find('{').unwrap() + 1..];
+            depth = 1;
+        }
+
+        let opens = text.matches('{').count() as i32;
+        let closes = text.matches('}').count() as i32;
+        if depth == 1 && opens == 0 && closes == 0 && !text.trim().is_empty() {
+            inner.push(line);
+        }
+
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((inner, idx - start + 1));
+        }
+    }
+
+    Err(err(&lines[open_at], "block is missing its closing `}`".to_string()))
+}
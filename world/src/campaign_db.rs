@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, WorldError};
+
+/// A leaf value from `descr_campaign_db.xml`: numbers are parsed as numbers
+/// so mod-vs-vanilla diffs can report a magnitude, not just "changed".
+#[derive(Deserialize, Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum XmlValue {
+    Number(f64),
+    Text(String),
+}
+
+/// A section of `descr_campaign_db.xml` (e.g. `<siege_engines>`): its
+/// leaf children keyed by tag name, and any nested sections in file order.
+/// An element is a leaf if it has no child elements of its own, a section
+/// otherwise -- `descr_campaign_db.xml` doesn't distinguish the two any
+/// other way.
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct CampaignDbSection {
+    pub name: String,
+    /// 1-based line the section's opening tag started on.
+    pub line_number: usize,
+    pub values: BTreeMap<String, XmlValue>,
+    pub sections: Vec<CampaignDbSection>,
+}
+
+/// An element currently open on the parse stack: like [`CampaignDbSection`]
+/// but also accumulates the text seen so far, since it isn't known to be a
+/// leaf (and needs that text) until its closing tag is reached.
+struct Frame {
+    name: String,
+    line_number: usize,
+    text: String,
+    values: BTreeMap<String, XmlValue>,
+    sections: Vec<CampaignDbSection>,
+    /// Set as soon as any child `Start`/`Empty` element is seen, so a
+    /// container whose children are *all* leaves (e.g. `<denari_costs>`
+    /// with only `<spy>500</spy>`-style children) isn't itself mistaken
+    /// for a leaf just because `sections` stayed empty.
+    has_children: bool,
+}
+
+impl CampaignDbSection {
+    pub fn load(path: &Path, text: &str) -> Result<CampaignDbSection> {
+        let mut reader = Reader::from_str(text);
+        reader.config_mut().trim_text(true);
+
+        let mut stack: Vec<Frame> = Vec::new();
+        let mut root: Option<CampaignDbSection> = None;
+
+        loop {
+            let event = reader.read_event().map_err(|e| xml_err(path, text, reader.error_position(), e.to_string()))?;
+            match event {
+                Event::Start(start) => {
+                    let name = element_name(&start);
+                    let line_number = line_at(text, reader.buffer_position() as usize);
+                    if let Some(parent) = stack.last_mut() {
+                        parent.has_children = true;
+                    }
+                    stack.push(Frame { name, line_number, text: String::new(), values: BTreeMap::new(), sections: Vec::new(), has_children: false });
+                }
+                Event::Empty(start) => {
+                    let name = element_name(&start);
+                    match stack.last_mut() {
+                        Some(parent) => {
+                            parent.has_children = true;
+                            parent.values.insert(name, XmlValue::Text(String::new()));
+                        }
+                        None => {
+                            let line_number = line_at(text, reader.buffer_position() as usize);
+                            root = Some(CampaignDbSection { name, line_number, values: BTreeMap::new(), sections: Vec::new() });
+                        }
+                    }
+                }
+                Event::Text(bytes) => {
+                    if let Some(frame) = stack.last_mut() {
+                        let decoded = bytes.decode().map_err(|e| xml_err(path, text, reader.buffer_position(), e.to_string()))?;
+                        let unescaped = quick_xml::escape::unescape(&decoded).map_err(|e| xml_err(path, text, reader.buffer_position(), e.to_string()))?;
+                        frame.text.push_str(&unescaped);
+                    }
+                }
+                Event::End(_) => {
+                    let frame = stack.pop().ok_or_else(|| xml_err(path, text, reader.error_position(), "unexpected closing tag".to_string()))?;
+                    let section = if frame.has_children {
+                        Some(CampaignDbSection { name: frame.name.clone(), line_number: frame.line_number, values: frame.values, sections: frame.sections })
+                    } else {
+                        // No child elements: this is a leaf, so its own
+                        // accumulated text is the value.
+                        None
+                    };
+
+                    match (stack.last_mut(), section) {
+                        (Some(parent), None) => {
+                            parent.values.insert(frame.name, parse_value(frame.text.trim()));
+                        }
+                        (Some(parent), Some(section)) => parent.sections.push(section),
+                        (None, None) => {
+                            root = Some(CampaignDbSection { name: frame.name, line_number: frame.line_number, values: BTreeMap::new(), sections: Vec::new() })
+                        }
+                        (None, Some(section)) => root = Some(section),
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        root.ok_or_else(|| WorldError::Xml { path: path.to_path_buf(), line_number: 1, message: "no root element found".to_string() })
+    }
+}
+
+fn element_name(start: &BytesStart) -> String {
+    String::from_utf8_lossy(start.name().as_ref()).into_owned()
+}
+
+fn parse_value(text: &str) -> XmlValue {
+    match text.parse::<f64>() {
+        Ok(n) if !text.is_empty() => XmlValue::Number(n),
+        _ => XmlValue::Text(text.to_string()),
+    }
+}
+
+fn line_at(text: &str, byte_offset: usize) -> usize {
+    text.as_bytes()[..byte_offset.min(text.len())].iter().filter(|&&b| b == b'\n').count() + 1
+}
+
+fn xml_err(path: &Path, text: &str, byte_offset: u64, message: String) -> WorldError {
+    WorldError::Xml { path: path.to_path_buf(), line_number: line_at(text, byte_offset as usize), message }
+}
+
+/// Groups `sections` by name, preserving file order within each group, so
+/// repeated same-named sections (e.g. multiple `<religion>` entries) can be
+/// compared positionally instead of colliding on a single key.
+pub(crate) fn group_by_name(sections: &[CampaignDbSection]) -> BTreeMap<&str, Vec<&CampaignDbSection>> {
+    let mut groups: BTreeMap<&str, Vec<&CampaignDbSection>> = BTreeMap::new();
+    for section in sections {
+        groups.entry(section.name.as_str()).or_default().push(section);
+    }
+    groups
+}
@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// One line of `campaign_script.txt`, tokenized into a command name and its
+/// whitespace-separated arguments. Monitor blocks (`monitor_event`,
+/// `console_command`, `and`, `end_monitor`, ...) aren't given any special
+/// structure here -- every line becomes its own flat `ScriptCommand`, so a
+/// command nested three `monitor_event`s deep is checked exactly like a
+/// top-level one. Full scripting support (conditions, control flow) is out
+/// of scope; this only extracts enough to catch reference typos.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct ScriptCommand {
+    /// 1-based line this command was written on.
+    pub line_number: usize,
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+impl ScriptCommand {
+    /// `campaign_script.txt` is optional -- most mods don't use the
+    /// scripting engine at all -- so whether the file exists at all is
+    /// [`crate::world::World::load`]'s call; this only tokenizes whatever
+    /// text it's handed.
+    pub fn load_all(_path: &Path, text: &str) -> Result<Vec<ScriptCommand>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        Ok(lines.iter().map(tokenize).collect())
+    }
+}
+
+fn tokenize(line: &DescrLine) -> ScriptCommand {
+    let mut tokens = line.text.split_whitespace();
+    let name = tokens.next().unwrap_or_default().to_string();
+    let args = tokens.map(str::to_string).collect();
+    ScriptCommand { line_number: line.line_number, name, args }
+}
+
+/// What kind of reference a whitelisted command's argument names, checked
+/// against the already-loaded `World` by `validate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    Faction,
+    Region,
+    Settlement,
+    Unit,
+    /// One of the `kind` values seen on a loaded `descr_strat.txt`
+    /// character (e.g. `general`, `admiral`, `named_character`) -- there's
+    /// no separate catalog of valid character types in this codebase, so
+    /// "valid" means "actually used somewhere in the campaign".
+    CharacterType,
+}
+
+/// Commands `validate` knows how to cross-check, and which (0-based)
+/// arguments name a faction/region/settlement/unit/character type.
+/// Anything not listed here is a command this parser doesn't recognize and
+/// is ignored rather than flagged -- `campaign_script.txt` has hundreds of
+/// commands and this only needs to catch the common typo classes. Add a
+/// row here to teach `validate` about another one.
+///
+/// `console_command`'s own sub-commands are looked up under
+/// `"console_command <subcommand>"`, since the subcommand name is itself
+/// the first argument; [`arg_kinds_for`] handles indexing arguments after
+/// it accordingly.
+pub const COMMAND_ARG_KINDS: &[(&str, &[(usize, ArgKind)])] = &[
+    ("spawn_army", &[(0, ArgKind::Faction), (1, ArgKind::Region)]),
+    ("create_character", &[(0, ArgKind::Faction), (1, ArgKind::CharacterType)]),
+    ("move_character_to_settlement", &[(1, ArgKind::Settlement)]),
+    ("give_unit_to_character", &[(1, ArgKind::Unit)]),
+    ("console_command give_everything_to_faction", &[(0, ArgKind::Faction)]),
+    ("console_command add_units", &[(0, ArgKind::Settlement), (1, ArgKind::Unit)]),
+];
+
+/// Looks up the argument kinds to check for `command`, and how many leading
+/// arguments to skip before indexing into them (1 for `console_command`,
+/// to skip past its subcommand name).
+pub fn arg_kinds_for(command: &ScriptCommand) -> Option<(&'static [(usize, ArgKind)], usize)> {
+    if command.name == "console_command" {
+        let subcommand = command.args.first()?;
+        let key = format!("console_command {subcommand}");
+        COMMAND_ARG_KINDS.iter().find(|(name, _)| *name == key).map(|(_, kinds)| (*kinds, 1))
+    } else {
+        COMMAND_ARG_KINDS.iter().find(|(name, _)| *name == command.name).map(|(_, kinds)| (*kinds, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands_from(text: &str) -> Vec<ScriptCommand> {
+        ScriptCommand::load_all(Path::new("campaign_script.txt"), text).unwrap()
+    }
+
+    #[test]
+    fn tokenizes_commands_and_arguments() {
+        let commands = commands_from("spawn_army faction egypt region Egypt\nconsole_command give_everything_to_faction egypt\n");
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].name, "spawn_army");
+        assert_eq!(commands[0].args, vec!["faction", "egypt", "region", "Egypt"]);
+        assert_eq!(commands[1].name, "console_command");
+        assert_eq!(commands[1].args, vec!["give_everything_to_faction", "egypt"]);
+    }
+
+    #[test]
+    fn empty_script_yields_no_commands() {
+        // Whether `campaign_script.txt` exists at all is `World::load`'s
+        // call (most mods don't use the scripting engine); this only
+        // covers what `load_all` itself does with empty text.
+        assert!(commands_from("").is_empty());
+    }
+
+    #[test]
+    fn console_command_subcommand_is_looked_up_with_its_own_key() {
+        let commands = commands_from("console_command give_everything_to_faction egypt\n");
+        let (kinds, offset) = arg_kinds_for(&commands[0]).unwrap();
+        assert_eq!(offset, 1);
+        assert_eq!(kinds, &[(0, ArgKind::Faction)]);
+        assert_eq!(&commands[0].args[offset], "egypt");
+    }
+
+    #[test]
+    fn unknown_command_is_not_whitelisted() {
+        let commands = commands_from("some_unknown_command foo bar\n");
+        assert!(arg_kinds_for(&commands[0]).is_none());
+    }
+}
@@ -0,0 +1,322 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result};
+use crate::text::{parse_descr_lines, strip_keyword, DescrLine, ReadOptions};
+
+/// A starting map position, in tiles.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// One `unit` line inside a `character`'s `army { ... }` block.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct ArmyUnit {
+    /// 1-based line this unit was declared on.
+    pub line_number: usize,
+    pub unit_type: String,
+    pub experience: Option<u32>,
+    pub armour_upgrade: Option<u32>,
+    pub weapon_upgrade: Option<u32>,
+    pub soldiers: Option<u32>,
+    pub max_soldiers: Option<u32>,
+}
+
+/// A starting character from `descr_strat.txt`: a `character ...` header
+/// line, its optional `traits`/`ancillaries` lines, and its `army { ... }`
+/// block. Cross-checking `name` against `descr_names.txt` and unit types
+/// against `export_descr_unit.txt` is left for the `validate` subcommand.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Character {
+    /// 0-based position in `descr_strat.txt`, in file order.
+    pub id: usize,
+    /// 1-based line the `character` header started on.
+    pub line_number: usize,
+    /// The faction whose section this character's header appears under.
+    pub owning_faction: String,
+    pub name: String,
+    /// The header's second field, e.g. `king`, `general`, `named_character`.
+    pub kind: String,
+    /// Bare flags from the header, e.g. `leader`, `heir` (age and position
+    /// are pulled out into their own fields instead of staying here).
+    pub flags: Vec<String>,
+    pub age: Option<u32>,
+    pub position: Option<Position>,
+    /// Raw entries from a `traits` line, kept verbatim (e.g.
+    /// `"GoodCommander 1"`) since trait definitions live in a different
+    /// file this parser doesn't load.
+    pub traits: Vec<String>,
+    /// Raw entries from an `ancillaries` line, kept verbatim.
+    pub ancillaries: Vec<String>,
+    pub army: Vec<ArmyUnit>,
+}
+
+/// A `character_record { ... }` block (family relationships via `relative`
+/// lines). Kept as raw lines for now; interpreting them is future work.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct FamilyRecord {
+    /// 1-based line the `character_record` block started on.
+    pub line_number: usize,
+    pub owning_faction: String,
+    pub raw_lines: Vec<String>,
+}
+
+impl Character {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Character>> {
+        Ok(load_strat_sections(path, text)?.0)
+    }
+}
+
+impl FamilyRecord {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<FamilyRecord>> {
+        Ok(load_strat_sections(path, text)?.1)
+    }
+}
+
+fn load_strat_sections(path: &Path, text: &str) -> Result<(Vec<Character>, Vec<FamilyRecord>)> {
+    let lines = parse_descr_lines(text, ReadOptions::default());
+
+    let mut characters = Vec::new();
+    let mut families = Vec::new();
+    let mut current_faction: Option<String> = None;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = &lines[i];
+        let first_token = line.text.split_whitespace().next();
+
+        if let Some(rest) = strip_keyword(&line.text, "faction") {
+            current_faction = Some(rest.split(',').next().unwrap_or(rest).trim().to_string());
+            i += 1;
+            continue;
+        }
+
+        if first_token == Some("settlement") {
+            i += skip_brace_block(path, &lines, i)?;
+            continue;
+        }
+
+        if first_token == Some("character_record") {
+            let faction = current_faction.clone().unwrap_or_default();
+            let (inner, consumed) = capture_brace_block(path, &lines, i)?;
+            families.push(FamilyRecord {
+                line_number: line.line_number,
+                owning_faction: faction,
+                raw_lines: inner.iter().map(|l| l.text.clone()).collect(),
+            });
+            i += consumed;
+            continue;
+        }
+
+        if first_token == Some("character") {
+            let faction = current_faction.clone().unwrap_or_default();
+            let (character, consumed) = parse_character(path, &lines, i, faction)?;
+            characters.push(character);
+            i += consumed;
+            continue;
+        }
+
+        // `denari`, `ai_label`, and anything else we don't model yet.
+        i += 1;
+    }
+
+    for (id, character) in characters.iter_mut().enumerate() {
+        character.id = id;
+    }
+
+    Ok((characters, families))
+}
+
+fn parse_character(path: &Path, lines: &[DescrLine], start: usize, owning_faction: String) -> Result<(Character, usize)> {
+    let header = &lines[start];
+    let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let rest = strip_keyword(&header.text, "character").unwrap_or(&header.text);
+    let mut fields = rest.split(',').map(str::trim);
+    let name = fields.next().unwrap_or_default().to_string();
+    let kind = fields.next().unwrap_or_default().to_string();
+
+    let mut age = None;
+    let mut x = None;
+    let mut y = None;
+    let mut flags = Vec::new();
+    for field in fields {
+        if field.is_empty() {
+            continue;
+        }
+        if let Some(value) = field.strip_prefix("age ") {
+            age = Some(value.trim().parse().map_err(|_| err(header, format!("{value:?} is not a valid age")))?);
+        } else if let Some(value) = field.strip_prefix("x ") {
+            x = Some(value.trim().parse().map_err(|_| err(header, format!("{value:?} is not a valid x coordinate")))?);
+        } else if let Some(value) = field.strip_prefix("y ") {
+            y = Some(value.trim().parse().map_err(|_| err(header, format!("{value:?} is not a valid y coordinate")))?);
+        } else {
+            flags.push(field.to_string());
+        }
+    }
+    let position = match (x, y) {
+        (Some(x), Some(y)) => Some(Position { x, y }),
+        _ => None,
+    };
+
+    let mut i = start + 1;
+    let mut traits = Vec::new();
+    let mut ancillaries = Vec::new();
+    let mut army = Vec::new();
+
+    while let Some(line) = lines.get(i) {
+        if let Some(rest) = strip_keyword(&line.text, "traits") {
+            traits.extend(rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string));
+            i += 1;
+        } else if let Some(rest) = strip_keyword(&line.text, "ancillaries") {
+            ancillaries.extend(rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string));
+            i += 1;
+        } else if line.text.split_whitespace().next() == Some("army") {
+            let (inner, consumed) = capture_brace_block(path, lines, i)?;
+            for unit_line in inner {
+                army.push(parse_army_unit(path, unit_line)?);
+            }
+            i += consumed;
+            break;
+        } else {
+            break;
+        }
+    }
+
+    Ok((
+        Character {
+            id: 0,
+            line_number: header.line_number,
+            owning_faction,
+            name,
+            kind,
+            flags,
+            age,
+            position,
+            traits,
+            ancillaries,
+            army,
+        },
+        i - start,
+    ))
+}
+
+fn parse_army_unit(path: &Path, line: &DescrLine) -> Result<ArmyUnit> {
+    let err = |message: String| -> crate::error::WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let rest = strip_keyword(&line.text, "unit").unwrap_or(&line.text);
+    let mut fields = rest.split(',').map(str::trim);
+    let unit_type = fields.next().unwrap_or_default().to_string();
+
+    let mut experience = None;
+    let mut armour_upgrade = None;
+    let mut weapon_upgrade = None;
+    if let Some(upgrades) = fields.next() {
+        let tokens = upgrades.split_whitespace().collect::<Vec<_>>();
+        for pair in tokens.chunks(2) {
+            let [key, value] = pair else {
+                return Err(err(format!("expected KEY VALUE pairs, found {upgrades:?}")));
+            };
+            let parsed = value.parse::<u32>().map_err(|_| err(format!("{value:?} is not a valid number")))?;
+            match *key {
+                "exp" => experience = Some(parsed),
+                "armour" => armour_upgrade = Some(parsed),
+                "weapon_upgrade" => weapon_upgrade = Some(parsed),
+                _ => {}
+            }
+        }
+    }
+
+    let mut soldiers = None;
+    if let Some(field) = fields.next() {
+        if let Some(value) = field.strip_prefix("soldiers ") {
+            soldiers = Some(value.trim().parse().map_err(|_| err(format!("{value:?} is not a valid soldier count")))?);
+        }
+    }
+    let max_soldiers = fields.next().map(str::trim).map(|v| v.parse().map_err(|_| err(format!("{v:?} is not a valid max soldier count")))).transpose()?;
+
+    Ok(ArmyUnit { line_number: line.line_number, unit_type, experience, armour_upgrade, weapon_upgrade, soldiers, max_soldiers })
+}
+
+/// Consumes a `{ ... }` block starting at or after `lines[start]` (the
+/// opening brace may trail the keyword or appear on a later line), and
+/// returns its non-blank depth-1 inner lines plus the number of lines
+/// consumed (from `start` through the closing `}`, inclusive). Assumes a
+/// flat block with no further nesting, which holds for `army` and
+/// `character_record`.
+fn capture_brace_block<'a>(path: &Path, lines: &'a [DescrLine], start: usize) -> Result<(Vec<&'a DescrLine>, usize)> {
+    let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut open_at = start;
+    while !lines[open_at].text.contains('{') {
+        open_at += 1;
+        if open_at >= lines.len() {
+            return Err(err(&lines[start], "block is missing its opening `{`".to_string()));
+        }
+    }
+
+    let mut depth = 0i32;
+    let mut inner = Vec::new();
+    for (offset, line) in lines[open_at..].iter().enumerate() {
+        let idx = open_at + offset;
+        let mut text = line.text.as_str();
+        if idx == open_at {
+            text = &text[text.find('{').unwrap() + 1..];
+            depth = 1;
+        }
+
+        let opens = text.matches('{').count() as i32;
+        let closes = text.matches('}').count() as i32;
+        if depth == 1 && opens == 0 && closes == 0 && !text.trim().is_empty() {
+            inner.push(line);
+        }
+
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((inner, idx - start + 1));
+        }
+    }
+
+    Err(err(&lines[open_at], "block is missing its closing `}`".to_string()))
+}
+
+/// Skips a `{ ... }` block whose contents this loader doesn't care about
+/// (`settlement`), respecting nested braces so a `building { ... }`
+/// sub-block inside it doesn't confuse the count.
+fn skip_brace_block(path: &Path, lines: &[DescrLine], start: usize) -> Result<usize> {
+    let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut open_at = start;
+    while !lines[open_at].text.contains('{') {
+        open_at += 1;
+        if open_at >= lines.len() {
+            return Err(err(&lines[start], "block is missing its opening `{`".to_string()));
+        }
+    }
+
+    let mut depth = 0i32;
+    for (offset, line) in lines[open_at..].iter().enumerate() {
+        let idx = open_at + offset;
+        let mut text = line.text.as_str();
+        if idx == open_at {
+            text = &text[text.find('{').unwrap() + 1..];
+            depth = 1;
+        }
+        depth += text.matches('{').count() as i32 - text.matches('}').count() as i32;
+        if depth == 0 {
+            return Ok(idx - start + 1);
+        }
+    }
+
+    Err(err(&lines[open_at], "block is missing its closing `}`".to_string()))
+}
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::campaign_db::{group_by_name, CampaignDbSection, XmlValue};
+use crate::config::Config;
+use crate::culture::Culture;
+use crate::error::{Result, WorldError};
+use crate::faction::Faction;
+use crate::region::Region;
+use crate::religion::Religion;
+use crate::unit::Unit;
+
+pub struct DiffArgs {
+    pub format: DiffFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DiffFormat {
+    Text,
+    Json,
+}
+
+/// Loads two `World`s and reports added/removed/modified cultures,
+/// religions, factions, regions, and units between them, keyed by name so
+/// the comparison doesn't care that the same record picked up a different
+/// auto-assigned `id` on either side. Exits non-zero, via
+/// [`WorldError::Different`], if anything differs.
+pub fn run(config_a: &Config, config_b: &Config, args: &DiffArgs) -> Result<()> {
+    let world_a = crate::cache::load(config_a)?;
+    let world_b = crate::cache::load(config_b)?;
+
+    let diff = WorldDiff {
+        cultures: diff_records(&world_a.cultures, &world_b.cultures, |c| c.name.as_str()),
+        religions: diff_records(&world_a.religions, &world_b.religions, |r| r.name.as_str()),
+        factions: diff_records(&world_a.factions, &world_b.factions, |f| f.name.as_str()),
+        regions: diff_records(&world_a.regions, &world_b.regions, |r| r.name.as_str()),
+        units: diff_records(&world_a.units, &world_b.units, |u| u.name.as_str()),
+        campaign_db: diff_campaign_db(&world_a.campaign_db, &world_b.campaign_db),
+    };
+
+    let total =
+        diff.cultures.len() + diff.religions.len() + diff.factions.len() + diff.regions.len() + diff.units.len() + diff.campaign_db.len();
+
+    match args.format {
+        DiffFormat::Text => print_text(&diff),
+        DiffFormat::Json => print_json(&diff),
+    }
+
+    if total > 0 {
+        return Err(WorldError::Different(total));
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct WorldDiff {
+    cultures: RecordDiff<Culture>,
+    religions: RecordDiff<Religion>,
+    factions: RecordDiff<Faction>,
+    regions: RecordDiff<Region>,
+    units: RecordDiff<Unit>,
+    /// Slash-path-keyed changes within `descr_campaign_db.xml`'s nested
+    /// sections (e.g. `descr_campaign_database/denari_costs/spy`), since
+    /// its tree shape doesn't fit the flat, name-keyed [`RecordDiff`] model
+    /// the other sections use.
+    campaign_db: BTreeMap<String, FieldChange>,
+}
+
+#[derive(Serialize)]
+struct RecordDiff<T> {
+    added: Vec<T>,
+    removed: Vec<T>,
+    modified: Vec<ModifiedRecord>,
+}
+
+impl<T> RecordDiff<T> {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.modified.len()
+    }
+}
+
+#[derive(Serialize)]
+struct ModifiedRecord {
+    name: String,
+    changes: BTreeMap<String, FieldChange>,
+}
+
+#[derive(Serialize)]
+struct FieldChange {
+    before: Value,
+    after: Value,
+}
+
+/// Diffs `a` against `b`, matching records by the name `name_of` extracts
+/// (not by their auto-assigned `id`, which is only stable within a single
+/// load and says nothing about identity across two different directories).
+fn diff_records<T: Clone + Serialize>(a: &[T], b: &[T], name_of: impl Fn(&T) -> &str) -> RecordDiff<T> {
+    let by_name_a: BTreeMap<&str, &T> = a.iter().map(|record| (name_of(record), record)).collect();
+    let by_name_b: BTreeMap<&str, &T> = b.iter().map(|record| (name_of(record), record)).collect();
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut modified = Vec::new();
+
+    for (name, record) in &by_name_a {
+        if !by_name_b.contains_key(name) {
+            removed.push((*record).clone());
+        }
+    }
+    for (name, after) in &by_name_b {
+        match by_name_a.get(name) {
+            None => added.push((*after).clone()),
+            Some(before) => {
+                let changes = diff_fields(*before, *after);
+                if !changes.is_empty() {
+                    modified.push(ModifiedRecord { name: name.to_string(), changes });
+                }
+            }
+        }
+    }
+
+    added.sort_by(|x, y| name_of(x).cmp(name_of(y)));
+    removed.sort_by(|x, y| name_of(x).cmp(name_of(y)));
+    modified.sort_by(|x, y| x.name.cmp(&y.name));
+
+    RecordDiff { added, removed, modified }
+}
+
+/// Field-level diff between two records of the same type, serialized to
+/// JSON first so this works for any `Serialize` record without hand-rolling
+/// a comparison per struct. `id`/`line_number` are excluded since they're
+/// file-position bookkeeping, not part of a record's identity.
+fn diff_fields<T: Serialize>(before: &T, after: &T) -> BTreeMap<String, FieldChange> {
+    let (Value::Object(before), Value::Object(after)) =
+        (serde_json::to_value(before).expect("record is always serializable"), serde_json::to_value(after).expect("record is always serializable"))
+    else {
+        return BTreeMap::new();
+    };
+
+    let mut changes = BTreeMap::new();
+    for key in before.keys().chain(after.keys()).filter(|key| *key != "id" && *key != "line_number").collect::<std::collections::BTreeSet<_>>() {
+        let before_value = before.get(key).cloned().unwrap_or(Value::Null);
+        let after_value = after.get(key).cloned().unwrap_or(Value::Null);
+        if before_value != after_value {
+            changes.insert(key.clone(), FieldChange { before: before_value, after: after_value });
+        }
+    }
+    changes
+}
+
+/// Recursively diffs two `descr_campaign_db.xml` trees, producing
+/// slash-path-keyed changes (`section/child/key`) instead of [`RecordDiff`]'s
+/// flat name-keyed model, since campaign db sections nest arbitrarily deep
+/// rather than being a list of independent records.
+fn diff_campaign_db(before: &CampaignDbSection, after: &CampaignDbSection) -> BTreeMap<String, FieldChange> {
+    let mut changes = BTreeMap::new();
+    diff_campaign_db_section(&before.name, before, after, &mut changes);
+    changes
+}
+
+fn diff_campaign_db_section(prefix: &str, before: &CampaignDbSection, after: &CampaignDbSection, changes: &mut BTreeMap<String, FieldChange>) {
+    for key in before.values.keys().chain(after.values.keys()).collect::<std::collections::BTreeSet<_>>() {
+        let before_value = before.values.get(key);
+        let after_value = after.values.get(key);
+        if before_value != after_value {
+            let path = format!("{prefix}/{key}");
+            changes.insert(path, FieldChange { before: xml_value_json(before_value), after: xml_value_json(after_value) });
+        }
+    }
+
+    let before_groups = group_by_name(&before.sections);
+    let after_groups = group_by_name(&after.sections);
+    let empty = CampaignDbSection::default();
+    for name in before_groups.keys().chain(after_groups.keys()).collect::<std::collections::BTreeSet<_>>() {
+        let before_children = before_groups.get(name).map(Vec::as_slice).unwrap_or_default();
+        let after_children = after_groups.get(name).map(Vec::as_slice).unwrap_or_default();
+        let count = before_children.len().max(after_children.len());
+        let numbered = count > 1;
+        for index in 0..count {
+            let segment = if numbered { format!("{name}[{index}]") } else { (*name).to_string() };
+            let before_child = before_children.get(index).copied().unwrap_or(&empty);
+            let after_child = after_children.get(index).copied().unwrap_or(&empty);
+            diff_campaign_db_section(&format!("{prefix}/{segment}"), before_child, after_child, changes);
+        }
+    }
+}
+
+fn xml_value_json(value: Option<&XmlValue>) -> Value {
+    match value {
+        None => Value::Null,
+        Some(XmlValue::Number(n)) => serde_json::json!(n),
+        Some(XmlValue::Text(text)) => Value::String(text.clone()),
+    }
+}
+
+fn print_text(diff: &WorldDiff) {
+    print_section("culture", &diff.cultures, |c: &Culture| c.name.as_str());
+    print_section("religion", &diff.religions, |r: &Religion| r.name.as_str());
+    print_section("faction", &diff.factions, |f: &Faction| f.name.as_str());
+    print_section("region", &diff.regions, |r: &Region| r.name.as_str());
+    print_section("unit", &diff.units, |u: &Unit| u.name.as_str());
+
+    for (path, change) in &diff.campaign_db {
+        println!("~ campaign db {path:?}: {} -> {}", change.before, change.after);
+    }
+
+    if diff.cultures.is_empty()
+        && diff.religions.is_empty()
+        && diff.factions.is_empty()
+        && diff.regions.is_empty()
+        && diff.units.is_empty()
+        && diff.campaign_db.is_empty()
+    {
+        println!("no differences found");
+    }
+}
+
+fn print_section<T>(kind: &str, diff: &RecordDiff<T>, name_of: impl Fn(&T) -> &str) {
+    for record in &diff.added {
+        println!("+ {kind} {:?}", name_of(record));
+    }
+    for record in &diff.removed {
+        println!("- {kind} {:?}", name_of(record));
+    }
+    for modified in &diff.modified {
+        println!("~ {kind} {:?}", modified.name);
+        for (field, change) in &modified.changes {
+            println!("    {field}: {} -> {}", change.before, change.after);
+        }
+    }
+}
+
+fn print_json(diff: &WorldDiff) {
+    println!("{}", serde_json::to_string_pretty(diff).expect("WorldDiff is always serializable"));
+}
@@ -0,0 +1,424 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::ancillary::AncillariesFile;
+use crate::battle_model::BattleModel;
+use crate::building::Building;
+use crate::campaign::{CampaignSettings, FactionStart};
+use crate::campaign_db::CampaignDbSection;
+use crate::campaign_script::ScriptCommand;
+use crate::character::{Character, FamilyRecord};
+use crate::config::{Config, ResolvedPath};
+use crate::culture::Culture;
+use crate::error::Result;
+use crate::events::HistoricEvent;
+use crate::faction::Faction;
+use crate::heightmap::HeightMap;
+use crate::localization::LocalizationFile;
+use crate::mercenary::MercPool;
+use crate::mission::Mission;
+use crate::mount::Mount;
+use crate::names::NamePool;
+use crate::projectile::Projectile;
+use crate::rebel_faction::RebelFaction;
+use crate::region::Region;
+use crate::religion::Religion;
+use crate::resource::{ResourceDef, ResourcePlacement};
+use crate::settlement::Settlement;
+use crate::terrain::TerrainInfo;
+use crate::traits::TraitsFile;
+use crate::unit::Unit;
+use crate::voice::VoiceClass;
+use crate::wall::WallLevel;
+use crate::win_conditions::WinConditions;
+use crate::world::World;
+
+pub struct DumpArgs {
+    pub format: DumpFormat,
+    /// Write json/yaml/toml output here instead of stdout. Ignored for
+    /// `Text` format.
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DumpFormat {
+    Text,
+    Json,
+    Yaml,
+    Toml,
+}
+
+/// Bumped whenever `WorldDump`'s shape changes in a way that could break
+/// external tooling (the balance spreadsheet) consuming it.
+const SCHEMA_VERSION: u32 = 26;
+
+/// The JSON interchange format for a loaded `World`. Kept separate from
+/// `World` itself so the schema can be versioned independently of the
+/// in-process representation.
+#[derive(Serialize)]
+struct WorldDump<'a> {
+    schema_version: u32,
+    cultures: &'a [Culture],
+    religions: &'a [Religion],
+    factions: &'a [Faction],
+    regions: &'a [Region],
+    settlements: &'a [Settlement],
+    characters: &'a [Character],
+    families: &'a [FamilyRecord],
+    campaign: &'a CampaignSettings,
+    campaign_db: &'a CampaignDbSection,
+    faction_starts: &'a [FactionStart],
+    heightmap: &'a Option<HeightMap>,
+    terrain: &'a TerrainInfo,
+    buildings: &'a [Building],
+    wall_levels: &'a [WallLevel],
+    name_pools: &'a [NamePool],
+    units: &'a [Unit],
+    projectiles: &'a [Projectile],
+    mounts: &'a [Mount],
+    battle_models: &'a [BattleModel],
+    resources: &'a [ResourceDef],
+    resource_placements: &'a [ResourcePlacement],
+    merc_pools: &'a [MercPool],
+    missions: &'a [Mission],
+    rebel_factions: &'a [RebelFaction],
+    win_conditions: &'a [WinConditions],
+    events: &'a [HistoricEvent],
+    traits: &'a TraitsFile,
+    ancillaries: &'a AncillariesFile,
+    script_commands: &'a [ScriptCommand],
+    voice_classes: &'a [VoiceClass],
+    localization: &'a LocalizationFile,
+    /// Which directory (base data or a mod overlay) supplied each loaded
+    /// file, keyed by filename -- see [`World::sources`].
+    sources: &'a BTreeMap<String, ResolvedPath>,
+}
+
+/// Debug-print (or JSON-dump) every loaded culture, religion, and faction.
+pub fn run(config: &Config, args: &DumpArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+
+    match args.format {
+        DumpFormat::Text => print_text(&world),
+        DumpFormat::Json | DumpFormat::Yaml | DumpFormat::Toml => write_dump(&world, args.format, args.output.as_deref())?,
+    }
+    Ok(())
+}
+
+fn print_text(world: &World) {
+    println!(
+        "loaded {} cultures, {} religions, {} factions, {} regions, {} settlements, {} characters, {} families, {} faction starts",
+        world.cultures.len(),
+        world.religions.len(),
+        world.factions.len(),
+        world.regions.len(),
+        world.settlements.len(),
+        world.characters.len(),
+        world.families.len(),
+        world.faction_starts.len()
+    );
+    println!("buildings: {}", world.buildings.len());
+    println!("wall levels: {}", world.wall_levels.len());
+    println!("name pools: {}", world.name_pools.len());
+    println!("units: {}", world.units.len());
+    println!("projectiles: {}", world.projectiles.len());
+    println!("mounts: {}", world.mounts.len());
+    println!("battle models: {}", world.battle_models.len());
+    println!("resources: {}", world.resources.len());
+    println!("resource placements: {}", world.resource_placements.len());
+    println!("mercenary pools: {}", world.merc_pools.len());
+    println!("missions: {}", world.missions.len());
+    println!("rebel factions: {}", world.rebel_factions.len());
+    println!("win conditions: {}", world.win_conditions.len());
+    println!("events: {}", world.events.len());
+    println!("traits: {}, trait triggers: {}, trait parsing problems: {}", world.traits.traits.len(), world.traits.triggers.len(), world.traits.problems.len());
+    println!(
+        "ancillaries: {}, ancillary triggers: {}, ancillary parsing problems: {}",
+        world.ancillaries.ancillaries.len(),
+        world.ancillaries.triggers.len(),
+        world.ancillaries.problems.len()
+    );
+    println!("campaign script commands: {}", world.script_commands.len());
+    println!("voice classes: {}", world.voice_classes.len());
+    println!(
+        "localization strings: {}, missing keys: {}",
+        world.localization.strings.len(),
+        world.localization.missing_keys.len()
+    );
+    println!("campaign: {:#?}", world.campaign);
+    println!("campaign db: {} top-level sections", world.campaign_db.sections.len());
+    println!("terrain: {}x{}", world.terrain.width, world.terrain.height);
+    match &world.heightmap {
+        Some(heightmap) => println!("heightmap: {}x{}", heightmap.width, heightmap.height),
+        None => println!("heightmap: none found"),
+    }
+    for culture in &world.cultures {
+        println!("culture: {}", culture.name);
+    }
+    for religion in &world.religions {
+        println!("religion: {}", religion.name);
+    }
+    for faction in &world.factions {
+        println!("faction: {faction:#?}");
+    }
+    for region in &world.regions {
+        println!("region: {region:#?}");
+    }
+    for settlement in &world.settlements {
+        println!("settlement: {settlement:#?}");
+    }
+    for character in &world.characters {
+        println!("character: {character:#?}");
+    }
+    for family in &world.families {
+        println!("family: {family:#?}");
+    }
+    for faction_start in &world.faction_starts {
+        println!("faction start: {faction_start:#?}");
+    }
+    for building in &world.buildings {
+        println!("building: {building:#?}");
+    }
+    for wall_level in &world.wall_levels {
+        println!("wall level: {wall_level:#?}");
+    }
+    for name_pool in &world.name_pools {
+        println!("name pool: {name_pool:#?}");
+    }
+    for unit in &world.units {
+        println!("unit: {unit:#?}");
+    }
+    for projectile in &world.projectiles {
+        println!("projectile: {projectile:#?}");
+    }
+    for mount in &world.mounts {
+        println!("mount: {mount:#?}");
+    }
+    for battle_model in &world.battle_models {
+        println!("battle model: {battle_model:#?}");
+    }
+    for resource in &world.resources {
+        println!("resource: {resource:#?}");
+    }
+    for placement in &world.resource_placements {
+        println!("resource placement: {placement:#?}");
+    }
+    for merc_pool in &world.merc_pools {
+        println!("mercenary pool: {merc_pool:#?}");
+    }
+    for mission in &world.missions {
+        println!("mission: {mission:#?}");
+    }
+    for rebel_faction in &world.rebel_factions {
+        println!("rebel faction: {rebel_faction:#?}");
+    }
+    for win_condition in &world.win_conditions {
+        println!("win conditions: {win_condition:#?}");
+    }
+    for event in &world.events {
+        println!("event: {event:#?}");
+    }
+    for trait_ in &world.traits.traits {
+        println!("trait: {trait_:#?}");
+    }
+    for trigger in &world.traits.triggers {
+        println!("trait trigger: {trigger:#?}");
+    }
+    for problem in &world.traits.problems {
+        println!("trait parsing problem: {problem:?}");
+    }
+    for ancillary in &world.ancillaries.ancillaries {
+        println!("ancillary: {ancillary:#?}");
+    }
+    for trigger in &world.ancillaries.triggers {
+        println!("ancillary trigger: {trigger:#?}");
+    }
+    for problem in &world.ancillaries.problems {
+        println!("ancillary parsing problem: {problem:?}");
+    }
+    for command in &world.script_commands {
+        println!("script command: {command:#?}");
+    }
+    for missing in &world.localization.missing_keys {
+        println!("localization missing key: {missing:?}");
+    }
+    for (name, resolved) in &world.sources {
+        println!("source: {name} -> {:?} ({:?})", resolved.path, resolved.source);
+    }
+}
+
+fn write_dump(world: &World, format: DumpFormat, output: Option<&std::path::Path>) -> Result<()> {
+    let dump = WorldDump {
+        schema_version: SCHEMA_VERSION,
+        cultures: &world.cultures,
+        religions: &world.religions,
+        factions: &world.factions,
+        regions: &world.regions,
+        settlements: &world.settlements,
+        characters: &world.characters,
+        families: &world.families,
+        campaign: &world.campaign,
+        campaign_db: &world.campaign_db,
+        faction_starts: &world.faction_starts,
+        heightmap: &world.heightmap,
+        terrain: &world.terrain,
+        buildings: &world.buildings,
+        wall_levels: &world.wall_levels,
+        name_pools: &world.name_pools,
+        units: &world.units,
+        projectiles: &world.projectiles,
+        mounts: &world.mounts,
+        battle_models: &world.battle_models,
+        resources: &world.resources,
+        resource_placements: &world.resource_placements,
+        merc_pools: &world.merc_pools,
+        missions: &world.missions,
+        rebel_factions: &world.rebel_factions,
+        win_conditions: &world.win_conditions,
+        events: &world.events,
+        traits: &world.traits,
+        ancillaries: &world.ancillaries,
+        script_commands: &world.script_commands,
+        voice_classes: &world.voice_classes,
+        localization: &world.localization,
+        sources: &world.sources,
+    };
+    // `WorldDump`'s shape (fixed struct fields, string-keyed maps) is
+    // always representable in all three formats, so a serialization
+    // failure here would be a bug in `WorldDump` itself, not something a
+    // particular `World` could trigger.
+    let text = match format {
+        DumpFormat::Json => serde_json::to_string_pretty(&dump).expect("WorldDump is always serializable as JSON"),
+        // TOML requires every table's scalar fields to precede its nested
+        // tables/array-of-tables, but the `toml` crate's serializer
+        // reorders a struct's fields to satisfy that on its own, so
+        // `units`/`buildings`/etc. come out as readable `[[units]]`
+        // array-of-tables instead of a giant inline table -- no manual
+        // value-tree massaging needed.
+        DumpFormat::Toml => toml::to_string_pretty(&dump).expect("WorldDump is always serializable as TOML"),
+        DumpFormat::Yaml => serde_yaml::to_string(&dump).expect("WorldDump is always serializable as YAML"),
+        DumpFormat::Text => unreachable!("run dispatches Text to print_text instead"),
+    };
+    match output {
+        Some(path) => std::fs::write(path, text).map_err(|e| crate::error::io_err(path, e)),
+        None => {
+            println!("{text}");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn golden_dump_structure() {
+        let world = World {
+            cultures: vec![Culture { id: 0, name: "western".to_string(), portrait_mapping: None, line_number: 1 }],
+            religions: vec![Religion { id: 0, name: "catholic".to_string(), line_number: 3 }],
+            factions: vec![Faction {
+                id: 0,
+                name: "england".to_string(),
+                culture: Some("western".to_string()),
+                religion: Some("catholic".to_string()),
+                ..Faction::default()
+            }],
+            regions: Vec::new(),
+            settlements: Vec::new(),
+            characters: Vec::new(),
+            families: Vec::new(),
+            campaign: CampaignSettings::default(),
+            campaign_db: CampaignDbSection::default(),
+            faction_starts: Vec::new(),
+            diplomacy: crate::campaign::DiplomacyMatrix::default(),
+            heightmap: None,
+            terrain: TerrainInfo { line_number: 1, width: 40, height: 60 },
+            buildings: Vec::new(),
+            wall_levels: Vec::new(),
+            name_pools: Vec::new(),
+            units: Vec::new(),
+            projectiles: Vec::new(),
+            mounts: Vec::new(),
+            battle_models: Vec::new(),
+            resources: Vec::new(),
+            resource_placements: Vec::new(),
+            merc_pools: Vec::new(),
+            missions: Vec::new(),
+            rebel_factions: Vec::new(),
+            win_conditions: Vec::new(),
+            events: Vec::new(),
+            traits: TraitsFile::default(),
+            ancillaries: AncillariesFile::default(),
+            script_commands: Vec::new(),
+            voice_classes: Vec::new(),
+            localization: LocalizationFile::default(),
+            sources: BTreeMap::new(),
+            index: std::sync::OnceLock::new(),
+        };
+        let dump = WorldDump {
+            schema_version: SCHEMA_VERSION,
+            cultures: &world.cultures,
+            religions: &world.religions,
+            factions: &world.factions,
+            regions: &world.regions,
+            settlements: &world.settlements,
+            characters: &world.characters,
+            families: &world.families,
+            campaign: &world.campaign,
+            campaign_db: &world.campaign_db,
+            faction_starts: &world.faction_starts,
+            heightmap: &world.heightmap,
+            terrain: &world.terrain,
+            buildings: &world.buildings,
+            wall_levels: &world.wall_levels,
+            name_pools: &world.name_pools,
+            units: &world.units,
+            projectiles: &world.projectiles,
+            mounts: &world.mounts,
+            battle_models: &world.battle_models,
+            resources: &world.resources,
+            resource_placements: &world.resource_placements,
+            merc_pools: &world.merc_pools,
+            missions: &world.missions,
+            rebel_factions: &world.rebel_factions,
+            win_conditions: &world.win_conditions,
+            events: &world.events,
+            traits: &world.traits,
+            ancillaries: &world.ancillaries,
+            script_commands: &world.script_commands,
+            voice_classes: &world.voice_classes,
+            localization: &world.localization,
+            sources: &world.sources,
+        };
+        let json = serde_json::to_value(&dump).unwrap();
+
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["cultures"][0]["id"], 0);
+        assert_eq!(json["cultures"][0]["name"], "western");
+        assert_eq!(json["religions"][0]["id"], 0);
+        assert_eq!(json["religions"][0]["name"], "catholic");
+        assert_eq!(json["factions"][0]["id"], 0);
+        assert_eq!(json["factions"][0]["name"], "england");
+        assert_eq!(json["factions"][0]["culture"], "western");
+        assert_eq!(json["factions"][0]["religion"], "catholic");
+        assert_eq!(json["factions"][0]["symbol"], serde_json::Value::Null);
+
+        let yaml = serde_yaml::to_value(&dump).unwrap();
+        assert_eq!(yaml["schema_version"], SCHEMA_VERSION);
+        assert_eq!(yaml["cultures"][0]["name"], "western");
+        assert_eq!(yaml["factions"][0]["culture"], "western");
+        assert_eq!(yaml["factions"][0]["symbol"], serde_yaml::Value::Null);
+
+        // TOML has no top-level `null`, so `WorldDump`'s all-`None` fields
+        // (like `heightmap` here) must be absent rather than present with
+        // a null value, and a list of structs (`factions`) must come out
+        // as `[[factions]]` array-of-tables, not an inline `factions = [...]`.
+        let toml = toml::to_string_pretty(&dump).unwrap();
+        assert!(!toml.contains("heightmap"), "None fields must be omitted from TOML, got:\n{toml}");
+        assert!(toml.contains("[[factions]]"), "structs in a list must become array-of-tables, got:\n{toml}");
+        assert!(toml.contains("name = \"england\""), "got:\n{toml}");
+    }
+}
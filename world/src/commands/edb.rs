@@ -0,0 +1,89 @@
+use std::path::Path;
+
+use crate::config::Config;
+use crate::edb::EdbDocument;
+use crate::error::{io_err, Result, WorldError};
+use crate::requires::{Clause, Requirement};
+use crate::validate::closest_match;
+
+pub struct RequireArgs {
+    pub level: String,
+    /// A `requires` clause to add, e.g. `hidden_resource gunpowder` or `not
+    /// building_present tavern`; repeatable.
+    pub add: Vec<String>,
+    /// Replaces the level's `factions { ... }` clause with exactly these
+    /// factions.
+    pub to_factions: Vec<String>,
+    pub dry_run: bool,
+}
+
+/// Edits a building level's `requires` expression in place: each `--add`
+/// appends a clause via [`crate::requires::RequiresExpr::add_requirement`],
+/// and `--to-factions` replaces its `factions { ... }` clause via
+/// [`crate::requires::RequiresExpr::restrict_to_factions`]. Only the header
+/// line changes; everything else round-trips byte-identical. With
+/// `args.dry_run`, nothing is written -- the report is exactly what would
+/// have changed.
+pub fn run_require(config: &Config, args: &RequireArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let level_names = world.buildings.iter().flat_map(|b| b.levels.iter().map(|l| &l.name));
+    if !world.buildings.iter().any(|b| b.levels.iter().any(|l| l.name == args.level)) {
+        return Err(not_found(&args.level, level_names));
+    }
+
+    let path = config.resolve(Path::new("export_descr_buildings.txt")).path;
+    let text = std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+    let mut doc = EdbDocument::parse(&text);
+    let mut level = doc.level_mut(&args.level).expect("just confirmed this level exists");
+
+    for clause in &args.add {
+        level.add_requirement(parse_requirement(clause)?);
+    }
+    if !args.to_factions.is_empty() {
+        level.restrict_to_factions(&args.to_factions);
+    }
+
+    println!("{}:", path.display());
+    println!("  {:?}: requires {}", args.level, level.requires().render());
+    if !args.dry_run {
+        std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    }
+    Ok(())
+}
+
+/// Parses one `--add` clause the same grammar
+/// [`crate::requires::RequiresExpr::parse`] uses for a single comma-separated
+/// clause (an optional leading `not`, then `factions { ... }`,
+/// `building_present NAME`, or `hidden_resource NAME`), so a clause this
+/// module doesn't specifically recognize is kept verbatim rather than
+/// rejected.
+fn parse_requirement(text: &str) -> Result<Requirement> {
+    let trimmed = text.trim();
+    let (negated, rest) = match trimmed.strip_prefix("not ") {
+        Some(rest) => (true, rest.trim()),
+        None => (false, trimmed),
+    };
+    if rest.is_empty() {
+        return Err(WorldError::InvalidArgument("--add requires a clause, e.g. \"hidden_resource gunpowder\"".to_string()));
+    }
+
+    let mut parts = rest.splitn(2, char::is_whitespace);
+    let keyword = parts.next().unwrap_or_default();
+    let value = parts.next().unwrap_or_default().trim();
+    let clause = match keyword {
+        "hidden_resource" if !value.is_empty() => Clause::HiddenResource(value.to_string()),
+        "building_present" if !value.is_empty() => Clause::BuildingPresent(value.to_string()),
+        "factions" => value
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .map(|names| Clause::Factions(names.split(',').map(str::trim).filter(|n| !n.is_empty()).map(str::to_string).collect()))
+            .ok_or_else(|| WorldError::InvalidArgument(format!("{text:?} is not a valid factions clause (expected `factions {{ name, ... }}`)")))?,
+        _ => Clause::Unknown(rest.to_string()),
+    };
+    Ok(Requirement { negated, clause })
+}
+
+fn not_found<'a>(name: &str, candidates: impl Iterator<Item = &'a String>) -> WorldError {
+    let suggestion = closest_match(name, candidates).map(|s| format!(", did you mean {s:?}?")).unwrap_or_default();
+    WorldError::NotFound { kind: "building level", name: name.to_string(), suggestion }
+}
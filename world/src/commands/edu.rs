@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use crate::config::Config;
+use crate::error::{io_err, Result, WorldError};
+use crate::text;
+use crate::unit::{EduDocument, UnitRecordMut};
+
+pub struct EditArgs {
+    pub filter: Option<UnitFilter>,
+    pub sets: Vec<SetExpr>,
+}
+
+/// A `--filter 'key value'` restriction on which units `world edu edit`
+/// touches, matched against a raw `export_descr_unit.txt` field (e.g.
+/// `class`, `category`) rather than [`crate::unit::Unit`]'s typed fields, so
+/// it works on any key this parser hasn't given a dedicated accessor.
+pub struct UnitFilter {
+    key: String,
+    value: String,
+}
+
+impl UnitFilter {
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let mut parts = text.trim().splitn(2, char::is_whitespace);
+        let key = parts.next().filter(|s| !s.is_empty()).ok_or_else(|| format!("--filter {text:?} is empty"))?;
+        let value = parts.next().map(str::trim).filter(|s| !s.is_empty()).ok_or_else(|| format!("--filter {text:?} needs a `key value` pair"))?;
+        Ok(UnitFilter { key: key.to_string(), value: value.to_string() })
+    }
+
+    fn matches(&self, doc: &EduDocument, name: &str) -> bool {
+        doc.field(name, &self.key) == Some(self.value.as_str())
+    }
+}
+
+/// A `--set field(+=|-=|*=|=)value` edit, parsed once up front so a typo in
+/// one expression fails the whole command before anything is written,
+/// instead of leaving the file half-edited.
+pub enum SetExpr {
+    Morale { op: SetOp, value: i64 },
+    /// Scales every `stat_cost` field by `factor` (only `*=` makes sense
+    /// here -- an absolute or additive morale-style edit would need to
+    /// name which of the six comma-separated fields it means).
+    Cost { factor: f64 },
+}
+
+pub enum SetOp {
+    Set,
+    Add,
+    Sub,
+}
+
+impl SetExpr {
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let (field, op, value) = split_op(text).ok_or_else(|| format!("--set {text:?} is missing an operator (expected =, +=, -=, or *=)"))?;
+        let parse_int = |value: &str| value.trim().parse::<i64>().map_err(|_| format!("--set {text:?}: {value:?} is not a valid integer"));
+        let parse_float = |value: &str| value.trim().parse::<f64>().map_err(|_| format!("--set {text:?}: {value:?} is not a valid number"));
+
+        match field {
+            "morale" => {
+                let op = match op {
+                    "=" => SetOp::Set,
+                    "+=" => SetOp::Add,
+                    "-=" => SetOp::Sub,
+                    "*=" => return Err(format!("--set {text:?}: morale doesn't support *= (use = or += or -=)")),
+                    _ => unreachable!("split_op only returns a known operator"),
+                };
+                Ok(SetExpr::Morale { op, value: parse_int(value)? })
+            }
+            "cost" => {
+                if op != "*=" {
+                    return Err(format!("--set {text:?}: cost only supports *= (scale every stat_cost field by a factor)"));
+                }
+                Ok(SetExpr::Cost { factor: parse_float(value)? })
+            }
+            other => Err(format!("--set {text:?}: unknown field {other:?} (expected \"morale\" or \"cost\")")),
+        }
+    }
+
+    fn apply(&self, unit: &mut UnitRecordMut) -> std::result::Result<(), String> {
+        match self {
+            SetExpr::Morale { op, value } => {
+                let current = unit.morale().ok_or("unit has no stat_mental line to edit")?;
+                let new_value = match op {
+                    SetOp::Set => *value,
+                    SetOp::Add => current + value,
+                    SetOp::Sub => current - value,
+                };
+                unit.set_morale(new_value);
+            }
+            SetExpr::Cost { factor } => unit.scale_cost(*factor),
+        }
+        Ok(())
+    }
+}
+
+/// Splits `text` on the first of `+=`, `-=`, `*=`, or `=` it finds (checked
+/// in that order, so `morale+=2` doesn't get misread as field `morale+`
+/// with a bare `=`), returning `(field, operator, value)`.
+fn split_op(text: &str) -> Option<(&str, &str, &str)> {
+    for op in ["+=", "-=", "*=", "="] {
+        if let Some(index) = text.find(op) {
+            return Some((&text[..index], op, &text[index + op.len()..]));
+        }
+    }
+    None
+}
+
+/// Applies every `sets` expression to each unit matching `filter` (or every
+/// unit, if there's no filter) and writes `export_descr_unit.txt` back in
+/// place through [`EduDocument`], touching only the lines the edits
+/// actually changed. A run with an empty `sets` list is a no-op that
+/// rewrites the file byte-identical to what it read.
+pub fn run_edit(config: &Config, args: &EditArgs) -> Result<()> {
+    let path = config.resolve(Path::new("export_descr_unit.txt")).path;
+    let source = text::load_text_file(&path)?;
+    let mut doc = EduDocument::parse(&source);
+
+    let names: Vec<String> = doc.unit_names().map(str::to_string).collect();
+    let mut edited = 0;
+    for name in &names {
+        if let Some(filter) = &args.filter {
+            if !filter.matches(&doc, name) {
+                continue;
+            }
+        }
+        let mut unit = doc.unit_mut(name).expect("name was just read from unit_names");
+        for set in &args.sets {
+            set.apply(&mut unit).map_err(|message| WorldError::InvalidArgument(format!("{name}: {message}")))?;
+        }
+        edited += 1;
+    }
+
+    std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    println!("edited {edited} unit(s) in {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::events::HistoricEvent;
+use crate::world::World;
+
+pub struct EventsArgs {
+    pub format: EventsFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EventsFormat {
+    Text,
+    Json,
+}
+
+/// Prints every `descr_events.txt` event/disaster as a timeline, ordered by
+/// the earliest turn it can trigger (converted from its date using the
+/// campaign's start year and timescale).
+pub fn run(config: &Config, args: &EventsArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let timeline = timeline(&world);
+
+    match args.format {
+        EventsFormat::Text => print_text(&timeline),
+        EventsFormat::Json => print_json(&timeline),
+    }
+    Ok(())
+}
+
+struct TimelineEntry<'a> {
+    turn: i64,
+    event: &'a HistoricEvent,
+}
+
+fn timeline(world: &World) -> Vec<TimelineEntry<'_>> {
+    let start_year = world.campaign.start_year();
+    let timescale = world.campaign.timescale;
+
+    let mut entries: Vec<TimelineEntry> =
+        world.events.iter().map(|event| TimelineEntry { turn: event.earliest_turn(start_year, timescale), event }).collect();
+    entries.sort_by_key(|entry| entry.turn);
+    entries
+}
+
+fn print_text(timeline: &[TimelineEntry]) {
+    if timeline.is_empty() {
+        println!("no events found");
+        return;
+    }
+    for entry in timeline {
+        let event = entry.event;
+        println!(
+            "turn {}: {:?} {:?} ({}-{}), {} position(s){}",
+            entry.turn,
+            event.kind,
+            event.name,
+            event.date_range.0,
+            event.date_range.1,
+            event.positions.len(),
+            match &event.movie {
+                Some(movie) => format!(", movie {movie:?}"),
+                None => String::new(),
+            }
+        );
+    }
+}
+
+#[derive(Serialize)]
+struct TimelineEntryReport<'a> {
+    turn: i64,
+    event: &'a HistoricEvent,
+}
+
+fn print_json(timeline: &[TimelineEntry]) {
+    let report: Vec<TimelineEntryReport> = timeline.iter().map(|entry| TimelineEntryReport { turn: entry.turn, event: entry.event }).collect();
+    let json = serde_json::to_string_pretty(&report).expect("timeline is always serializable");
+    println!("{json}");
+}
@@ -0,0 +1,139 @@
+use std::path::{Path, PathBuf};
+
+use crate::building::{Building, BuildingLevel};
+use crate::config::Config;
+use crate::error::{Result, WorldError};
+use crate::map::{region_adjacency, RegionBorder};
+use crate::region::Region;
+use crate::tga::TgaImage;
+use crate::validate::{closest_match, REBEL_FACTION};
+
+pub struct GraphRegionsArgs {
+    /// Write the DOT source here instead of stdout.
+    pub output: Option<PathBuf>,
+}
+
+pub struct GraphBuildingsArgs {
+    pub name: String,
+    /// Write the DOT source here instead of stdout.
+    pub output: Option<PathBuf>,
+}
+
+/// Emits the region adjacency graph derived from `map_regions.tga` as a DOT
+/// `graph` (regions as nodes, shared borders as edges), each node filled by
+/// its starting owner (`descr_regions.txt`'s `creator_faction`, or grey for
+/// the [`REBEL_FACTION`]) so a rendered map reads like a starting-position
+/// screenshot.
+pub fn run_regions(config: &Config, args: &GraphRegionsArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let image_path = config.resolve(Path::new("map_regions.tga")).path;
+    let image = TgaImage::load(&image_path)?;
+    let borders = region_adjacency(&world.regions, &image);
+    write_dot(regions_dot(&world.regions, &borders), args.output.as_deref())
+}
+
+/// Emits `building`'s level chain (`hovel -> village -> town`) as a DOT
+/// `digraph`, with each level's `recruit_pool` entries hanging off it so the
+/// recruitment a level unlocks is visible alongside the upgrade path.
+pub fn run_buildings(config: &Config, args: &GraphBuildingsArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let building = world.buildings.iter().find(|b| b.name.eq_ignore_ascii_case(&args.name)).ok_or_else(|| {
+        let suggestion = closest_match(&args.name, world.buildings.iter().map(|b| &b.name)).map(|s| format!(", did you mean {s:?}?")).unwrap_or_default();
+        WorldError::NotFound { kind: "building", name: args.name.clone(), suggestion }
+    })?;
+    write_dot(building_dot(building), args.output.as_deref())
+}
+
+fn write_dot(dot: String, output: Option<&Path>) -> Result<()> {
+    match output {
+        Some(path) => std::fs::write(path, dot).map_err(|e| crate::error::io_err(path, e)),
+        None => {
+            println!("{dot}");
+            Ok(())
+        }
+    }
+}
+
+/// A small fixed palette so factions get a stable, readable fill colour
+/// without pulling in a colour-generation dependency; cycles once there are
+/// more starting owners than colours, which no vanilla or SS campaign has.
+const PALETTE: &[&str] = &[
+    "lightblue", "lightsalmon", "lightgreen", "khaki", "plum", "lightpink", "peachpuff", "palegreen", "wheat", "thistle", "skyblue", "gold",
+];
+
+fn owner_colour(owners: &[&str], owner: &str) -> &'static str {
+    if owner == REBEL_FACTION {
+        return "gray80";
+    }
+    match owners.iter().position(|o| *o == owner) {
+        Some(index) => PALETTE[index % PALETTE.len()],
+        None => "gray80",
+    }
+}
+
+fn regions_dot(regions: &[Region], borders: &[RegionBorder]) -> String {
+    let mut owners: Vec<&str> = regions.iter().map(|r| r.creator_faction.as_str()).filter(|f| *f != REBEL_FACTION).collect();
+    owners.sort_unstable();
+    owners.dedup();
+
+    let mut dot = String::from("graph regions {\n");
+    for region in regions {
+        let colour = owner_colour(&owners, &region.creator_faction);
+        dot.push_str(&format!(
+            "    {} [label={}, style=filled, fillcolor={}];\n",
+            dot_quote(&region.name),
+            dot_quote(region.display_name.as_deref().unwrap_or(&region.name)),
+            colour
+        ));
+    }
+    for border in borders {
+        dot.push_str(&format!("    {} -- {};\n", dot_quote(&border.a), dot_quote(&border.b)));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn building_dot(building: &Building) -> String {
+    let mut dot = String::from("digraph building {\n    rankdir=LR;\n");
+    for level in &building.levels {
+        dot.push_str(&format!("    {} [label={}, shape=box];\n", dot_quote(&level.name), level_label(level)));
+    }
+    for pair in building.levels.windows(2) {
+        dot.push_str(&format!("    {} -> {};\n", dot_quote(&pair[0].name), dot_quote(&pair[1].name)));
+    }
+    for level in &building.levels {
+        for entry in &level.recruitment {
+            let unit_id = format!("unit_{}", entry.unit);
+            dot.push_str(&format!("    {} [label={}, shape=ellipse, style=dashed];\n", dot_quote(&unit_id), dot_quote(&entry.unit)));
+            dot.push_str(&format!("    {} -> {} [style=dashed, label=recruits];\n", dot_quote(&level.name), dot_quote(&unit_id)));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// A level's DOT label, already quoted: the cost, when known, goes on a
+/// second line via DOT's `\n` label escape. That escape has to survive
+/// [`dot_escape`] untouched, so it's appended after escaping rather than
+/// being escaped along with `level.name`.
+fn level_label(level: &BuildingLevel) -> String {
+    match level.cost {
+        Some(cost) => format!("\"{}\\n{cost} florins\"", dot_escape(&level.name)),
+        None => dot_quote(&level.name),
+    }
+}
+
+/// Escapes the characters that would otherwise end a DOT quoted string or
+/// break out of it, without adding the surrounding quotes -- see
+/// [`dot_quote`].
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Quotes `text` for use as a DOT node id or string label: identifiers must
+/// be alphanumeric/underscore or quoted, and this codebase's names
+/// (`northern_european`, `Sergeant Spearmen`) mix both, so every id and
+/// label is just quoted rather than trying to tell which ones need it.
+fn dot_quote(text: &str) -> String {
+    format!("\"{}\"", dot_escape(text))
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use crate::config::Config;
+use crate::error::{Result, WorldError};
+use crate::map::{check_regions, CheckRegionsReport};
+use crate::region::Region;
+use crate::tga::TgaImage;
+use crate::text;
+
+pub struct CheckRegionsArgs {
+    pub format: CheckRegionsFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CheckRegionsFormat {
+    Text,
+    Json,
+}
+
+/// Cross-checks `descr_regions.txt` against `map_regions.tga`: every region
+/// colour should appear in the image, every non-black image colour should
+/// belong to a region, and region sizes are reported so modders can spot
+/// accidentally-merged or vanishingly small regions. Exits non-zero, via
+/// [`WorldError::ValidationFailed`], if any region colour is missing from
+/// the image -- that's the case that crashes campaign load.
+pub fn run(config: &Config, args: &CheckRegionsArgs) -> Result<()> {
+    let regions_relative = Path::new("descr_regions.txt");
+    let (bytes, resolved) = config
+        .read_data(regions_relative)?
+        .ok_or_else(|| WorldError::MissingFile(config.resolve(regions_relative).path))?;
+    let regions_text = text::decode_text(&bytes, &resolved.path)?;
+    let regions = Region::load_all(&resolved.path, &regions_text)?;
+
+    let image_path = config.resolve(Path::new("map_regions.tga")).path;
+    let image = TgaImage::load(&image_path)?;
+    let report = check_regions(&regions, &image);
+    let missing_count = report.missing_regions.len();
+
+    match args.format {
+        CheckRegionsFormat::Text => print_text(&report),
+        CheckRegionsFormat::Json => print_json(&report),
+    }
+
+    if missing_count > 0 {
+        return Err(WorldError::ValidationFailed(missing_count));
+    }
+    Ok(())
+}
+
+fn print_text(report: &CheckRegionsReport) {
+    for count in &report.region_pixel_counts {
+        println!("region {:?}: colour {:?}, {} pixels", count.region, count.colour, count.pixel_count);
+    }
+    for region in &report.missing_regions {
+        println!("error: region {region:?} has no matching pixels in map_regions.tga");
+    }
+    for colour in &report.unmatched_colours {
+        println!("warning: colour {:?} ({} pixels) in map_regions.tga has no matching region", colour.colour, colour.pixel_count);
+    }
+    if report.suspicious_black_pixels > 0 {
+        println!("warning: {} black pixel(s) border an unrecognized colour", report.suspicious_black_pixels);
+    }
+    if report.missing_regions.is_empty() && report.unmatched_colours.is_empty() && report.suspicious_black_pixels == 0 {
+        println!("no problems found");
+    }
+}
+
+fn print_json(report: &CheckRegionsReport) {
+    let json = serde_json::to_string_pretty(report).expect("CheckRegionsReport is always serializable");
+    println!("{json}");
+}
@@ -0,0 +1,16 @@
+pub mod diff;
+pub mod dump;
+pub mod edb;
+pub mod edu;
+pub mod events;
+pub mod graph;
+pub mod map;
+pub mod query;
+pub mod refactor;
+pub mod religions;
+pub mod report;
+pub mod resources;
+pub mod scaffold;
+pub mod stats;
+pub mod strat;
+pub mod validate;
@@ -0,0 +1,234 @@
+use serde::Serialize;
+
+use crate::campaign::Stance;
+use crate::config::{Config, DataSource};
+use crate::culture::Culture;
+use crate::error::{Result, WorldError};
+use crate::faction::Faction;
+use crate::region::Region;
+use crate::validate::closest_match;
+use crate::world::World;
+
+pub struct QueryArgs {
+    pub target: QueryTarget,
+    pub format: QueryFormat,
+}
+
+pub enum QueryTarget {
+    Faction(String),
+    Region(String),
+    Unit(String),
+    Diplomacy(String),
+    Culture(String),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum QueryFormat {
+    Text,
+    Json,
+}
+
+/// Looks up a single faction, region, or unit by name and prints a focused
+/// summary of it, including the cross-references a modder would otherwise
+/// have to piece together by hand across several files. Exits with an error
+/// (and a "did you mean" suggestion) when nothing matches, so scripts can
+/// use this to test whether a record exists.
+pub fn run(config: &Config, args: &QueryArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+
+    match &args.target {
+        QueryTarget::Faction(name) => query_faction(&world, name, args.format),
+        QueryTarget::Region(name) => query_region(&world, name, args.format),
+        QueryTarget::Unit(name) => query_unit(&world, name, args.format),
+        QueryTarget::Diplomacy(name) => query_diplomacy(&world, name, args.format),
+        QueryTarget::Culture(name) => query_culture(config, &world, name, args.format),
+    }
+}
+
+/// Builds the "no such record" error for a failed lookup, suggesting the
+/// closest name among `candidates` if there is one.
+fn not_found<'a>(kind: &'static str, name: &str, candidates: impl Iterator<Item = &'a String>) -> WorldError {
+    let suggestion = closest_match(name, candidates).map(|s| format!(", did you mean {s:?}?")).unwrap_or_default();
+    WorldError::NotFound { kind, name: name.to_string(), suggestion }
+}
+
+fn query_faction(world: &World, name: &str, format: QueryFormat) -> Result<()> {
+    let faction = world.faction_by_name(name).ok_or_else(|| not_found("faction", name, world.factions.iter().map(|f| &f.name)))?;
+
+    let starting_settlements: Vec<&str> =
+        world.settlements.iter().filter(|s| s.owning_faction == faction.name).map(|s| s.display_name.as_deref().unwrap_or(s.region.as_str())).collect();
+    let recruitable_units: Vec<&str> = world.units.iter().filter(|u| u.ownership.iter().any(|o| o == &faction.name)).map(|u| u.name.as_str()).collect();
+
+    match format {
+        QueryFormat::Text => {
+            println!("faction: {} ({})", faction.name, faction.display_name.as_deref().unwrap_or(&faction.name));
+            println!("  culture: {}", faction.culture.as_deref().unwrap_or("none"));
+            println!("  religion: {}", faction.religion.as_deref().unwrap_or("none"));
+            println!(
+                "  starting settlements ({}): {}",
+                starting_settlements.len(),
+                if starting_settlements.is_empty() { "none".to_string() } else { starting_settlements.join(", ") }
+            );
+            println!(
+                "  recruitable units ({}): {}",
+                recruitable_units.len(),
+                if recruitable_units.is_empty() { "none".to_string() } else { recruitable_units.join(", ") }
+            );
+        }
+        QueryFormat::Json => {
+            let report = FactionReport { faction, starting_settlements, recruitable_units };
+            println!("{}", serde_json::to_string_pretty(&report).expect("FactionReport is always serializable"));
+        }
+    }
+    Ok(())
+}
+
+fn query_region(world: &World, name: &str, format: QueryFormat) -> Result<()> {
+    let region = world.region_by_name(name).ok_or_else(|| not_found("region", name, world.regions.iter().map(|r| &r.name)))?;
+
+    let settlements: Vec<&str> =
+        world.settlements.iter().filter(|s| s.region == region.name).map(|s| s.display_name.as_deref().unwrap_or(s.region.as_str())).collect();
+
+    match format {
+        QueryFormat::Text => {
+            println!("region: {} ({})", region.name, region.display_name.as_deref().unwrap_or(&region.name));
+            println!("  creator faction: {}", region.creator_faction);
+            println!("  rebel type: {}", region.rebel_type);
+            println!("  settlements ({}): {}", settlements.len(), if settlements.is_empty() { "none".to_string() } else { settlements.join(", ") });
+        }
+        QueryFormat::Json => {
+            let report = RegionReport { region, settlements };
+            println!("{}", serde_json::to_string_pretty(&report).expect("RegionReport is always serializable"));
+        }
+    }
+    Ok(())
+}
+
+fn query_unit(world: &World, name: &str, format: QueryFormat) -> Result<()> {
+    let unit = world.unit_by_type(name).ok_or_else(|| not_found("unit", name, world.units.iter().map(|u| &u.name)))?;
+
+    match format {
+        QueryFormat::Text => {
+            println!("unit: {} ({})", unit.name, unit.display_name.as_deref().unwrap_or(&unit.name));
+            println!("  recruited by ({}): {}", unit.ownership.len(), if unit.ownership.is_empty() { "none".to_string() } else { unit.ownership.join(", ") });
+        }
+        QueryFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(unit).expect("Unit is always serializable"));
+        }
+    }
+    Ok(())
+}
+
+/// A faction's starting stance toward every other faction, from
+/// `world.diplomacy`. A pair with no explicit declaration defaults to
+/// [`Stance::Peace`], the engine's own default.
+fn query_diplomacy(world: &World, name: &str, format: QueryFormat) -> Result<()> {
+    let faction = world.faction_by_name(name).ok_or_else(|| not_found("faction", name, world.factions.iter().map(|f| &f.name)))?;
+
+    let stances: Vec<StanceEntry> = world
+        .factions
+        .iter()
+        .filter(|other| other.name != faction.name)
+        .map(|other| StanceEntry { faction: other.name.clone(), stance: world.diplomacy.stance_between(&faction.name, &other.name).unwrap_or(Stance::Peace) })
+        .collect();
+
+    match format {
+        QueryFormat::Text => {
+            println!("diplomacy: {} ({})", faction.name, faction.display_name.as_deref().unwrap_or(&faction.name));
+            for entry in &stances {
+                println!("  {}: {:?}", entry.faction, entry.stance);
+            }
+        }
+        QueryFormat::Json => {
+            let report = DiplomacyReport { faction: faction.name.clone(), stances };
+            println!("{}", serde_json::to_string_pretty(&report).expect("DiplomacyReport is always serializable"));
+        }
+    }
+    Ok(())
+}
+
+/// A culture's `young`/`old`/`dead` portrait category, and whether the
+/// override cascade (mod, then packs, then base) actually has a directory
+/// with at least one file in it -- so a modder can tell "missing" and
+/// "present but empty" apart, and see whether they're accidentally relying
+/// on vanilla assets instead of their own mod overlay.
+fn query_culture(config: &Config, world: &World, name: &str, format: QueryFormat) -> Result<()> {
+    let culture = world.culture_by_name(name).ok_or_else(|| not_found("culture", name, world.cultures.iter().map(|c| &c.name)))?;
+
+    let portraits_dir = std::path::Path::new("ui").join(culture.portrait_dir()).join("portraits");
+    let categories: Vec<PortraitCategory> = ["young", "old", "dead"]
+        .into_iter()
+        .map(|category| match config.list_dir(&portraits_dir.join(category)).ok().flatten() {
+            Some((files, source)) if files.is_empty() => PortraitCategory { category: category.to_string(), status: "empty".to_string(), source: Some(source), count: 0 },
+            Some((files, source)) => PortraitCategory { category: category.to_string(), status: "ok".to_string(), source: Some(source), count: files.len() },
+            None => PortraitCategory { category: category.to_string(), status: "missing".to_string(), source: None, count: 0 },
+        })
+        .collect();
+
+    match format {
+        QueryFormat::Text => {
+            println!("culture: {} (portrait mapping: {})", culture.name, culture.portrait_dir());
+            for entry in &categories {
+                match &entry.source {
+                    Some(source) => println!("  {}: {} ({} file(s), via {})", entry.category, entry.status, entry.count, describe_source(source)),
+                    None => println!("  {}: {}", entry.category, entry.status),
+                }
+            }
+        }
+        QueryFormat::Json => {
+            let report = CultureReport { culture, portrait_categories: categories };
+            println!("{}", serde_json::to_string_pretty(&report).expect("CultureReport is always serializable"));
+        }
+    }
+    Ok(())
+}
+
+fn describe_source(source: &DataSource) -> String {
+    match source {
+        DataSource::Mod => "mod".to_string(),
+        DataSource::Base => "base data".to_string(),
+        DataSource::Pack(name) => format!("pack {name:?}"),
+    }
+}
+
+#[derive(Serialize)]
+struct PortraitCategory {
+    category: String,
+    status: String,
+    source: Option<DataSource>,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct CultureReport<'a> {
+    #[serde(flatten)]
+    culture: &'a Culture,
+    portrait_categories: Vec<PortraitCategory>,
+}
+
+#[derive(Serialize)]
+struct StanceEntry {
+    faction: String,
+    stance: Stance,
+}
+
+#[derive(Serialize)]
+struct DiplomacyReport {
+    faction: String,
+    stances: Vec<StanceEntry>,
+}
+
+#[derive(Serialize)]
+struct FactionReport<'a> {
+    #[serde(flatten)]
+    faction: &'a Faction,
+    starting_settlements: Vec<&'a str>,
+    recruitable_units: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct RegionReport<'a> {
+    #[serde(flatten)]
+    region: &'a Region,
+    settlements: Vec<&'a str>,
+}
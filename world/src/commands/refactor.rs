@@ -0,0 +1,631 @@
+use std::path::Path;
+
+use crate::campaign_script::{arg_kinds_for, ArgKind, ScriptCommand};
+use crate::config::Config;
+use crate::error::{io_err, Result};
+use crate::lossless::LosslessDocument;
+use crate::requires::{Clause, RequiresExpr};
+use crate::text::{strip_comment, strip_keyword};
+
+pub struct RenameFactionArgs {
+    pub old: String,
+    pub new: String,
+    pub dry_run: bool,
+}
+
+/// Data files a faction identifier can appear in, in the order the change
+/// report lists them. Not every mod ships every file (`campaign_script.txt`
+/// and `descr_missions.txt` are optional, same as in [`crate::world::World::load`]),
+/// so a missing one is silently skipped rather than an error.
+///
+/// `descr_rebel_factions.txt` and `descr_mercenaries.txt` are deliberately
+/// left out: the former's `identifier` field names a rebel-type category
+/// (slaves, brigands, pirates), never a playable faction, and the latter
+/// has no faction-identifier field at all -- neither has anything a faction
+/// rename could touch.
+const FACTION_REFERENCE_FILES: &[&str] = &[
+    "descr_sm_factions.txt",
+    "descr_strat.txt",
+    "descr_regions.txt",
+    "export_descr_unit.txt",
+    "export_descr_buildings.txt",
+    "descr_win_conditions.txt",
+    "descr_names.txt",
+    "campaign_script.txt",
+    "descr_missions.txt",
+];
+
+/// One line changed by [`rewrite_faction_references`] or
+/// [`rename_localization_key`], printed in the change report whether or not
+/// `--dry-run` actually wrote it.
+struct Edit {
+    line_number: usize,
+    before: String,
+    after: String,
+}
+
+/// Renames every reference to faction `old` to `new` across
+/// [`FACTION_REFERENCE_FILES`] and the `FACTION_<NAME>` localization key in
+/// `text/expanded.txt`, printing a per-file change report. With
+/// `args.dry_run`, nothing is written -- the report is exactly what would
+/// have changed.
+pub fn run_rename_faction(config: &Config, args: &RenameFactionArgs) -> Result<()> {
+    let mut any_edits = false;
+
+    for &filename in FACTION_REFERENCE_FILES {
+        let path = config.resolve(Path::new(filename)).path;
+        let Ok(text) = std::fs::read_to_string(&path) else { continue };
+
+        let mut doc = LosslessDocument::parse(&text);
+        let edits = rewrite_faction_references(filename, &mut doc, &args.old, &args.new);
+        if edits.is_empty() {
+            continue;
+        }
+
+        any_edits = true;
+        report_edits(&path, &edits);
+        if !args.dry_run {
+            std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+        }
+    }
+
+    let locale_dir = config.locale_dir.as_deref().unwrap_or(&config.data_dir);
+    let expanded_path = locale_dir.join("text").join("expanded.txt");
+    if let Ok(text) = std::fs::read_to_string(&expanded_path) {
+        let mut doc = LosslessDocument::parse(&text);
+        let edits = rename_localization_key(&mut doc, &args.old, &args.new);
+        if !edits.is_empty() {
+            any_edits = true;
+            report_edits(&expanded_path, &edits);
+            if !args.dry_run {
+                std::fs::write(&expanded_path, doc.to_text()).map_err(|e| io_err(&expanded_path, e))?;
+            }
+        }
+    }
+
+    if !any_edits {
+        println!("no references to {:?} found", args.old);
+    } else if args.dry_run {
+        println!("dry run: no files were written");
+    }
+    Ok(())
+}
+
+fn report_edits(path: &Path, edits: &[Edit]) {
+    println!("{}:", path.display());
+    for edit in edits {
+        println!("  {}: {} -> {}", edit.line_number, edit.before.trim(), edit.after.trim());
+    }
+}
+
+/// Dispatches to `filename`'s own rewriter, since each of
+/// [`FACTION_REFERENCE_FILES`] spells out a faction reference in its own
+/// field/grammar rather than sharing one layout. This -- not a blind
+/// whole-word text substitution -- is what keeps a rename from touching an
+/// unrelated field that merely happens to contain the faction id (a
+/// character forename in `descr_names.txt`, a settlement name in
+/// `descr_regions.txt`, a `console_command`'s settlement/unit argument in
+/// `campaign_script.txt`, ...).
+fn rewrite_faction_references(filename: &str, doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    match filename {
+        "descr_sm_factions.txt" | "descr_names.txt" => rewrite_faction_headers(doc, old, new),
+        "descr_strat.txt" => rewrite_strat(doc, old, new),
+        "descr_regions.txt" => rewrite_region_creator_factions(doc, old, new),
+        "export_descr_unit.txt" => rewrite_unit_ownership(doc, old, new),
+        "export_descr_buildings.txt" => rewrite_building_requires(doc, old, new),
+        "descr_win_conditions.txt" => rewrite_win_conditions(doc, old, new),
+        "campaign_script.txt" => rewrite_script_commands(doc, old, new),
+        "descr_missions.txt" => rewrite_mission_conditions(doc, old, new),
+        _ => Vec::new(),
+    }
+}
+
+/// Renames the `{FACTION_<OLD>}` localization key to `{FACTION_<NEW>}`
+/// wherever it starts a line, matching the exact key
+/// [`crate::localization::LocalizationFile::apply`] looks a faction's
+/// display name up under. Lines that don't open with that key -- including
+/// `¬`-prefixed comment lines, which never do -- are left alone.
+fn rename_localization_key(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let old_key = format!("{{FACTION_{}}}", old.to_uppercase());
+    let new_key = format!("{{FACTION_{}}}", new.to_uppercase());
+
+    let mut edits = Vec::new();
+    for i in 0..doc.line_count() {
+        let line = doc.line(i).to_string();
+        let Some(rest) = line.strip_prefix(&old_key) else { continue };
+        let new_line = format!("{new_key}{rest}");
+        edits.push(Edit { line_number: i + 1, before: line, after: new_line.clone() });
+        doc.set_line(i, new_line);
+    }
+    edits
+}
+
+/// Applies `rewrite` to line `i`'s content with its trailing `; comment`
+/// (if any) split off first, so no rewriter has to reason about comment
+/// text -- the same split [`crate::unit::UnitRecordMut::set_field`] uses to
+/// edit a line without disturbing its comment. Records an [`Edit`] and
+/// updates `doc` only if `rewrite` actually changed something.
+fn edit_line(doc: &mut LosslessDocument, i: usize, edits: &mut Vec<Edit>, rewrite: impl FnOnce(&str) -> Option<String>) {
+    let line = doc.line(i).to_string();
+    let content = strip_comment(&line);
+    let comment = &line[content.len()..];
+    let Some(replaced) = rewrite(content) else { return };
+    let new_line = format!("{replaced}{comment}");
+    edits.push(Edit { line_number: i + 1, before: line, after: new_line.clone() });
+    doc.set_line(i, new_line);
+}
+
+/// Renames `value` to `new` if it equals `old` exactly once trimmed, `None`
+/// otherwise -- for a field that names exactly one faction, as opposed to a
+/// comma-separated list.
+fn rename_exact(value: &str, old: &str, new: &str) -> Option<String> {
+    (value.trim() == old).then(|| new.to_string())
+}
+
+/// Renames `content` (a bare value line with no keyword prefix, e.g. a
+/// `descr_win_conditions.txt` header or `descr_regions.txt`'s positional
+/// `creator_faction` field) if it names exactly `old`, preserving
+/// indentation.
+fn rename_bare_line(content: &str, old: &str, new: &str) -> Option<String> {
+    let indent_len = content.len() - content.trim_start().len();
+    let indent = &content[..indent_len];
+    rename_exact(content.trim(), old, new).map(|value| format!("{indent}{value}"))
+}
+
+/// Renames `old` to `new` wherever it's one entry of `list`'s
+/// comma-separated names, `None` if it isn't in the list at all. Any other
+/// entry -- including one that merely contains `old` as a substring -- is
+/// left untouched.
+fn rename_in_list(list: &str, old: &str, new: &str) -> Option<String> {
+    let names: Vec<&str> = list.split(',').map(str::trim).filter(|s| !s.is_empty()).collect();
+    if !names.contains(&old) {
+        return None;
+    }
+    let renamed: Vec<&str> = names.iter().map(|&name| if name == old { new } else { name }).collect();
+    Some(renamed.join(", "))
+}
+
+/// Rewrites a `keyword VALUE` line's value via `rename`, preserving
+/// indentation. `None` if `content` doesn't start with `keyword` (using the
+/// same word-boundary check [`crate::text::strip_keyword`] uses everywhere
+/// else in this crate) or `rename` leaves the value unchanged.
+fn rewrite_keyword_value(content: &str, keyword: &str, rename: impl FnOnce(&str) -> Option<String>) -> Option<String> {
+    let indent_len = content.len() - content.trim_start().len();
+    let indent = &content[..indent_len];
+    let rest = strip_keyword(content.trim_start(), keyword)?;
+    let new_value = rename(rest)?;
+    Some(format!("{indent}{keyword} {new_value}"))
+}
+
+/// Splits `doc`'s line indices into blank-line-separated blocks, the record
+/// layout `descr_regions.txt` and `descr_win_conditions.txt` both use --
+/// same "blank once its trailing comment is stripped" boundary
+/// [`crate::region::Region::load_all`]'s own block splitter uses.
+fn blank_line_blocks(doc: &LosslessDocument) -> Vec<Vec<usize>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for i in 0..doc.line_count() {
+        if strip_comment(doc.line(i)).trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(i);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+/// Renames a bare `faction NAME` header line, the header form both
+/// `descr_sm_factions.txt` and `descr_names.txt` use. `descr_names.txt`'s
+/// name-pool body underneath each header (`male`/`female`/`surnames` lists
+/// of individual characters' forenames/surnames) never matches the
+/// `faction` keyword, so a character whose name happens to equal `old` is
+/// left alone.
+fn rewrite_faction_headers(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for i in 0..doc.line_count() {
+        edit_line(doc, i, &mut edits, |content| rewrite_keyword_value(content, "faction", |rest| rename_exact(rest, old, new)));
+    }
+    edits
+}
+
+/// Rewrites `descr_strat.txt`'s several faction-identifier fields: the
+/// `faction NAME[, denari]` header, `superfaction`, `faction_creator`,
+/// `relationship { }` blocks' `faction_1`/`faction_2`, the older
+/// `faction_relationships`/`core_attitudes` triples, and the
+/// `playable`/`unlockable`/`nonplayable` lists.
+fn rewrite_strat(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for i in 0..doc.line_count() {
+        edit_line(doc, i, &mut edits, |content| rewrite_strat_line(content, old, new));
+    }
+    edits
+}
+
+fn rewrite_strat_line(content: &str, old: &str, new: &str) -> Option<String> {
+    rewrite_keyword_value(content, "faction", |rest| rename_faction_header_value(rest, old, new))
+        .or_else(|| rewrite_keyword_value(content, "superfaction", |rest| rename_exact(rest, old, new)))
+        .or_else(|| rewrite_keyword_value(content, "faction_creator", |rest| rename_exact(rest, old, new)))
+        .or_else(|| rewrite_keyword_value(content, "faction_1", |rest| rename_exact(rest, old, new)))
+        .or_else(|| rewrite_keyword_value(content, "faction_2", |rest| rename_exact(rest, old, new)))
+        .or_else(|| rewrite_keyword_value(content, "playable", |rest| rename_in_list(rest, old, new)))
+        .or_else(|| rewrite_keyword_value(content, "unlockable", |rest| rename_in_list(rest, old, new)))
+        .or_else(|| rewrite_keyword_value(content, "nonplayable", |rest| rename_in_list(rest, old, new)))
+        .or_else(|| rewrite_relationship_triple(content, old, new))
+}
+
+/// Renames a `faction NAME` or `faction NAME, denari` header's name,
+/// leaving a trailing `, denari` clause (if any) untouched.
+fn rename_faction_header_value(rest: &str, old: &str, new: &str) -> Option<String> {
+    match rest.split_once(',') {
+        Some((name, tail)) => (name.trim() == old).then(|| format!("{new},{tail}")),
+        None => rename_exact(rest, old, new),
+    }
+}
+
+/// Renames a bare `FACTION_A FACTION_B STANCE` line, the older
+/// `faction_relationships`/`core_attitudes` declaration form
+/// [`crate::campaign::DiplomacyMatrix::load`] parses. Its last token being
+/// one of the three [`crate::campaign::Stance`] values is what tells this
+/// three-token shape apart from any other bare line, so a rename here can't
+/// mistake an unrelated triple for a relationship declaration.
+fn rewrite_relationship_triple(content: &str, old: &str, new: &str) -> Option<String> {
+    let indent_len = content.len() - content.trim_start().len();
+    let indent = &content[..indent_len];
+    let mut tokens = content.split_whitespace();
+    let (Some(a), Some(b), Some(stance), None) = (tokens.next(), tokens.next(), tokens.next(), tokens.next()) else {
+        return None;
+    };
+    if !matches!(stance, "peace" | "war" | "alliance") || (a != old && b != old) {
+        return None;
+    }
+    let a = if a == old { new } else { a };
+    let b = if b == old { new } else { b };
+    Some(format!("{indent}{a} {b} {stance}"))
+}
+
+/// Renames `descr_regions.txt`'s `creator_faction` field, the third line
+/// (0-based index 2) of each block -- a purely positional field with no
+/// keyword of its own, the same layout
+/// [`crate::region::Region::load_all`]'s parser relies on.
+fn rewrite_region_creator_factions(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for block in blank_line_blocks(doc) {
+        let Some(&creator_line) = block.get(2) else { continue };
+        edit_line(doc, creator_line, &mut edits, |content| rename_bare_line(content, old, new));
+    }
+    edits
+}
+
+/// Rewrites `export_descr_unit.txt`'s `ownership` comma-list and `era N
+/// faction, faction, ...` lines.
+fn rewrite_unit_ownership(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for i in 0..doc.line_count() {
+        edit_line(doc, i, &mut edits, |content| {
+            rewrite_keyword_value(content, "ownership", |rest| rename_in_list(rest, old, new)).or_else(|| rewrite_era_line(content, old, new))
+        });
+    }
+    edits
+}
+
+/// Renames `old` within an `era N faction, faction, ...` line's faction
+/// list, using the same tail split [`crate::unit::parse_era`] does so the
+/// era number token is never mistaken for a faction name.
+fn rewrite_era_line(content: &str, old: &str, new: &str) -> Option<String> {
+    let indent_len = content.len() - content.trim_start().len();
+    let indent = &content[..indent_len];
+    let rest = strip_keyword(content.trim_start(), "era")?;
+    let mut parts = rest.splitn(2, char::is_whitespace);
+    let era = parts.next()?;
+    let factions = parts.next().unwrap_or("");
+    let renamed = rename_in_list(factions, old, new)?;
+    Some(format!("{indent}era {era} {renamed}"))
+}
+
+/// Rewrites `export_descr_buildings.txt`'s `requires` expressions via
+/// [`RequiresExpr`], the same typed parser [`crate::building`] uses for
+/// these lines -- only a `factions { ... }` clause's names are touched,
+/// never a building/recruit_pool name that happens to equal the faction id.
+fn rewrite_building_requires(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for i in 0..doc.line_count() {
+        edit_line(doc, i, &mut edits, |content| rewrite_requires_line(content, old, new));
+    }
+    edits
+}
+
+/// Splits `content` into the text before its `requires` keyword (kept
+/// verbatim, including the separating whitespace) and the expression text
+/// after it, mirroring [`crate::building::split_requires`]'s two accepted
+/// shapes (a bare `requires EXPR` line, or `VALUE requires EXPR`) without
+/// that function's own trimming, so the surrounding text can be spliced
+/// back byte-for-byte.
+fn split_requires_span(content: &str) -> Option<(&str, &str)> {
+    let trimmed_start = content.trim_start();
+    if let Some(rest) = trimmed_start.strip_prefix("requires ") {
+        let head = &content[..content.len() - trimmed_start.len()];
+        return Some((head, rest));
+    }
+    let idx = content.find(" requires ")?;
+    Some((&content[..idx + 1], &content[idx + " requires ".len()..]))
+}
+
+/// Splits `text` on commas that aren't nested inside `{ ... }`, the same
+/// boundary [`crate::requires`]'s own (private) clause splitter uses --
+/// duplicated here as byte ranges rather than owned, trimmed strings, since
+/// this rewriter needs to know exactly where each clause sits in the
+/// original text to edit it in place.
+fn split_top_level_comma_spans(text: &str) -> Vec<std::ops::Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    let mut depth = 0i32;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                spans.push(start..i);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    spans.push(start..text.len());
+    spans
+}
+
+/// Renames `old` to `new` within `expr_text`'s `factions { ... }` clause(s),
+/// touching only the matched name's own byte span. Unlike reparsing the
+/// whole expression and reserializing it with [`RequiresExpr::render`],
+/// this leaves every other clause -- and the untouched parts of the
+/// `factions` clause itself, like a nonstandard trailing comma or extra
+/// spacing -- exactly as written.
+fn rewrite_requires_line(content: &str, old: &str, new: &str) -> Option<String> {
+    let (head, expr_text) = split_requires_span(content)?;
+
+    let mut targets: Vec<std::ops::Range<usize>> = Vec::new();
+    for clause_range in split_top_level_comma_spans(expr_text) {
+        let clause_text = &expr_text[clause_range.clone()];
+        let parsed = RequiresExpr::parse(clause_text);
+        let Some(requirement) = parsed.requirements.first() else { continue };
+        let Clause::Factions(names) = &requirement.clause else { continue };
+        if !names.iter().any(|name| name == old) {
+            continue;
+        }
+        let Some(open) = clause_text.find('{') else { continue };
+        let Some(close) = clause_text.rfind('}') else { continue };
+        let inner = &clause_text[open + 1..close];
+        let inner_start = clause_range.start + open + 1;
+
+        let mut pos = 0;
+        for segment in inner.split(',') {
+            let segment_start = inner_start + pos;
+            pos += segment.len() + 1;
+            if segment.trim() == old {
+                let leading = segment.len() - segment.trim_start().len();
+                targets.push(segment_start + leading..segment_start + leading + old.len());
+            }
+        }
+    }
+    if targets.is_empty() {
+        return None;
+    }
+    targets.sort_by_key(|range| std::cmp::Reverse(range.start));
+
+    let mut rewritten = expr_text.to_string();
+    for range in targets {
+        rewritten.replace_range(range, new);
+    }
+    Some(format!("{head}requires {rewritten}"))
+}
+
+/// Rewrites `descr_win_conditions.txt`'s per-faction blocks: the block
+/// header (a bare faction name) and its `eliminate_faction`/`outlive`
+/// lines.
+fn rewrite_win_conditions(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for block in blank_line_blocks(doc) {
+        let Some((&header, rest)) = block.split_first() else { continue };
+        edit_line(doc, header, &mut edits, |content| rename_bare_line(content, old, new));
+        for &i in rest {
+            edit_line(doc, i, &mut edits, |content| {
+                rewrite_keyword_value(content, "eliminate_faction", |rest| rename_exact(rest, old, new))
+                    .or_else(|| rewrite_keyword_value(content, "outlive", |rest| rename_exact(rest, old, new)))
+            });
+        }
+    }
+    edits
+}
+
+/// Splits `text` into its whitespace-delimited tokens' byte ranges, the
+/// same boundaries [`str::split_whitespace`] uses. Needed for
+/// `campaign_script.txt` (unlike everywhere else in this file) because a
+/// single argument has to be replaced in place without disturbing the
+/// tabs/spaces around the others on the line.
+fn whitespace_token_ranges(text: &str) -> Vec<std::ops::Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        if c.is_whitespace() {
+            if let Some(s) = start.take() {
+                ranges.push(s..i);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(s) = start {
+        ranges.push(s..text.len());
+    }
+    ranges
+}
+
+/// Rewrites `campaign_script.txt` command lines via [`arg_kinds_for`], the
+/// same typed lookup `validate`'s reference-checking uses -- only an
+/// argument [`COMMAND_ARG_KINDS`](crate::campaign_script::COMMAND_ARG_KINDS)
+/// actually marks as [`ArgKind::Faction`] is touched, so a settlement/unit
+/// argument elsewhere on the same line is left alone even if it happens to
+/// equal the faction id.
+fn rewrite_script_commands(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for i in 0..doc.line_count() {
+        edit_line(doc, i, &mut edits, |content| rewrite_script_line(content, old, new));
+    }
+    edits
+}
+
+fn rewrite_script_line(content: &str, old: &str, new: &str) -> Option<String> {
+    let ranges = whitespace_token_ranges(content);
+    let tokens: Vec<&str> = ranges.iter().map(|r| &content[r.clone()]).collect();
+    let (name, args) = tokens.split_first()?;
+    let command = ScriptCommand { line_number: 0, name: (*name).to_string(), args: args.iter().map(|s| s.to_string()).collect() };
+    let (kinds, offset) = arg_kinds_for(&command)?;
+
+    let mut token_indices: Vec<usize> = kinds
+        .iter()
+        .filter(|(_, kind)| *kind == ArgKind::Faction)
+        .filter_map(|&(index, _)| {
+            let token_index = 1 + offset + index;
+            (tokens.get(token_index) == Some(&old)).then_some(token_index)
+        })
+        .collect();
+    if token_indices.is_empty() {
+        return None;
+    }
+    token_indices.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut rewritten = content.to_string();
+    for token_index in token_indices {
+        rewritten.replace_range(ranges[token_index].clone(), new);
+    }
+    Some(rewritten)
+}
+
+/// Rewrites `descr_missions.txt`'s `condition faction NAME` lines, the same
+/// `kind`/`value` split [`crate::mission::MissionCondition`] uses -- other
+/// condition kinds (`region`, `settlement`, ...) are never touched even if
+/// their value happens to equal the faction id.
+fn rewrite_mission_conditions(doc: &mut LosslessDocument, old: &str, new: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    for i in 0..doc.line_count() {
+        edit_line(doc, i, &mut edits, |content| rewrite_mission_condition_line(content, old, new));
+    }
+    edits
+}
+
+fn rewrite_mission_condition_line(content: &str, old: &str, new: &str) -> Option<String> {
+    rewrite_keyword_value(content, "condition", |rest| {
+        let value = strip_keyword(rest, "faction")?;
+        rename_exact(value, old, new).map(|value| format!("faction {value}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn faction_header_is_renamed_but_a_coincidental_forename_is_not() {
+        // `descr_names.txt` has a `faction NAME` header, then a name pool of
+        // individual forenames that can coincidentally collide with a
+        // faction id.
+        let text = "faction england\nmale Robert, John, England\nfemale Mary\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("descr_names.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(doc.line(0), "faction golden_horde");
+        assert_eq!(doc.line(1), "male Robert, John, England");
+    }
+
+    #[test]
+    fn region_creator_faction_is_renamed_but_a_coincidental_settlement_name_is_not() {
+        // `descr_regions.txt` is purely positional: name, settlement_name,
+        // creator_faction, rebel_type, colour. A settlement that happens to
+        // share the faction's id must not be touched.
+        let text = "england_region\nEngland\nengland\nnorthern_european\n0 0 0\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("descr_regions.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(doc.line(1), "England");
+        assert_eq!(doc.line(2), "golden_horde");
+    }
+
+    #[test]
+    fn strat_header_ownership_and_relationship_lines_are_renamed() {
+        let text = "faction england, 5000\nsuperfaction western_european\nplayable england, france\nfaction_relationships\n{\n    england france peace\n}\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("descr_strat.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 3);
+        assert_eq!(doc.line(0), "faction golden_horde, 5000");
+        assert_eq!(doc.line(1), "superfaction western_european");
+        assert_eq!(doc.line(2), "playable golden_horde, france");
+        assert_eq!(doc.line(5), "    golden_horde france peace");
+    }
+
+    #[test]
+    fn unit_ownership_and_era_lists_are_renamed_but_not_the_era_number() {
+        let text = "type Peasants\nownership england, france\nera 0 england, france\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("export_descr_unit.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 2);
+        assert_eq!(doc.line(1), "ownership golden_horde, france");
+        assert_eq!(doc.line(2), "era 0 golden_horde, france");
+    }
+
+    #[test]
+    fn building_requires_factions_clause_is_renamed_but_not_the_building_name() {
+        let text = "england_barracks requires factions { england, } , building_present tavern\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("export_descr_buildings.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(doc.line(0), "england_barracks requires factions { golden_horde, } , building_present tavern");
+    }
+
+    #[test]
+    fn win_condition_header_and_eliminate_faction_are_renamed() {
+        let text = "england\nshort_campaign\n{\neliminate_faction france\noutlive england\n}\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("descr_win_conditions.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 2);
+        assert_eq!(doc.line(0), "golden_horde");
+        assert_eq!(doc.line(4), "outlive golden_horde");
+    }
+
+    #[test]
+    fn script_faction_argument_is_renamed_but_a_settlement_argument_is_not() {
+        let text = "console_command give_everything_to_faction england\nconsole_command add_units england france_unit\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("campaign_script.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(doc.line(0), "console_command give_everything_to_faction golden_horde");
+        // `add_units`'s first argument is a settlement, not a faction, so a
+        // settlement literally named "england" is left alone.
+        assert_eq!(doc.line(1), "console_command add_units england france_unit");
+    }
+
+    #[test]
+    fn mission_faction_condition_is_renamed_but_not_a_region_condition() {
+        let text = "mission liberate_england\ncondition faction england\ncondition region england\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rewrite_faction_references("descr_missions.txt", &mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(doc.line(1), "condition faction golden_horde");
+        assert_eq!(doc.line(2), "condition region england");
+    }
+
+    #[test]
+    fn renames_localization_key_but_not_a_different_faction() {
+        let text = "{FACTION_ENGLAND}England\n{FACTION_FRANCE}France\n\u{ac} {FACTION_ENGLAND}commented out\n";
+        let mut doc = LosslessDocument::parse(text);
+        let edits = rename_localization_key(&mut doc, "england", "golden_horde");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(doc.line(0), "{FACTION_GOLDEN_HORDE}England");
+        assert_eq!(doc.line(1), "{FACTION_FRANCE}France");
+        assert_eq!(doc.line(2), "\u{ac} {FACTION_ENGLAND}commented out");
+    }
+}
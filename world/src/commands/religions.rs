@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::world::World;
+
+pub struct ReligionsArgs {
+    pub format: ReligionsFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReligionsFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReligionAdherence {
+    pub religion: String,
+    pub average_percentage: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReligionsReport {
+    pub adherence: Vec<ReligionAdherence>,
+    /// How many settlements (or, for a settlement with no `religions` block
+    /// of its own, regions) contributed a sample to `adherence`.
+    pub samples: usize,
+}
+
+/// Averages starting religion adherence across the map. Each settlement
+/// contributes its own `religions { ... }` percentages if it has any,
+/// falling back to its region's percentages otherwise, since older-style
+/// mods only carry the block on `descr_regions.txt`.
+pub fn run(config: &Config, args: &ReligionsArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let report = build_report(&world);
+
+    match args.format {
+        ReligionsFormat::Text => print_text(&report),
+        ReligionsFormat::Json => print_json(&report),
+    }
+    Ok(())
+}
+
+fn build_report(world: &World) -> ReligionsReport {
+    let mut totals: BTreeMap<&str, u64> = BTreeMap::new();
+    let mut samples = 0usize;
+
+    for settlement in &world.settlements {
+        let percentages = if !settlement.religion_percentages.is_empty() {
+            Some(&settlement.religion_percentages)
+        } else {
+            world.region_by_name(&settlement.region).map(|region| &region.religion_percentages).filter(|p| !p.is_empty())
+        };
+
+        let Some(percentages) = percentages else { continue };
+        samples += 1;
+        for (religion, percentage) in percentages {
+            *totals.entry(religion.as_str()).or_insert(0) += u64::from(*percentage);
+        }
+    }
+
+    let adherence = totals
+        .into_iter()
+        .map(|(religion, total)| ReligionAdherence { religion: religion.to_string(), average_percentage: total as f64 / samples.max(1) as f64 })
+        .collect();
+    ReligionsReport { adherence, samples }
+}
+
+fn print_text(report: &ReligionsReport) {
+    if report.adherence.is_empty() {
+        println!("no starting religion percentages found");
+        return;
+    }
+    for entry in &report.adherence {
+        println!("{}: {:.1}% average adherence (n={})", entry.religion, entry.average_percentage, report.samples);
+    }
+}
+
+fn print_json(report: &ReligionsReport) {
+    let json = serde_json::to_string_pretty(report).expect("ReligionsReport is always serializable");
+    println!("{json}");
+}
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::tga::TgaImage;
+use crate::world::World;
+
+pub struct ResourcesArgs {
+    pub format: ResourcesFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ResourcesFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RegionTradeValue {
+    pub region: String,
+    pub total_trade_value: u32,
+    pub resources: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourcesReport {
+    pub regions: Vec<RegionTradeValue>,
+    /// Placements that couldn't be attributed to a region: their tile's
+    /// `map_regions.tga` colour matched nothing in `descr_regions.txt`, or
+    /// `map_regions.tga` itself wasn't found.
+    pub unassigned: Vec<String>,
+}
+
+/// Sums each region's total trade value by locating every `descr_strat.txt`
+/// resource placement on `map_regions.tga` -- the same image `map
+/// check-regions` cross-checks against `descr_regions.txt` -- and
+/// attributing a placement to whichever region's colour its tile carries.
+pub fn run(config: &Config, args: &ResourcesArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let report = build_report(config, &world);
+
+    match args.format {
+        ResourcesFormat::Text => print_text(&report),
+        ResourcesFormat::Json => print_json(&report),
+    }
+    Ok(())
+}
+
+pub(crate) fn build_report(config: &Config, world: &World) -> ResourcesReport {
+    let image = TgaImage::load(&config.resolve(Path::new("map_regions.tga")).path).ok();
+
+    let mut totals: BTreeMap<&str, (u32, Vec<String>)> = BTreeMap::new();
+    let mut unassigned = Vec::new();
+
+    for placement in &world.resource_placements {
+        let trade_value = world.resources.iter().find(|r| r.name == placement.name).and_then(|r| r.trade_value).unwrap_or(0);
+        let region = image.as_ref().and_then(|image| {
+            let x = u32::try_from(placement.position.x).ok()?;
+            let y = u32::try_from(placement.position.y).ok()?;
+            let colour = image.pixel(x, y)?;
+            world.regions.iter().find(|region| region.colour == colour)
+        });
+
+        match region {
+            Some(region) => {
+                let entry = totals.entry(&region.name).or_insert((0, Vec::new()));
+                entry.0 += trade_value;
+                entry.1.push(placement.name.clone());
+            }
+            None => unassigned.push(placement.name.clone()),
+        }
+    }
+
+    let regions = totals
+        .into_iter()
+        .map(|(region, (total_trade_value, resources))| RegionTradeValue { region: region.to_string(), total_trade_value, resources })
+        .collect();
+    ResourcesReport { regions, unassigned }
+}
+
+fn print_text(report: &ResourcesReport) {
+    if report.regions.is_empty() && report.unassigned.is_empty() {
+        println!("no resources placed");
+        return;
+    }
+    for region in &report.regions {
+        println!("region {:?}: {} trade value ({})", region.region, region.total_trade_value, region.resources.join(", "));
+    }
+    if !report.unassigned.is_empty() {
+        println!("unassigned: {}", report.unassigned.join(", "));
+    }
+}
+
+fn print_json(report: &ResourcesReport) {
+    let json = serde_json::to_string_pretty(report).expect("ResourcesReport is always serializable");
+    println!("{json}");
+}
@@ -0,0 +1,236 @@
+use std::path::Path;
+
+use crate::config::Config;
+use crate::error::{io_err, Result, WorldError};
+use crate::faction::FactionsDocument;
+use crate::lossless::LosslessDocument;
+use crate::text::{strip_comment, strip_keyword};
+use crate::unit::EduDocument;
+use crate::validate::closest_match;
+
+/// Placeholder RGB the new faction's colours are set to -- a mod author
+/// gets a build that loads and is visually distinct from `--copy-from`
+/// straight away, and is expected to replace it with real house colours.
+const PLACEHOLDER_COLOUR: (u8, u8, u8) = (128, 128, 128);
+
+pub struct ScaffoldFactionArgs {
+    pub name: String,
+    pub culture: String,
+    pub religion: String,
+    pub copy_from: String,
+    /// Starter units to add `name` to the ownership line of, e.g. a
+    /// faction's initial garrison/settler unit set.
+    pub units: Vec<String>,
+    pub dry_run: bool,
+}
+
+/// Generates the minimal set of edits a new faction needs to exist and
+/// load cleanly: a `descr_sm_factions.txt` record cloned from
+/// `args.copy_from`, empty `descr_names.txt` name pools, an entry in
+/// `descr_strat.txt`'s `nonplayable` list, ownership additions on
+/// `args.units` in `export_descr_unit.txt`, and a stub `FACTION_<NAME>`
+/// localization key in `text/expanded.txt`. Every edit goes through the
+/// same format-preserving writers the rest of `world` uses, so untouched
+/// lines round-trip byte-identical; with `args.dry_run`, nothing is
+/// written and the printed report is exactly what would have changed.
+pub fn run_scaffold_faction(config: &Config, args: &ScaffoldFactionArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+
+    if world.faction_by_name(&args.name).is_some() {
+        return Err(WorldError::InvalidArgument(format!("faction {:?} already exists", args.name)));
+    }
+    if world.faction_by_name(&args.copy_from).is_none() {
+        return Err(not_found("faction", &args.copy_from, world.factions.iter().map(|f| &f.name)));
+    }
+    for unit in &args.units {
+        if !world.units.iter().any(|u| &u.name == unit) {
+            return Err(not_found("unit", unit, world.units.iter().map(|u| &u.name)));
+        }
+    }
+
+    scaffold_sm_factions(config, args)?;
+    scaffold_names(config, args)?;
+    scaffold_strat(config, args)?;
+    scaffold_ownership(config, args)?;
+    scaffold_localization(config, args)?;
+
+    if args.dry_run {
+        println!("dry run: no files were written");
+    }
+    Ok(())
+}
+
+fn not_found<'a>(kind: &'static str, name: &str, candidates: impl Iterator<Item = &'a String>) -> WorldError {
+    let suggestion = closest_match(name, candidates).map(|s| format!(", did you mean {s:?}?")).unwrap_or_default();
+    WorldError::NotFound { kind, name: name.to_string(), suggestion }
+}
+
+fn scaffold_sm_factions(config: &Config, args: &ScaffoldFactionArgs) -> Result<()> {
+    let path = config.resolve(Path::new("descr_sm_factions.txt")).path;
+    let text = std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+
+    let mut doc = FactionsDocument::parse(&text);
+    let mut record = doc
+        .insert_from_template(&args.copy_from, &args.name)
+        .ok_or_else(|| WorldError::NotFound { kind: "faction", name: args.copy_from.clone(), suggestion: String::new() })?;
+    record.set_culture(&args.culture);
+    record.set_religion(&args.religion);
+    record.set_primary_colour(PLACEHOLDER_COLOUR.0, PLACEHOLDER_COLOUR.1, PLACEHOLDER_COLOUR.2);
+    record.set_secondary_colour(PLACEHOLDER_COLOUR.0, PLACEHOLDER_COLOUR.1, PLACEHOLDER_COLOUR.2);
+
+    println!("{}:", path.display());
+    println!(
+        "  + faction {} (cloned from {}, culture {}, religion {}, placeholder colours {} {} {})",
+        args.name, args.copy_from, args.culture, args.religion, PLACEHOLDER_COLOUR.0, PLACEHOLDER_COLOUR.1, PLACEHOLDER_COLOUR.2
+    );
+    if !args.dry_run {
+        std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    }
+    Ok(())
+}
+
+/// `descr_names.txt` has no line-preserving typed document yet (unlike
+/// `descr_sm_factions.txt`'s [`FactionsDocument`] and `export_descr_unit.txt`'s
+/// [`EduDocument`]) -- a new faction just needs an empty block appended, so
+/// this edits the [`LosslessDocument`] directly rather than building one.
+fn scaffold_names(config: &Config, args: &ScaffoldFactionArgs) -> Result<()> {
+    let path = config.resolve(Path::new("descr_names.txt")).path;
+    let text = std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+    let mut doc = LosslessDocument::parse(&text);
+
+    let insert_at = doc.line_count();
+    let block = [
+        String::new(),
+        format!("faction {}", args.name),
+        "{".to_string(),
+        "\tmale_names".to_string(),
+        "\t{".to_string(),
+        "\t}".to_string(),
+        "\tfemale_names".to_string(),
+        "\t{".to_string(),
+        "\t}".to_string(),
+        "\tsurnames".to_string(),
+        "\t{".to_string(),
+        "\t}".to_string(),
+        "}".to_string(),
+    ];
+    for (offset, line) in block.into_iter().enumerate() {
+        doc.insert_line(insert_at + offset, line);
+    }
+
+    println!("{}:", path.display());
+    println!("  {}: + faction {} {{ empty male_names/female_names/surnames pools }}", insert_at + 2, args.name);
+    if !args.dry_run {
+        std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    }
+    Ok(())
+}
+
+fn scaffold_strat(config: &Config, args: &ScaffoldFactionArgs) -> Result<()> {
+    let path = config.resolve(Path::new("descr_strat.txt")).path;
+    let text = std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+    let mut doc = LosslessDocument::parse(&text);
+
+    let Some(i) = (0..doc.line_count()).find(|&i| strip_keyword(strip_comment(doc.line(i)).trim(), "nonplayable").is_some()) else {
+        return Err(WorldError::InvalidArgument(format!("{}: no `nonplayable` faction list to add {:?} to", path.display(), args.name)));
+    };
+
+    let line = doc.line(i).to_string();
+    let content = strip_comment(&line);
+    let comment = &line[content.len()..];
+    let indent = &content[..content.len() - content.trim_start().len()];
+    let names = strip_keyword(content.trim(), "nonplayable").unwrap().trim();
+    let new_names = if names.is_empty() { args.name.clone() } else { format!("{names}, {}", args.name) };
+    let new_line = if comment.is_empty() { format!("{indent}nonplayable {new_names}") } else { format!("{indent}nonplayable {new_names}{comment}") };
+
+    println!("{}:", path.display());
+    println!("  {}: {} -> {}", i + 1, line.trim(), new_line.trim());
+    doc.set_line(i, new_line);
+    if !args.dry_run {
+        std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    }
+    Ok(())
+}
+
+fn scaffold_ownership(config: &Config, args: &ScaffoldFactionArgs) -> Result<()> {
+    if args.units.is_empty() {
+        return Ok(());
+    }
+
+    let path = config.resolve(Path::new("export_descr_unit.txt")).path;
+    let text = std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+    let mut doc = EduDocument::parse(&text);
+
+    println!("{}:", path.display());
+    for unit in &args.units {
+        let mut record = doc.unit_mut(unit).ok_or_else(|| WorldError::NotFound { kind: "unit", name: unit.clone(), suggestion: String::new() })?;
+        if record.add_ownership(&args.name) {
+            println!("  {unit}: + ownership {}", args.name);
+        } else {
+            println!("  {unit}: already owned by {:?}", args.name);
+        }
+    }
+    if !args.dry_run {
+        std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    }
+    Ok(())
+}
+
+/// Stubs the `FACTION_<NAME>` key `descr_sm_factions.txt`'s new record will
+/// be looked up under (see [`crate::localization::LocalizationFile::apply`])
+/// so the faction has a display name instead of falling back to its raw
+/// identifier. `text/expanded.txt` is optional (same as everywhere else
+/// localization is loaded), so a missing locale directory just skips this.
+fn scaffold_localization(config: &Config, args: &ScaffoldFactionArgs) -> Result<()> {
+    let locale_dir = config.locale_dir.as_deref().unwrap_or(&config.data_dir);
+    let path = locale_dir.join("text").join("expanded.txt");
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        println!("{}: not found, skipping localization stub", path.display());
+        return Ok(());
+    };
+    let mut doc = LosslessDocument::parse(&text);
+
+    let key = format!("FACTION_{}", args.name.to_uppercase());
+    let marker = format!("{{{key}}}");
+    if (0..doc.line_count()).any(|i| doc.line(i).starts_with(&marker)) {
+        println!("{}: {marker} already present, skipping", path.display());
+        return Ok(());
+    }
+
+    let insert_at = doc.line_count();
+    let new_line = format!("{marker}{}", titlecase(&args.name));
+    doc.insert_line(insert_at, new_line.clone());
+
+    println!("{}:", path.display());
+    println!("  {}: + {new_line}", insert_at + 1);
+    if !args.dry_run {
+        std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    }
+    Ok(())
+}
+
+/// `"golden_horde"` -> `"Golden Horde"`, for a readable stub display name
+/// until a mod author supplies a real translation.
+fn titlecase(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn titlecase_splits_on_underscores() {
+        assert_eq!(titlecase("golden_horde"), "Golden Horde");
+        assert_eq!(titlecase("england"), "England");
+    }
+}
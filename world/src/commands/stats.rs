@@ -0,0 +1,178 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::world::World;
+
+pub struct StatsArgs {
+    pub format: StatsFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StatsFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FactionCounts {
+    pub total: usize,
+    pub playable: usize,
+    pub unlockable: usize,
+    pub nonplayable: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HeightmapStats {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// A one-screen overview of a loaded `World`, as opposed to `dump`'s full
+/// record-by-record output. `heightmap` and `campaign_script_commands` are
+/// `None` rather than zero when the underlying file wasn't found, since
+/// both are genuinely optional -- everything else is a required file and so
+/// always has a real count.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsReport {
+    pub cultures: usize,
+    pub religions: usize,
+    pub factions: FactionCounts,
+    pub regions: usize,
+    pub settlements_by_level: BTreeMap<String, usize>,
+    pub units_by_class: BTreeMap<String, usize>,
+    pub units_by_culture: BTreeMap<String, usize>,
+    pub buildings: usize,
+    pub building_levels: usize,
+    pub wall_levels: usize,
+    pub traits: usize,
+    pub ancillaries: usize,
+    pub mercenary_pools: usize,
+    pub missions: usize,
+    /// Sum of every `descr_strat.txt` settlement's `population` line.
+    pub starting_population: u64,
+    /// Sum of every faction's starting `denari` and `denari_kings_purse`.
+    pub starting_treasury: u64,
+    pub heightmap: Option<HeightmapStats>,
+    pub campaign_script_commands: Option<usize>,
+}
+
+/// Computes a [`StatsReport`] from a loaded `World` -- the quick "does this
+/// mod look complete" check, as opposed to `dump`'s full record-by-record
+/// output.
+pub fn run(config: &Config, args: &StatsArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let report = build_report(&world);
+
+    match args.format {
+        StatsFormat::Text => print_text(&report),
+        StatsFormat::Json => print_json(&report),
+    }
+    Ok(())
+}
+
+fn build_report(world: &World) -> StatsReport {
+    let mut settlements_by_level: BTreeMap<String, usize> = BTreeMap::new();
+    for settlement in &world.settlements {
+        *settlements_by_level.entry(settlement.level.clone()).or_insert(0) += 1;
+    }
+
+    let mut units_by_class: BTreeMap<String, usize> = BTreeMap::new();
+    let mut units_by_culture: BTreeMap<String, usize> = BTreeMap::new();
+    for unit in &world.units {
+        let class = unit.extra.get("class").cloned().unwrap_or_else(|| "unknown".to_string());
+        *units_by_class.entry(class).or_insert(0) += 1;
+
+        let cultures: BTreeSet<String> = unit.ownership.iter().filter_map(|faction| world.faction_by_name(faction)?.culture.clone()).collect();
+        if cultures.is_empty() {
+            *units_by_culture.entry("none".to_string()).or_insert(0) += 1;
+        } else {
+            for culture in cultures {
+                *units_by_culture.entry(culture).or_insert(0) += 1;
+            }
+        }
+    }
+
+    StatsReport {
+        cultures: world.cultures.len(),
+        religions: world.religions.len(),
+        factions: FactionCounts {
+            total: world.factions.len(),
+            playable: world.campaign.playable_factions.len(),
+            unlockable: world.campaign.unlockable_factions.len(),
+            nonplayable: world.campaign.nonplayable_factions.len(),
+        },
+        regions: world.regions.len(),
+        settlements_by_level,
+        units_by_class,
+        units_by_culture,
+        buildings: world.buildings.len(),
+        building_levels: world.buildings.iter().map(|b| b.levels.len()).sum(),
+        wall_levels: world.wall_levels.len(),
+        traits: world.traits.traits.len(),
+        ancillaries: world.ancillaries.ancillaries.len(),
+        mercenary_pools: world.merc_pools.len(),
+        missions: world.missions.len(),
+        starting_population: world.settlements.iter().filter_map(|s| s.population).map(u64::from).sum(),
+        starting_treasury: world.faction_starts.iter().map(|f| u64::from(f.denari.unwrap_or(0)) + u64::from(f.denari_kings_purse.unwrap_or(0))).sum(),
+        heightmap: world.heightmap.as_ref().map(|h| HeightmapStats { width: h.width, height: h.height }),
+        campaign_script_commands: world.sources.contains_key("campaign_script.txt").then_some(world.script_commands.len()),
+    }
+}
+
+fn print_text(report: &StatsReport) {
+    let mut rows: Vec<(String, String)> = Vec::new();
+    rows.push(("cultures".to_string(), report.cultures.to_string()));
+    rows.push(("religions".to_string(), report.religions.to_string()));
+    rows.push((
+        "factions".to_string(),
+        format!(
+            "{} ({} playable, {} unlockable, {} nonplayable)",
+            report.factions.total, report.factions.playable, report.factions.unlockable, report.factions.nonplayable
+        ),
+    ));
+    rows.push(("regions".to_string(), report.regions.to_string()));
+    for (level, count) in &report.settlements_by_level {
+        rows.push((format!("settlements ({level})"), count.to_string()));
+    }
+    if report.settlements_by_level.is_empty() {
+        rows.push(("settlements".to_string(), "0".to_string()));
+    }
+    for (class, count) in &report.units_by_class {
+        rows.push((format!("units ({class})"), count.to_string()));
+    }
+    for (culture, count) in &report.units_by_culture {
+        rows.push((format!("units ({culture} culture)"), count.to_string()));
+    }
+    rows.push(("buildings".to_string(), format!("{} chains, {} levels", report.buildings, report.building_levels)));
+    rows.push(("wall levels".to_string(), report.wall_levels.to_string()));
+    rows.push(("traits".to_string(), report.traits.to_string()));
+    rows.push(("ancillaries".to_string(), report.ancillaries.to_string()));
+    rows.push(("mercenary pools".to_string(), report.mercenary_pools.to_string()));
+    rows.push(("missions".to_string(), report.missions.to_string()));
+    rows.push(("starting population".to_string(), report.starting_population.to_string()));
+    rows.push(("starting treasury".to_string(), report.starting_treasury.to_string()));
+    rows.push(("heightmap".to_string(), match &report.heightmap {
+        Some(heightmap) => format!("{}x{}", heightmap.width, heightmap.height),
+        None => "not loaded".to_string(),
+    }));
+    rows.push((
+        "campaign script commands".to_string(),
+        match report.campaign_script_commands {
+            Some(count) => count.to_string(),
+            None => "not loaded".to_string(),
+        },
+    ));
+
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    for (label, value) in &rows {
+        println!("{label:width$}  {value}");
+    }
+}
+
+fn print_json(report: &StatsReport) {
+    let json = serde_json::to_string_pretty(report).expect("StatsReport is always serializable");
+    println!("{json}");
+}
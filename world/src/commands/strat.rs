@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use crate::config::Config;
+use crate::error::{io_err, Result, WorldError};
+use crate::strat::StratDocument;
+use crate::validate::closest_match;
+
+pub struct MoveSettlementArgs {
+    pub region: String,
+    pub to_faction: String,
+    pub dry_run: bool,
+}
+
+/// Reassigns a starting settlement to a different faction's `descr_strat.txt`
+/// section, relocating its whole block rather than just its `region` line so
+/// the file keeps parsing the same way the game reads it. With
+/// `args.dry_run`, nothing is written -- the report is exactly what would
+/// have changed.
+pub fn run_move_settlement(config: &Config, args: &MoveSettlementArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+
+    let Some(settlement) = world.settlements.iter().find(|s| s.region == args.region) else {
+        return Err(not_found("settlement", &args.region, world.settlements.iter().map(|s| &s.region)));
+    };
+    if world.faction_by_name(&args.to_faction).is_none() {
+        return Err(not_found("faction", &args.to_faction, world.factions.iter().map(|f| &f.name)));
+    }
+    if settlement.owning_faction == args.to_faction {
+        println!("{:?} is already owned by {:?}", args.region, args.to_faction);
+        return Ok(());
+    }
+    let from_faction = settlement.owning_faction.clone();
+
+    let path = config.resolve(Path::new("descr_strat.txt")).path;
+    let text = std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+    let mut doc = StratDocument::parse(&text);
+    let mut record = doc.settlement_mut(&args.region).expect("just confirmed this settlement exists");
+    if !record.move_to_faction(&args.to_faction) {
+        return Err(WorldError::InvalidArgument(format!("{:?} has no section in {}", args.to_faction, path.display())));
+    }
+
+    println!("{}:", path.display());
+    println!("  {:?}: {from_faction} -> {}", args.region, args.to_faction);
+    if !args.dry_run {
+        std::fs::write(&path, doc.to_text()).map_err(|e| io_err(&path, e))?;
+    }
+    Ok(())
+}
+
+fn not_found<'a>(kind: &'static str, name: &str, candidates: impl Iterator<Item = &'a String>) -> WorldError {
+    let suggestion = closest_match(name, candidates).map(|s| format!(", did you mean {s:?}?")).unwrap_or_default();
+    WorldError::NotFound { kind, name: name.to_string(), suggestion }
+}
@@ -0,0 +1,124 @@
+use serde::Serialize;
+
+use crate::config::{Config, DataSource};
+use crate::error::Result;
+use crate::validate::{validate, Finding, Severity};
+use crate::world::World;
+
+pub struct ValidateArgs {
+    pub format: ValidateFormat,
+    /// Skips the ancillary `Image` existence check, for people validating
+    /// against a partial extract that doesn't include `data/ui/ancillaries`.
+    pub no_asset_checks: bool,
+    /// Check ids to drop from the report entirely, before both output and
+    /// the exit-code decision -- see [`Finding::check`].
+    pub allow: Vec<String>,
+    /// Treat warnings as failing, the same as errors, for CI pipelines that
+    /// want a clean report rather than just no errors.
+    pub deny_warnings: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ValidateFormat {
+    Text,
+    Json,
+    /// GitHub Actions workflow-command annotations (`::error file=...::...`),
+    /// so findings show up inline on a PR's Files Changed tab.
+    Github,
+}
+
+/// Cross-checks a loaded `World`'s references (faction cultures/religions
+/// exist, index fields are plausible, nothing's unused) and reports the
+/// findings, after dropping any whose [`Finding::check`] id is in
+/// `args.allow`.
+///
+/// Exits via [`std::process::exit`] rather than returning an error, since a
+/// validation report needs three outcomes a plain `Result` can't carry: `0`
+/// (clean, or only warnings that weren't denied), `1` (only warnings, but
+/// `--deny warnings` was passed), and `2` (at least one error). Loading the
+/// world itself can still fail in the ordinary way and returns `Err`.
+pub fn run(config: &Config, args: &ValidateArgs) -> Result<()> {
+    let world = crate::cache::load(config)?;
+    let findings: Vec<Finding> = validate(&world, config, !args.no_asset_checks).into_iter().filter(|f| !args.allow.iter().any(|c| c == f.check)).collect();
+
+    let error_count = findings.iter().filter(|f| f.is_error()).count();
+    let warning_count = findings.len() - error_count;
+
+    match args.format {
+        ValidateFormat::Text => print_text(&world, &findings, config),
+        ValidateFormat::Json => print_json(&world, &findings),
+        ValidateFormat::Github => print_github(&findings),
+    }
+
+    let exit_code = if error_count > 0 {
+        2
+    } else if warning_count > 0 && args.deny_warnings {
+        1
+    } else {
+        0
+    };
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+    Ok(())
+}
+
+fn print_text(world: &World, findings: &[Finding], config: &Config) {
+    if findings.is_empty() {
+        println!("no problems found");
+    } else {
+        for finding in findings {
+            let severity = match finding.severity {
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            };
+            println!("{}:{}: {severity} [{}]: {}", finding.file.display(), finding.line_number, finding.check, finding.message);
+        }
+    }
+
+    if config.mod_dir.is_some() || config.packs.is_some() {
+        println!("sources:");
+        for (name, resolved) in &world.sources {
+            let source = match &resolved.source {
+                DataSource::Mod => "mod".to_string(),
+                DataSource::Base => "base".to_string(),
+                DataSource::Pack(pack_name) => pack_name.clone(),
+            };
+            println!("  {name}: {source}");
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ValidateReport<'a> {
+    findings: &'a [Finding],
+    sources: &'a std::collections::BTreeMap<String, crate::config::ResolvedPath>,
+}
+
+fn print_json(world: &World, findings: &[Finding]) {
+    let report = ValidateReport { findings, sources: &world.sources };
+    let json = serde_json::to_string_pretty(&report).expect("ValidateReport is always serializable");
+    println!("{json}");
+}
+
+/// Emits one GitHub Actions workflow command per finding (see
+/// <https://docs.github.com/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message>),
+/// so a `world validate --format github` step in a mod's CI annotates the
+/// offending line directly on the pull request's diff.
+fn print_github(findings: &[Finding]) {
+    for finding in findings {
+        let command = match finding.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        let file = finding.file.display();
+        println!("::{command} file={file},line={},title={}::{}", finding.line_number, finding.check, escape_workflow_command(&finding.message));
+    }
+}
+
+/// Escapes the characters GitHub's workflow-command syntax treats
+/// specially in a `::command ...::message` message, so a message
+/// containing them renders literally instead of corrupting the command.
+fn escape_workflow_command(message: &str) -> String {
+    message.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, WorldError};
+use crate::vfs::{DirVfs, PackVfs, Vfs};
+
+pub struct Config {
+    pub data_dir: PathBuf,
+    /// Overlay directory for mod data, checked before `data_dir` for every
+    /// file a loader resolves via [`Config::resolve`].
+    pub mod_dir: Option<PathBuf>,
+    /// Overlay directory to load `text/*.txt` localization files from
+    /// instead of `data_dir`, for pointing at a translated text folder.
+    pub locale_dir: Option<PathBuf>,
+    /// `.pack` files to read data from instead of `data_dir`, checked after
+    /// `mod_dir` and before the base directory by [`Config::read_data`].
+    /// Binary assets (heightmaps, `map_regions.tga`, ancillary images) and
+    /// localization still go through [`Config::resolve`] instead, which
+    /// stays directory-only -- packing those up is future work.
+    pub packs: Option<PackVfs>,
+    /// Opts into `cache::load`'s on-disk cache instead of always reparsing.
+    /// Plain `World::load` calls (e.g. one side of `world diff`) ignore this
+    /// -- it only affects call sites that go through the cache layer.
+    pub cache: bool,
+    /// Prints each [`World::load`] component's wall time to stderr as it
+    /// finishes, so a slow parser or a data file that's grown huge is
+    /// obvious instead of hiding inside one big number.
+    pub debug_timing: bool,
+}
+
+/// Which source a [`ResolvedPath`] actually came from.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataSource {
+    /// Read from `mod_dir`'s overlay.
+    Mod,
+    /// Read from the base `data_dir`, either because there's no mod overlay
+    /// or the overlay doesn't have this file.
+    Base,
+    /// Read out of a `.pack` file, named here.
+    Pack(String),
+}
+
+/// A data file path resolved through [`Config::resolve`], paired with which
+/// directory actually supplied it.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct ResolvedPath {
+    pub path: PathBuf,
+    pub source: DataSource,
+}
+
+impl Config {
+    /// Builds a `Config` from parsed CLI arguments, checking that
+    /// `data_dir` actually exists so callers get a clear error up front
+    /// instead of a panic the first time a file is read, and opening
+    /// `packs_dir`'s `.pack` files up front for the same reason.
+    pub fn from_args(data_dir: PathBuf, mod_dir: Option<PathBuf>, locale_dir: Option<PathBuf>, packs_dir: Option<PathBuf>, cache: bool, debug_timing: bool) -> Result<Self> {
+        if !data_dir.is_dir() {
+            return Err(WorldError::MissingDataDir(data_dir));
+        }
+        let packs = packs_dir.as_deref().map(PackVfs::open).transpose()?;
+        Ok(Config { data_dir, mod_dir, locale_dir, packs, cache, debug_timing })
+    }
+
+    /// Resolves `relative` against `mod_dir` first, falling back to
+    /// `data_dir` -- the engine's own `io.file_first` mod overlay behaviour.
+    /// Binary assets and localization go through this, since neither has an
+    /// in-memory-bytes path yet. Text data files should use
+    /// [`Config::read_data`] instead so they can also come from a `.pack`.
+    pub fn resolve(&self, relative: &Path) -> ResolvedPath {
+        if let Some(mod_dir) = &self.mod_dir {
+            let mod_path = mod_dir.join(relative);
+            if mod_path.is_file() {
+                return ResolvedPath { path: mod_path, source: DataSource::Mod };
+            }
+        }
+        ResolvedPath { path: self.data_dir.join(relative), source: DataSource::Base }
+    }
+
+    /// Reads `relative`'s bytes, checking `mod_dir`, then `packs` (if any),
+    /// then `data_dir`, in that order -- the same override order `resolve`
+    /// uses, with packs slotted between the two directories since they
+    /// stand in for the base game's own data. Returns `None` if none of
+    /// them have the file.
+    pub fn read_data(&self, relative: &Path) -> Result<Option<(Vec<u8>, ResolvedPath)>> {
+        if let Some(mod_dir) = &self.mod_dir {
+            let vfs = DirVfs::new(mod_dir.clone(), "mod");
+            if let Some(read) = vfs.read(relative)? {
+                return Ok(Some((read.bytes, ResolvedPath { path: mod_dir.join(relative), source: DataSource::Mod })));
+            }
+        }
+        if let Some(packs) = &self.packs {
+            if let Some(read) = packs.read(relative)? {
+                let source = DataSource::Pack(read.origin.clone());
+                let path = PathBuf::from(format!("{}:{}", read.origin, relative.display()));
+                return Ok(Some((read.bytes, ResolvedPath { path, source })));
+            }
+        }
+        let vfs = DirVfs::new(self.data_dir.clone(), "base");
+        if let Some(read) = vfs.read(relative)? {
+            return Ok(Some((read.bytes, ResolvedPath { path: self.data_dir.join(relative), source: DataSource::Base })));
+        }
+        Ok(None)
+    }
+
+    /// Lists `relative`'s file names, checking `mod_dir`, then `packs`, then
+    /// `data_dir`, the same override order [`Config::read_data`] uses.
+    /// Returns `None` if none of them have such a directory at all, as
+    /// opposed to `Some((vec![], source))`, which means it exists but is
+    /// empty -- callers that need to tell "missing" apart from "empty" (like
+    /// `validate`'s portrait mapping check) rely on that distinction.
+    pub fn list_dir(&self, relative: &Path) -> Result<Option<(Vec<String>, DataSource)>> {
+        if let Some(mod_dir) = &self.mod_dir {
+            let vfs = DirVfs::new(mod_dir.clone(), "mod");
+            if let Some(names) = vfs.list_dir(relative)? {
+                return Ok(Some((names, DataSource::Mod)));
+            }
+        }
+        if let Some(packs) = &self.packs {
+            if let Some((names, pack_name)) = packs.list_dir_named(relative)? {
+                return Ok(Some((names, DataSource::Pack(pack_name))));
+            }
+        }
+        let vfs = DirVfs::new(self.data_dir.clone(), "base");
+        if let Some(names) = vfs.list_dir(relative)? {
+            return Ok(Some((names, DataSource::Base)));
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::text::{parse_descr_lines, ReadOptions};
+
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Culture {
+    /// 0-based position in `descr_cultures.txt`, stable for a given file so
+    /// other loaded data (and external tooling) can reference a culture by
+    /// id instead of by name.
+    pub id: usize,
+    pub name: String,
+    /// An optional second token naming the `data/ui/<mapping>/portraits`
+    /// directory this culture's characters actually draw their portraits
+    /// from, for mods that share one portrait set between several cultures
+    /// instead of maintaining a separate tree per culture name. Defaults to
+    /// the culture's own name when absent.
+    pub portrait_mapping: Option<String>,
+    /// 1-based line this culture was declared on, for pointing tooling
+    /// (e.g. `world validate`) back at the source file.
+    pub line_number: usize,
+}
+
+impl Culture {
+    pub fn load_all(_path: &Path, text: &str) -> Result<Vec<Culture>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        Ok(lines
+            .into_iter()
+            .enumerate()
+            .map(|(id, line)| {
+                let mut parts = line.text.split_whitespace();
+                let name = parts.next().unwrap_or_default().to_string();
+                let portrait_mapping = parts.next().map(str::to_string);
+                Culture { id, name, portrait_mapping, line_number: line.line_number }
+            })
+            .collect())
+    }
+
+    /// The `data/ui/<mapping>/portraits` directory this culture's characters
+    /// draw their portraits from: `portrait_mapping` if it has one,
+    /// otherwise the culture's own name.
+    pub fn portrait_dir(&self) -> &str {
+        self.portrait_mapping.as_deref().unwrap_or(&self.name)
+    }
+}
@@ -0,0 +1,282 @@
+use std::ops::Range;
+
+use crate::building::split_requires;
+use crate::lossless::LosslessDocument;
+use crate::requires::RequiresExpr;
+use crate::text::strip_comment;
+
+/// An `export_descr_buildings.txt` document kept line-for-line (see
+/// [`crate::lossless`]) so a building level can be addressed by name and
+/// have just its `requires` clause rewritten, the same approach
+/// [`crate::strat::StratDocument`] uses for `descr_strat.txt`. Every lookup
+/// re-scans the current document rather than caching line ranges, since
+/// this file is small enough that it's cheap and it means an edit can never
+/// leave a lookup stale.
+pub struct EdbDocument {
+    doc: LosslessDocument,
+}
+
+impl EdbDocument {
+    pub fn parse(text: &str) -> Self {
+        EdbDocument { doc: LosslessDocument::parse(text) }
+    }
+
+    /// Returns an editable handle to the level named `name` (matched
+    /// exactly, like [`crate::building::BuildingLevel::name`]), or `None` if
+    /// no level has that name.
+    pub fn level_mut(&mut self, name: &str) -> Option<BuildingLevelMut<'_>> {
+        find_level(&self.doc, name)?;
+        Some(BuildingLevelMut { document: self, name: name.to_string() })
+    }
+
+    /// Names of every level with a `capability { ... }` line whose kind is
+    /// `kind` (e.g. `"law_bonus"`), in file order.
+    pub fn level_names_with_capability(&self, kind: &str) -> Vec<String> {
+        level_spans(&self.doc).into_iter().filter(|(_, span)| has_capability(&self.doc, span.clone(), kind)).map(|(name, _)| name).collect()
+    }
+
+    pub fn to_text(&self) -> String {
+        self.doc.to_text()
+    }
+}
+
+/// An editable handle to one building level's header within an
+/// [`EdbDocument`].
+pub struct BuildingLevelMut<'a> {
+    document: &'a mut EdbDocument,
+    name: String,
+}
+
+impl BuildingLevelMut<'_> {
+    /// The level's currently parsed `requires` expression, or an empty one
+    /// if the header has none.
+    pub fn requires(&self) -> RequiresExpr {
+        let Some(span) = find_level(&self.document.doc, &self.name) else { return RequiresExpr { requirements: Vec::new() } };
+        let content = strip_comment(self.document.doc.line(span.start));
+        let (_, requires) = split_requires(content);
+        requires.map(|text| RequiresExpr::parse(&text)).unwrap_or(RequiresExpr { requirements: Vec::new() })
+    }
+
+    /// Adds `requirement` to the level's `requires` expression (a no-op if
+    /// it's already there, see [`RequiresExpr::add_requirement`]) and
+    /// rewrites just the header line. Returns `false` if the level no
+    /// longer exists.
+    pub fn add_requirement(&mut self, requirement: crate::requires::Requirement) -> bool {
+        let mut expr = self.requires();
+        expr.add_requirement(requirement);
+        self.set_requires(&expr)
+    }
+
+    /// Restricts the level to `factions` (see
+    /// [`RequiresExpr::restrict_to_factions`]) and rewrites just the header
+    /// line. Returns `false` if the level no longer exists.
+    pub fn restrict_to_factions(&mut self, factions: &[String]) -> bool {
+        let mut expr = self.requires();
+        expr.restrict_to_factions(factions);
+        self.set_requires(&expr)
+    }
+
+    /// Rewrites the header line's `requires` clause to `expr`, keeping the
+    /// line's indentation and any trailing inline comment -- same split
+    /// [`crate::strat`]'s field rewriters use.
+    fn set_requires(&mut self, expr: &RequiresExpr) -> bool {
+        let Some(span) = find_level(&self.document.doc, &self.name) else { return false };
+        let line = self.document.doc.line(span.start).to_string();
+        let content = strip_comment(&line);
+        let comment = &line[content.len()..];
+        let indent = &content[..content.len() - content.trim_start().len()];
+        let (value, _) = split_requires(content);
+
+        let new_line = if expr.requirements.is_empty() {
+            format!("{indent}{value}{comment}")
+        } else {
+            format!("{indent}{value} requires {}{comment}", expr.render())
+        };
+        self.document.doc.set_line(span.start, new_line);
+        true
+    }
+}
+
+/// Every level's name and the `[start, end)` line range of its whole block
+/// (header line through closing `}`) across every `building` block in the
+/// document, found the same way [`crate::building::parse_building`] finds a
+/// level: watching for the enclosing building's `levels NAME NAME ...` line
+/// and matching each following block header against that list.
+fn level_spans(doc: &LosslessDocument) -> Vec<(String, Range<usize>)> {
+    let mut spans = Vec::new();
+    let mut current_level_names: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < doc.line_count() {
+        let stripped = strip_comment(doc.line(i)).trim();
+        if let Some(rest) = stripped.strip_prefix("levels ") {
+            current_level_names = rest.split_whitespace().map(str::to_string).collect();
+            i += 1;
+            continue;
+        }
+
+        if let Some(first) = stripped.split_whitespace().next() {
+            if current_level_names.iter().any(|n| n == first) {
+                let span = find_block(doc, i);
+                spans.push((first.to_string(), span.clone()));
+                i = span.end;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    spans
+}
+
+fn find_level(doc: &LosslessDocument, name: &str) -> Option<Range<usize>> {
+    level_spans(doc).into_iter().find(|(level_name, _)| level_name == name).map(|(_, span)| span)
+}
+
+fn has_capability(doc: &LosslessDocument, span: Range<usize>, kind: &str) -> bool {
+    let mut i = span.start;
+    while i < span.end {
+        if strip_comment(doc.line(i)).trim() == "capability" {
+            let capability_span = find_block(doc, i);
+            for j in capability_span.clone() {
+                if strip_comment(doc.line(j)).split_whitespace().next() == Some(kind) {
+                    return true;
+                }
+            }
+            i = capability_span.end;
+        } else {
+            i += 1;
+        }
+    }
+    false
+}
+
+/// Extent of the `{ ... }` block starting at or after `lines[start]`,
+/// returned as `start..` one past its closing `}`. Braces that balance
+/// within a single line (e.g. a level header's `requires factions { all }`)
+/// don't count as the block's own delimiters, matching
+/// [`crate::building`]'s own block finder.
+fn find_block(doc: &LosslessDocument, start: usize) -> Range<usize> {
+    let mut open_at = start;
+    loop {
+        if open_at >= doc.line_count() {
+            return start..doc.line_count();
+        }
+        let text = strip_comment(doc.line(open_at));
+        if text.matches('{').count() > text.matches('}').count() {
+            break;
+        }
+        open_at += 1;
+    }
+
+    let mut depth = 0i32;
+    for i in open_at..doc.line_count() {
+        let text = strip_comment(doc.line(i));
+        depth += text.matches('{').count() as i32 - text.matches('}').count() as i32;
+        if depth == 0 {
+            return start..i + 1;
+        }
+    }
+    start..doc.line_count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requires::{Clause, Requirement};
+
+    fn sample() -> String {
+        "building\n\
+{\n\
+\tlevels tavern town_watch\n\
+\ttavern  requires factions { all }\n\
+\t{\n\
+\t\tcapability\n\
+\t\t{\n\
+\t\t\tlaw_bonus 1\n\
+\t\t\tcrime -1\n\
+\t\t}\n\
+\t\tconstruction 1\n\
+\t\tcost 600\n\
+\t}\n\
+\ttown_watch\n\
+\t{\n\
+\t\tcapability\n\
+\t\t{\n\
+\t\t\tlaw_bonus 2\n\
+\t\t}\n\
+\t\tconstruction 2\n\
+\t\tcost 1300\n\
+\t}\n\
+}\n"
+            .to_string()
+    }
+
+    #[test]
+    fn unedited_round_trips_byte_identical() {
+        let text = sample();
+        assert_eq!(EdbDocument::parse(&text).to_text(), text);
+    }
+
+    #[test]
+    fn level_mut_returns_none_for_an_unknown_name() {
+        let mut doc = EdbDocument::parse(&sample());
+        assert!(doc.level_mut("hovel").is_none());
+    }
+
+    #[test]
+    fn requires_reads_the_header_expression() {
+        let mut doc = EdbDocument::parse(&sample());
+        let expr = doc.level_mut("tavern").unwrap().requires();
+        assert_eq!(expr.render(), "factions { all }");
+    }
+
+    #[test]
+    fn requires_is_empty_when_the_header_has_none() {
+        let mut doc = EdbDocument::parse(&sample());
+        assert!(doc.level_mut("town_watch").unwrap().requires().requirements.is_empty());
+    }
+
+    #[test]
+    fn add_requirement_appends_to_an_existing_expression() {
+        let mut doc = EdbDocument::parse(&sample());
+        assert!(doc
+            .level_mut("tavern")
+            .unwrap()
+            .add_requirement(Requirement { negated: false, clause: Clause::HiddenResource("gunpowder".to_string()) }));
+        assert!(doc.to_text().contains("tavern requires factions { all }, hidden_resource gunpowder\n"));
+    }
+
+    #[test]
+    fn add_requirement_inserts_a_requires_clause_when_there_was_none() {
+        let mut doc = EdbDocument::parse(&sample());
+        assert!(doc
+            .level_mut("town_watch")
+            .unwrap()
+            .add_requirement(Requirement { negated: false, clause: Clause::HiddenResource("iron".to_string()) }));
+        assert!(doc.to_text().contains("town_watch requires hidden_resource iron\n"));
+    }
+
+    #[test]
+    fn restrict_to_factions_replaces_the_factions_clause() {
+        let mut doc = EdbDocument::parse(&sample());
+        assert!(doc.level_mut("tavern").unwrap().restrict_to_factions(&["scotland".to_string(), "england".to_string()]));
+        assert!(doc.to_text().contains("tavern requires factions { scotland, england }\n"));
+    }
+
+    #[test]
+    fn only_the_edited_header_line_changes() {
+        let mut doc = EdbDocument::parse(&sample());
+        doc.level_mut("tavern").unwrap().restrict_to_factions(&["scotland".to_string()]);
+        let text = doc.to_text();
+        assert!(text.contains("law_bonus 1\n"));
+        assert!(text.contains("construction 1\n"));
+        assert!(text.contains("town_watch\n\t{\n"));
+    }
+
+    #[test]
+    fn level_names_with_capability_finds_matching_levels_across_the_file() {
+        let doc = EdbDocument::parse(&sample());
+        assert_eq!(doc.level_names_with_capability("law_bonus"), vec!["tavern".to_string(), "town_watch".to_string()]);
+        assert!(doc.level_names_with_capability("crime").contains(&"tavern".to_string()));
+        assert!(doc.level_names_with_capability("nonexistent").is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+/// A malformed line (or missing structural marker) in a data file, precise
+/// enough that a modder can open `file` at `line_number` and see the
+/// problem themselves.
+#[derive(thiserror::Error, Debug)]
+#[error("{file}:{line_number}: {message} (near {line_text:?})")]
+pub struct ParseError {
+    pub file: PathBuf,
+    pub line_number: usize,
+    pub line_text: String,
+    pub message: String,
+}
+
+/// Errors produced while loading world data from a data directory.
+#[derive(thiserror::Error, Debug)]
+pub enum WorldError {
+    #[error("data directory {0:?} does not exist")]
+    MissingDataDir(PathBuf),
+
+    #[error("expected data file at {0:?}, but it doesn't exist")]
+    MissingFile(PathBuf),
+
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{path}: failed to decode as {encoding}")]
+    Decode { path: PathBuf, encoding: &'static str },
+
+    #[error("{path}: {message}")]
+    Tga { path: PathBuf, message: String },
+
+    #[error("{path}:{line_number}: {message}")]
+    Xml { path: PathBuf, line_number: usize, message: String },
+
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    #[error(transparent)]
+    Pack(#[from] pack::error::PackError),
+
+    #[error("`{0}` is not implemented yet")]
+    NotImplemented(&'static str),
+
+    #[error("no {kind} named {name:?}{suggestion}")]
+    NotFound { kind: &'static str, name: String, suggestion: String },
+
+    #[error("{0} record(s) differ")]
+    Different(usize),
+
+    #[error("{0} finding(s) reported an error")]
+    ValidationFailed(usize),
+
+    #[error("{0}")]
+    InvalidArgument(String),
+}
+
+pub type Result<T> = std::result::Result<T, WorldError>;
+
+pub(crate) fn io_err(path: &std::path::Path, source: std::io::Error) -> WorldError {
+    WorldError::Io { path: path.to_path_buf(), source }
+}
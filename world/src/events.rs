@@ -0,0 +1,210 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::character::Position;
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, strip_keyword, DescrLine, ReadOptions};
+
+/// Whether a `descr_events.txt` block is a scripted historic event or a
+/// random disaster. Told apart by which keyword starts the block.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventKind {
+    Event,
+    Disaster,
+}
+
+/// One `event`/`disaster` block from `descr_events.txt`: a name, the year(s)
+/// it can trigger, the map tile(s) it can trigger at, and an optional movie
+/// to play when it fires.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct HistoricEvent {
+    /// 0-based position in `descr_events.txt`, in file order.
+    pub id: usize,
+    /// 1-based line the `event`/`disaster` header started on.
+    pub line_number: usize,
+    pub kind: EventKind,
+    pub name: String,
+    /// `(earliest, latest)` year the event can trigger, from one or two
+    /// `date` lines. A single `date` line makes both ends the same year.
+    pub date_range: (u32, u32),
+    pub positions: Vec<Position>,
+    pub movie: Option<String>,
+}
+
+impl HistoricEvent {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<HistoricEvent>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut events = Vec::new();
+        for block in split_blocks(&lines) {
+            events.push(parse_record(path, &block)?);
+        }
+
+        for (id, event) in events.iter_mut().enumerate() {
+            event.id = id;
+        }
+
+        Ok(events)
+    }
+
+    /// The turn number the event first becomes possible, given the
+    /// campaign's start year and how many turns make up a year. Used to
+    /// order the `world events` timeline; falls back to the raw start year
+    /// if the campaign's start date or timescale wasn't parseable.
+    pub fn earliest_turn(&self, start_year: Option<u32>, timescale: Option<u32>) -> i64 {
+        match (start_year, timescale) {
+            (Some(start_year), Some(timescale)) => (self.date_range.0 as i64 - start_year as i64) * timescale as i64,
+            _ => self.date_range.0 as i64,
+        }
+    }
+}
+
+/// Groups `lines` into blocks separated by one or more blank (or
+/// comment-only, since [`read_descr_lines`] already stripped comments)
+/// lines. Same technique as `descr_regions.txt` and the other flat,
+/// keyword-based file formats in this crate.
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<HistoricEvent> {
+    let header = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let (kind, name) = if let Some(rest) = strip_keyword(&header.text, "event") {
+        (EventKind::Event, rest)
+    } else if let Some(rest) = strip_keyword(&header.text, "disaster") {
+        (EventKind::Disaster, rest)
+    } else {
+        return Err(err(header, format!("expected `event NAME` or `disaster NAME`, found {:?}", header.text)));
+    };
+    if name.is_empty() {
+        return Err(err(header, "event/disaster block has no name".to_string()));
+    }
+    let name = name.to_string();
+
+    let mut dates: Vec<u32> = Vec::new();
+    let mut positions = Vec::new();
+    let mut movie = None;
+
+    for line in &block[1..] {
+        if let Some(rest) = strip_keyword(&line.text, "date") {
+            let year = rest.parse().map_err(|_| err(line, format!("{rest:?} is not a valid date")))?;
+            dates.push(year);
+        } else if let Some(rest) = strip_keyword(&line.text, "position") {
+            let (x, y) = rest.split_once(',').ok_or_else(|| err(line, format!("{rest:?} is not a valid \"x, y\" position")))?;
+            let x = x.trim().parse().map_err(|_| err(line, format!("{:?} is not a valid position x", x.trim())))?;
+            let y = y.trim().parse().map_err(|_| err(line, format!("{:?} is not a valid position y", y.trim())))?;
+            positions.push(Position { x, y });
+        } else if let Some(rest) = strip_keyword(&line.text, "movie") {
+            movie = Some(rest.to_string());
+        } else {
+            return Err(err(line, format!("unexpected line {:?} in {kind:?} {name:?}'s block", line.text)));
+        }
+    }
+
+    if dates.is_empty() {
+        return Err(err(header, format!("{kind:?} {name:?} has no `date` line")));
+    }
+    if positions.is_empty() {
+        return Err(err(header, format!("{kind:?} {name:?} has no `position` line")));
+    }
+    let earliest = *dates.iter().min().unwrap();
+    let latest = *dates.iter().max().unwrap();
+
+    Ok(HistoricEvent { id: 0, line_number: header.line_number, kind, name, date_range: (earliest, latest), positions, movie })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn events_from(text: &str) -> Vec<HistoricEvent> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_events_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut events = Vec::new();
+        for block in split_blocks(&lines) {
+            events.push(parse_record(&path, &block).unwrap());
+        }
+        events
+    }
+
+    fn parse_err(text: &str) -> String {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_events_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        parse_record(&path, &blocks[0]).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parses_event_and_disaster_blocks() {
+        let events = events_from(
+            r#"
+event battle_of_hastings
+date 1066
+position 100, 50
+movie hastings.bik
+
+disaster great_fire
+date 1210
+date 1212
+position 55, 30
+"#,
+        );
+        assert_eq!(events.len(), 2);
+
+        let battle = &events[0];
+        assert_eq!(battle.kind, EventKind::Event);
+        assert_eq!(battle.name, "battle_of_hastings");
+        assert_eq!(battle.date_range, (1066, 1066));
+        assert_eq!(battle.positions, vec![Position { x: 100, y: 50 }]);
+        assert_eq!(battle.movie.as_deref(), Some("hastings.bik"));
+
+        let fire = &events[1];
+        assert_eq!(fire.kind, EventKind::Disaster);
+        assert_eq!(fire.date_range, (1210, 1212));
+        assert!(fire.movie.is_none());
+    }
+
+    #[test]
+    fn missing_date_is_rejected() {
+        let message = parse_err("event no_date\nposition 1, 1\n");
+        assert!(message.contains("has no `date` line"), "{message}");
+    }
+
+    #[test]
+    fn earliest_turn_uses_start_year_and_timescale() {
+        let events = events_from("event coronation\ndate 1210\nposition 1, 1\n");
+        assert_eq!(events[0].earliest_turn(Some(1200), Some(2)), 20);
+        assert_eq!(events[0].earliest_turn(None, None), 1210);
+    }
+}
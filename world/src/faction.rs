@@ -0,0 +1,384 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result};
+use crate::lossless::LosslessDocument;
+use crate::text::{parse_descr_lines, strip_comment, strip_keyword, ReadOptions};
+
+/// An RGB colour triple, as used for `primary_colour`/`secondary_colour`.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// A faction record from `descr_sm_factions.txt`: a `faction NAME` line
+/// followed by its key/value lines, up to the next `faction` line or EOF.
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct Faction {
+    /// 0-based position in `descr_sm_factions.txt`, stable for a given file
+    /// so other loaded data (and external tooling) can reference a faction
+    /// by id instead of by name.
+    pub id: usize,
+    /// 1-based line the `faction NAME` declaration started on, for pointing
+    /// tooling (e.g. `world validate`) back at the source file.
+    pub line_number: usize,
+    pub name: String,
+    /// Looked up from a `text/expanded.txt`-style localization file by
+    /// [`crate::localization::LocalizationFile::apply`]; `None` if no
+    /// locale directory was loaded or it has no matching key.
+    pub display_name: Option<String>,
+    pub culture: Option<String>,
+    pub religion: Option<String>,
+    pub symbol: Option<String>,
+    pub rebel_symbol: Option<String>,
+    pub primary_colour: Option<Rgb>,
+    pub secondary_colour: Option<Rgb>,
+    pub loading_logo: Option<String>,
+    pub standard_index: Option<u32>,
+    pub logo_index: Option<u32>,
+    pub small_logo_index: Option<u32>,
+    pub triumph_value: Option<u32>,
+    pub custom_battle_availability: Option<bool>,
+    pub periods_unavailable_in_custom_battle: Option<u32>,
+    pub can_sap: Option<bool>,
+    pub prefers_naval_invasions: Option<bool>,
+    pub spawned_on_event: Option<String>,
+    /// Any key this parser doesn't know about yet, kept verbatim (as
+    /// written, whitespace-joined) so a record round-trips instead of
+    /// silently losing data.
+    pub extra: BTreeMap<String, String>,
+    /// The line each recognized or `extra` key was set from, keyed by field
+    /// name, so a violation can be reported against the line that actually
+    /// caused it instead of just the record's `faction NAME` line.
+    pub field_lines: BTreeMap<String, usize>,
+}
+
+impl Faction {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Faction>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+
+        let mut factions = Vec::new();
+        let mut current: Option<Faction> = None;
+
+        for descr_line in &lines {
+            let line_number = descr_line.line_number;
+            let line = descr_line.text.as_str();
+
+            if let Some(name) = line.strip_prefix("faction ").map(str::trim) {
+                factions.extend(current.take());
+                current = Some(Faction {
+                    name: name.to_string(),
+                    line_number,
+                    ..Faction::default()
+                });
+                continue;
+            }
+
+            let Some(faction) = current.as_mut() else {
+                return Err(ParseError {
+                    file: path.to_path_buf(),
+                    line_number,
+                    line_text: line.to_string(),
+                    message: "key/value line before the first `faction` record".to_string(),
+                }
+                .into());
+            };
+
+            let mut parts = line.split_whitespace();
+            let Some(key) = parts.next() else { continue };
+            let value = parts.collect::<Vec<_>>().join(" ");
+            let field_error = |message: String| ParseError {
+                file: path.to_path_buf(),
+                line_number,
+                line_text: line.to_string(),
+                message,
+            };
+
+            faction.field_lines.insert(key.to_string(), line_number);
+
+            match key {
+                "culture" => faction.culture = Some(value),
+                "religion" => faction.religion = Some(value),
+                "symbol" => faction.symbol = Some(value),
+                "rebel_symbol" => faction.rebel_symbol = Some(value),
+                "loading_logo" => faction.loading_logo = Some(value),
+                "spawned_on_event" => faction.spawned_on_event = Some(value),
+                "primary_colour" => faction.primary_colour = Some(parse_rgb(&value).map_err(field_error)?),
+                "secondary_colour" => faction.secondary_colour = Some(parse_rgb(&value).map_err(field_error)?),
+                "standard_index" => faction.standard_index = Some(parse_u32(&value).map_err(field_error)?),
+                "logo_index" => faction.logo_index = Some(parse_u32(&value).map_err(field_error)?),
+                "small_logo_index" => faction.small_logo_index = Some(parse_u32(&value).map_err(field_error)?),
+                "triumph_value" => faction.triumph_value = Some(parse_u32(&value).map_err(field_error)?),
+                "periods_unavailable_in_custom_battle" => {
+                    faction.periods_unavailable_in_custom_battle = Some(parse_u32(&value).map_err(field_error)?);
+                }
+                "custom_battle_availability" => {
+                    faction.custom_battle_availability = Some(parse_bool(&value).map_err(field_error)?);
+                }
+                "can_sap" => faction.can_sap = Some(parse_bool(&value).map_err(field_error)?),
+                "prefers_naval_invasions" => {
+                    faction.prefers_naval_invasions = Some(parse_bool(&value).map_err(field_error)?);
+                }
+                _ => {
+                    faction.extra.insert(key.to_string(), value);
+                }
+            }
+        }
+        factions.extend(current.take());
+
+        for (id, faction) in factions.iter_mut().enumerate() {
+            faction.id = id;
+        }
+
+        Ok(factions)
+    }
+}
+
+/// A `descr_sm_factions.txt` document kept line-for-line (see
+/// [`LosslessDocument`]) so it can be edited through a faction's typed
+/// setters and written back with only the touched lines changed --
+/// [`FactionsDocument::to_text`] is byte-identical to the input it was
+/// parsed from if nothing has been edited. This is the template for doing
+/// the same to other `descr_*.txt` files: the line-preserving part lives in
+/// [`crate::lossless`], only the record layout below is specific to this
+/// file.
+pub struct FactionsDocument {
+    doc: LosslessDocument,
+    /// Each faction's `[start, end)` line range, `start` being its
+    /// `faction NAME` line, in file order.
+    spans: Vec<(String, Range<usize>)>,
+}
+
+impl FactionsDocument {
+    pub fn parse(text: &str) -> Self {
+        let doc = LosslessDocument::parse(text);
+
+        let mut spans = Vec::new();
+        let mut current: Option<(String, usize)> = None;
+        for i in 0..doc.line_count() {
+            let stripped = strip_comment(doc.line(i)).trim();
+            if let Some(name) = strip_keyword(stripped, "faction") {
+                if let Some((name, start)) = current.take() {
+                    spans.push((name, start..i));
+                }
+                current = Some((name.to_string(), i));
+            }
+        }
+        if let Some((name, start)) = current {
+            let end = doc.line_count();
+            spans.push((name, start..end));
+        }
+
+        FactionsDocument { doc, spans }
+    }
+
+    /// Returns an editable handle to `name`'s record (matched exactly, like
+    /// [`Faction::load_all`]), or `None` if there's no such faction.
+    pub fn faction_mut(&mut self, name: &str) -> Option<FactionRecordMut<'_>> {
+        let index = self.spans.iter().position(|(faction_name, _)| faction_name == name)?;
+        Some(FactionRecordMut { document: self, index })
+    }
+
+    /// Clones `template`'s whole record onto the end of the document with
+    /// its header line renamed to `new_name`, for scaffolding a new faction
+    /// off an existing one instead of building its boilerplate field by
+    /// field. Returns an editable handle to the new record, or `None` if
+    /// there's no `template` faction to clone.
+    pub fn insert_from_template(&mut self, template: &str, new_name: &str) -> Option<FactionRecordMut<'_>> {
+        let (_, span) = self.spans.iter().find(|(name, _)| name == template)?.clone();
+        let mut lines: Vec<String> = span.map(|i| self.doc.line(i).to_string()).collect();
+        lines[0] = format!("faction {new_name}");
+
+        let insert_at = self.doc.line_count();
+        self.doc.insert_line(insert_at, String::new());
+        for (offset, line) in lines.into_iter().enumerate() {
+            self.doc.insert_line(insert_at + 1 + offset, line);
+        }
+
+        let new_end = self.doc.line_count();
+        self.spans.push((new_name.to_string(), insert_at + 1..new_end));
+        self.faction_mut(new_name)
+    }
+
+    pub fn to_text(&self) -> String {
+        self.doc.to_text()
+    }
+}
+
+/// An editable handle to one faction's lines within a [`FactionsDocument`].
+/// Holds the whole document (rather than just its own line range) because
+/// appending a new field line shifts every later faction's span, which has
+/// to stay in sync for a second `faction_mut` call on the same document to
+/// still land on the right lines.
+pub struct FactionRecordMut<'a> {
+    document: &'a mut FactionsDocument,
+    index: usize,
+}
+
+impl FactionRecordMut<'_> {
+    pub fn set_culture(&mut self, culture: &str) {
+        self.set_field("culture", culture);
+    }
+
+    pub fn set_religion(&mut self, religion: &str) {
+        self.set_field("religion", religion);
+    }
+
+    pub fn set_symbol(&mut self, path: &str) {
+        self.set_field("symbol", path);
+    }
+
+    pub fn set_rebel_symbol(&mut self, path: &str) {
+        self.set_field("rebel_symbol", path);
+    }
+
+    pub fn set_primary_colour(&mut self, r: u8, g: u8, b: u8) {
+        self.set_field("primary_colour", &format!("{r} {g} {b}"));
+    }
+
+    pub fn set_secondary_colour(&mut self, r: u8, g: u8, b: u8) {
+        self.set_field("secondary_colour", &format!("{r} {g} {b}"));
+    }
+
+    /// Rewrites `key`'s existing `key value` line in place if the record
+    /// has one -- keeping its indentation and any trailing inline comment
+    /// -- or appends a new line just before the blank line(s) that end the
+    /// record's block if it doesn't. Every other line, in this record and
+    /// every other one, is left untouched.
+    fn set_field(&mut self, key: &str, value: &str) {
+        let span = self.document.spans[self.index].1.clone();
+        for i in span.clone() {
+            let line = self.document.doc.line(i);
+            let content = strip_comment(line);
+            let comment = &line[content.len()..];
+            let indent = &content[..content.len() - content.trim_start().len()];
+            if strip_keyword(content.trim(), key).is_some() {
+                let new_line = if comment.is_empty() { format!("{indent}{key} {value}") } else { format!("{indent}{key} {value} {comment}") };
+                self.document.doc.set_line(i, new_line);
+                return;
+            }
+        }
+
+        let mut insert_at = span.end;
+        while insert_at > span.start + 1 && self.document.doc.line(insert_at - 1).trim().is_empty() {
+            insert_at -= 1;
+        }
+        self.document.doc.insert_line(insert_at, format!("{key} {value}"));
+        for (_, other_span) in &mut self.document.spans {
+            if other_span.start >= insert_at {
+                other_span.start += 1;
+            }
+            if other_span.end >= insert_at {
+                other_span.end += 1;
+            }
+        }
+    }
+}
+
+pub(crate) fn parse_rgb(value: &str) -> std::result::Result<Rgb, String> {
+    let mut parts = value.split_whitespace();
+    let (Some(r), Some(g), Some(b), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+        return Err(format!("expected 3 space-separated 0-255 values, found {value:?}"));
+    };
+    let channel = |s: &str| s.parse::<u8>().map_err(|_| format!("{s:?} is not a valid colour channel (0-255)"));
+    Ok(Rgb {
+        r: channel(r)?,
+        g: channel(g)?,
+        b: channel(b)?,
+    })
+}
+
+pub(crate) fn parse_u32(value: &str) -> std::result::Result<u32, String> {
+    value.parse().map_err(|_| format!("{value:?} is not a valid non-negative integer"))
+}
+
+fn parse_bool(value: &str) -> std::result::Result<bool, String> {
+    match value {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        other => Err(format!("{other:?} is not a valid boolean (expected true/false or 1/0)")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unedited_document_round_trips_byte_identical() {
+        let text = "faction england\n\tculture northern_european\n\treligion catholic\t; footnote\n\nfaction france\n\tculture western_european\n";
+        assert_eq!(FactionsDocument::parse(text).to_text(), text);
+    }
+
+    #[test]
+    fn set_colour_rewrites_only_that_line() {
+        let text = "faction england\n\tculture northern_european\n\tprimary_colour 255 0 0\n\nfaction france\n\tprimary_colour 0 0 255\n";
+        let mut doc = FactionsDocument::parse(text);
+        doc.faction_mut("england").unwrap().set_primary_colour(0, 128, 255);
+        assert_eq!(
+            doc.to_text(),
+            "faction england\n\tculture northern_european\n\tprimary_colour 0 128 255\n\nfaction france\n\tprimary_colour 0 0 255\n"
+        );
+    }
+
+    #[test]
+    fn set_field_preserves_indentation_and_trailing_comment() {
+        let text = "faction england\n\tprimary_colour 255 0 0 ; house colours\n";
+        let mut doc = FactionsDocument::parse(text);
+        doc.faction_mut("england").unwrap().set_primary_colour(1, 2, 3);
+        assert_eq!(doc.to_text(), "faction england\n\tprimary_colour 1 2 3 ; house colours\n");
+    }
+
+    #[test]
+    fn set_field_appends_a_new_line_before_the_blank_separator_when_the_key_is_missing() {
+        let text = "faction england\n\tculture northern_european\n\nfaction france\n\tculture western_european\n";
+        let mut doc = FactionsDocument::parse(text);
+        doc.faction_mut("england").unwrap().set_symbol("data/symbol.tga");
+        assert_eq!(
+            doc.to_text(),
+            "faction england\n\tculture northern_european\nsymbol data/symbol.tga\n\nfaction france\n\tculture western_european\n"
+        );
+    }
+
+    #[test]
+    fn set_field_on_one_faction_keeps_a_later_faction_editable() {
+        let text = "faction england\n\tculture northern_european\n\nfaction france\n\tculture western_european\n";
+        let mut doc = FactionsDocument::parse(text);
+        doc.faction_mut("england").unwrap().set_symbol("data/symbol.tga");
+        doc.faction_mut("france").unwrap().set_religion("catholic");
+        assert_eq!(
+            doc.to_text(),
+            "faction england\n\tculture northern_european\nsymbol data/symbol.tga\n\nfaction france\n\tculture western_european\nreligion catholic\n"
+        );
+    }
+
+    #[test]
+    fn faction_mut_returns_none_for_an_unknown_name() {
+        let text = "faction england\n\tculture northern_european\n";
+        let mut doc = FactionsDocument::parse(text);
+        assert!(doc.faction_mut("wales").is_none());
+    }
+
+    #[test]
+    fn insert_from_template_clones_the_record_under_a_new_name() {
+        let text = "faction england\n\tculture northern_european\n\treligion catholic\n\tprimary_colour 255 0 0\n";
+        let mut doc = FactionsDocument::parse(text);
+        doc.insert_from_template("england", "wales").unwrap().set_religion("pagan");
+        assert_eq!(
+            doc.to_text(),
+            "faction england\n\tculture northern_european\n\treligion catholic\n\tprimary_colour 255 0 0\n\nfaction wales\n\tculture northern_european\n\treligion pagan\n\tprimary_colour 255 0 0\n"
+        );
+    }
+
+    #[test]
+    fn insert_from_template_returns_none_for_an_unknown_template() {
+        let text = "faction england\n\tculture northern_european\n";
+        let mut doc = FactionsDocument::parse(text);
+        assert!(doc.insert_from_template("wales", "scotland").is_none());
+    }
+}
@@ -0,0 +1,180 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, WorldError};
+use crate::tga::TgaImage;
+
+/// Grayscale height value at/below which a heightmap pixel is treated as
+/// sea. The game's actual sea-level encoding isn't public; this matches
+/// every vanilla/modded heightmap this tool has been run against so far.
+const SEA_LEVEL_HEIGHT: u8 = 0;
+
+/// A parsed heightmap, from either `map_heights.hgt` (this tool's own raw
+/// binary form: an 8-byte little-endian width/height header followed by
+/// one grayscale byte per pixel) or `map_heights.tga` (a grayscale image).
+/// Both describe the same `(2w+1) x (2h+1)` pixel grid, where `w` and `h`
+/// are the strat map's tile dimensions.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct HeightMap {
+    pub width: u32,
+    pub height: u32,
+    #[serde(skip)]
+    heights: Vec<u8>,
+}
+
+/// Where a strat-map tile falls on the heightmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileClass {
+    Land,
+    Sea,
+    /// The tile coordinate has no corresponding heightmap pixel at all.
+    OffMap,
+}
+
+impl HeightMap {
+    /// Loads `hgt_path` if present, else `tga_path`. Returns `Ok(None)` if
+    /// neither file exists, since the heightmap is optional input for
+    /// `validate`'s land/sea check.
+    pub fn try_load(hgt_path: &Path, tga_path: &Path) -> Result<Option<HeightMap>> {
+        if hgt_path.exists() {
+            return Ok(Some(Self::load_hgt(hgt_path)?));
+        }
+        if tga_path.exists() {
+            return Ok(Some(Self::load_tga(tga_path)?));
+        }
+        Ok(None)
+    }
+
+    pub fn load_tga(path: &Path) -> Result<HeightMap> {
+        let image = TgaImage::load(path)?;
+        let heights = image.pixels().map(|(_, _, colour)| colour.r).collect();
+        Ok(HeightMap { width: image.width(), height: image.height(), heights })
+    }
+
+    pub fn load_hgt(path: &Path) -> Result<HeightMap> {
+        let bytes = std::fs::read(path).map_err(|e| crate::error::io_err(path, e))?;
+        let tga_err = |message: String| WorldError::Tga { path: path.to_path_buf(), message };
+        if bytes.len() < 8 {
+            return Err(tga_err("file is shorter than an .hgt header".to_string()));
+        }
+        let width = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let height = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let expected = width as usize * height as usize;
+        let heights = &bytes[8..];
+        if heights.len() != expected {
+            return Err(tga_err(format!("expected {expected} height bytes for a {width}x{height} map, found {}", heights.len())));
+        }
+        Ok(HeightMap { width, height, heights: heights.to_vec() })
+    }
+
+    pub fn height_at(&self, x: u32, y: u32) -> Option<u8> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.heights.get((y * self.width + x) as usize).copied()
+    }
+
+    pub fn is_sea(&self, x: u32, y: u32) -> Option<bool> {
+        self.height_at(x, y).map(|h| h == SEA_LEVEL_HEIGHT)
+    }
+
+    /// Converts a strat-map tile coordinate to the heightmap pixel that
+    /// represents that tile's centre. The heightmap is `(2w+1)x(2h+1)`:
+    /// even pixel coordinates are vertices shared between adjacent tiles,
+    /// odd coordinates are tile centres -- so tile `(x, y)` centres on
+    /// pixel `(2x+1, 2y+1)`.
+    pub fn tile_pixel(tile_x: u32, tile_y: u32) -> (u32, u32) {
+        (2 * tile_x + 1, 2 * tile_y + 1)
+    }
+
+    /// Classifies a strat-map tile coordinate as land, sea, or off the edge
+    /// of the map. Negative coordinates (which `descr_strat.txt` shouldn't
+    /// contain, but modders sometimes typo) are always off-map.
+    pub fn classify_tile(&self, tile_x: i32, tile_y: i32) -> TileClass {
+        let (Ok(tile_x), Ok(tile_y)) = (u32::try_from(tile_x), u32::try_from(tile_y)) else {
+            return TileClass::OffMap;
+        };
+        let (px, py) = Self::tile_pixel(tile_x, tile_y);
+        match self.is_sea(px, py) {
+            Some(true) => TileClass::Sea,
+            Some(false) => TileClass::Land,
+            None => TileClass::OffMap,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn write_hgt(width: u32, height: u32, heights: &[u8]) -> std::path::PathBuf {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("map_heights_test_{id}.hgt"));
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&width.to_le_bytes());
+        bytes.extend_from_slice(&height.to_le_bytes());
+        bytes.extend_from_slice(heights);
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn tile_pixel_uses_the_2x_plus_1_relationship() {
+        assert_eq!(HeightMap::tile_pixel(0, 0), (1, 1));
+        assert_eq!(HeightMap::tile_pixel(1, 0), (3, 1));
+        assert_eq!(HeightMap::tile_pixel(0, 1), (1, 3));
+        assert_eq!(HeightMap::tile_pixel(4, 7), (9, 15));
+    }
+
+    #[test]
+    fn classifies_land_tile() {
+        #[rustfmt::skip]
+        let heights = vec![
+            0, 0, 0,
+            0, 10, 0,
+            0, 0, 0,
+        ];
+        let heightmap = HeightMap { width: 3, height: 3, heights };
+        assert_eq!(heightmap.classify_tile(0, 0), TileClass::Land);
+    }
+
+    #[test]
+    fn classifies_sea_tile() {
+        let heightmap = HeightMap { width: 3, height: 3, heights: vec![0; 9] };
+        assert_eq!(heightmap.classify_tile(0, 0), TileClass::Sea);
+    }
+
+    #[test]
+    fn classifies_off_map_tiles() {
+        let heightmap = HeightMap { width: 3, height: 3, heights: vec![10; 9] };
+        assert_eq!(heightmap.classify_tile(-1, 0), TileClass::OffMap);
+        assert_eq!(heightmap.classify_tile(0, -1), TileClass::OffMap);
+        assert_eq!(heightmap.classify_tile(5, 5), TileClass::OffMap);
+    }
+
+    #[test]
+    fn load_hgt_round_trips_header_and_bytes() {
+        let path = write_hgt(2, 2, &[0, 10, 20, 0]);
+        let heightmap = HeightMap::load_hgt(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(heightmap.width, 2);
+        assert_eq!(heightmap.height, 2);
+        assert_eq!(heightmap.height_at(1, 0), Some(10));
+        assert_eq!(heightmap.is_sea(0, 0), Some(true));
+        assert_eq!(heightmap.is_sea(1, 0), Some(false));
+    }
+
+    #[test]
+    fn load_hgt_rejects_wrong_byte_count() {
+        let path = write_hgt(2, 2, &[0, 10, 20]);
+        let err = HeightMap::load_hgt(&path).unwrap_err();
+        std::fs::remove_file(&path).ok();
+        assert!(err.to_string().contains("expected 4 height bytes"));
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::world::World;
+
+/// Case-insensitive, lazily built cross-reference index over a loaded
+/// [`World`], built once via [`World::index`] and reused for every
+/// subsequent lookup instead of re-scanning the relevant `Vec` every time.
+///
+/// Only ever stores `id`s (each record's stable `Vec`-position id, e.g.
+/// [`crate::faction::Faction::id`]) rather than references into `World`,
+/// so it doesn't need to borrow from the `World` it indexes -- an id is
+/// already the "name back to record" half of an id/name mapping, since
+/// `world.factions[id].name` recovers the name a lookup here started from.
+#[derive(Debug, Default)]
+pub struct WorldIndex {
+    faction_by_name: HashMap<String, usize>,
+    region_by_name: HashMap<String, usize>,
+    unit_by_type: HashMap<String, usize>,
+    culture_by_name: HashMap<String, usize>,
+    /// Every case-insensitive name collision found while building the maps
+    /// above -- two records of the same kind claiming the same name, where
+    /// the later one would otherwise have silently shadowed the earlier
+    /// one in a plain `HashMap`. `World::index` builds this once; `validate`
+    /// turns it into findings instead of leaving it as silent shadowing.
+    pub duplicates: Vec<DuplicateName>,
+}
+
+/// One case-insensitive name collision found while building a [`WorldIndex`]
+/// map, identifying both records by id so a caller can look up whichever
+/// details it needs (line number, display name, ...) from the `World`.
+#[derive(Debug, Clone)]
+pub struct DuplicateName {
+    /// The kind of record, e.g. `"faction"` -- matches the name of the
+    /// `World` field the records came from, singular.
+    pub kind: &'static str,
+    pub name: String,
+    pub first_id: usize,
+    pub second_id: usize,
+}
+
+impl WorldIndex {
+    pub(crate) fn build(world: &World) -> Self {
+        let mut index = WorldIndex::default();
+        index_names(&mut index.faction_by_name, &mut index.duplicates, "faction", world.factions.iter().map(|f| (f.id, f.name.as_str())));
+        index_names(&mut index.region_by_name, &mut index.duplicates, "region", world.regions.iter().map(|r| (r.id, r.name.as_str())));
+        index_names(&mut index.unit_by_type, &mut index.duplicates, "unit", world.units.iter().map(|u| (u.id, u.name.as_str())));
+        index_names(&mut index.culture_by_name, &mut index.duplicates, "culture", world.cultures.iter().map(|c| (c.id, c.name.as_str())));
+        index
+    }
+
+    pub fn faction_id(&self, name: &str) -> Option<usize> {
+        self.faction_by_name.get(&name.to_ascii_lowercase()).copied()
+    }
+
+    pub fn region_id(&self, name: &str) -> Option<usize> {
+        self.region_by_name.get(&name.to_ascii_lowercase()).copied()
+    }
+
+    pub fn unit_id(&self, unit_type: &str) -> Option<usize> {
+        self.unit_by_type.get(&unit_type.to_ascii_lowercase()).copied()
+    }
+
+    pub fn culture_id(&self, name: &str) -> Option<usize> {
+        self.culture_by_name.get(&name.to_ascii_lowercase()).copied()
+    }
+}
+
+/// Inserts every `(id, name)` pair into `map` under its lowercased name,
+/// recording a [`DuplicateName`] instead of overwriting when a name's
+/// already taken -- the first record to claim a name keeps it, matching
+/// the linear `iter().find()` scans this index replaces (which would also
+/// have returned the first match).
+fn index_names<'a>(map: &mut HashMap<String, usize>, duplicates: &mut Vec<DuplicateName>, kind: &'static str, items: impl Iterator<Item = (usize, &'a str)>) {
+    for (id, name) in items {
+        let key = name.to_ascii_lowercase();
+        match map.get(&key) {
+            Some(&first_id) => duplicates.push(DuplicateName { kind, name: name.to_string(), first_id, second_id: id }),
+            None => {
+                map.insert(key, id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_names_looks_up_case_insensitively() {
+        let mut map = HashMap::new();
+        let mut duplicates = Vec::new();
+        index_names(&mut map, &mut duplicates, "faction", [(0, "England"), (1, "france")].into_iter());
+        assert_eq!(map.get("england"), Some(&0));
+        assert_eq!(map.get("FRANCE".to_ascii_lowercase().as_str()), Some(&1));
+        assert!(duplicates.is_empty());
+    }
+
+    #[test]
+    fn index_names_records_a_duplicate_without_overwriting_the_first() {
+        let mut map = HashMap::new();
+        let mut duplicates = Vec::new();
+        index_names(&mut map, &mut duplicates, "unit", [(0, "peasant"), (1, "Peasant")].into_iter());
+        assert_eq!(map.get("peasant"), Some(&0));
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].kind, "unit");
+        assert_eq!(duplicates[0].first_id, 0);
+        assert_eq!(duplicates[0].second_id, 1);
+    }
+}
@@ -0,0 +1,67 @@
+//! Parsers, data types, and cross-checks for Medieval II: Total War's
+//! campaign data files (`descr_sm_factions.txt`, `export_descr_unit.txt`,
+//! and the rest of the `descr_*`/`export_descr_*` family), independent of
+//! the `world` CLI built on top of it in `main.rs`. Every type here is
+//! plain data plus loaders that take an explicit [`config::Config`] (or,
+//! for a single file, a path and its already-read text) -- nothing reads
+//! from the environment or a global, so this crate is as usable from a GUI
+//! or another tool as it is from the CLI. CLI-only dependencies like
+//! `clap` stay confined to `main.rs` and aren't needed to build this
+//! library surface.
+//!
+//! ```no_run
+//! use world::config::Config;
+//! use world::world::World;
+//!
+//! let config = Config::from_args("path/to/data".into(), None, None, None, false, false)?;
+//! let world = World::load(&config)?;
+//! for faction in &world.factions {
+//!     println!("{}", faction.display_name.as_deref().unwrap_or(&faction.name));
+//! }
+//! # Ok::<(), world::error::WorldError>(())
+//! ```
+
+pub mod ancillary;
+pub mod battle_model;
+pub mod building;
+pub mod cache;
+pub mod campaign;
+pub mod campaign_db;
+pub mod campaign_script;
+pub mod character;
+pub mod commands;
+pub mod config;
+pub mod culture;
+pub mod edb;
+pub mod error;
+pub mod events;
+pub mod faction;
+pub mod heightmap;
+pub mod index;
+pub mod localization;
+pub mod lossless;
+pub mod map;
+pub mod mercenary;
+pub mod mission;
+pub mod mount;
+pub mod names;
+pub mod projectile;
+pub mod rebel_faction;
+pub mod region;
+pub mod religion;
+pub mod requires;
+pub mod resource;
+pub mod settlement;
+pub mod strat;
+pub mod terrain;
+pub mod text;
+pub mod tga;
+pub mod traits;
+pub mod trigger;
+pub mod unit;
+pub mod validate;
+pub mod vfs;
+pub mod voice;
+pub mod wall;
+pub mod win_conditions;
+pub mod world;
@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::faction::Faction;
+use crate::region::Region;
+use crate::settlement::Settlement;
+use crate::text::load_text_file;
+use crate::unit::Unit;
+
+/// Text files under a locale directory's `text` folder this loader knows
+/// how to read. Real M2TW installs ship several more (`names.txt`,
+/// `menu_english.txt`, ...); only the ones the loaded structs actually join
+/// against, or that `validate`'s localization-completeness check reads, are
+/// listed here.
+pub const LOCALIZATION_FILES: &[&str] = &["expanded.txt", "export_units.txt", "export_buildings.txt", "imperial_campaign_regions_and_settlement_names.txt"];
+
+/// A key that a [`LocalizationFile::apply`] lookup didn't find. Collected
+/// into a report rather than failing the load -- most mods ship with at
+/// least a few untranslated entries.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MissingKey {
+    pub key: String,
+}
+
+/// The `{KEY} value` string tables under `data/text/`, merged into a single
+/// key -> string map. Both the `text` folder and each file inside it are
+/// optional: a mod with no translations at all still loads fine, it just
+/// leaves every `display_name` field `None`.
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct LocalizationFile {
+    pub strings: BTreeMap<String, String>,
+    pub missing_keys: Vec<MissingKey>,
+    /// Keys loaded from `export_units.txt` specifically, kept separate from
+    /// `strings`' merged view so `validate`'s localization-completeness
+    /// check can tell an `export_units.txt` key from one that only happens
+    /// to collide with a key from another text file.
+    pub unit_keys: BTreeMap<String, String>,
+    /// Keys loaded from `export_buildings.txt` specifically, same reason as
+    /// `unit_keys`.
+    pub building_keys: BTreeMap<String, String>,
+}
+
+impl LocalizationFile {
+    pub fn load(locale_dir: &Path) -> Result<LocalizationFile> {
+        let mut strings = BTreeMap::new();
+        let mut unit_keys = BTreeMap::new();
+        let mut building_keys = BTreeMap::new();
+        let text_dir = locale_dir.join("text");
+        if text_dir.is_dir() {
+            for file_name in LOCALIZATION_FILES {
+                let path = text_dir.join(file_name);
+                if path.is_file() {
+                    let mut file_strings = BTreeMap::new();
+                    parse_file(&load_text_file(&path)?, &mut file_strings);
+                    match *file_name {
+                        "export_units.txt" => unit_keys = file_strings.clone(),
+                        "export_buildings.txt" => building_keys = file_strings.clone(),
+                        _ => {}
+                    }
+                    strings.extend(file_strings);
+                }
+            }
+        }
+        Ok(LocalizationFile { strings, missing_keys: Vec::new(), unit_keys, building_keys })
+    }
+
+    /// Joins a `display_name` onto every faction, unit, region, and
+    /// settlement, following each type's own key convention:
+    ///
+    /// - a faction looks up `FACTION_<NAME>` (uppercased)
+    /// - a unit looks up its `dictionary` value if it has one, else its own
+    ///   name, matching `export_units.txt`'s `{Peasants_descr}` style keys
+    /// - a region looks up its own name directly
+    /// - a settlement has no name of its own, so it looks up the
+    ///   `settlement_name` of the region it sits in
+    ///
+    /// A key with no match doesn't fail anything -- the field is left
+    /// `None` and the key is recorded in `self.missing_keys`.
+    pub fn apply(&mut self, factions: &mut [Faction], units: &mut [Unit], regions: &mut [Region], settlements: &mut [Settlement]) {
+        for faction in factions.iter_mut() {
+            let key = format!("FACTION_{}", faction.name.to_uppercase());
+            faction.display_name = self.lookup(&key);
+        }
+        for unit in units.iter_mut() {
+            let key = unit.extra.get("dictionary").cloned().unwrap_or_else(|| unit.name.clone());
+            unit.display_name = self.lookup(&key);
+        }
+        for region in regions.iter_mut() {
+            let key = region.name.clone();
+            region.display_name = self.lookup(&key);
+        }
+        for settlement in settlements.iter_mut() {
+            let settlement_name = regions.iter().find(|region| region.name == settlement.region).map(|region| region.settlement_name.clone());
+            settlement.display_name = settlement_name.and_then(|key| self.lookup(&key));
+        }
+    }
+
+    fn lookup(&mut self, key: &str) -> Option<String> {
+        match self.strings.get(key) {
+            Some(value) => Some(value.clone()),
+            None => {
+                self.missing_keys.push(MissingKey { key: key.to_string() });
+                None
+            }
+        }
+    }
+}
+
+/// Parses `text`'s `{KEY} value` lines into `strings`. Lines that don't
+/// start with a `{...}` key (blank lines, a `¬`-prefixed comment line,
+/// anything else) are skipped rather than reported -- these files are dense
+/// with entries and the odd stray line isn't worth a `problems` list. A
+/// trailing `¬ comment` after the value on the same line is dropped too,
+/// rather than being folded into the translated string.
+fn parse_file(text: &str, strings: &mut BTreeMap<String, String>) {
+    for raw_line in text.lines() {
+        let line = raw_line.trim_end_matches('\r');
+        let Some(rest) = line.strip_prefix('{') else { continue };
+        let Some(close) = rest.find('}') else { continue };
+        let key = &rest[..close];
+        let value = rest[close + 1..].trim_start();
+        let value = value.split('¬').next().unwrap_or("").trim_end();
+        strings.insert(key.to_string(), value.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn locale_dir_with(files: &[(&str, &str)]) -> std::path::PathBuf {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("world_localization_test_{id}"));
+        let text_dir = dir.join("text");
+        std::fs::create_dir_all(&text_dir).unwrap();
+        for (name, contents) in files {
+            std::fs::write(text_dir.join(name), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn parses_key_value_lines() {
+        let dir = locale_dir_with(&[("expanded.txt", "{FACTION_ENGLAND}England\n{FACTION_FRANCE} France\n")]);
+        let localization = LocalizationFile::load(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(localization.strings.get("FACTION_ENGLAND"), Some(&"England".to_string()));
+        assert_eq!(localization.strings.get("FACTION_FRANCE"), Some(&"France".to_string()));
+    }
+
+    #[test]
+    fn a_trailing_comment_marker_is_dropped_from_the_value() {
+        let dir = locale_dir_with(&[("expanded.txt", "¬ this whole line is a comment\n{FACTION_ENGLAND}England ¬ working translation\n")]);
+        let localization = LocalizationFile::load(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(localization.strings.get("FACTION_ENGLAND"), Some(&"England".to_string()));
+        assert_eq!(localization.strings.len(), 1);
+    }
+
+    #[test]
+    fn unit_and_building_keys_are_kept_separate_from_the_merged_map() {
+        let dir = locale_dir_with(&[
+            ("export_units.txt", "{Peasants_descr}Peasants\n"),
+            ("export_buildings.txt", "{barracks}Barracks\n"),
+        ]);
+        let localization = LocalizationFile::load(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(localization.unit_keys.get("Peasants_descr"), Some(&"Peasants".to_string()));
+        assert!(!localization.building_keys.contains_key("Peasants_descr"));
+        assert_eq!(localization.building_keys.get("barracks"), Some(&"Barracks".to_string()));
+        assert!(!localization.unit_keys.contains_key("barracks"));
+        assert_eq!(localization.strings.get("Peasants_descr"), Some(&"Peasants".to_string()));
+        assert_eq!(localization.strings.get("barracks"), Some(&"Barracks".to_string()));
+    }
+
+    #[test]
+    fn missing_text_directory_yields_an_empty_table() {
+        let dir = std::env::temp_dir().join("world_localization_test_missing");
+        std::fs::create_dir_all(&dir).unwrap();
+        let localization = LocalizationFile::load(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert!(localization.strings.is_empty());
+    }
+
+    #[test]
+    fn apply_joins_display_names_and_reports_missing_keys() {
+        let dir = locale_dir_with(&[("expanded.txt", "{FACTION_ENGLAND}England\n{England}Britannia\n{London}London\n")]);
+        let mut localization = LocalizationFile::load(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        let mut factions = vec![Faction { name: "england".to_string(), ..Faction::default() }, Faction { name: "france".to_string(), ..Faction::default() }];
+        let mut units: Vec<Unit> = Vec::new();
+        let mut regions = vec![Region {
+            id: 0,
+            line_number: 1,
+            name: "England".to_string(),
+            display_name: None,
+            settlement_name: "London".to_string(),
+            creator_faction: "england".to_string(),
+            rebel_type: "slave".to_string(),
+            colour: crate::faction::Rgb { r: 0, g: 0, b: 0 },
+            resources: Vec::new(),
+            triumph_value: None,
+            farming_level: None,
+            hidden_resources: Vec::new(),
+            religion_percentages: BTreeMap::new(),
+            extra: BTreeMap::new(),
+        }];
+        let mut settlements = vec![Settlement {
+            id: 0,
+            line_number: 1,
+            owning_faction: "england".to_string(),
+            region: "England".to_string(),
+            level: "city".to_string(),
+            position: None,
+            population: None,
+            year_founded: None,
+            plan_set: None,
+            buildings: Vec::new(),
+            religion_percentages: BTreeMap::new(),
+            extra: BTreeMap::new(),
+            display_name: None,
+        }];
+
+        localization.apply(&mut factions, &mut units, &mut regions, &mut settlements);
+
+        assert_eq!(factions[0].display_name, Some("England".to_string()));
+        assert_eq!(factions[1].display_name, None);
+        assert_eq!(regions[0].display_name, Some("Britannia".to_string()));
+        assert_eq!(settlements[0].display_name, Some("London".to_string()));
+        assert_eq!(localization.missing_keys, vec![MissingKey { key: "FACTION_FRANCE".to_string() }]);
+    }
+}
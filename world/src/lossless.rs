@@ -0,0 +1,112 @@
+/// A data file kept as a flat list of its original lines, verbatim --
+/// comments, blank lines, and indentation included -- so an editor can
+/// change just the line(s) it targets and write everything else back
+/// byte-for-byte unchanged. This is the generic machinery a per-file
+/// document type (e.g. [`crate::faction::FactionsDocument`]) builds a typed
+/// editing API on top of; it knows nothing about any particular file's
+/// record layout.
+#[derive(Debug, Clone)]
+pub struct LosslessDocument {
+    lines: Vec<String>,
+    /// The line ending the file used, so it round-trips instead of getting
+    /// normalized to one style.
+    line_ending: &'static str,
+    /// Whether the original text ended with a trailing line ending.
+    trailing_newline: bool,
+}
+
+impl LosslessDocument {
+    /// Splits `text` into lines, remembering its line-ending style (mixed
+    /// CRLF/LF within one file isn't handled, same as [`crate::text`]'s
+    /// line reader) and whether it ended with a trailing newline, so
+    /// [`LosslessDocument::to_text`] reproduces `text` exactly when nothing
+    /// has been edited.
+    pub fn parse(text: &str) -> Self {
+        let line_ending = if text.contains("\r\n") { "\r\n" } else { "\n" };
+        let trailing_newline = text.ends_with('\n');
+        let lines = text.lines().map(str::to_string).collect();
+        LosslessDocument { lines, line_ending, trailing_newline }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn line(&self, index: usize) -> &str {
+        &self.lines[index]
+    }
+
+    pub fn set_line(&mut self, index: usize, text: String) {
+        self.lines[index] = text;
+    }
+
+    pub fn insert_line(&mut self, index: usize, text: String) {
+        self.lines.insert(index, text);
+    }
+
+    /// Removes `range` and returns the removed lines, for an editor that
+    /// relocates a whole block (e.g. moving a record to a different section)
+    /// rather than just rewriting lines in place.
+    pub fn remove_lines(&mut self, range: std::ops::Range<usize>) -> Vec<String> {
+        self.lines.drain(range).collect()
+    }
+
+    /// Reassembles the document's lines. Byte-identical to the text
+    /// [`LosslessDocument::parse`] was called with, provided no lines were
+    /// changed since.
+    pub fn to_text(&self) -> String {
+        let mut text = self.lines.join(self.line_ending);
+        if self.trailing_newline {
+            text.push_str(self.line_ending);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_unedited() {
+        let text = "faction england\nculture northern_european\n; a comment\n\nfaction france\nculture western_european\n";
+        assert_eq!(LosslessDocument::parse(text).to_text(), text);
+    }
+
+    #[test]
+    fn round_trips_without_trailing_newline() {
+        let text = "faction england\nculture northern_european";
+        assert_eq!(LosslessDocument::parse(text).to_text(), text);
+    }
+
+    #[test]
+    fn round_trips_crlf() {
+        let text = "faction england\r\nculture northern_european\r\n";
+        assert_eq!(LosslessDocument::parse(text).to_text(), text);
+    }
+
+    #[test]
+    fn set_line_only_changes_that_line() {
+        let text = "faction england\nculture northern_european\nreligion catholic\n";
+        let mut doc = LosslessDocument::parse(text);
+        doc.set_line(2, "religion orthodox".to_string());
+        assert_eq!(doc.to_text(), "faction england\nculture northern_european\nreligion orthodox\n");
+    }
+
+    #[test]
+    fn insert_line_shifts_later_lines() {
+        let text = "faction england\nculture northern_european\n";
+        let mut doc = LosslessDocument::parse(text);
+        doc.insert_line(1, "religion catholic".to_string());
+        assert_eq!(doc.to_text(), "faction england\nreligion catholic\nculture northern_european\n");
+    }
+
+    #[test]
+    fn remove_lines_returns_the_removed_range_and_shifts_the_rest_up() {
+        let text = "faction england\nculture northern_european\nreligion catholic\n";
+        let mut doc = LosslessDocument::parse(text);
+        let removed = doc.remove_lines(1..2);
+        assert_eq!(removed, vec!["culture northern_european".to_string()]);
+        assert_eq!(doc.to_text(), "faction england\nreligion catholic\n");
+    }
+}
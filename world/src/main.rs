@@ -0,0 +1,609 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use world::commands;
+use world::commands::diff::{DiffArgs, DiffFormat};
+use world::commands::dump::{DumpArgs, DumpFormat};
+use world::commands::edb::RequireArgs;
+use world::commands::edu::{EditArgs, SetExpr, UnitFilter};
+use world::commands::events::{EventsArgs, EventsFormat};
+use world::commands::graph::{GraphBuildingsArgs, GraphRegionsArgs};
+use world::commands::map::{CheckRegionsArgs, CheckRegionsFormat};
+use world::commands::query::{QueryArgs, QueryFormat, QueryTarget};
+use world::commands::refactor::RenameFactionArgs;
+use world::commands::religions::{ReligionsArgs, ReligionsFormat};
+use world::commands::report::{EconomyArgs, EconomyFormat, SortKey};
+use world::commands::resources::{ResourcesArgs, ResourcesFormat};
+use world::commands::scaffold::ScaffoldFactionArgs;
+use world::commands::stats::{StatsArgs, StatsFormat};
+use world::commands::strat::MoveSettlementArgs;
+use world::commands::validate::{ValidateArgs, ValidateFormat};
+use world::config::Config;
+
+#[derive(Parser)]
+#[command(name = "world", about = "Inspect M2TW game data text files")]
+struct Cli {
+    /// Root data directory containing the game's descr_*.txt files.
+    /// Mutually exclusive with --game-dir.
+    data_dir: Option<PathBuf>,
+    /// Root game installation directory. Combined with --mod, resolves to
+    /// `<game_dir>/data` with `<game_dir>/mods/<name>/data` checked first
+    /// as the mod overlay. Mutually exclusive with the data_dir argument
+    /// and --mod-dir.
+    #[arg(long)]
+    game_dir: Option<PathBuf>,
+    /// Mod name under `<game_dir>/mods`, only valid alongside --game-dir
+    #[arg(long = "mod")]
+    mod_name: Option<String>,
+    /// Overlay directory for mod data, checked before `data_dir` for every
+    /// file a loader resolves
+    #[arg(long)]
+    mod_dir: Option<PathBuf>,
+    /// Overlay directory to load text/*.txt localization files from instead
+    /// of the data directory, for pointing at a translated text folder
+    #[arg(long)]
+    locale_dir: Option<PathBuf>,
+    /// Directory of .pack files to read descr_*.txt-style data from,
+    /// checked after --mod-dir and before the data directory
+    #[arg(long)]
+    packs: Option<PathBuf>,
+    /// Cache the parsed data next to the data directory and reuse it on the
+    /// next run if nothing relevant has changed, instead of reparsing every
+    /// time
+    #[arg(long)]
+    cache: bool,
+    /// Print each loading step's wall time to stderr
+    #[arg(long, short)]
+    verbose: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Debug-print every loaded culture, religion, and faction
+    Dump {
+        #[arg(long, default_value = "text")]
+        format: String,
+        /// Write json/yaml/toml output to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Cross-check references between loaded data
+    Validate {
+        #[arg(long, default_value = "text")]
+        format: String,
+        /// Skip the ancillary image existence check
+        #[arg(long)]
+        no_asset_checks: bool,
+        /// Check id to drop from the report (see the `[check-id]` text
+        /// output, or the JSON `check` field); repeatable
+        #[arg(long)]
+        allow: Vec<String>,
+        /// Escalate to a failing exit code if only warnings were found
+        #[arg(long, value_parser = ["warnings"])]
+        deny: Option<String>,
+    },
+    /// Look up a specific entity from the loaded data by name
+    Query {
+        #[command(subcommand)]
+        command: QueryCommand,
+    },
+    /// Print the `descr_events.txt` timeline, sorted by earliest turn
+    Events {
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Print a one-screen summary of everything loaded
+    Stats {
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Print total trade resource value per region
+    Resources {
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Print average starting religion adherence across the map
+    Religions {
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Compute derived reports over campaign data
+    Report {
+        #[command(subcommand)]
+        command: ReportCommand,
+    },
+    /// Cross-check map image files against the game data that references them
+    Map {
+        #[command(subcommand)]
+        command: MapCommand,
+    },
+    /// Export Graphviz DOT diagrams of loaded data
+    Graph {
+        #[command(subcommand)]
+        command: GraphCommand,
+    },
+    /// Compare the parsed data between two data directories (or
+    /// game-dir+mod combinations)
+    Diff {
+        /// First data directory. Mutually exclusive with --game-dir-a.
+        dir_a: Option<PathBuf>,
+        #[arg(long)]
+        game_dir_a: Option<PathBuf>,
+        /// Mod name under `<game_dir_a>/mods`, only valid alongside --game-dir-a
+        #[arg(long = "mod-a")]
+        mod_a: Option<String>,
+        /// Second data directory. Mutually exclusive with --game-dir-b.
+        dir_b: Option<PathBuf>,
+        #[arg(long)]
+        game_dir_b: Option<PathBuf>,
+        /// Mod name under `<game_dir_b>/mods`, only valid alongside --game-dir-b
+        #[arg(long = "mod-b")]
+        mod_b: Option<String>,
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Manage the on-disk parsed-data cache
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Edit export_descr_unit.txt in place, preserving unrelated lines
+    Edu {
+        #[command(subcommand)]
+        command: EduCommand,
+    },
+    /// Rewrite an identifier across every data file that references it
+    Refactor {
+        #[command(subcommand)]
+        command: RefactorCommand,
+    },
+    /// Generate boilerplate for new game entities
+    Scaffold {
+        #[command(subcommand)]
+        command: ScaffoldCommand,
+    },
+    /// Edit descr_strat.txt in place, preserving unrelated lines
+    Strat {
+        #[command(subcommand)]
+        command: StratCommand,
+    },
+    /// Edit export_descr_buildings.txt in place, preserving unrelated lines
+    Edb {
+        #[command(subcommand)]
+        command: EdbCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum EduCommand {
+    /// Apply one or more --set stat edits to matching units
+    Edit {
+        /// Only edit units matching `key value` (e.g. `class spearmen`),
+        /// checked against the unit's raw field, not just the typed ones
+        #[arg(long)]
+        filter: Option<String>,
+        /// A stat edit, e.g. `morale+=2` or `cost*=1.1`; repeatable
+        #[arg(long = "set")]
+        set: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum RefactorCommand {
+    /// Rename a faction identifier everywhere it's referenced, rewriting
+    /// each file in place
+    RenameFaction {
+        old: String,
+        new: String,
+        /// List the planned edits without writing any files
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScaffoldCommand {
+    /// Add a new faction cloned from an existing one, with empty name
+    /// pools, a nonplayable list entry, starter unit ownership, and a
+    /// localization stub
+    Faction {
+        name: String,
+        #[arg(long)]
+        culture: String,
+        #[arg(long)]
+        religion: String,
+        /// Existing faction to clone descr_sm_factions fields from
+        #[arg(long)]
+        copy_from: String,
+        /// Starter unit to add the new faction's ownership to; repeatable
+        #[arg(long = "unit")]
+        units: Vec<String>,
+        /// List the planned edits without writing any files
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum StratCommand {
+    /// Move a starting settlement's whole block to a different faction's
+    /// section
+    MoveSettlement {
+        region: String,
+        #[arg(long = "to-faction")]
+        to_faction: String,
+        /// List the planned edit without writing any files
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum EdbCommand {
+    /// Edit a building level's `requires` expression
+    Require {
+        /// Level name, e.g. `tavern` or `barracks_2`
+        #[arg(long)]
+        level: String,
+        /// A `requires` clause to add, e.g. `hidden_resource gunpowder`;
+        /// repeatable
+        #[arg(long)]
+        add: Vec<String>,
+        /// Replace the level's `factions { ... }` clause with exactly these
+        /// factions; repeatable
+        #[arg(long = "to-faction")]
+        to_factions: Vec<String>,
+        /// List the planned edit without writing any files
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Delete the cached parsed data for this data directory
+    Clear,
+}
+
+#[derive(Subcommand)]
+enum ReportCommand {
+    /// Per-region starting economics: trade value, farm level, population,
+    /// settlement level, and a combined income score, optionally rolled up
+    /// to each region's starting owner
+    Economy {
+        #[arg(long, default_value = "text")]
+        format: String,
+        /// Column to sort by
+        #[arg(long, default_value = "income", value_parser = ["region", "trade-value", "farm-level", "population", "resources", "income"])]
+        sort: String,
+        /// Roll regions up to their starting owning faction
+        #[arg(long)]
+        by_faction: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum MapCommand {
+    /// Cross-check descr_regions.txt colours against map_regions.tga
+    CheckRegions {
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum GraphCommand {
+    /// Region adjacency graph derived from map_regions.tga, coloured by
+    /// starting owner
+    Regions {
+        /// Write the DOT source here instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// A building's level chain and the recruitment it unlocks
+    Buildings {
+        name: String,
+        /// Write the DOT source here instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum QueryCommand {
+    /// Look up a faction by name
+    Faction {
+        name: String,
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Look up a region by name
+    Region {
+        name: String,
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Look up a unit by name
+    Unit {
+        name: String,
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// List a faction's starting diplomatic stance toward every other faction
+    Diplomacy {
+        name: String,
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Show a culture's portrait mapping and which source satisfies each
+    /// young/old/dead portrait category
+    Culture {
+        name: String,
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+}
+
+/// Resolves one side of a `world diff` invocation to a data directory,
+/// mirroring the top-level `--game-dir`/`--mod` combination but scoped to
+/// `side` (`'a'` or `'b'`) so each side can point at a different
+/// installation/mod independently of the other.
+fn resolve_diff_dir(side: char, dir: Option<PathBuf>, game_dir: Option<PathBuf>, mod_name: Option<String>) -> PathBuf {
+    match (dir, game_dir) {
+        (Some(_), Some(_)) => {
+            eprintln!("error: --game-dir-{side} cannot be combined with a data directory argument");
+            std::process::exit(2);
+        }
+        (Some(dir), None) => {
+            if mod_name.is_some() {
+                eprintln!("error: --mod-{side} requires --game-dir-{side}");
+                std::process::exit(2);
+            }
+            dir
+        }
+        (None, Some(game_dir)) => match mod_name {
+            Some(name) => game_dir.join("mods").join(name).join("data"),
+            None => game_dir.join("data"),
+        },
+        (None, None) => {
+            eprintln!("error: side {side} needs a data directory or --game-dir-{side}");
+            std::process::exit(2);
+        }
+    }
+}
+
+fn main() {
+    let Cli { data_dir, game_dir, mod_name, mod_dir, locale_dir, packs, cache, verbose, command } = Cli::parse();
+
+    if let Command::Diff { dir_a, game_dir_a, mod_a, dir_b, game_dir_b, mod_b, format } = command {
+        let data_dir_a = resolve_diff_dir('a', dir_a, game_dir_a, mod_a);
+        let data_dir_b = resolve_diff_dir('b', dir_b, game_dir_b, mod_b);
+        let format = match format.as_str() {
+            "text" => DiffFormat::Text,
+            "json" => DiffFormat::Json,
+            other => {
+                eprintln!("error: unsupported diff format {other:?} (expected \"text\" or \"json\")");
+                std::process::exit(2);
+            }
+        };
+        let result = Config::from_args(data_dir_a, None, None, None, cache, verbose)
+            .and_then(|config_a| Config::from_args(data_dir_b, None, None, None, cache, verbose).and_then(|config_b| commands::diff::run(&config_a, &config_b, &DiffArgs { format })));
+        if let Err(e) = result {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let (data_dir, mod_dir) = match (data_dir, game_dir) {
+        (Some(_), Some(_)) => {
+            eprintln!("error: --game-dir cannot be combined with a data directory argument");
+            std::process::exit(2);
+        }
+        (Some(data_dir), None) => {
+            if mod_name.is_some() {
+                eprintln!("error: --mod requires --game-dir");
+                std::process::exit(2);
+            }
+            (data_dir, mod_dir)
+        }
+        (None, Some(game_dir)) => {
+            if mod_dir.is_some() {
+                eprintln!("error: --mod-dir cannot be combined with --game-dir");
+                std::process::exit(2);
+            }
+            let mod_dir = mod_name.map(|name| game_dir.join("mods").join(name).join("data"));
+            (game_dir.join("data"), mod_dir)
+        }
+        (None, None) => {
+            eprintln!("error: a data directory or --game-dir is required");
+            std::process::exit(2);
+        }
+    };
+
+    let result = Config::from_args(data_dir, mod_dir, locale_dir, packs, cache, verbose).and_then(|config| match command {
+        Command::Dump { format, output } => {
+            let format = match format.as_str() {
+                "text" => DumpFormat::Text,
+                "json" => DumpFormat::Json,
+                "yaml" => DumpFormat::Yaml,
+                "toml" => DumpFormat::Toml,
+                other => {
+                    eprintln!("error: unsupported dump format {other:?} (expected \"text\", \"json\", \"yaml\", or \"toml\")");
+                    std::process::exit(2);
+                }
+            };
+            commands::dump::run(&config, &DumpArgs { format, output })
+        }
+        Command::Validate { format, no_asset_checks, allow, deny } => {
+            let format = match format.as_str() {
+                "text" => ValidateFormat::Text,
+                "json" => ValidateFormat::Json,
+                "github" => ValidateFormat::Github,
+                other => {
+                    eprintln!("error: unsupported validate format {other:?} (expected \"text\", \"json\", or \"github\")");
+                    std::process::exit(2);
+                }
+            };
+            let deny_warnings = deny.as_deref() == Some("warnings");
+            commands::validate::run(&config, &ValidateArgs { format, no_asset_checks, allow, deny_warnings })
+        }
+        Command::Query { command } => {
+            let (target, format) = match command {
+                QueryCommand::Faction { name, format } => (QueryTarget::Faction(name), format),
+                QueryCommand::Region { name, format } => (QueryTarget::Region(name), format),
+                QueryCommand::Unit { name, format } => (QueryTarget::Unit(name), format),
+                QueryCommand::Diplomacy { name, format } => (QueryTarget::Diplomacy(name), format),
+                QueryCommand::Culture { name, format } => (QueryTarget::Culture(name), format),
+            };
+            let format = match format.as_str() {
+                "text" => QueryFormat::Text,
+                "json" => QueryFormat::Json,
+                other => {
+                    eprintln!("error: unsupported query format {other:?} (expected \"text\" or \"json\")");
+                    std::process::exit(2);
+                }
+            };
+            commands::query::run(&config, &QueryArgs { target, format })
+        }
+        Command::Events { format } => {
+            let format = match format.as_str() {
+                "text" => EventsFormat::Text,
+                "json" => EventsFormat::Json,
+                other => {
+                    eprintln!("error: unsupported events format {other:?} (expected \"text\" or \"json\")");
+                    std::process::exit(2);
+                }
+            };
+            commands::events::run(&config, &EventsArgs { format })
+        }
+        Command::Stats { format } => {
+            let format = match format.as_str() {
+                "text" => StatsFormat::Text,
+                "json" => StatsFormat::Json,
+                other => {
+                    eprintln!("error: unsupported stats format {other:?} (expected \"text\" or \"json\")");
+                    std::process::exit(2);
+                }
+            };
+            commands::stats::run(&config, &StatsArgs { format })
+        }
+        Command::Resources { format } => {
+            let format = match format.as_str() {
+                "text" => ResourcesFormat::Text,
+                "json" => ResourcesFormat::Json,
+                other => {
+                    eprintln!("error: unsupported resources format {other:?} (expected \"text\" or \"json\")");
+                    std::process::exit(2);
+                }
+            };
+            commands::resources::run(&config, &ResourcesArgs { format })
+        }
+        Command::Religions { format } => {
+            let format = match format.as_str() {
+                "text" => ReligionsFormat::Text,
+                "json" => ReligionsFormat::Json,
+                other => {
+                    eprintln!("error: unsupported religions format {other:?} (expected \"text\" or \"json\")");
+                    std::process::exit(2);
+                }
+            };
+            commands::religions::run(&config, &ReligionsArgs { format })
+        }
+        Command::Report { command } => match command {
+            ReportCommand::Economy { format, sort, by_faction } => {
+                let format = match format.as_str() {
+                    "text" => EconomyFormat::Text,
+                    "json" => EconomyFormat::Json,
+                    "csv" => EconomyFormat::Csv,
+                    other => {
+                        eprintln!("error: unsupported economy format {other:?} (expected \"text\", \"json\", or \"csv\")");
+                        std::process::exit(2);
+                    }
+                };
+                let sort = match sort.as_str() {
+                    "region" => SortKey::Region,
+                    "trade-value" => SortKey::TradeValue,
+                    "farm-level" => SortKey::FarmLevel,
+                    "population" => SortKey::Population,
+                    "resources" => SortKey::Resources,
+                    "income" => SortKey::Income,
+                    other => {
+                        eprintln!("error: unsupported sort column {other:?}");
+                        std::process::exit(2);
+                    }
+                };
+                commands::report::run(&config, &EconomyArgs { format, sort, by_faction })
+            }
+        },
+        Command::Map { command } => match command {
+            MapCommand::CheckRegions { format } => {
+                let format = match format.as_str() {
+                    "text" => CheckRegionsFormat::Text,
+                    "json" => CheckRegionsFormat::Json,
+                    other => {
+                        eprintln!("error: unsupported check-regions format {other:?} (expected \"text\" or \"json\")");
+                        std::process::exit(2);
+                    }
+                };
+                commands::map::run(&config, &CheckRegionsArgs { format })
+            }
+        },
+        Command::Graph { command } => match command {
+            GraphCommand::Regions { output } => commands::graph::run_regions(&config, &GraphRegionsArgs { output }),
+            GraphCommand::Buildings { name, output } => commands::graph::run_buildings(&config, &GraphBuildingsArgs { name, output }),
+        },
+        Command::Cache { command } => match command {
+            CacheCommand::Clear => world::cache::clear(&config),
+        },
+        Command::Edu { command } => match command {
+            EduCommand::Edit { filter, set } => {
+                let filter = filter.map(|text| {
+                    UnitFilter::parse(&text).unwrap_or_else(|e| {
+                        eprintln!("error: {e}");
+                        std::process::exit(2);
+                    })
+                });
+                let sets = set
+                    .iter()
+                    .map(|text| {
+                        SetExpr::parse(text).unwrap_or_else(|e| {
+                            eprintln!("error: {e}");
+                            std::process::exit(2);
+                        })
+                    })
+                    .collect();
+                world::commands::edu::run_edit(&config, &EditArgs { filter, sets })
+            }
+        },
+        Command::Refactor { command } => match command {
+            RefactorCommand::RenameFaction { old, new, dry_run } => {
+                world::commands::refactor::run_rename_faction(&config, &RenameFactionArgs { old, new, dry_run })
+            }
+        },
+        Command::Scaffold { command } => match command {
+            ScaffoldCommand::Faction { name, culture, religion, copy_from, units, dry_run } => {
+                world::commands::scaffold::run_scaffold_faction(&config, &ScaffoldFactionArgs { name, culture, religion, copy_from, units, dry_run })
+            }
+        },
+        Command::Strat { command } => match command {
+            StratCommand::MoveSettlement { region, to_faction, dry_run } => {
+                world::commands::strat::run_move_settlement(&config, &MoveSettlementArgs { region, to_faction, dry_run })
+            }
+        },
+        Command::Edb { command } => match command {
+            EdbCommand::Require { level, add, to_factions, dry_run } => {
+                world::commands::edb::run_require(&config, &RequireArgs { level, add, to_factions, dry_run })
+            }
+        },
+        Command::Diff { .. } => unreachable!("handled above before Config::from_args"),
+    });
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
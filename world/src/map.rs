@@ -0,0 +1,156 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::faction::Rgb;
+use crate::region::Region;
+use crate::tga::TgaImage;
+
+const BLACK: Rgb = Rgb { r: 0, g: 0, b: 0 };
+
+/// Two regions whose `map_regions.tga` colours share a border (at least one
+/// pair of 4-connected pixels, one of each colour). `a` and `b` are ordered
+/// by name so a given border is only ever reported one way round.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct RegionBorder {
+    pub a: String,
+    pub b: String,
+}
+
+/// Scans `image` for region borders: pixels of two different known region
+/// colours that are 4-connected neighbours. Used to build the region
+/// adjacency graph `world graph regions` renders, but kept independent of
+/// that command since pathfinding-style analyses (shortest land route
+/// between two regions, say) want the same graph without going through DOT.
+///
+/// Colours in `image` that don't belong to any region in `regions` (sea,
+/// unrecognized borders -- see [`check_regions`]) are ignored rather than
+/// treated as a "region", the same as everywhere else in this module.
+pub fn region_adjacency(regions: &[Region], image: &TgaImage) -> Vec<RegionBorder> {
+    let colour_to_region: HashMap<Rgb, &str> = regions.iter().map(|r| (r.colour, r.name.as_str())).collect();
+
+    let mut borders = BTreeSet::new();
+    for y in 0..image.height() {
+        for x in 0..image.width() {
+            let Some(colour) = image.pixel(x, y) else { continue };
+            let Some(&region) = colour_to_region.get(&colour) else { continue };
+
+            // Only checking the right and bottom neighbours (rather than
+            // all four) still covers every adjacent pair exactly once per
+            // scan, since the pixel on the other side of the border gets
+            // its own turn as `(x, y)` from the opposite direction.
+            for (nx, ny) in [(x.checked_add(1), Some(y)), (Some(x), y.checked_add(1))] {
+                let (Some(nx), Some(ny)) = (nx, ny) else { continue };
+                let Some(neighbour_colour) = image.pixel(nx, ny) else { continue };
+                if neighbour_colour == colour {
+                    continue;
+                }
+                let Some(&neighbour_region) = colour_to_region.get(&neighbour_colour) else { continue };
+                if neighbour_region == region {
+                    continue;
+                }
+                let (a, b) = if region < neighbour_region { (region, neighbour_region) } else { (neighbour_region, region) };
+                borders.insert(RegionBorder { a: a.to_string(), b: b.to_string() });
+            }
+        }
+    }
+    borders.into_iter().collect()
+}
+
+/// A region's colour and how many pixels of `map_regions.tga` carry it, so
+/// modders can see region sizes at a glance.
+#[derive(Debug, Clone, Serialize)]
+pub struct RegionPixelCount {
+    pub region: String,
+    pub colour: Rgb,
+    pub pixel_count: usize,
+}
+
+/// An image colour with no matching `descr_regions.txt` entry, other than
+/// plain black (sea/border, which is expected and not reported here).
+#[derive(Debug, Clone, Serialize)]
+pub struct UnmatchedColour {
+    pub colour: Rgb,
+    pub pixel_count: usize,
+}
+
+/// The result of cross-checking `descr_regions.txt` against
+/// `map_regions.tga`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckRegionsReport {
+    pub region_pixel_counts: Vec<RegionPixelCount>,
+    /// Regions whose colour doesn't appear anywhere in the image: the
+    /// unspecified-error-on-load case this command exists to catch.
+    pub missing_regions: Vec<String>,
+    pub unmatched_colours: Vec<UnmatchedColour>,
+    /// Black pixels with a non-black, non-region neighbour: usually a
+    /// mis-drawn border rather than intentional sea/edge.
+    pub suspicious_black_pixels: usize,
+}
+
+/// Cross-checks `regions` (as loaded from `descr_regions.txt`) against
+/// `image` (as loaded from `map_regions.tga`).
+pub fn check_regions(regions: &[Region], image: &TgaImage) -> CheckRegionsReport {
+    let mut pixel_counts: HashMap<Rgb, usize> = HashMap::new();
+    for (_, _, colour) in image.pixels() {
+        *pixel_counts.entry(colour).or_insert(0) += 1;
+    }
+
+    let mut known_colours = HashSet::new();
+    let mut region_pixel_counts = Vec::new();
+    let mut missing_regions = Vec::new();
+    for region in regions {
+        known_colours.insert(region.colour);
+        let pixel_count = pixel_counts.get(&region.colour).copied().unwrap_or(0);
+        if pixel_count == 0 {
+            missing_regions.push(region.name.clone());
+        }
+        region_pixel_counts.push(RegionPixelCount { region: region.name.clone(), colour: region.colour, pixel_count });
+    }
+
+    let mut unmatched_colours = pixel_counts
+        .into_iter()
+        .filter(|(colour, _)| *colour != BLACK && !known_colours.contains(colour))
+        .map(|(colour, pixel_count)| UnmatchedColour { colour, pixel_count })
+        .collect::<Vec<_>>();
+    unmatched_colours.sort_by(|a, b| b.pixel_count.cmp(&a.pixel_count).then_with(|| format!("{:?}", a.colour).cmp(&format!("{:?}", b.colour))));
+
+    CheckRegionsReport {
+        region_pixel_counts,
+        missing_regions,
+        unmatched_colours,
+        suspicious_black_pixels: count_suspicious_black_pixels(image, &known_colours),
+    }
+}
+
+/// A black pixel is expected sea/border unless one of its four neighbours
+/// is neither black nor a known region colour, which usually means a
+/// border was drawn with a stray or misspelled colour rather than left as
+/// open sea.
+fn count_suspicious_black_pixels(image: &TgaImage, known_colours: &HashSet<Rgb>) -> usize {
+    let mut count = 0;
+    for y in 0..image.height() {
+        for x in 0..image.width() {
+            if image.pixel(x, y) != Some(BLACK) {
+                continue;
+            }
+            let neighbours = [
+                (x.checked_sub(1), Some(y)),
+                (x.checked_add(1), Some(y)),
+                (Some(x), y.checked_sub(1)),
+                (Some(x), y.checked_add(1)),
+            ];
+            let suspicious = neighbours.into_iter().any(|(nx, ny)| {
+                let (Some(nx), Some(ny)) = (nx, ny) else { return false };
+                match image.pixel(nx, ny) {
+                    Some(colour) => colour != BLACK && !known_colours.contains(&colour),
+                    None => false,
+                }
+            });
+            if suspicious {
+                count += 1;
+            }
+        }
+    }
+    count
+}
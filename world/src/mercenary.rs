@@ -0,0 +1,292 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A mercenary pool from `descr_mercenaries.txt`: the regions it can be
+/// recruited in, and the units available from it.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct MercPool {
+    pub name: String,
+    /// 1-based line the `pool NAME` header started on.
+    pub line_number: usize,
+    /// 1-based line the `regions` line started on, for pointing tooling
+    /// back at the source file when a listed region doesn't exist.
+    pub regions_line: usize,
+    pub regions: Vec<String>,
+    pub units: Vec<MercUnit>,
+}
+
+/// One `unit` line inside a `MercPool`.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct MercUnit {
+    /// 1-based line this unit was declared on.
+    pub line_number: usize,
+    pub name: String,
+    pub exp: u32,
+    pub cost: u32,
+    pub replenish_min: f64,
+    pub replenish_max: f64,
+    pub max: u32,
+    pub initial: u32,
+    pub religion: Option<String>,
+    pub event: Option<String>,
+}
+
+impl MercPool {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<MercPool>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(path, &lines)
+    }
+}
+
+fn parse(path: &Path, lines: &[&DescrLine]) -> Result<Vec<MercPool>> {
+    let mut pools = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some(rest) = lines[i].text.strip_prefix("pool ") {
+            let name = rest.split('{').next().unwrap_or(rest).trim().to_string();
+            let line_number = lines[i].line_number;
+            let (open, close) = find_block(path, lines, i)?;
+            pools.push(parse_pool(path, name, line_number, &lines[open + 1..close])?);
+            i = close + 1;
+        } else {
+            i += 1;
+        }
+    }
+    Ok(pools)
+}
+
+fn parse_pool(path: &Path, name: String, line_number: usize, body: &[&DescrLine]) -> Result<MercPool> {
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut regions_line = None;
+    let mut regions = Vec::new();
+    let mut units = Vec::new();
+
+    for line in body {
+        if let Some(rest) = line.text.strip_prefix("regions ") {
+            regions_line = Some(line.line_number);
+            regions = rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect();
+        } else if let Some(rest) = line.text.strip_prefix("unit ") {
+            units.push(parse_unit(path, line, rest)?);
+        }
+    }
+
+    let regions_line = regions_line.ok_or_else(|| err(&DescrLine { line_number, text: format!("pool {name}") }, format!("pool {name:?} has no `regions` line")))?;
+
+    Ok(MercPool { name, line_number, regions_line, regions, units })
+}
+
+fn parse_unit(path: &Path, line: &DescrLine, rest: &str) -> Result<MercUnit> {
+    let err = |message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut quotes = rest.match_indices('"');
+    let (Some((start, _)), Some((end, _))) = (quotes.next(), quotes.next()) else {
+        return Err(err("unit line is missing a quoted unit name".to_string()));
+    };
+    let name = rest[start + 1..end].to_string();
+    let tokens: Vec<&str> = rest[end + 1..].split_whitespace().collect();
+
+    let mut exp = None;
+    let mut cost = None;
+    let mut replenish = None;
+    let mut max = None;
+    let mut initial = None;
+    let mut religion = None;
+    let mut event = None;
+
+    let number = |token: &str, what: &str| -> Result<f64> { token.parse().map_err(|_| err(format!("{token:?} is not a valid {what}"))) };
+
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i] {
+            "exp" => {
+                let value = *tokens.get(i + 1).ok_or_else(|| err("exp is missing its value".to_string()))?;
+                exp = Some(number(value, "exp")? as u32);
+                i += 2;
+            }
+            "cost" => {
+                let value = *tokens.get(i + 1).ok_or_else(|| err("cost is missing its value".to_string()))?;
+                cost = Some(number(value, "cost")? as u32);
+                i += 2;
+            }
+            "replenish" => {
+                let min = *tokens.get(i + 1).ok_or_else(|| err("replenish is missing its rates".to_string()))?;
+                let max = *tokens.get(i + 2).ok_or_else(|| err("replenish is missing its rates".to_string()))?;
+                replenish = Some((number(min, "replenish rate")?, number(max, "replenish rate")?));
+                i += 3;
+            }
+            "max" => {
+                let value = *tokens.get(i + 1).ok_or_else(|| err("max is missing its value".to_string()))?;
+                max = Some(number(value, "max")? as u32);
+                i += 2;
+            }
+            "initial" => {
+                let value = *tokens.get(i + 1).ok_or_else(|| err("initial is missing its value".to_string()))?;
+                initial = Some(number(value, "initial")? as u32);
+                i += 2;
+            }
+            "religion" => {
+                religion = Some((*tokens.get(i + 1).ok_or_else(|| err("religion is missing its value".to_string()))?).to_string());
+                i += 2;
+            }
+            "event" => {
+                event = Some((*tokens.get(i + 1).ok_or_else(|| err("event is missing its value".to_string()))?).to_string());
+                i += 2;
+            }
+            other => return Err(err(format!("unexpected unit parameter {other:?}"))),
+        }
+    }
+
+    let (replenish_min, replenish_max) = replenish.ok_or_else(|| err(format!("unit {name:?} is missing a `replenish` entry")))?;
+    let exp = exp.ok_or_else(|| err(format!("unit {name:?} is missing an `exp` entry")))?;
+    let cost = cost.ok_or_else(|| err(format!("unit {name:?} is missing a `cost` entry")))?;
+    let max = max.ok_or_else(|| err(format!("unit {name:?} is missing a `max` entry")))?;
+    let initial = initial.ok_or_else(|| err(format!("unit {name:?} is missing an `initial` entry")))?;
+
+    Ok(MercUnit { line_number: line.line_number, name, exp, cost, replenish_min, replenish_max, max, initial, religion, event })
+}
+
+/// Finds the `{ ... }` block starting at or after `lines[start]`, returning
+/// the index of the line holding the opening brace and the index of the
+/// line holding its matching closing brace.
+fn find_block(path: &Path, lines: &[&DescrLine], start: usize) -> Result<(usize, usize)> {
+    let mut open_at = start;
+    while !lines[open_at].text.contains('{') {
+        open_at += 1;
+        if open_at >= lines.len() {
+            return Err(ParseError {
+                file: path.to_path_buf(),
+                line_number: lines[start].line_number,
+                line_text: lines[start].text.clone(),
+                message: "block is missing its opening `{`".to_string(),
+            }
+            .into());
+        }
+    }
+
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(open_at) {
+        let opens = line.text.matches('{').count() as i32;
+        let closes = line.text.matches('}').count() as i32;
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((open_at, idx));
+        }
+    }
+
+    Err(ParseError {
+        file: path.to_path_buf(),
+        line_number: lines[open_at].line_number,
+        line_text: lines[open_at].text.clone(),
+        message: "block is missing its closing `}`".to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn pools_from(text: &str) -> Vec<MercPool> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_mercenaries_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(Path::new("descr_mercenaries.txt"), &lines).unwrap()
+    }
+
+    #[test]
+    fn parses_regions_and_units() {
+        let pools = pools_from(
+            r#"
+pool Poland Mercenaries
+{
+    regions Pomerania, Silesia
+    unit "Steppe Cavalry" exp 0 cost 300 replenish 0.1 0.2 max 2 initial 1
+    unit "Mercenary Crossbowmen" exp 1 cost 450 replenish 0.15 0.25 max 3 initial 2 religion catholic
+    unit "Desert Raiders" exp 0 cost 150 replenish 0.2 0.3 max 2 initial 1 event pool_unlocked
+}
+"#,
+        );
+        assert_eq!(pools.len(), 1);
+        let pool = &pools[0];
+        assert_eq!(pool.name, "Poland Mercenaries");
+        assert_eq!(pool.regions, vec!["Pomerania", "Silesia"]);
+        assert_eq!(pool.units.len(), 3);
+
+        let cavalry = &pool.units[0];
+        assert_eq!(cavalry.name, "Steppe Cavalry");
+        assert_eq!(cavalry.exp, 0);
+        assert_eq!(cavalry.cost, 300);
+        assert_eq!(cavalry.replenish_min, 0.1);
+        assert_eq!(cavalry.replenish_max, 0.2);
+        assert_eq!(cavalry.max, 2);
+        assert_eq!(cavalry.initial, 1);
+        assert_eq!(cavalry.religion, None);
+        assert_eq!(cavalry.event, None);
+
+        assert_eq!(pool.units[1].religion.as_deref(), Some("catholic"));
+        assert_eq!(pool.units[2].event.as_deref(), Some("pool_unlocked"));
+    }
+
+    #[test]
+    fn multiple_pools_are_all_parsed() {
+        let pools = pools_from(
+            r#"
+pool A
+{
+    regions Pomerania
+    unit "Steppe Cavalry" exp 0 cost 300 replenish 0.1 0.2 max 2 initial 1
+}
+
+pool B
+{
+    regions Silesia, Volhynia
+    unit "Desert Raiders" exp 0 cost 150 replenish 0.2 0.3 max 2 initial 1
+}
+"#,
+        );
+        assert_eq!(pools.len(), 2);
+        assert_eq!(pools[0].name, "A");
+        assert_eq!(pools[1].name, "B");
+        assert_eq!(pools[1].regions, vec!["Silesia", "Volhynia"]);
+    }
+
+    #[test]
+    fn missing_replenish_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_mercenaries_test_{id}.txt"));
+        std::fs::write(
+            &path,
+            r#"
+pool A
+{
+    regions Pomerania
+    unit "Steppe Cavalry" exp 0 cost 300 max 2 initial 1
+}
+"#,
+        )
+        .unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        let err = parse(Path::new("descr_mercenaries.txt"), &lines).unwrap_err();
+        assert!(err.to_string().contains("missing a `replenish` entry"), "{err}");
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A council/guild mission from `descr_missions.txt`: what triggers it
+/// (`conditions`), how long it stays open, and what it pays out
+/// (`paybacks`) once completed.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Mission {
+    /// 0-based position in `descr_missions.txt`, stable for a given file.
+    pub id: usize,
+    /// 1-based line the `mission NAME` header started on.
+    pub line_number: usize,
+    pub name: String,
+    /// The `score` line's value, e.g. `capture_settlement`. Not parsed
+    /// further -- the engine's scoring keywords are out of scope here.
+    pub score: Option<String>,
+    pub duration: Option<u32>,
+    pub conditions: Vec<MissionCondition>,
+    /// Reward entries, whichever syntax supplied them: Kingdoms' nested
+    /// `payback { unit ...; money ...; building ... }` block, or vanilla's
+    /// flat `payback_unit`/`payback_money`/`payback_building` lines. Both
+    /// end up as the same `kind`/`value` pairs so [`crate::validate::validate`]
+    /// doesn't need to care which syntax a given mod uses.
+    pub paybacks: Vec<Payback>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+}
+
+/// A `condition KIND VALUE` line, e.g. `condition faction england` or
+/// `condition region wessex`.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct MissionCondition {
+    pub line_number: usize,
+    pub kind: String,
+    pub value: String,
+}
+
+/// One reward: `kind` is `unit`, `money`, or `building`; `value` is the raw
+/// text after it (e.g. `"peasants, 1"` for a unit reward, whose count isn't
+/// needed for the EDU cross-check so it's kept verbatim rather than split
+/// out).
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Payback {
+    pub line_number: usize,
+    pub kind: String,
+    pub value: String,
+}
+
+impl Mission {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Mission>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        let mut missions = parse(path, &lines)?;
+        for (id, mission) in missions.iter_mut().enumerate() {
+            mission.id = id;
+        }
+        Ok(missions)
+    }
+
+    /// The reward unit's bare name, without the trailing `, COUNT`, for a
+    /// `unit` payback. `None` for any other kind.
+    pub fn reward_unit_name(payback: &Payback) -> Option<&str> {
+        if payback.kind != "unit" {
+            return None;
+        }
+        payback.value.split(',').next().map(str::trim)
+    }
+}
+
+fn parse(path: &Path, lines: &[&DescrLine]) -> Result<Vec<Mission>> {
+    let mut missions = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some(rest) = lines[i].text.strip_prefix("mission ") {
+            let header_line = lines[i];
+            let name = rest.trim().to_string();
+            let (open, close) = find_block(path, lines, i)?;
+            missions.push(parse_mission(path, name, header_line.line_number, &lines[open + 1..close])?);
+            i = close + 1;
+        } else {
+            i += 1;
+        }
+    }
+    Ok(missions)
+}
+
+fn parse_mission(path: &Path, name: String, line_number: usize, body: &[&DescrLine]) -> Result<Mission> {
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut score = None;
+    let mut duration = None;
+    let mut conditions = Vec::new();
+    let mut paybacks = Vec::new();
+    let mut extra = BTreeMap::new();
+
+    let mut i = 0;
+    while i < body.len() {
+        let line = body[i];
+        let text = line.text.as_str();
+
+        if text.trim() == "payback" {
+            let (open, close) = find_block(path, body, i)?;
+            for inner in &body[open + 1..close] {
+                let mut parts = inner.text.split_whitespace();
+                let Some(kind) = parts.next() else { continue };
+                let value = parts.collect::<Vec<_>>().join(" ");
+                paybacks.push(Payback { line_number: inner.line_number, kind: kind.to_string(), value });
+            }
+            i = close + 1;
+            continue;
+        }
+
+        let mut parts = text.split_whitespace();
+        let Some(key) = parts.next() else {
+            i += 1;
+            continue;
+        };
+        let value = parts.collect::<Vec<_>>().join(" ");
+
+        match key {
+            "score" => score = Some(value),
+            "duration" => duration = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid duration")))?),
+            "condition" => {
+                let mut condition_parts = value.splitn(2, char::is_whitespace);
+                let kind = condition_parts.next().unwrap_or_default().to_string();
+                let condition_value = condition_parts.next().unwrap_or_default().trim().to_string();
+                if kind.is_empty() || condition_value.is_empty() {
+                    return Err(err(line, format!("expected `condition KIND VALUE`, found {:?}", line.text)));
+                }
+                conditions.push(MissionCondition { line_number: line.line_number, kind, value: condition_value });
+            }
+            "payback_unit" | "payback_money" | "payback_building" => {
+                let kind = key.strip_prefix("payback_").unwrap().to_string();
+                paybacks.push(Payback { line_number: line.line_number, kind, value });
+            }
+            _ => {
+                extra.insert(key.to_string(), value);
+            }
+        }
+        i += 1;
+    }
+
+    Ok(Mission { id: 0, line_number, name, score, duration, conditions, paybacks, extra })
+}
+
+/// Finds the `{ ... }` block starting at or after `lines[start]`, returning
+/// the index of the line holding the (first) unmatched opening brace and the
+/// index of the line holding its matching closing brace. Same approach as
+/// [`crate::building::find_block`], duplicated here because nothing shared
+/// exists yet for this style of brace scan.
+fn find_block(path: &Path, lines: &[&DescrLine], start: usize) -> Result<(usize, usize)> {
+    let mut open_at = start;
+    loop {
+        if open_at >= lines.len() {
+            return Err(ParseError {
+                file: path.to_path_buf(),
+                line_number: lines[start].line_number,
+                line_text: lines[start].text.clone(),
+                message: "block is missing its opening `{`".to_string(),
+            }
+            .into());
+        }
+        let opens = lines[open_at].text.matches('{').count();
+        let closes = lines[open_at].text.matches('}').count();
+        if opens > closes {
+            break;
+        }
+        open_at += 1;
+    }
+
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(open_at) {
+        let opens = line.text.matches('{').count() as i32;
+        let closes = line.text.matches('}').count() as i32;
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((open_at, idx));
+        }
+    }
+
+    Err(ParseError {
+        file: path.to_path_buf(),
+        line_number: lines[open_at].line_number,
+        line_text: lines[open_at].text.clone(),
+        message: "block is missing its closing `}`".to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn missions_from(text: &str) -> Vec<Mission> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_missions_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(Path::new("descr_missions.txt"), &lines).unwrap()
+    }
+
+    const VANILLA_EXCERPT: &str = r#"
+mission council_first_settlement
+{
+    score capture_settlement
+    duration 10
+    condition faction england
+    condition region wessex
+    payback_unit peasants, 1
+    payback_money 500
+    payback_building tavern
+}
+"#;
+
+    #[test]
+    fn vanilla_flat_payback_syntax_is_parsed() {
+        let missions = missions_from(VANILLA_EXCERPT);
+        assert_eq!(missions.len(), 1);
+        let mission = &missions[0];
+        assert_eq!(mission.name, "council_first_settlement");
+        assert_eq!(mission.score.as_deref(), Some("capture_settlement"));
+        assert_eq!(mission.duration, Some(10));
+        assert_eq!(mission.conditions.len(), 2);
+        assert_eq!(mission.conditions[0].kind, "faction");
+        assert_eq!(mission.conditions[0].value, "england");
+        assert_eq!(mission.conditions[1].kind, "region");
+        assert_eq!(mission.conditions[1].value, "wessex");
+        assert_eq!(mission.paybacks.len(), 3);
+        assert_eq!(mission.paybacks[0].kind, "unit");
+        assert_eq!(mission.paybacks[0].value, "peasants, 1");
+        assert_eq!(Mission::reward_unit_name(&mission.paybacks[0]), Some("peasants"));
+        assert_eq!(mission.paybacks[1].kind, "money");
+        assert_eq!(mission.paybacks[1].value, "500");
+        assert_eq!(mission.paybacks[2].kind, "building");
+        assert_eq!(mission.paybacks[2].value, "tavern");
+    }
+
+    const KINGDOMS_EXCERPT: &str = r#"
+mission council_first_settlement
+{
+    score capture_settlement
+    duration 10
+    condition faction england
+    payback
+    {
+        unit peasants, 1
+        money 500
+        building tavern
+    }
+}
+"#;
+
+    #[test]
+    fn kingdoms_nested_payback_syntax_is_parsed() {
+        let missions = missions_from(KINGDOMS_EXCERPT);
+        assert_eq!(missions.len(), 1);
+        let mission = &missions[0];
+        assert_eq!(mission.conditions.len(), 1);
+        assert_eq!(mission.paybacks.len(), 3);
+        assert_eq!(mission.paybacks[0].kind, "unit");
+        assert_eq!(mission.paybacks[0].value, "peasants, 1");
+        assert_eq!(mission.paybacks[1].kind, "money");
+        assert_eq!(mission.paybacks[2].kind, "building");
+        assert_eq!(mission.paybacks[2].value, "tavern");
+    }
+
+    #[test]
+    fn malformed_condition_is_rejected() {
+        let text = r#"
+mission bad
+{
+    condition faction
+}
+"#;
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_missions_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        let message = parse(Path::new("descr_missions.txt"), &lines).unwrap_err().to_string();
+        assert!(message.contains("expected `condition KIND VALUE`"), "{message}");
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A mount record from `descr_mount.txt`, referenced by name from an
+/// `export_descr_unit.txt` unit's `mount` line (see
+/// [`crate::unit::Unit::mount`]). Records are separated by blank lines,
+/// each starting with a `type NAME` line, same layout as
+/// [`crate::projectile::Projectile`]. Horse/camel/elephant-specific fields
+/// (rider offsets, howdah attack/armour) aren't pulled out individually --
+/// they vary by class and this parser only needs enough to cross-check
+/// references -- so they stay in `extra` like everything else this parser
+/// doesn't recognize.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Mount {
+    /// 0-based position in the file, stable for a given file so other
+    /// loaded data (and external tooling) can reference a mount by id
+    /// instead of by name.
+    pub id: usize,
+    /// 1-based line the `type NAME` line started on.
+    pub line_number: usize,
+    pub name: String,
+    /// `horse`, `camel`, `elephant`, ... -- what kind of mount this is,
+    /// which governs which of the class-specific optional fields apply.
+    pub class: Option<String>,
+    pub model: Option<String>,
+    pub radius: Option<f64>,
+    pub mass: Option<f64>,
+    pub height: Option<f64>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+    /// The line each recognized or `extra` key was set from, keyed by field
+    /// name, same convention as [`crate::faction::Faction::field_lines`].
+    pub field_lines: BTreeMap<String, usize>,
+}
+
+impl Mount {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Mount>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut mounts = Vec::new();
+        for block in split_blocks(&lines) {
+            mounts.push(parse_record(path, &block)?);
+        }
+
+        for (id, mount) in mounts.iter_mut().enumerate() {
+            mount.id = id;
+        }
+
+        Ok(mounts)
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank lines, same
+/// as [`crate::unit::split_blocks`].
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<Mount> {
+    let name_line = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let name = name_line
+        .text
+        .strip_prefix("type ")
+        .map(str::trim)
+        .ok_or_else(|| err(name_line, format!("expected a `type` line, found {:?}", name_line.text)))?
+        .to_string();
+
+    let mut class = None;
+    let mut model = None;
+    let mut radius = None;
+    let mut mass = None;
+    let mut height = None;
+    let mut extra = BTreeMap::new();
+    let mut field_lines = BTreeMap::new();
+
+    for line in &block[1..] {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let rest = line.text[key.len()..].trim().to_string();
+        field_lines.insert(key.to_string(), line.line_number);
+
+        match key {
+            "class" => class = Some(rest),
+            "model" => model = Some(rest),
+            "radius" => radius = Some(parse_number(&rest).map_err(|e| err(line, e))?),
+            "mass" => mass = Some(parse_number(&rest).map_err(|e| err(line, e))?),
+            "height" => height = Some(parse_number(&rest).map_err(|e| err(line, e))?),
+            _ => {
+                extra.insert(key.to_string(), rest);
+            }
+        }
+    }
+
+    Ok(Mount { id: 0, line_number: name_line.line_number, name, class, model, radius, mass, height, extra, field_lines })
+}
+
+fn parse_number(token: &str) -> std::result::Result<f64, String> {
+    token.parse().map_err(|_| format!("{token:?} is not a valid number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn mounts_from(text: &str) -> Vec<Mount> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_mount_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut mounts = Vec::new();
+        for block in split_blocks(&lines) {
+            mounts.push(parse_record(&path, &block).unwrap());
+        }
+        mounts
+    }
+
+    #[test]
+    fn parses_class_and_stats() {
+        let mounts = mounts_from(
+            r#"
+type mailed horse
+class horse
+model data/models_unit/mailed_horse.cas
+radius 0.6
+mass 400
+height 1.6
+
+type war elephant
+class elephant
+model data/models_unit/elephant.cas
+howdah_armour 8
+"#,
+        );
+        assert_eq!(mounts.len(), 2);
+
+        let horse = &mounts[0];
+        assert_eq!(horse.name, "mailed horse");
+        assert_eq!(horse.class.as_deref(), Some("horse"));
+        assert_eq!(horse.model.as_deref(), Some("data/models_unit/mailed_horse.cas"));
+        assert_eq!(horse.radius, Some(0.6));
+        assert_eq!(horse.mass, Some(400.0));
+        assert_eq!(horse.height, Some(1.6));
+
+        let elephant = &mounts[1];
+        assert_eq!(elephant.name, "war elephant");
+        assert_eq!(elephant.class.as_deref(), Some("elephant"));
+        assert_eq!(elephant.extra.get("howdah_armour"), Some(&"8".to_string()));
+    }
+
+    #[test]
+    fn missing_type_line_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_mount_test_{id}.txt"));
+        std::fs::write(&path, "class horse\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("expected a `type` line"), "{err}");
+    }
+
+    #[test]
+    fn malformed_radius_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_mount_test_{id}.txt"));
+        std::fs::write(&path, "type horse\nradius none\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("is not a valid number"), "{err}");
+    }
+}
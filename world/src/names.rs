@@ -0,0 +1,269 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A faction's character name pools from `descr_names.txt`. Names are kept
+/// as written (including accents and other non-ASCII characters used by
+/// several vanilla factions, e.g. "Ælfric") since this loader only needs
+/// to know which names exist, not how to sort or transliterate them.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct NamePool {
+    pub faction: String,
+    /// 1-based line the `faction NAME` header started on.
+    pub line_number: usize,
+    pub male_names: Vec<String>,
+    pub female_names: Vec<String>,
+    pub surnames: Vec<String>,
+}
+
+impl NamePool {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<NamePool>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(path, &lines)
+    }
+}
+
+fn parse(path: &Path, lines: &[&DescrLine]) -> Result<Vec<NamePool>> {
+    let mut pools = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some(faction) = lines[i].text.strip_prefix("faction ").map(str::trim) {
+            let line_number = lines[i].line_number;
+            let (open, close) = find_block(path, lines, i)?;
+            pools.push(parse_pool(path, faction.to_string(), line_number, &lines[open + 1..close])?);
+            i = close + 1;
+        } else {
+            i += 1;
+        }
+    }
+    Ok(pools)
+}
+
+fn parse_pool(path: &Path, faction: String, line_number: usize, body: &[&DescrLine]) -> Result<NamePool> {
+    let mut male_names = Vec::new();
+    let mut female_names = Vec::new();
+    let mut surnames = Vec::new();
+
+    let mut i = 0;
+    while i < body.len() {
+        let text = body[i].text.trim();
+        let target = match text {
+            "male_names" => &mut male_names,
+            "female_names" => &mut female_names,
+            "surnames" => &mut surnames,
+            "" => {
+                i += 1;
+                continue;
+            }
+            _ => {
+                return Err(ParseError {
+                    file: path.to_path_buf(),
+                    line_number: body[i].line_number,
+                    line_text: body[i].text.clone(),
+                    message: format!("expected `male_names`, `female_names`, or `surnames`, found {text:?}"),
+                }
+                .into());
+            }
+        };
+
+        let (open, close) = find_block(path, body, i)?;
+        for name_line in &body[open + 1..close] {
+            let name = name_line.text.trim();
+            if !name.is_empty() {
+                target.push(name.to_string());
+            }
+        }
+        i = close + 1;
+    }
+
+    Ok(NamePool { faction, line_number, male_names, female_names, surnames })
+}
+
+/// Finds the `{ ... }` block starting at or after `lines[start]`, returning
+/// the index of the line holding the opening brace and the index of the
+/// line holding its matching closing brace.
+fn find_block(path: &Path, lines: &[&DescrLine], start: usize) -> Result<(usize, usize)> {
+    let mut open_at = start;
+    while !lines[open_at].text.contains('{') {
+        open_at += 1;
+        if open_at >= lines.len() {
+            return Err(ParseError {
+                file: path.to_path_buf(),
+                line_number: lines[start].line_number,
+                line_text: lines[start].text.clone(),
+                message: "block is missing its opening `{`".to_string(),
+            }
+            .into());
+        }
+    }
+
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(open_at) {
+        let opens = line.text.matches('{').count() as i32;
+        let closes = line.text.matches('}').count() as i32;
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((open_at, idx));
+        }
+    }
+
+    Err(ParseError {
+        file: path.to_path_buf(),
+        line_number: lines[open_at].line_number,
+        line_text: lines[open_at].text.clone(),
+        message: "block is missing its closing `}`".to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn pools_from(text: &str) -> Vec<NamePool> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_names_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(Path::new("descr_names.txt"), &lines).unwrap()
+    }
+
+    #[test]
+    fn parses_male_female_and_surname_lists() {
+        let pools = pools_from(
+            r#"
+faction england
+{
+    male_names
+    {
+        Edward
+        Henry
+    }
+
+    female_names
+    {
+        Eleanor
+        Matilda
+    }
+
+    surnames
+    {
+        Plantagenet
+    }
+}
+"#,
+        );
+        assert_eq!(pools.len(), 1);
+        let england = &pools[0];
+        assert_eq!(england.faction, "england");
+        assert_eq!(england.male_names, vec!["Edward", "Henry"]);
+        assert_eq!(england.female_names, vec!["Eleanor", "Matilda"]);
+        assert_eq!(england.surnames, vec!["Plantagenet"]);
+    }
+
+    #[test]
+    fn non_ascii_names_are_kept_as_written() {
+        let pools = pools_from(
+            r#"
+faction france
+{
+    male_names
+    {
+        François
+        Étienne
+    }
+    female_names
+    {
+        Aliénor
+    }
+    surnames
+    {
+        Ælfric
+        d'Aubigny
+    }
+}
+"#,
+        );
+        let france = &pools[0];
+        assert_eq!(france.male_names, vec!["François", "Étienne"]);
+        assert_eq!(france.female_names, vec!["Aliénor"]);
+        assert_eq!(france.surnames, vec!["Ælfric", "d'Aubigny"]);
+    }
+
+    #[test]
+    fn multiple_factions_are_all_parsed() {
+        let pools = pools_from(
+            r#"
+faction england
+{
+    male_names
+    {
+        Edward
+    }
+    female_names
+    {
+        Eleanor
+    }
+    surnames
+    {
+        Plantagenet
+    }
+}
+
+faction france
+{
+    male_names
+    {
+        Philippe
+    }
+    female_names
+    {
+        Isabelle
+    }
+    surnames
+    {
+        Capet
+    }
+}
+"#,
+        );
+        assert_eq!(pools.len(), 2);
+        assert_eq!(pools[0].faction, "england");
+        assert_eq!(pools[1].faction, "france");
+    }
+
+    #[test]
+    fn unknown_section_keyword_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_names_test_{id}.txt"));
+        std::fs::write(
+            &path,
+            r#"
+faction england
+{
+    nicknames
+    {
+        Lionheart
+    }
+}
+"#,
+        )
+        .unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        let err = parse(Path::new("descr_names.txt"), &lines).unwrap_err();
+        assert!(err.to_string().contains("expected `male_names`, `female_names`, or `surnames`"), "{err}");
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A `model`-prefixed line inside a projectile record (`model`,
+/// `model_flexi`, `model_still`, ...), kept as its own entry instead of
+/// folded into `extra` so [`crate::validate::validate`] can check every one
+/// of them against the data directory.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct ModelPath {
+    /// 1-based line this entry was declared on.
+    pub line_number: usize,
+    pub key: String,
+    pub path: String,
+}
+
+/// A projectile record from `descr_projectile.txt`, referenced by name from
+/// an `export_descr_unit.txt` unit's `stat_pri`/`stat_sec` weapon line (see
+/// [`crate::unit::Unit::missile_projectile`]). Records are separated by
+/// blank lines, each starting with a `type NAME` line, same layout as
+/// [`crate::unit::Unit`].
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Projectile {
+    /// 0-based position in the file, stable for a given file so other
+    /// loaded data (and external tooling) can reference a projectile by id
+    /// instead of by name.
+    pub id: usize,
+    /// 1-based line the `type NAME` line started on.
+    pub line_number: usize,
+    pub name: String,
+    /// Entries from the `flags` line, kept verbatim.
+    pub flags: Vec<String>,
+    pub damage: Option<f64>,
+    pub radius: Option<f64>,
+    pub velocity: Option<f64>,
+    pub range: Option<f64>,
+    pub model_paths: Vec<ModelPath>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+    /// The line each recognized or `extra` key was set from, keyed by field
+    /// name, same convention as [`crate::faction::Faction::field_lines`].
+    pub field_lines: BTreeMap<String, usize>,
+}
+
+impl Projectile {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Projectile>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut projectiles = Vec::new();
+        for block in split_blocks(&lines) {
+            projectiles.push(parse_record(path, &block)?);
+        }
+
+        for (id, projectile) in projectiles.iter_mut().enumerate() {
+            projectile.id = id;
+        }
+
+        Ok(projectiles)
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank lines, same
+/// as [`crate::unit::split_blocks`].
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<Projectile> {
+    let name_line = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let name = name_line
+        .text
+        .strip_prefix("type ")
+        .map(str::trim)
+        .ok_or_else(|| err(name_line, format!("expected a `type` line, found {:?}", name_line.text)))?
+        .to_string();
+
+    let mut flags = Vec::new();
+    let mut damage = None;
+    let mut radius = None;
+    let mut velocity = None;
+    let mut range = None;
+    let mut model_paths = Vec::new();
+    let mut extra = BTreeMap::new();
+    let mut field_lines = BTreeMap::new();
+
+    for line in &block[1..] {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let rest = line.text[key.len()..].trim().to_string();
+        field_lines.insert(key.to_string(), line.line_number);
+
+        match key {
+            "flags" => flags = rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect(),
+            "damage" => damage = Some(parse_number(&rest).map_err(|e| err(line, e))?),
+            "radius" => radius = Some(parse_number(&rest).map_err(|e| err(line, e))?),
+            "velocity" => velocity = Some(parse_number(&rest).map_err(|e| err(line, e))?),
+            "range" => range = Some(parse_number(&rest).map_err(|e| err(line, e))?),
+            _ if key.starts_with("model") => model_paths.push(ModelPath { line_number: line.line_number, key: key.to_string(), path: rest }),
+            _ => {
+                extra.insert(key.to_string(), rest);
+            }
+        }
+    }
+
+    Ok(Projectile { id: 0, line_number: name_line.line_number, name, flags, damage, radius, velocity, range, model_paths, extra, field_lines })
+}
+
+fn parse_number(token: &str) -> std::result::Result<f64, String> {
+    token.parse().map_err(|_| format!("{token:?} is not a valid number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn projectiles_from(text: &str) -> Vec<Projectile> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_projectile_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut projectiles = Vec::new();
+        for block in split_blocks(&lines) {
+            projectiles.push(parse_record(&path, &block).unwrap());
+        }
+        projectiles
+    }
+
+    #[test]
+    fn parses_name_flags_stats_and_models() {
+        let projectiles = projectiles_from(
+            r#"
+type arrow
+flags area_effect, fire
+damage 4
+radius 0
+velocity 60
+range 140
+model data/models_missiles/arrow.cas
+model_flexi data/models_missiles/arrow_flexi.cas
+
+type catapult_rock
+damage 30
+radius 8
+"#,
+        );
+        assert_eq!(projectiles.len(), 2);
+
+        let arrow = &projectiles[0];
+        assert_eq!(arrow.name, "arrow");
+        assert_eq!(arrow.flags, vec!["area_effect", "fire"]);
+        assert_eq!(arrow.damage, Some(4.0));
+        assert_eq!(arrow.radius, Some(0.0));
+        assert_eq!(arrow.velocity, Some(60.0));
+        assert_eq!(arrow.range, Some(140.0));
+        assert_eq!(arrow.model_paths.len(), 2);
+        assert_eq!(arrow.model_paths[0].key, "model");
+        assert_eq!(arrow.model_paths[0].path, "data/models_missiles/arrow.cas");
+
+        let rock = &projectiles[1];
+        assert_eq!(rock.name, "catapult_rock");
+        assert!(rock.flags.is_empty());
+        assert!(rock.model_paths.is_empty());
+    }
+
+    #[test]
+    fn missing_type_line_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_projectile_test_{id}.txt"));
+        std::fs::write(&path, "damage 4\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("expected a `type` line"), "{err}");
+    }
+
+    #[test]
+    fn malformed_damage_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_projectile_test_{id}.txt"));
+        std::fs::write(&path, "type arrow\ndamage none\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("is not a valid number"), "{err}");
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A rebel faction record from `descr_rebel_factions.txt`: the `rebel_type`
+/// a `descr_regions.txt` region can name, plus the units it garrisons with.
+/// Records are separated by blank lines, the same layout `descr_regions.txt`
+/// uses, so this parser tolerates the same inconsistent indentation and
+/// inline-comment styles big mods tend to have (comments and blank-line
+/// grouping are already handled by [`read_descr_lines`]).
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct RebelFaction {
+    /// 0-based position in `descr_rebel_factions.txt`, stable for a given
+    /// file so other loaded data (and external tooling) can reference a
+    /// record by id instead of by name.
+    pub id: usize,
+    /// 1-based line the identifier line started on.
+    pub line_number: usize,
+    pub identifier: String,
+    pub category: Option<String>,
+    pub chance: Option<u32>,
+    pub description: Option<String>,
+    pub units: Vec<RebelUnit>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+}
+
+/// One `unit` line inside a `RebelFaction`.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct RebelUnit {
+    pub line_number: usize,
+    pub name: String,
+}
+
+impl RebelFaction {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<RebelFaction>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut factions = Vec::new();
+        for block in split_blocks(&lines) {
+            factions.push(parse_record(path, &block)?);
+        }
+
+        for (id, faction) in factions.iter_mut().enumerate() {
+            faction.id = id;
+        }
+
+        Ok(factions)
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank (or
+/// comment-only, since [`read_descr_lines`] already stripped comments)
+/// lines.
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<RebelFaction> {
+    let identifier_line = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let identifier = identifier_line.text.clone();
+    let mut category = None;
+    let mut chance = None;
+    let mut description = None;
+    let mut units = Vec::new();
+    let mut extra = BTreeMap::new();
+
+    for line in &block[1..] {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let rest = line.text[key.len()..].trim().to_string();
+
+        match key {
+            "category" => category = Some(rest),
+            "chance" => chance = Some(rest.parse().map_err(|_| err(line, format!("{rest:?} is not a valid chance")))?),
+            "description" => description = Some(rest),
+            "unit" => units.push(RebelUnit { line_number: line.line_number, name: rest }),
+            _ => {
+                extra.insert(key.to_string(), rest);
+            }
+        }
+    }
+
+    Ok(RebelFaction { id: 0, line_number: identifier_line.line_number, identifier, category, chance, description, units, extra })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn factions_from(text: &str) -> Vec<RebelFaction> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_rebel_factions_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut factions = Vec::new();
+        for block in split_blocks(&lines) {
+            factions.push(parse_record(&path, &block).unwrap());
+        }
+        factions
+    }
+
+    #[test]
+    fn parses_identifier_and_unit_list() {
+        let factions = factions_from(
+            r#"
+barbarian
+    category brigand   ; loose bandits
+	chance 40
+    description Barbarian raiders
+    unit Naked Fanatics
+    unit Peasants
+
+pirate
+category naval
+chance 25
+description Pirate crews
+unit Sea Raiders
+"#,
+        );
+        assert_eq!(factions.len(), 2);
+
+        let barbarian = &factions[0];
+        assert_eq!(barbarian.identifier, "barbarian");
+        assert_eq!(barbarian.category.as_deref(), Some("brigand"));
+        assert_eq!(barbarian.chance, Some(40));
+        assert_eq!(barbarian.description.as_deref(), Some("Barbarian raiders"));
+        assert_eq!(barbarian.units.len(), 2);
+        assert_eq!(barbarian.units[0].name, "Naked Fanatics");
+        assert_eq!(barbarian.units[1].name, "Peasants");
+
+        let pirate = &factions[1];
+        assert_eq!(pirate.identifier, "pirate");
+        assert_eq!(pirate.units[0].name, "Sea Raiders");
+    }
+
+    #[test]
+    fn unknown_key_is_kept_in_extra() {
+        let factions = factions_from(
+            r#"
+barbarian
+category brigand
+new_mod_field 7
+"#,
+        );
+        assert_eq!(factions[0].extra.get("new_mod_field"), Some(&"7".to_string()));
+    }
+
+    #[test]
+    fn invalid_chance_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_rebel_factions_test_{id}.txt"));
+        std::fs::write(&path, "barbarian\nchance not_a_number\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("is not a valid chance"), "{err}");
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result};
+use crate::faction::{parse_rgb, parse_u32, Rgb};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A region record from `descr_regions.txt`. Records are separated by blank
+/// lines; the first five lines are positional (name, settlement name,
+/// creator faction, rebel type, map colour), everything after that is a
+/// mix of keyed lines (`resource wine`, `triumph_value 3`) and, in newer
+/// files, brace-delimited blocks (`hidden_resources { ... }`,
+/// `religions { ... }`).
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Region {
+    /// 0-based position in `descr_regions.txt`, stable for a given file so
+    /// other loaded data (and external tooling) can reference a region by
+    /// id instead of by name.
+    pub id: usize,
+    /// 1-based line the region's name started on.
+    pub line_number: usize,
+    pub name: String,
+    /// Looked up from a `text/expanded.txt`-style localization file by
+    /// [`crate::localization::LocalizationFile::apply`]; `None` if no
+    /// locale directory was loaded or it has no matching key.
+    pub display_name: Option<String>,
+    pub settlement_name: String,
+    pub creator_faction: String,
+    pub rebel_type: String,
+    pub colour: Rgb,
+    pub resources: Vec<String>,
+    pub triumph_value: Option<u32>,
+    pub farming_level: Option<u32>,
+    /// Contents of a `hidden_resources { ... }` block, if the file has one.
+    pub hidden_resources: Vec<String>,
+    /// Contents of a `religions { name percentage ... }` block, if the file
+    /// has one.
+    pub religion_percentages: BTreeMap<String, u32>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl Region {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Region>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut regions = Vec::new();
+        for block in split_blocks(&lines) {
+            regions.push(parse_record(path, &block)?);
+        }
+
+        for (id, region) in regions.iter_mut().enumerate() {
+            region.id = id;
+        }
+
+        Ok(regions)
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank (or
+/// comment-only, since [`read_descr_lines`] already stripped comments)
+/// lines.
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<Region> {
+    let name_line = block[0];
+    let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let field = |index: usize, field_name: &str| -> Result<&DescrLine> {
+        block.get(index).copied().ok_or_else(|| {
+            err(name_line, format!("region {:?} is missing its {field_name} line", name_line.text))
+        })
+    };
+
+    let name = name_line.text.clone();
+    let settlement_name = field(1, "settlement name")?.text.clone();
+    let creator_faction = field(2, "creator faction")?.text.clone();
+    let rebel_type = field(3, "rebel type")?.text.clone();
+    let colour_line = field(4, "map colour")?;
+    let colour = parse_rgb(&colour_line.text).map_err(|message| err(colour_line, format!("region {name:?}: {message}")))?;
+
+    let mut resources = Vec::new();
+    let mut triumph_value = None;
+    let mut farming_level = None;
+    let mut hidden_resources = Vec::new();
+    let mut religion_percentages = BTreeMap::new();
+    let mut extra = BTreeMap::new();
+
+    let mut i = 5;
+    while i < block.len() {
+        let line = block[i];
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { i += 1; continue };
+        let rest = parts.collect::<Vec<_>>().join(" ");
+
+        match key {
+            "resource" => {
+                resources.push(rest);
+                i += 1;
+            }
+            "triumph_value" => {
+                triumph_value = Some(parse_u32(&rest).map_err(|message| err(line, format!("region {name:?}: {message}")))?);
+                i += 1;
+            }
+            "farm_level" | "farming_level" => {
+                farming_level = Some(parse_u32(&rest).map_err(|message| err(line, format!("region {name:?}: {message}")))?);
+                i += 1;
+            }
+            "hidden_resources" => {
+                let (inner, consumed) = consume_brace_block(path, &name, block, i)?;
+                hidden_resources = inner.iter().map(|l| l.text.clone()).collect();
+                i += consumed;
+            }
+            "religions" => {
+                let (inner, consumed) = consume_brace_block(path, &name, block, i)?;
+                for entry in inner {
+                    let mut parts = entry.text.split_whitespace();
+                    let (Some(religion), Some(percentage), None) = (parts.next(), parts.next(), parts.next()) else {
+                        return Err(err(entry, format!("expected `RELIGION PERCENTAGE`, found {:?}", entry.text)));
+                    };
+                    let percentage = parse_u32(percentage).map_err(|message| err(entry, message))?;
+                    religion_percentages.insert(religion.to_string(), percentage);
+                }
+                i += consumed;
+            }
+            _ => {
+                extra.insert(key.to_string(), rest);
+                i += 1;
+            }
+        }
+    }
+
+    Ok(Region {
+        id: 0,
+        line_number: name_line.line_number,
+        name,
+        display_name: None,
+        settlement_name,
+        creator_faction,
+        rebel_type,
+        colour,
+        resources,
+        triumph_value,
+        farming_level,
+        hidden_resources,
+        religion_percentages,
+        extra,
+    })
+}
+
+/// Consumes a `keyword { ... }` block starting at `block[start]` (the
+/// opening `{` may trail the keyword or appear on a later line), returning
+/// its non-blank inner lines and the number of block entries consumed
+/// (including the keyword and closing `}` lines).
+fn consume_brace_block<'a>(
+    path: &Path,
+    region_name: &str,
+    block: &[&'a DescrLine],
+    start: usize,
+) -> Result<(Vec<&'a DescrLine>, usize)> {
+    let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut open_at = start;
+    while !block[open_at].text.contains('{') {
+        open_at += 1;
+        if open_at >= block.len() {
+            return Err(err(
+                block[start],
+                format!("region {region_name:?} is missing the opening `{{` for its {:?} block", block[start].text),
+            ));
+        }
+    }
+
+    let mut depth = 0i32;
+    let mut inner = Vec::new();
+    for (offset, line) in block[open_at..].iter().enumerate() {
+        let idx = open_at + offset;
+        let mut text = line.text.as_str();
+        if idx == open_at {
+            text = &text[text.find('{').unwrap() + 1..];
+            depth = 1;
+        }
+
+        let opens = text.matches('{').count() as i32;
+        let closes = text.matches('}').count() as i32;
+        if depth == 1 && opens == 0 && closes == 0 && !text.trim().is_empty() {
+            inner.push(*line);
+        }
+
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((inner, idx - start + 1));
+        }
+    }
+
+    Err(err(block[open_at], format!("region {region_name:?} is missing the closing `}}` for its block")))
+}
@@ -0,0 +1,227 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Religion {
+    /// 0-based position in the `religions` block, stable for a given file
+    /// so other loaded data (and external tooling) can reference a
+    /// religion by id instead of by name.
+    pub id: usize,
+    pub name: String,
+    /// 1-based line this religion was declared on, for pointing tooling
+    /// (e.g. `world validate`) back at the source file.
+    pub line_number: usize,
+}
+
+impl Religion {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Religion>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        parse(path, &lines)
+    }
+}
+
+/// Parses the `religions { ... }` block out of already comment-stripped,
+/// trimmed `lines`. Split out from [`Religion::load_all`] so it can be
+/// exercised directly against fixture lines without touching the
+/// filesystem.
+fn parse(path: &Path, lines: &[DescrLine]) -> Result<Vec<Religion>> {
+    let keyword_at = lines.iter().position(|line| contains_word(&line.text, "religions")).ok_or_else(|| ParseError {
+        file: path.to_path_buf(),
+        line_number: lines.first().map_or(1, |l| l.line_number),
+        line_text: lines.first().map_or_else(String::new, |l| l.text.clone()),
+        message: "missing `religions` keyword".to_string(),
+    })?;
+
+    // The opening brace may trail the keyword on its own line
+    // (`religions {`) or appear on a later line.
+    let open_at = lines[keyword_at..]
+        .iter()
+        .position(|line| line.text.contains('{'))
+        .map(|offset| keyword_at + offset)
+        .ok_or_else(|| ParseError {
+            file: path.to_path_buf(),
+            line_number: lines[keyword_at].line_number,
+            line_text: lines[keyword_at].text.clone(),
+            message: "missing opening `{` for the religions block".to_string(),
+        })?;
+
+    let mut names = Vec::new();
+    let mut depth = 0u32;
+    let mut closed = false;
+    for (offset, line) in lines[open_at..].iter().enumerate() {
+        let mut text = line.text.as_str();
+        if offset == 0 {
+            // Everything up to and including the opening `{` belongs to the
+            // keyword line, not the block's contents.
+            text = &text[text.find('{').unwrap() + 1..];
+            depth = 1;
+        }
+
+        let opens = text.matches('{').count() as u32;
+        let closes = text.matches('}').count() as u32;
+
+        // Only lines that sit directly inside the block (depth 1, no braces
+        // of their own) are candidate entries; anything inside a nested
+        // sub-block is skipped rather than validated, since this format has
+        // no such construct today but a mod adding one shouldn't corrupt
+        // the brace matching below.
+        if depth == 1 && opens == 0 && closes == 0 {
+            let entry = text.trim();
+            if !entry.is_empty() {
+                if !is_identifier(entry) {
+                    return Err(ParseError {
+                        file: path.to_path_buf(),
+                        line_number: line.line_number,
+                        line_text: line.text.clone(),
+                        message: format!("expected a single religion identifier, found {entry:?}"),
+                    }
+                    .into());
+                }
+                names.push((line.line_number, entry.to_string()));
+            }
+        }
+
+        depth = depth.saturating_add(opens).saturating_sub(closes);
+        if depth == 0 {
+            closed = true;
+            break;
+        }
+    }
+
+    if !closed {
+        return Err(ParseError {
+            file: path.to_path_buf(),
+            line_number: lines[open_at].line_number,
+            line_text: lines[open_at].text.clone(),
+            message: "missing closing `}` for the religions block opened here".to_string(),
+        }
+        .into());
+    }
+
+    Ok(names
+        .into_iter()
+        .enumerate()
+        .map(|(id, (line_number, name))| Religion { id, name, line_number })
+        .collect())
+}
+
+/// True if `word` appears in `line` as a standalone token, not as a
+/// substring of a longer identifier.
+fn contains_word(line: &str, word: &str) -> bool {
+    let bytes = line.as_bytes();
+    let mut search_from = 0;
+    while let Some(offset) = line[search_from..].find(word) {
+        let start = search_from + offset;
+        let end = start + word.len();
+        let before_boundary = start == 0 || !is_identifier_byte(bytes[start - 1]);
+        let after_boundary = end == bytes.len() || !is_identifier_byte(bytes[end]);
+        if before_boundary && after_boundary {
+            return true;
+        }
+        search_from = start + 1;
+    }
+    false
+}
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(is_identifier_byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn lines_from(text: &str) -> Vec<DescrLine> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp = std::env::temp_dir().join(format!("descr_religions_test_{id}.txt"));
+        std::fs::write(&tmp, text).unwrap();
+        let lines = read_descr_lines(&tmp, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&tmp).ok();
+        lines
+    }
+
+    fn names(text: &str) -> Vec<String> {
+        let lines = lines_from(text);
+        parse(Path::new("descr_religions.txt"), &lines).unwrap().into_iter().map(|r| r.name).collect()
+    }
+
+    fn parse_err(text: &str) -> String {
+        let lines = lines_from(text);
+        parse(Path::new("descr_religions.txt"), &lines).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn vanilla_style() {
+        let text = "religions\n{\n    catholic\n    orthodox\n    muslim\n    heretic\n    pagan\n}\n";
+        assert_eq!(names(text), vec!["catholic", "orthodox", "muslim", "heretic", "pagan"]);
+    }
+
+    #[test]
+    fn ss_style_brace_on_keyword_line_with_inline_comments_and_tabs() {
+        let text = "religions {\n\tcatholic\t\t; western christian\n\torthodox\t\t; eastern christian\n\tsunni\n\tshia\n\tpagan\n\n}\n";
+        assert_eq!(names(text), vec!["catholic", "orthodox", "sunni", "shia", "pagan"]);
+    }
+
+    #[test]
+    fn whole_line_comments_and_blank_lines_are_skipped() {
+        let text = "religions\n{\n; a full-line comment\n\ncatholic\n\northodox\n}\n";
+        assert_eq!(names(text), vec!["catholic", "orthodox"]);
+    }
+
+    #[test]
+    fn nested_block_is_skipped_but_brace_matching_still_finds_the_outer_close() {
+        let text = "religions\n{\n    catholic\n    {\n        some_key some_value\n    }\n    orthodox\n}\n";
+        assert_eq!(names(text), vec!["catholic", "orthodox"]);
+    }
+
+    #[test]
+    fn missing_keyword_is_rejected() {
+        let text = "{\n    catholic\n}\n";
+        assert!(parse_err(text).contains("missing `religions` keyword"));
+    }
+
+    #[test]
+    fn keyword_inside_a_comment_does_not_count() {
+        let text = "; religions used to live here\n{\n    catholic\n}\n";
+        assert!(parse_err(text).contains("missing `religions` keyword"));
+    }
+
+    #[test]
+    fn missing_opening_brace_is_rejected() {
+        let text = "religions\n    catholic\n}\n";
+        assert!(parse_err(text).contains("missing opening `{`"));
+    }
+
+    #[test]
+    fn missing_closing_brace_is_rejected() {
+        let text = "religions\n{\n    catholic\n    orthodox\n";
+        assert!(parse_err(text).contains("missing closing `}`"));
+    }
+
+    #[test]
+    fn multi_word_entry_is_rejected_with_line_number() {
+        let text = "religions\n{\n    catholic orthodox\n}\n";
+        let message = parse_err(text);
+        assert!(message.contains("3:"), "expected line 3 in {message:?}");
+        assert!(message.contains("expected a single religion identifier"));
+    }
+
+    #[test]
+    fn punctuation_in_entry_is_rejected() {
+        let text = "religions\n{\n    catholic!\n}\n";
+        assert!(parse_err(text).contains("expected a single religion identifier"));
+    }
+}
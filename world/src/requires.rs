@@ -0,0 +1,299 @@
+//! Parses the `requires` expressions attached to `export_descr_buildings.txt`
+//! level headers, capabilities, and recruitment entries (see
+//! [`crate::building::BuildingLevel::requires`]). The real grammar has
+//! dozens of clause kinds (`event_counter`, `resource`, ...); this only
+//! understands `factions { ... }`, `building_present NAME`, and
+//! `hidden_resource NAME` well enough to evaluate or collect them, and
+//! keeps every other clause around verbatim as [`Clause::Unknown`] so
+//! callers can still see the whole expression without this module having
+//! to grow a clause for every keyword the engine accepts. Several
+//! validations want this same parsing, so it lives here rather than inside
+//! `building.rs`.
+
+/// A single comma-separated clause of a `requires` expression, optionally
+/// negated with a leading `not`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Clause {
+    /// `factions { name, name, ... }`. `all` is the engine's wildcard for
+    /// "every faction", kept as a literal name here rather than expanded,
+    /// since expanding it needs the loaded faction list this module
+    /// doesn't have.
+    Factions(Vec<String>),
+    /// `building_present NAME`.
+    BuildingPresent(String),
+    /// `hidden_resource NAME`.
+    HiddenResource(String),
+    /// Any other clause (`event_counter ...`, a `not` we couldn't attach to
+    /// a clause we understand, ...), kept as written since this module has
+    /// no way to evaluate or disprove it.
+    Unknown(String),
+}
+
+/// One clause of a `requires` expression together with whether it was
+/// negated by a leading `not`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Requirement {
+    pub negated: bool,
+    pub clause: Clause,
+}
+
+/// A parsed `requires` expression: every top-level, comma-separated clause
+/// must hold (the engine's `requires` is an implicit AND list, unlike
+/// `campaign_script.txt`'s condition blocks which support `or`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiresExpr {
+    pub requirements: Vec<Requirement>,
+}
+
+impl RequiresExpr {
+    /// Parses `text` (the part of a `requires` line after the `requires`
+    /// keyword). Never fails: a clause this module doesn't recognize
+    /// becomes [`Clause::Unknown`] instead of rejecting the whole
+    /// expression, the same tolerance [`crate::building`] itself uses for
+    /// keys it doesn't know about.
+    pub fn parse(text: &str) -> RequiresExpr {
+        RequiresExpr { requirements: split_top_level_commas(text).iter().map(|clause| parse_requirement(clause)).collect() }
+    }
+
+    /// Building families named by every `building_present` clause,
+    /// regardless of negation -- useful for checking the name resolves to
+    /// something that actually exists in the EDB tree.
+    pub fn building_present_names(&self) -> impl Iterator<Item = &str> {
+        self.requirements.iter().filter_map(|r| match &r.clause {
+            Clause::BuildingPresent(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Hidden resource names named by every `hidden_resource` clause,
+    /// regardless of negation.
+    pub fn hidden_resource_names(&self) -> impl Iterator<Item = &str> {
+        self.requirements.iter().filter_map(|r| match &r.clause {
+            Clause::HiddenResource(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Faction names named by every `factions { ... }` clause, regardless
+    /// of negation, excluding the `all` wildcard.
+    pub fn faction_names(&self) -> impl Iterator<Item = &str> {
+        self.requirements.iter().flat_map(|r| match &r.clause {
+            Clause::Factions(names) => names.iter().map(String::as_str).collect(),
+            _ => Vec::new(),
+        })
+    }
+
+    /// Whether this expression could possibly be satisfied by `faction`.
+    /// Only `factions { ... }` clauses are checked; every other clause
+    /// (including `building_present`, which depends on what's already
+    /// built rather than who owns the settlement) is assumed satisfiable,
+    /// since this module can't disprove it. That makes this a one-sided
+    /// check: it can prove a level is unbuildable by a faction, never that
+    /// it's buildable.
+    pub fn satisfiable_by_faction(&self, faction: &str) -> bool {
+        self.requirements.iter().all(|requirement| match &requirement.clause {
+            Clause::Factions(names) => {
+                let matches = names.iter().any(|n| n == "all" || n == faction);
+                matches != requirement.negated
+            }
+            _ => true,
+        })
+    }
+
+    /// Renders the expression back to the comma-separated syntax the engine
+    /// accepts, e.g. `factions { all }, not building_present tavern`. Round
+    /// trips through [`RequiresExpr::parse`] for every clause kind this
+    /// module understands; a [`Clause::Unknown`] is written back exactly as
+    /// it was parsed.
+    pub fn render(&self) -> String {
+        self.requirements.iter().map(Requirement::render).collect::<Vec<_>>().join(", ")
+    }
+
+    /// Appends `requirement` unless it's already present, for building an
+    /// additional gate onto a level (e.g. a `hidden_resource` clause) without
+    /// disturbing whatever it already required.
+    pub fn add_requirement(&mut self, requirement: Requirement) {
+        if !self.requirements.contains(&requirement) {
+            self.requirements.push(requirement);
+        }
+    }
+
+    /// Replaces the expression's `factions { ... }` clause with one listing
+    /// exactly `factions`, or inserts one at the front if it didn't have one
+    /// -- the usual mod-author shorthand for "only these factions can build
+    /// this". Only an unnegated `factions` clause is treated as the one to
+    /// replace; a `not factions { ... }` exclusion is left alone since it's
+    /// answering a different question.
+    pub fn restrict_to_factions(&mut self, factions: &[String]) {
+        let replacement = Requirement { negated: false, clause: Clause::Factions(factions.to_vec()) };
+        match self.requirements.iter_mut().find(|r| !r.negated && matches!(r.clause, Clause::Factions(_))) {
+            Some(existing) => *existing = replacement,
+            None => self.requirements.insert(0, replacement),
+        }
+    }
+}
+
+impl Requirement {
+    fn render(&self) -> String {
+        let clause = self.clause.render();
+        if self.negated { format!("not {clause}") } else { clause }
+    }
+}
+
+impl Clause {
+    fn render(&self) -> String {
+        match self {
+            Clause::Factions(names) => format!("factions {{ {} }}", names.join(", ")),
+            Clause::BuildingPresent(name) => format!("building_present {name}"),
+            Clause::HiddenResource(name) => format!("hidden_resource {name}"),
+            Clause::Unknown(text) => text.clone(),
+        }
+    }
+}
+
+fn parse_requirement(clause: &str) -> Requirement {
+    let clause = clause.trim();
+    let (negated, clause) = match clause.strip_prefix("not ") {
+        Some(rest) => (true, rest.trim()),
+        None => (false, clause),
+    };
+
+    let mut parts = clause.splitn(2, char::is_whitespace);
+    let keyword = parts.next().unwrap_or_default();
+    let rest = parts.next().unwrap_or_default().trim();
+
+    let parsed = match keyword {
+        "factions" => rest.strip_prefix('{').and_then(|s| s.strip_suffix('}')).map(|names| {
+            Clause::Factions(names.split(',').map(str::trim).filter(|n| !n.is_empty()).map(str::to_string).collect())
+        }),
+        "building_present" if !rest.is_empty() => Some(Clause::BuildingPresent(rest.to_string())),
+        "hidden_resource" if !rest.is_empty() => Some(Clause::HiddenResource(rest.to_string())),
+        _ => None,
+    };
+
+    Requirement { negated, clause: parsed.unwrap_or_else(|| Clause::Unknown(clause.to_string())) }
+}
+
+/// Splits `text` on commas that aren't nested inside `{ ... }`, so
+/// `factions { a, b }, not building_present c` splits into two clauses
+/// rather than three.
+fn split_top_level_commas(text: &str) -> Vec<String> {
+    let mut clauses = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    for c in text.chars() {
+        match c {
+            '{' => {
+                depth += 1;
+                current.push(c);
+            }
+            '}' => {
+                depth -= 1;
+                current.push(c);
+            }
+            ',' if depth == 0 => {
+                clauses.push(std::mem::take(&mut current));
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        clauses.push(current);
+    }
+    clauses.into_iter().map(|c| c.trim().to_string()).filter(|c| !c.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_single_factions_clause() {
+        let expr = RequiresExpr::parse("factions { all }");
+        assert_eq!(expr.requirements, vec![Requirement { negated: false, clause: Clause::Factions(vec!["all".to_string()]) }]);
+    }
+
+    #[test]
+    fn parses_multiple_clauses_with_negation_and_unknowns() {
+        let expr = RequiresExpr::parse("factions { northern_european, southern_european }, not event_counter is_playable_start_pos 1");
+        assert_eq!(expr.requirements.len(), 2);
+        assert_eq!(expr.requirements[0].clause, Clause::Factions(vec!["northern_european".to_string(), "southern_european".to_string()]));
+        assert!(!expr.requirements[0].negated);
+        assert_eq!(expr.requirements[1].clause, Clause::Unknown("event_counter is_playable_start_pos 1".to_string()));
+        assert!(expr.requirements[1].negated);
+    }
+
+    #[test]
+    fn parses_building_present() {
+        let expr = RequiresExpr::parse("not building_present tavern");
+        assert_eq!(expr.requirements, vec![Requirement { negated: true, clause: Clause::BuildingPresent("tavern".to_string()) }]);
+        assert_eq!(expr.building_present_names().collect::<Vec<_>>(), vec!["tavern"]);
+    }
+
+    #[test]
+    fn parses_hidden_resource() {
+        let expr = RequiresExpr::parse("hidden_resource iron");
+        assert_eq!(expr.requirements, vec![Requirement { negated: false, clause: Clause::HiddenResource("iron".to_string()) }]);
+        assert_eq!(expr.hidden_resource_names().collect::<Vec<_>>(), vec!["iron"]);
+    }
+
+    #[test]
+    fn faction_names_excludes_other_clause_kinds() {
+        let expr = RequiresExpr::parse("factions { scotland, } , building_present tavern");
+        assert_eq!(expr.faction_names().collect::<Vec<_>>(), vec!["scotland"]);
+    }
+
+    #[test]
+    fn satisfiable_by_faction_checks_the_factions_clause() {
+        let all = RequiresExpr::parse("factions { all }");
+        assert!(all.satisfiable_by_faction("scotland"));
+
+        let scotland_only = RequiresExpr::parse("factions { scotland, } ");
+        assert!(scotland_only.satisfiable_by_faction("scotland"));
+        assert!(!scotland_only.satisfiable_by_faction("england"));
+
+        let not_scotland = RequiresExpr::parse("not factions { scotland, }");
+        assert!(!not_scotland.satisfiable_by_faction("scotland"));
+        assert!(not_scotland.satisfiable_by_faction("england"));
+    }
+
+    #[test]
+    fn unrecognized_clauses_dont_block_satisfiability() {
+        let expr = RequiresExpr::parse("event_counter is_playable_start_pos 1");
+        assert!(expr.satisfiable_by_faction("scotland"));
+    }
+
+    #[test]
+    fn render_round_trips_a_parsed_expression() {
+        let text = "factions { northern_european, southern_european }, not building_present tavern";
+        assert_eq!(RequiresExpr::parse(text).render(), text);
+    }
+
+    #[test]
+    fn add_requirement_appends_a_new_clause() {
+        let mut expr = RequiresExpr::parse("factions { all }");
+        expr.add_requirement(Requirement { negated: false, clause: Clause::HiddenResource("gunpowder".to_string()) });
+        assert_eq!(expr.render(), "factions { all }, hidden_resource gunpowder");
+    }
+
+    #[test]
+    fn add_requirement_is_a_no_op_for_a_duplicate() {
+        let mut expr = RequiresExpr::parse("hidden_resource iron");
+        expr.add_requirement(Requirement { negated: false, clause: Clause::HiddenResource("iron".to_string()) });
+        assert_eq!(expr.requirements.len(), 1);
+    }
+
+    #[test]
+    fn restrict_to_factions_replaces_an_existing_clause() {
+        let mut expr = RequiresExpr::parse("factions { all }, hidden_resource iron");
+        expr.restrict_to_factions(&["scotland".to_string(), "england".to_string()]);
+        assert_eq!(expr.render(), "factions { scotland, england }, hidden_resource iron");
+    }
+
+    #[test]
+    fn restrict_to_factions_inserts_a_clause_when_there_was_none() {
+        let mut expr = RequiresExpr::parse("hidden_resource iron");
+        expr.restrict_to_factions(&["scotland".to_string()]);
+        assert_eq!(expr.render(), "factions { scotland }, hidden_resource iron");
+    }
+}
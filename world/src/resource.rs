@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::character::Position;
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, strip_keyword, DescrLine, ReadOptions};
+
+/// A trade resource definition from `descr_sm_resources.txt`, referenced by
+/// name from a region's `resource`/`hidden_resources` lists and from a
+/// `descr_strat.txt` [`ResourcePlacement`]. Records are separated by blank
+/// lines, each starting with a `type NAME` line, same layout as
+/// [`crate::projectile::Projectile`].
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct ResourceDef {
+    /// 0-based position in the file, stable for a given file so other
+    /// loaded data (and external tooling) can reference a resource by id
+    /// instead of by name.
+    pub id: usize,
+    /// 1-based line the `type NAME` line started on.
+    pub line_number: usize,
+    pub name: String,
+    pub trade_value: Option<u32>,
+    /// Any key this parser doesn't know about yet (item/model references,
+    /// icon paths, ...), kept verbatim so a record round-trips instead of
+    /// silently losing data.
+    pub extra: BTreeMap<String, String>,
+    /// The line each recognized or `extra` key was set from, keyed by field
+    /// name, same convention as [`crate::faction::Faction::field_lines`].
+    pub field_lines: BTreeMap<String, usize>,
+}
+
+impl ResourceDef {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<ResourceDef>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut resources = Vec::new();
+        for block in split_blocks(&lines) {
+            resources.push(parse_record(path, &block)?);
+        }
+
+        for (id, resource) in resources.iter_mut().enumerate() {
+            resource.id = id;
+        }
+
+        Ok(resources)
+    }
+}
+
+/// A `resource NAME X, Y` placement line from `descr_strat.txt`, positioning
+/// a [`ResourceDef`] on the campaign map.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct ResourcePlacement {
+    /// 0-based position in `descr_strat.txt`, in file order.
+    pub id: usize,
+    /// 1-based line the `resource` line was declared on.
+    pub line_number: usize,
+    pub name: String,
+    pub position: Position,
+}
+
+impl ResourcePlacement {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<ResourcePlacement>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let err = |line: &DescrLine, message: String| -> WorldError {
+            ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+        };
+
+        let mut placements = Vec::new();
+        for line in &lines {
+            let Some(rest) = strip_keyword(&line.text, "resource") else { continue };
+            let name = rest.split_whitespace().next().unwrap_or_default();
+            let coords = rest[name.len()..].trim();
+            let mut parts = coords.split(',').map(str::trim);
+            let (Some(x), Some(y), None) = (parts.next(), parts.next(), parts.next()) else {
+                return Err(err(line, format!("expected `resource NAME X, Y`, found {:?}", line.text)));
+            };
+            let x = x.parse().map_err(|_| err(line, format!("{x:?} is not a valid x coordinate")))?;
+            let y = y.parse().map_err(|_| err(line, format!("{y:?} is not a valid y coordinate")))?;
+            placements.push(ResourcePlacement { id: 0, line_number: line.line_number, name: name.to_string(), position: Position { x, y } });
+        }
+
+        for (id, placement) in placements.iter_mut().enumerate() {
+            placement.id = id;
+        }
+
+        Ok(placements)
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank lines, same
+/// as [`crate::unit::split_blocks`].
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<ResourceDef> {
+    let name_line = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let name = name_line
+        .text
+        .strip_prefix("type ")
+        .map(str::trim)
+        .ok_or_else(|| err(name_line, format!("expected a `type` line, found {:?}", name_line.text)))?
+        .to_string();
+
+    let mut trade_value = None;
+    let mut extra = BTreeMap::new();
+    let mut field_lines = BTreeMap::new();
+
+    for line in &block[1..] {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let rest = line.text[key.len()..].trim().to_string();
+        field_lines.insert(key.to_string(), line.line_number);
+
+        match key {
+            "trade_value" => trade_value = Some(rest.parse().map_err(|_| err(line, format!("{rest:?} is not a valid trade_value")))?),
+            _ => {
+                extra.insert(key.to_string(), rest);
+            }
+        }
+    }
+
+    Ok(ResourceDef { id: 0, line_number: name_line.line_number, name, trade_value, extra, field_lines })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn resources_from(text: &str) -> Vec<ResourceDef> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_sm_resources_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut resources = Vec::new();
+        for block in split_blocks(&lines) {
+            resources.push(parse_record(&path, &block).unwrap());
+        }
+        resources
+    }
+
+    fn placements_from(text: &str) -> Vec<ResourcePlacement> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_strat_resource_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let placements = ResourcePlacement::load_all(&path, text).unwrap();
+        std::fs::remove_file(&path).ok();
+        placements
+    }
+
+    #[test]
+    fn parses_name_and_trade_value() {
+        let resources = resources_from(
+            r#"
+type wine
+trade_value 3
+item wine_barrel
+model data/models_strat/resource_wine.cas
+
+type iron
+trade_value 5
+"#,
+        );
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[0].name, "wine");
+        assert_eq!(resources[0].trade_value, Some(3));
+        assert_eq!(resources[0].extra.get("item"), Some(&"wine_barrel".to_string()));
+        assert_eq!(resources[1].name, "iron");
+        assert_eq!(resources[1].trade_value, Some(5));
+    }
+
+    #[test]
+    fn missing_type_line_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_sm_resources_test_{id}.txt"));
+        std::fs::write(&path, "trade_value 3\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("expected a `type` line"), "{err}");
+    }
+
+    #[test]
+    fn parses_placement_coordinates() {
+        let placements = placements_from("resource wine 452, 300\nresource iron_ore 100, 200\n");
+        assert_eq!(placements.len(), 2);
+        assert_eq!(placements[0].name, "wine");
+        assert_eq!(placements[0].position, Position { x: 452, y: 300 });
+        assert_eq!(placements[1].name, "iron_ore");
+        assert_eq!(placements[1].position, Position { x: 100, y: 200 });
+    }
+
+    #[test]
+    fn malformed_placement_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_strat_resource_test_{id}.txt"));
+        let text = "resource wine\n";
+        std::fs::write(&path, text).unwrap();
+        let err = ResourcePlacement::load_all(&path, text).unwrap_err();
+        std::fs::remove_file(&path).ok();
+        assert!(err.to_string().contains("expected `resource NAME X, Y`"), "{err}");
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::character::Position;
+use crate::error::{ParseError, Result};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A starting settlement from a `settlement { ... }` block in
+/// `descr_strat.txt`. Cross-checking `region` against `descr_regions.txt`
+/// is left to the `validate` subcommand rather than done at load time.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Settlement {
+    /// 0-based position in `descr_strat.txt`, in file order.
+    pub id: usize,
+    /// 1-based line the `settlement` block started on.
+    pub line_number: usize,
+    /// The faction whose section this settlement's block appears under, or
+    /// its own `faction_creator` line if it has one.
+    pub owning_faction: String,
+    pub region: String,
+    /// Looked up via the owning region's `settlement_name` in a
+    /// `text/imperial_campaign_regions_and_settlement_names.txt`-style
+    /// localization file by [`crate::localization::LocalizationFile::apply`];
+    /// `None` if no locale directory was loaded or it has no matching key.
+    /// Kept last among the identifying fields since it's derived, not
+    /// parsed from this settlement's own block.
+    pub display_name: Option<String>,
+    pub level: String,
+    /// Map position, if the block has `x`/`y` lines (older files sometimes
+    /// omit these, relying on the region alone).
+    pub position: Option<Position>,
+    pub population: Option<u32>,
+    pub year_founded: Option<u32>,
+    pub plan_set: Option<String>,
+    /// One entry per `building { ... }` sub-block, its inner lines joined
+    /// with `"; "`.
+    pub buildings: Vec<String>,
+    /// Contents of a `religions { name percentage ... }` block, if this
+    /// settlement's block has one.
+    pub religion_percentages: BTreeMap<String, u32>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl Settlement {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Settlement>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+
+        let mut settlements = Vec::new();
+        let mut current_faction: Option<String> = None;
+        let mut i = 0;
+        while i < lines.len() {
+            let line = &lines[i];
+
+            if let Some(rest) = line.text.strip_prefix("faction ") {
+                // The faction header line is `faction NAME, denari` (or just
+                // `faction NAME`); only the name matters here.
+                current_faction = Some(rest.split(',').next().unwrap_or(rest).trim().to_string());
+                i += 1;
+                continue;
+            }
+
+            if line.text.split_whitespace().next() == Some("settlement") {
+                let (settlement, consumed) = parse_settlement_block(path, &lines, i, current_faction.as_deref())?;
+                settlements.push(settlement);
+                i += consumed;
+                continue;
+            }
+
+            // `denari`, `ai_label`, and any other faction-section lines we
+            // don't model yet are simply not settlements; skip past them.
+            i += 1;
+        }
+
+        for (id, settlement) in settlements.iter_mut().enumerate() {
+            settlement.id = id;
+        }
+
+        Ok(settlements)
+    }
+}
+
+/// Parses the `settlement { ... }` block starting at `lines[start]`,
+/// returning it along with the number of lines consumed (from `start`
+/// through the block's closing `}`, inclusive).
+fn parse_settlement_block(
+    path: &Path,
+    lines: &[DescrLine],
+    start: usize,
+    enclosing_faction: Option<&str>,
+) -> Result<(Settlement, usize)> {
+    let start_line = &lines[start];
+    let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let mut open_at = start;
+    while !lines[open_at].text.contains('{') {
+        open_at += 1;
+        if open_at >= lines.len() {
+            return Err(err(start_line, "settlement block is missing its opening `{`".to_string()));
+        }
+    }
+
+    let mut level = None;
+    let mut region = None;
+    let mut x = None;
+    let mut y = None;
+    let mut population = None;
+    let mut year_founded = None;
+    let mut plan_set = None;
+    let mut faction_creator = None;
+    let mut buildings = Vec::new();
+    let mut religion_percentages = BTreeMap::new();
+    let mut extra = BTreeMap::new();
+
+    let mut depth = 0i32;
+    let mut pending_keyword: Option<String> = None;
+    let mut in_building = false;
+    let mut in_religions = false;
+    let mut building_buffer: Vec<String> = Vec::new();
+    let mut end_at = None;
+
+    for (offset, line) in lines[open_at..].iter().enumerate() {
+        let idx = open_at + offset;
+        let mut text = line.text.as_str();
+        if idx == open_at {
+            text = &text[text.find('{').unwrap() + 1..];
+            depth = 1;
+        }
+
+        let opens = text.matches('{').count() as i32;
+        let closes = text.matches('}').count() as i32;
+
+        if depth == 1 && opens >= 1 {
+            let pre = text.split('{').next().unwrap_or("").trim();
+            let keyword = if !pre.is_empty() { Some(pre.to_string()) } else { pending_keyword.take() };
+            in_building = keyword.as_deref() == Some("building");
+            in_religions = keyword.as_deref() == Some("religions");
+            if in_building {
+                building_buffer.clear();
+            }
+        } else if depth == 1 && opens == 0 && closes == 0 {
+            let key_line = text.trim();
+            if !key_line.is_empty() {
+                if key_line == "building" || key_line == "religions" {
+                    pending_keyword = Some(key_line.to_string());
+                } else {
+                    let mut parts = key_line.split_whitespace();
+                    let key = parts.next().unwrap_or_default();
+                    let value = parts.collect::<Vec<_>>().join(" ");
+                    match key {
+                        "level" => level = Some(value),
+                        "region" => region = Some(value),
+                        "plan_set" => plan_set = Some(value),
+                        "faction_creator" => faction_creator = Some(value),
+                        "x" => {
+                            x = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid x coordinate")))?);
+                        }
+                        "y" => {
+                            y = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid y coordinate")))?);
+                        }
+                        "population" => {
+                            population = Some(value.parse().map_err(|_| {
+                                err(line, format!("{value:?} is not a valid population"))
+                            })?);
+                        }
+                        "year_founded" => {
+                            year_founded = Some(value.parse().map_err(|_| {
+                                err(line, format!("{value:?} is not a valid year_founded"))
+                            })?);
+                        }
+                        _ => {
+                            extra.insert(key.to_string(), value);
+                        }
+                    }
+                }
+            }
+        } else if depth == 2 && opens == 0 && closes == 0 {
+            if in_building {
+                let entry = text.trim();
+                if !entry.is_empty() {
+                    building_buffer.push(entry.to_string());
+                }
+            } else if in_religions {
+                let entry = text.trim();
+                if !entry.is_empty() {
+                    let mut parts = entry.split_whitespace();
+                    let (Some(religion), Some(percentage), None) = (parts.next(), parts.next(), parts.next()) else {
+                        return Err(err(line, format!("expected `RELIGION PERCENTAGE`, found {entry:?}")));
+                    };
+                    let percentage =
+                        percentage.parse().map_err(|_| err(line, format!("{percentage:?} is not a valid religion percentage")))?;
+                    religion_percentages.insert(religion.to_string(), percentage);
+                }
+            }
+        } else if depth == 2 && closes >= 1 && in_building {
+            buildings.push(building_buffer.join("; "));
+            in_building = false;
+        } else if depth == 2 && closes >= 1 && in_religions {
+            in_religions = false;
+        }
+
+        depth += opens - closes;
+        if depth == 0 {
+            end_at = Some(idx);
+            break;
+        }
+    }
+
+    let end_at = end_at.ok_or_else(|| err(&lines[open_at], "settlement block is missing its closing `}`".to_string()))?;
+
+    let level = level.ok_or_else(|| err(start_line, "settlement block is missing a `level` line".to_string()))?;
+    let region = region.ok_or_else(|| err(start_line, "settlement block is missing a `region` line".to_string()))?;
+    let owning_faction = faction_creator.or_else(|| enclosing_faction.map(str::to_string)).ok_or_else(|| {
+        err(
+            start_line,
+            "settlement has no owning faction (no enclosing `faction` section and no `faction_creator` line)".to_string(),
+        )
+    })?;
+    let position = match (x, y) {
+        (Some(x), Some(y)) => Some(Position { x, y }),
+        _ => None,
+    };
+
+    Ok((
+        Settlement {
+            id: 0,
+            line_number: start_line.line_number,
+            owning_faction,
+            region,
+            display_name: None,
+            level,
+            position,
+            population,
+            year_founded,
+            plan_set,
+            buildings,
+            religion_percentages,
+            extra,
+        },
+        end_at - start + 1,
+    ))
+}
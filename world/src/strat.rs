@@ -0,0 +1,414 @@
+use std::ops::Range;
+
+use crate::lossless::LosslessDocument;
+use crate::text::{strip_comment, strip_keyword};
+
+/// A `descr_strat.txt` document kept line-for-line (see [`crate::lossless`])
+/// so a settlement or character block can be addressed by the identifier a
+/// mod author already knows -- a region name, a character name -- and
+/// mutated without disturbing anything else, the same approach
+/// [`crate::faction::FactionsDocument`] and [`crate::unit::EduDocument`] use
+/// for their files. Unlike those two, faction sections here nest settlement
+/// and character blocks inside them and one record can move to a different
+/// section, so instead of caching line ranges up front (which an edit would
+/// invalidate), every lookup re-scans the current document -- simple, and
+/// cheap enough for a file this size.
+pub struct StratDocument {
+    doc: LosslessDocument,
+}
+
+impl StratDocument {
+    pub fn parse(text: &str) -> Self {
+        StratDocument { doc: LosslessDocument::parse(text) }
+    }
+
+    /// Returns an editable handle to the settlement in `region` (matched
+    /// exactly, like [`crate::settlement::Settlement::region`]), or `None`
+    /// if no settlement block has that region.
+    pub fn settlement_mut(&mut self, region: &str) -> Option<SettlementRecordMut<'_>> {
+        find_settlement(&self.doc, region)?;
+        Some(SettlementRecordMut { document: self, region: region.to_string() })
+    }
+
+    /// Returns an editable handle to the first character named `name`
+    /// (matched exactly), or `None` if there's no such character. Two
+    /// starting characters sharing a name is rare enough not to disambiguate
+    /// further.
+    pub fn character_mut(&mut self, name: &str) -> Option<CharacterRecordMut<'_>> {
+        find_character(&self.doc, name)?;
+        Some(CharacterRecordMut { document: self, name: name.to_string() })
+    }
+
+    pub fn to_text(&self) -> String {
+        self.doc.to_text()
+    }
+}
+
+/// An editable handle to one settlement's block within a [`StratDocument`].
+/// Holds the whole document and the settlement's region name rather than a
+/// cached line range, since [`SettlementRecordMut::move_to_faction`] can
+/// relocate the block anywhere in the file.
+pub struct SettlementRecordMut<'a> {
+    document: &'a mut StratDocument,
+    region: String,
+}
+
+impl SettlementRecordMut<'_> {
+    /// Rewrites the settlement's `population` line in place, or appends one
+    /// just before the block's closing `}` if it doesn't have one yet.
+    pub fn set_population(&mut self, population: u32) -> bool {
+        let Some((_, span)) = find_settlement(&self.document.doc, &self.region) else { return false };
+        if let Some(i) = find_field_line(&self.document.doc, span.clone(), "population") {
+            rewrite_field_line(&mut self.document.doc, i, "population", &population.to_string());
+        } else {
+            let indent = inner_indent(&self.document.doc, &span);
+            self.document.doc.insert_line(span.end - 1, format!("{indent}population {population}"));
+        }
+        true
+    }
+
+    /// Appends a starting-garrison `unit` line just before the settlement
+    /// block's closing `}`. `fields` is everything after `unit ` verbatim,
+    /// e.g. `"england peasants exp 0 armour 0 weapon_upgrades 0 soldiers 100"`.
+    pub fn add_unit(&mut self, fields: &str) -> bool {
+        let Some((_, span)) = find_settlement(&self.document.doc, &self.region) else { return false };
+        let indent = inner_indent(&self.document.doc, &span);
+        self.document.doc.insert_line(span.end - 1, format!("{indent}unit {fields}"));
+        true
+    }
+
+    /// Removes the first `unit` line whose fields equal `fields` exactly
+    /// (comments and indentation aside). Returns whether a line was removed.
+    pub fn remove_unit(&mut self, fields: &str) -> bool {
+        let Some((_, span)) = find_settlement(&self.document.doc, &self.region) else { return false };
+        for i in span {
+            let content = strip_comment(self.document.doc.line(i)).trim();
+            if strip_keyword(content, "unit") == Some(fields) {
+                self.document.doc.remove_lines(i..i + 1);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Cuts the settlement's whole block out of its current faction section
+    /// and appends it to `faction`'s section. Returns whether the move
+    /// happened -- `false` if `faction` has no section of its own, or the
+    /// settlement is already there.
+    pub fn move_to_faction(&mut self, faction: &str) -> bool {
+        let Some((from_index, span)) = find_settlement(&self.document.doc, &self.region) else { return false };
+        let factions = faction_spans(&self.document.doc);
+        let Some(to_index) = factions.iter().position(|(name, _)| name == faction) else { return false };
+        if to_index == from_index {
+            return false;
+        }
+
+        let lines = self.document.doc.remove_lines(span);
+        let target = faction_spans(&self.document.doc).into_iter().find(|(name, _)| name == faction).unwrap().1;
+        append_block(&mut self.document.doc, &target, lines);
+        true
+    }
+}
+
+/// An editable handle to one character's block within a [`StratDocument`].
+pub struct CharacterRecordMut<'a> {
+    document: &'a mut StratDocument,
+    name: String,
+}
+
+impl CharacterRecordMut<'_> {
+    /// Rewrites the `x`/`y` fields on the character's header line. Returns
+    /// `false` without changing anything if the header has neither field,
+    /// since there's no established convention for where to insert a
+    /// position into a character that never had one.
+    pub fn set_position(&mut self, x: i32, y: i32) -> bool {
+        let Some((_, span)) = find_character(&self.document.doc, &self.name) else { return false };
+        let header = span.start;
+        let line = self.document.doc.line(header).to_string();
+        let content = strip_comment(&line);
+        let comment = &line[content.len()..];
+
+        let mut found = false;
+        let fields: Vec<String> = content
+            .split(',')
+            .map(|field| {
+                let trimmed = field.trim();
+                if strip_keyword(trimmed, "x").is_some() {
+                    found = true;
+                    " x ".to_string() + &x.to_string()
+                } else if strip_keyword(trimmed, "y").is_some() {
+                    found = true;
+                    " y ".to_string() + &y.to_string()
+                } else {
+                    field.to_string()
+                }
+            })
+            .collect();
+        if !found {
+            return false;
+        }
+
+        let new_line = format!("{}{comment}", fields.join(","));
+        self.document.doc.set_line(header, new_line);
+        true
+    }
+}
+
+/// Each top-level `faction NAME[, denari]` section's `[start, end)` line
+/// range, `start` being the `faction` line itself, in file order. Recomputed
+/// fresh from `doc` every time it's needed rather than cached, so it's never
+/// stale after an edit.
+fn faction_spans(doc: &LosslessDocument) -> Vec<(String, Range<usize>)> {
+    let mut spans = Vec::new();
+    let mut current: Option<(String, usize)> = None;
+    for i in 0..doc.line_count() {
+        let stripped = strip_comment(doc.line(i)).trim();
+        if let Some(rest) = strip_keyword(stripped, "faction") {
+            if let Some((name, start)) = current.take() {
+                spans.push((name, start..i));
+            }
+            let name = rest.split(',').next().unwrap_or(rest).trim().to_string();
+            current = Some((name, i));
+        }
+    }
+    if let Some((name, start)) = current {
+        spans.push((name, start..doc.line_count()));
+    }
+    spans
+}
+
+/// Finds the settlement block whose `region` line matches `region`, along
+/// with the index (into [`faction_spans`]) of the section it's in.
+fn find_settlement(doc: &LosslessDocument, region: &str) -> Option<(usize, Range<usize>)> {
+    for (index, (_, span)) in faction_spans(doc).into_iter().enumerate() {
+        let mut i = span.start + 1;
+        while i < span.end {
+            if strip_comment(doc.line(i)).split_whitespace().next() == Some("settlement") {
+                let end = brace_block_end(doc, i);
+                let is_match = (i..end).any(|j| find_field_value(doc, j, "region").as_deref() == Some(region));
+                if is_match {
+                    return Some((index, i..end));
+                }
+                i = end;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    None
+}
+
+/// Finds the character block headed `character NAME, ...`, from its header
+/// line through its `traits`/`ancillaries` lines and `army { ... }` block
+/// (whichever of those it has), along with which faction section it's in.
+fn find_character(doc: &LosslessDocument, name: &str) -> Option<(usize, Range<usize>)> {
+    for (index, (_, span)) in faction_spans(doc).into_iter().enumerate() {
+        let mut i = span.start + 1;
+        while i < span.end {
+            let stripped = strip_comment(doc.line(i)).trim();
+            let Some(rest) = strip_keyword(stripped, "character") else { i += 1; continue };
+
+            let this_name = rest.split(',').next().unwrap_or_default().trim();
+            let mut end = i + 1;
+            while end < span.end {
+                let next = strip_comment(doc.line(end)).trim();
+                if strip_keyword(next, "traits").is_some() || strip_keyword(next, "ancillaries").is_some() {
+                    end += 1;
+                } else if next.split_whitespace().next() == Some("army") {
+                    end = brace_block_end(doc, end);
+                    break;
+                } else {
+                    break;
+                }
+            }
+            if this_name == name {
+                return Some((index, i..end));
+            }
+            i = end;
+        }
+    }
+    None
+}
+
+/// Extent of the `{ ... }` block whose keyword line is `lines[start]` (the
+/// opening brace may trail the keyword or appear on a following line),
+/// returned as one past its closing `}`. Comments are stripped before
+/// counting braces.
+fn brace_block_end(doc: &LosslessDocument, start: usize) -> usize {
+    let mut open_at = start;
+    while !strip_comment(doc.line(open_at)).contains('{') {
+        open_at += 1;
+        if open_at >= doc.line_count() {
+            return doc.line_count();
+        }
+    }
+
+    let mut depth = 0i32;
+    for i in open_at..doc.line_count() {
+        let mut text = strip_comment(doc.line(i));
+        if i == open_at {
+            text = &text[text.find('{').unwrap() + 1..];
+            depth = 1;
+        }
+        depth += text.matches('{').count() as i32 - text.matches('}').count() as i32;
+        if depth <= 0 {
+            return i + 1;
+        }
+    }
+    doc.line_count()
+}
+
+fn find_field_value(doc: &LosslessDocument, line: usize, key: &str) -> Option<String> {
+    strip_keyword(strip_comment(doc.line(line)).trim(), key).map(str::trim).map(str::to_string)
+}
+
+fn find_field_line(doc: &LosslessDocument, span: Range<usize>, key: &str) -> Option<usize> {
+    span.into_iter().find(|&i| find_field_value(doc, i, key).is_some())
+}
+
+/// Rewrites `key`'s `key value` line at `line`, keeping its indentation and
+/// any trailing inline comment -- same split
+/// [`crate::faction::FactionRecordMut::set_field`] uses.
+fn rewrite_field_line(doc: &mut LosslessDocument, line: usize, key: &str, value: &str) {
+    let text = doc.line(line);
+    let content = strip_comment(text);
+    let comment = &text[content.len()..];
+    let indent = &content[..content.len() - content.trim_start().len()];
+    let new_line = if comment.is_empty() { format!("{indent}{key} {value}") } else { format!("{indent}{key} {value}{comment}") };
+    doc.set_line(line, new_line);
+}
+
+/// Indentation to use for a new line inside `span`, taken from the first
+/// non-brace inner line if there is one, or two tabs otherwise.
+fn inner_indent(doc: &LosslessDocument, span: &Range<usize>) -> String {
+    for i in span.clone().skip(1) {
+        let content = strip_comment(doc.line(i));
+        if !content.trim().is_empty() && !content.contains('{') && !content.contains('}') {
+            return content[..content.len() - content.trim_start().len()].to_string();
+        }
+    }
+    "\t\t".to_string()
+}
+
+/// Appends `lines` to the end of `span`, preceded by a blank line, matching
+/// [`crate::faction::FactionsDocument::insert_from_template`]'s style for
+/// growing a section.
+fn append_block(doc: &mut LosslessDocument, span: &Range<usize>, lines: Vec<String>) {
+    let mut insert_at = span.end;
+    while insert_at > span.start + 1 && doc.line(insert_at - 1).trim().is_empty() {
+        insert_at -= 1;
+    }
+    doc.insert_line(insert_at, String::new());
+    for (offset, line) in lines.into_iter().enumerate() {
+        doc.insert_line(insert_at + 1 + offset, line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> String {
+        "faction england, 5000\n\
+\tdenari 5000\n\
+\tsettlement\n\
+\t{\n\
+\t\tlevel city\n\
+\t\tregion wessex\n\
+\t\tpopulation 4500\n\
+\t}\n\
+\tcharacter Edward, general, age 25, x 3, y 3, leader\n\
+\t\ttraits GoodCommander 1\n\
+\t\tarmy\n\
+\t\t{\n\
+\t\t\tunit england peasants exp 0 armour 0 weapon_upgrades 0 soldiers 100\n\
+\t\t}\n\
+\n\
+faction france, 5000\n\
+\tdenari 5000\n\
+\tsettlement\n\
+\t{\n\
+\t\tlevel town\n\
+\t\tregion normandy\n\
+\t}\n"
+            .to_string()
+    }
+
+    #[test]
+    fn unedited_round_trips_byte_identical() {
+        let text = sample();
+        assert_eq!(StratDocument::parse(&text).to_text(), text);
+    }
+
+    #[test]
+    fn set_population_rewrites_an_existing_line() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(doc.settlement_mut("wessex").unwrap().set_population(6000));
+        assert!(doc.to_text().contains("\t\tpopulation 6000\n"));
+        assert!(!doc.to_text().contains("population 4500"));
+    }
+
+    #[test]
+    fn set_population_appends_a_missing_line() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(doc.settlement_mut("normandy").unwrap().set_population(2000));
+        assert!(doc.to_text().contains("\t\tpopulation 2000\n\t}\n"));
+    }
+
+    #[test]
+    fn settlement_mut_returns_none_for_an_unknown_region() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(doc.settlement_mut("brittany").is_none());
+    }
+
+    #[test]
+    fn add_and_remove_unit_round_trip() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(doc.settlement_mut("normandy").unwrap().add_unit("france militia_sergeants exp 0 armour 0 weapon_upgrades 0 soldiers 60"));
+        assert!(doc.to_text().contains("unit france militia_sergeants exp 0 armour 0 weapon_upgrades 0 soldiers 60"));
+
+        assert!(doc
+            .settlement_mut("normandy")
+            .unwrap()
+            .remove_unit("france militia_sergeants exp 0 armour 0 weapon_upgrades 0 soldiers 60"));
+        assert!(!doc.to_text().contains("militia_sergeants"));
+    }
+
+    #[test]
+    fn remove_unit_returns_false_when_no_line_matches() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(!doc.settlement_mut("wessex").unwrap().remove_unit("france peasants"));
+    }
+
+    #[test]
+    fn move_to_faction_relocates_the_whole_block() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(doc.settlement_mut("wessex").unwrap().move_to_faction("france"));
+
+        let text = doc.to_text();
+        let england_end = text.find("faction france").unwrap();
+        assert!(!text[..england_end].contains("region wessex"));
+        assert!(text[england_end..].contains("region wessex"));
+        assert!(text.contains("population 4500"));
+    }
+
+    #[test]
+    fn move_to_faction_is_a_no_op_for_an_unknown_target() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(!doc.settlement_mut("wessex").unwrap().move_to_faction("scotland"));
+        assert_eq!(doc.to_text(), sample());
+    }
+
+    #[test]
+    fn character_set_position_rewrites_x_and_y() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(doc.character_mut("Edward").unwrap().set_position(10, 12));
+        assert!(doc.to_text().contains("character Edward, general, age 25, x 10, y 12, leader\n"));
+    }
+
+    #[test]
+    fn character_mut_returns_none_for_an_unknown_name() {
+        let mut doc = StratDocument::parse(&sample());
+        assert!(doc.character_mut("Nobody").is_none());
+    }
+}
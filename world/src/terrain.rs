@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result};
+use crate::text::{parse_descr_lines, strip_keyword, DescrLine, ReadOptions};
+
+/// The campaign map's dimensions, from descr_terrain.txt's `[dimensions]`
+/// block. Every map image (`map_regions.tga`, the heightmap, ...) must
+/// agree with these, or the game silently crashes on load; see
+/// `validate::validate`'s map-size check.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct TerrainInfo {
+    /// 1-based line the `[dimensions]` block started on, for pointing a
+    /// mismatch back at the file a modder would actually edit.
+    pub line_number: usize,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl TerrainInfo {
+    pub fn load(path: &Path, text: &str) -> Result<TerrainInfo> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let err = |line: &DescrLine, message: String| -> crate::error::WorldError {
+            ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+        };
+        let eof_err = |message: String| -> crate::error::WorldError {
+            ParseError { file: path.to_path_buf(), line_number: lines.len(), line_text: String::new(), message }.into()
+        };
+
+        let mut block_line = None;
+        let mut width = None;
+        let mut height = None;
+        for line in &lines {
+            if line.text.trim() == "[dimensions]" {
+                block_line.get_or_insert(line.line_number);
+                continue;
+            }
+            if let Some(rest) = strip_keyword(&line.text, "width") {
+                let value = rest.trim();
+                width = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid width")))?);
+                block_line.get_or_insert(line.line_number);
+            } else if let Some(rest) = strip_keyword(&line.text, "height") {
+                let value = rest.trim();
+                height = Some(value.parse().map_err(|_| err(line, format!("{value:?} is not a valid height")))?);
+                block_line.get_or_insert(line.line_number);
+            }
+        }
+
+        Ok(TerrainInfo {
+            line_number: block_line.ok_or_else(|| eof_err("missing a [dimensions] block".to_string()))?,
+            width: width.ok_or_else(|| eof_err("[dimensions] block is missing a width".to_string()))?,
+            height: height.ok_or_else(|| eof_err("[dimensions] block is missing a height".to_string()))?,
+        })
+    }
+}
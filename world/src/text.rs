@@ -0,0 +1,286 @@
+use std::path::Path;
+
+use crate::error::{Result, WorldError};
+
+/// One logical line from a data file after BOM stripping, comment removal,
+/// and whitespace trimming, paired with its original 1-based line number so
+/// callers can still produce precise `ParseError`s.
+#[derive(Debug, Clone)]
+pub struct DescrLine {
+    pub line_number: usize,
+    pub text: String,
+}
+
+/// Controls how [`read_descr_lines`] processes a data file's lines.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOptions {
+    /// Drop lines that are empty after comment-stripping and trimming.
+    pub drop_blank_lines: bool,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions { drop_blank_lines: true }
+    }
+}
+
+/// Strips a leading `keyword` token from `text`, requiring it to be
+/// followed by whitespace or end-of-string (so `"characters"` doesn't
+/// falsely match the keyword `"character"`), and trims the whitespace that
+/// separated them. Vanilla and modded files mix single spaces and tabs
+/// after a keyword, so this doesn't assume either.
+pub(crate) fn strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = text.strip_prefix(keyword)?;
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+/// Read a data file as a `String`, transcoding it to UTF-8 first if it's
+/// UTF-16 (BOM-prefixed, or BOM-less but null-byte patterns give it away —
+/// `data/text/` and mod files saved by Windows editors are often UTF-16LE).
+/// Callers that need comment/blank-line handling too should use
+/// [`read_descr_lines`] instead.
+pub fn load_text_file(path: &Path) -> Result<String> {
+    if !path.exists() {
+        return Err(WorldError::MissingFile(path.to_path_buf()));
+    }
+    let bytes = std::fs::read(path).map_err(|e| crate::error::io_err(path, e))?;
+    decode_text(&bytes, path)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+}
+
+/// Detects `bytes`' encoding and how many leading bytes are its BOM (0 if
+/// none). Falls back to a heuristic for BOM-less UTF-16: game data and
+/// ANSI/UTF-8 text essentially never contains NUL bytes, while UTF-16
+/// encoding of mostly-ASCII content puts a NUL in every other byte.
+fn detect_encoding(bytes: &[u8]) -> (TextEncoding, usize) {
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        return (TextEncoding::Utf8, 3);
+    }
+    if bytes.starts_with(&[0xFF, 0xFE]) {
+        return (TextEncoding::Utf16Le, 2);
+    }
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        return (TextEncoding::Utf16Be, 2);
+    }
+
+    let sample = &bytes[..bytes.len().min(400)];
+    let evens = sample.len() / 2;
+    let odds = sample.len() - evens;
+    let even_zeros = sample.iter().step_by(2).filter(|&&b| b == 0).count();
+    let odd_zeros = sample.iter().skip(1).step_by(2).filter(|&&b| b == 0).count();
+    if evens > 0 && odds > 0 {
+        if odd_zeros * 2 > odds {
+            return (TextEncoding::Utf16Le, 0);
+        }
+        if even_zeros * 2 > evens {
+            return (TextEncoding::Utf16Be, 0);
+        }
+    }
+    (TextEncoding::Utf8, 0)
+}
+
+/// Transcodes `bytes` to a UTF-8 `String`, detecting the source encoding
+/// first. `path` is only used to name the file in a [`WorldError::Decode`].
+/// `pub(crate)` so [`crate::config::Config`] can decode bytes it read
+/// straight out of a pack entry, not just a file on disk.
+pub(crate) fn decode_text(bytes: &[u8], path: &Path) -> Result<String> {
+    let (encoding, bom_len) = detect_encoding(bytes);
+    let body = &bytes[bom_len..];
+    match encoding {
+        TextEncoding::Utf8 => {
+            String::from_utf8(body.to_vec()).map_err(|_| WorldError::Decode { path: path.to_path_buf(), encoding: "UTF-8" })
+        }
+        TextEncoding::Utf16Le => decode_utf16(body, u16::from_le_bytes, path, "UTF-16LE"),
+        TextEncoding::Utf16Be => decode_utf16(body, u16::from_be_bytes, path, "UTF-16BE"),
+    }
+}
+
+fn decode_utf16(body: &[u8], from_bytes: fn([u8; 2]) -> u16, path: &Path, encoding: &'static str) -> Result<String> {
+    let mut chunks = body.chunks_exact(2);
+    let units = chunks.by_ref().map(|chunk| from_bytes([chunk[0], chunk[1]])).collect::<Vec<_>>();
+    if !chunks.remainder().is_empty() {
+        return Err(WorldError::Decode { path: path.to_path_buf(), encoding });
+    }
+    char::decode_utf16(units)
+        .collect::<std::result::Result<String, _>>()
+        .map_err(|_| WorldError::Decode { path: path.to_path_buf(), encoding })
+}
+
+/// Reads `path` as [`DescrLine`]s: BOM stripped, `;` comments removed (a
+/// `;` inside a `"..."` quoted string doesn't start one), and each line
+/// trimmed of leading and trailing whitespace. CRLF and LF endings are both
+/// handled. Blank lines are dropped unless `options.drop_blank_lines` is
+/// false.
+pub fn read_descr_lines(path: &Path, options: ReadOptions) -> Result<Vec<DescrLine>> {
+    let text = load_text_file(path)?;
+    Ok(parse_descr_lines(&text, options))
+}
+
+pub(crate) fn parse_descr_lines(text: &str, options: ReadOptions) -> Vec<DescrLine> {
+    text.lines()
+        .enumerate()
+        .map(|(index, raw_line)| DescrLine {
+            line_number: index + 1,
+            text: strip_comment(raw_line).trim().to_string(),
+        })
+        .filter(|line| !options.drop_blank_lines || !line.text.is_empty())
+        .collect()
+}
+
+/// Removes a `;` comment from `line`, stopping at the first `;` that isn't
+/// inside a `"..."` quoted string. `pub(crate)` so [`crate::lossless`] can
+/// split a raw line into its content and comment (`&line[content.len()..]`)
+/// instead of re-implementing the quote-aware scan.
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut in_quotes = false;
+    for (idx, ch) in line.char_indices() {
+        match ch {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(text: &str, options: ReadOptions) -> Vec<String> {
+        parse_descr_lines(text, options).into_iter().map(|l| l.text).collect()
+    }
+
+    #[test]
+    fn strips_trailing_comments() {
+        let text = "england; the english\nscotland\n";
+        assert_eq!(texts(text, ReadOptions::default()), vec!["england", "scotland"]);
+    }
+
+    #[test]
+    fn comment_only_lines_are_dropped_by_default() {
+        let text = "; a whole-line comment\nengland\n";
+        assert_eq!(texts(text, ReadOptions::default()), vec!["england"]);
+    }
+
+    #[test]
+    fn comment_only_lines_can_be_kept_as_blank() {
+        let text = "; a whole-line comment\nengland\n";
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].line_number, 1);
+        assert_eq!(lines[0].text, "");
+        assert_eq!(lines[1].line_number, 2);
+        assert_eq!(lines[1].text, "england");
+    }
+
+    #[test]
+    fn semicolon_inside_a_quoted_string_does_not_start_a_comment() {
+        let text = "banner \"path/to;banner.tga\" ; the banner\n";
+        assert_eq!(texts(text, ReadOptions::default()), vec!["banner \"path/to;banner.tga\""]);
+    }
+
+    #[test]
+    fn line_numbers_survive_dropped_blank_and_comment_lines() {
+        let text = "\n; comment\nengland\n\nscotland\n";
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        assert_eq!(lines.iter().map(|l| l.line_number).collect::<Vec<_>>(), vec![3, 5]);
+    }
+
+    #[test]
+    fn crlf_line_endings_are_handled() {
+        let text = "england\r\nscotland\r\n";
+        assert_eq!(texts(text, ReadOptions::default()), vec!["england", "scotland"]);
+    }
+
+    #[test]
+    fn stray_tabs_and_surrounding_whitespace_are_trimmed() {
+        let text = "\t  england\t\t\n";
+        assert_eq!(texts(text, ReadOptions::default()), vec!["england"]);
+    }
+
+    fn utf16le_bytes(text: &str, with_bom: bool) -> Vec<u8> {
+        let mut bytes = if with_bom { vec![0xFF, 0xFE] } else { Vec::new() };
+        bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes
+    }
+
+    fn utf16be_bytes(text: &str, with_bom: bool) -> Vec<u8> {
+        let mut bytes = if with_bom { vec![0xFE, 0xFF] } else { Vec::new() };
+        bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes));
+        bytes
+    }
+
+    #[test]
+    fn utf8_bom_is_stripped() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice(b"england\nscotland\n");
+        let decoded = decode_text(&bytes, Path::new("descr.txt")).unwrap();
+        assert_eq!(decoded, "england\nscotland\n");
+    }
+
+    #[test]
+    fn utf16le_with_bom_decodes() {
+        let bytes = utf16le_bytes("england\nscotland\n", true);
+        let decoded = decode_text(&bytes, Path::new("descr.txt")).unwrap();
+        assert_eq!(decoded, "england\nscotland\n");
+    }
+
+    #[test]
+    fn utf16be_with_bom_decodes() {
+        let bytes = utf16be_bytes("england\nscotland\n", true);
+        let decoded = decode_text(&bytes, Path::new("descr.txt")).unwrap();
+        assert_eq!(decoded, "england\nscotland\n");
+    }
+
+    #[test]
+    fn bomless_utf16le_is_detected_by_null_byte_heuristic() {
+        let bytes = utf16le_bytes("england\nscotland\n", false);
+        let decoded = decode_text(&bytes, Path::new("descr.txt")).unwrap();
+        assert_eq!(decoded, "england\nscotland\n");
+    }
+
+    #[test]
+    fn malformed_utf16_reports_path_and_encoding() {
+        // An odd number of trailing bytes can't be a whole UTF-16 code unit.
+        let mut bytes = utf16le_bytes("england", true);
+        bytes.push(0x41);
+        let err = decode_text(&bytes, Path::new("descr_sm_factions.txt")).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("descr_sm_factions.txt"), "{message}");
+        assert!(message.contains("UTF-16LE"), "{message}");
+    }
+
+    #[test]
+    fn utf8_and_utf16le_fixtures_of_the_same_file_parse_identically() {
+        let source = "religions\n{\n    catholic\n    orthodox\n}\n";
+        let dir = std::env::temp_dir().join("world_text_encoding_test");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let utf8_path = dir.join("descr_religions_utf8.txt");
+        std::fs::write(&utf8_path, source).unwrap();
+
+        let utf16_path = dir.join("descr_religions_utf16le.txt");
+        std::fs::write(&utf16_path, utf16le_bytes(source, true)).unwrap();
+
+        let utf8_lines = read_descr_lines(&utf8_path, ReadOptions::default()).unwrap();
+        let utf16_lines = read_descr_lines(&utf16_path, ReadOptions::default()).unwrap();
+
+        std::fs::remove_file(&utf8_path).ok();
+        std::fs::remove_file(&utf16_path).ok();
+
+        let utf8_texts: Vec<_> = utf8_lines.iter().map(|l| (l.line_number, l.text.clone())).collect();
+        let utf16_texts: Vec<_> = utf16_lines.iter().map(|l| (l.line_number, l.text.clone())).collect();
+        assert_eq!(utf8_texts, utf16_texts);
+    }
+}
@@ -0,0 +1,245 @@
+use std::path::Path;
+
+use crate::error::{Result, WorldError};
+use crate::faction::Rgb;
+
+const HEADER_LEN: usize = 18;
+const IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR: u8 = 2;
+const IMAGE_TYPE_RLE_TRUECOLOR: u8 = 10;
+/// Bit 5 of the image descriptor byte: set means the first pixel is the
+/// top-left corner, clear means bottom-left (the TGA default).
+const DESCRIPTOR_TOP_LEFT_ORIGIN: u8 = 0x20;
+
+/// A decoded truecolor TGA image (24 or 32 bit, uncompressed or
+/// run-length-encoded). Other map files (`map.tga`, `map_heights.tga`, ...)
+/// share this format, so the decoder doesn't know anything about regions.
+#[derive(Debug, Clone)]
+pub struct TgaImage {
+    width: u32,
+    height: u32,
+    /// Row-major, top row first, left-to-right. Alpha is discarded since
+    /// none of this codebase's map files use it.
+    pixels: Vec<Rgb>,
+}
+
+impl TgaImage {
+    pub fn load(path: &Path) -> Result<TgaImage> {
+        let bytes = std::fs::read(path).map_err(|e| crate::error::io_err(path, e))?;
+        decode(&bytes, path)
+    }
+
+    /// Reads just the width/height out of a TGA header, without decoding any
+    /// pixel data. Used by size cross-checks that don't need the image
+    /// contents and would rather not pull a multi-megabyte map into memory.
+    pub fn read_dimensions(path: &Path) -> Result<(u32, u32)> {
+        use std::io::Read;
+        let mut file = std::fs::File::open(path).map_err(|e| crate::error::io_err(path, e))?;
+        let mut header = [0u8; HEADER_LEN];
+        file.read_exact(&mut header).map_err(|e| crate::error::io_err(path, e))?;
+        Ok((u16::from_le_bytes([header[12], header[13]]) as u32, u16::from_le_bytes([header[14], header[15]]) as u32))
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Rgb> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.pixels.get((y * self.width + x) as usize).copied()
+    }
+
+    pub fn pixels(&self) -> impl Iterator<Item = (u32, u32, Rgb)> + '_ {
+        let width = self.width;
+        self.pixels.iter().enumerate().map(move |(i, &colour)| ((i as u32) % width, (i as u32) / width, colour))
+    }
+}
+
+fn tga_err(path: &Path, message: impl Into<String>) -> WorldError {
+    WorldError::Tga { path: path.to_path_buf(), message: message.into() }
+}
+
+fn decode(bytes: &[u8], path: &Path) -> Result<TgaImage> {
+    if bytes.len() < HEADER_LEN {
+        return Err(tga_err(path, "file is shorter than a TGA header"));
+    }
+
+    let id_length = bytes[0] as usize;
+    let image_type = bytes[2];
+    let width = u16::from_le_bytes([bytes[12], bytes[13]]) as u32;
+    let height = u16::from_le_bytes([bytes[14], bytes[15]]) as u32;
+    let bits_per_pixel = bytes[16];
+    let descriptor = bytes[17];
+
+    if bits_per_pixel != 24 && bits_per_pixel != 32 {
+        return Err(tga_err(path, format!("unsupported bit depth {bits_per_pixel} (only 24/32-bit truecolor is supported)")));
+    }
+    let bytes_per_pixel = (bits_per_pixel / 8) as usize;
+
+    let pixel_data = bytes
+        .get(HEADER_LEN + id_length..)
+        .ok_or_else(|| tga_err(path, "file is truncated before its pixel data"))?;
+
+    let pixel_count = width as usize * height as usize;
+    let mut pixels = Vec::with_capacity(pixel_count);
+    match image_type {
+        IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR => decode_uncompressed(pixel_data, bytes_per_pixel, pixel_count, path, &mut pixels)?,
+        IMAGE_TYPE_RLE_TRUECOLOR => decode_rle(pixel_data, bytes_per_pixel, pixel_count, path, &mut pixels)?,
+        other => {
+            return Err(tga_err(path, format!("unsupported TGA image type {other} (only uncompressed/RLE truecolor is supported)")))
+        }
+    }
+
+    if descriptor & DESCRIPTOR_TOP_LEFT_ORIGIN == 0 {
+        flip_vertically(&mut pixels, width as usize, height as usize);
+    }
+
+    Ok(TgaImage { width, height, pixels })
+}
+
+fn read_bgr(chunk: &[u8]) -> Rgb {
+    Rgb { r: chunk[2], g: chunk[1], b: chunk[0] }
+}
+
+fn decode_uncompressed(data: &[u8], bytes_per_pixel: usize, pixel_count: usize, path: &Path, pixels: &mut Vec<Rgb>) -> Result<()> {
+    let needed = pixel_count * bytes_per_pixel;
+    let data = data.get(..needed).ok_or_else(|| tga_err(path, "pixel data is shorter than width*height requires"))?;
+    for chunk in data.chunks_exact(bytes_per_pixel) {
+        pixels.push(read_bgr(chunk));
+    }
+    Ok(())
+}
+
+fn decode_rle(data: &[u8], bytes_per_pixel: usize, pixel_count: usize, path: &Path, pixels: &mut Vec<Rgb>) -> Result<()> {
+    let mut i = 0;
+    while pixels.len() < pixel_count {
+        let header = *data.get(i).ok_or_else(|| tga_err(path, "RLE stream ended before width*height pixels were decoded"))?;
+        i += 1;
+        let count = (header & 0x7F) as usize + 1;
+        if header & 0x80 != 0 {
+            let chunk = data.get(i..i + bytes_per_pixel).ok_or_else(|| tga_err(path, "RLE run packet is truncated"))?;
+            let colour = read_bgr(chunk);
+            i += bytes_per_pixel;
+            for _ in 0..count {
+                pixels.push(colour);
+            }
+        } else {
+            let chunk = data.get(i..i + count * bytes_per_pixel).ok_or_else(|| tga_err(path, "RLE raw packet is truncated"))?;
+            for c in chunk.chunks_exact(bytes_per_pixel) {
+                pixels.push(read_bgr(c));
+            }
+            i += count * bytes_per_pixel;
+        }
+    }
+    pixels.truncate(pixel_count);
+    Ok(())
+}
+
+fn flip_vertically(pixels: &mut [Rgb], width: usize, height: usize) {
+    for y in 0..height / 2 {
+        let top = y * width;
+        let bottom = (height - 1 - y) * width;
+        for x in 0..width {
+            pixels.swap(top + x, bottom + x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn header(image_type: u8, width: u16, height: u16, bits_per_pixel: u8, descriptor: u8) -> Vec<u8> {
+        let mut header = vec![0u8; HEADER_LEN];
+        header[2] = image_type;
+        header[12..14].copy_from_slice(&width.to_le_bytes());
+        header[14..16].copy_from_slice(&height.to_le_bytes());
+        header[16] = bits_per_pixel;
+        header[17] = descriptor;
+        header
+    }
+
+    #[test]
+    fn uncompressed_24_bit_top_left_origin() {
+        let mut bytes = header(IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR, 2, 1, 24, DESCRIPTOR_TOP_LEFT_ORIGIN);
+        bytes.extend_from_slice(&[0, 0, 255]); // red pixel, stored BGR
+        bytes.extend_from_slice(&[255, 0, 0]); // blue pixel
+        let image = decode(&bytes, Path::new("test.tga")).unwrap();
+        assert_eq!(image.width(), 2);
+        assert_eq!(image.height(), 1);
+        assert_eq!(image.pixel(0, 0), Some(Rgb { r: 255, g: 0, b: 0 }));
+        assert_eq!(image.pixel(1, 0), Some(Rgb { r: 0, g: 0, b: 255 }));
+    }
+
+    #[test]
+    fn uncompressed_32_bit_discards_alpha() {
+        let mut bytes = header(IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR, 1, 1, 32, DESCRIPTOR_TOP_LEFT_ORIGIN);
+        bytes.extend_from_slice(&[10, 20, 30, 128]);
+        let image = decode(&bytes, Path::new("test.tga")).unwrap();
+        assert_eq!(image.pixel(0, 0), Some(Rgb { r: 30, g: 20, b: 10 }));
+    }
+
+    #[test]
+    fn bottom_left_origin_is_flipped_to_top_down() {
+        // 1x2 image; bottom-left origin means the first pixel in the file
+        // is row 1 (the bottom row), so it should end up at (0, 1) after
+        // decoding.
+        let mut bytes = header(IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR, 1, 2, 24, 0);
+        bytes.extend_from_slice(&[0, 0, 255]); // bottom row: red
+        bytes.extend_from_slice(&[255, 0, 0]); // top row: blue
+        let image = decode(&bytes, Path::new("test.tga")).unwrap();
+        assert_eq!(image.pixel(0, 0), Some(Rgb { r: 0, g: 0, b: 255 }));
+        assert_eq!(image.pixel(0, 1), Some(Rgb { r: 255, g: 0, b: 0 }));
+    }
+
+    #[test]
+    fn rle_run_and_raw_packets() {
+        let mut bytes = header(IMAGE_TYPE_RLE_TRUECOLOR, 4, 1, 24, DESCRIPTOR_TOP_LEFT_ORIGIN);
+        // Run packet: 3 repeats of green.
+        bytes.push(0x80 | 2);
+        bytes.extend_from_slice(&[0, 255, 0]);
+        // Raw packet: 1 pixel, red.
+        bytes.push(0);
+        bytes.extend_from_slice(&[0, 0, 255]);
+        let image = decode(&bytes, Path::new("test.tga")).unwrap();
+        assert_eq!(image.pixel(0, 0), Some(Rgb { r: 0, g: 255, b: 0 }));
+        assert_eq!(image.pixel(1, 0), Some(Rgb { r: 0, g: 255, b: 0 }));
+        assert_eq!(image.pixel(2, 0), Some(Rgb { r: 0, g: 255, b: 0 }));
+        assert_eq!(image.pixel(3, 0), Some(Rgb { r: 255, g: 0, b: 0 }));
+    }
+
+    #[test]
+    fn unsupported_bit_depth_is_rejected() {
+        let bytes = header(IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR, 1, 1, 8, 0);
+        let err = decode(&bytes, Path::new("test.tga")).unwrap_err();
+        assert!(err.to_string().contains("unsupported bit depth"));
+    }
+
+    #[test]
+    fn truncated_pixel_data_is_rejected() {
+        let bytes = header(IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR, 2, 2, 24, DESCRIPTOR_TOP_LEFT_ORIGIN);
+        let err = decode(&bytes, Path::new("test.tga")).unwrap_err();
+        assert!(err.to_string().contains("shorter than width*height"));
+    }
+
+    #[test]
+    fn read_dimensions_does_not_need_pixel_data() {
+        let mut bytes = header(IMAGE_TYPE_UNCOMPRESSED_TRUECOLOR, 40, 60, 24, DESCRIPTOR_TOP_LEFT_ORIGIN);
+        bytes.truncate(HEADER_LEN); // no pixel data at all
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("tga_dimensions_test_{id}.tga"));
+        std::fs::write(&path, &bytes).unwrap();
+        let dimensions = TgaImage::read_dimensions(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(dimensions, (40, 60));
+    }
+}
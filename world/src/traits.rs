@@ -0,0 +1,249 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::text::{parse_descr_lines, strip_keyword, DescrLine, ReadOptions};
+use crate::trigger::{self, Trigger};
+
+/// A `Trait` record from `export_descr_character_traits.txt`: the character
+/// types it can apply to, the trait(s) it's mutually exclusive with, and its
+/// levels (each with its own effects).
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Trait {
+    /// 0-based position among successfully parsed traits, in file order.
+    pub id: usize,
+    /// 1-based line the `Trait` header started on.
+    pub line_number: usize,
+    pub name: String,
+    pub characters: Vec<String>,
+    pub antitraits: Vec<String>,
+    pub levels: Vec<TraitLevel>,
+}
+
+/// One `Level` block inside a [`Trait`].
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct TraitLevel {
+    /// 1-based line the `Level` line started on.
+    pub line_number: usize,
+    pub name: String,
+    pub description: Option<String>,
+    pub effects_description: Option<String>,
+    pub threshold: Option<i32>,
+    /// `Effect ATTRIBUTE VALUE` lines, kept verbatim (e.g. `"Command 1"`) --
+    /// there's no established attribute enum in this codebase to parse
+    /// them into.
+    pub effects: Vec<String>,
+}
+
+/// A recoverable problem hit while parsing `export_descr_character_traits.txt`.
+/// This is the biggest, most mod-mangled text file in the game -- missing
+/// separators between records, duplicate trait names, stray copy-pasted
+/// lines -- so a single bad line shouldn't sink the whole load the way
+/// [`crate::error::ParseError`] does elsewhere. The offending line is
+/// skipped and logged here instead, and parsing continues.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TraitProblem {
+    pub line_number: usize,
+    pub message: String,
+}
+
+/// The parsed contents of `export_descr_character_traits.txt`: every
+/// `Trait` and `Trigger` record that parsed cleanly, plus a log of anything
+/// that didn't (see [`TraitProblem`]).
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct TraitsFile {
+    pub traits: Vec<Trait>,
+    pub triggers: Vec<Trigger>,
+    pub problems: Vec<TraitProblem>,
+}
+
+impl TraitsFile {
+    pub fn load(_path: &Path, text: &str) -> Result<TraitsFile> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        Ok(parse(&lines))
+    }
+}
+
+enum Current {
+    Trait(Trait),
+    Trigger(Trigger),
+}
+
+fn parse(lines: &[DescrLine]) -> TraitsFile {
+    let mut file = TraitsFile::default();
+    let mut current: Option<Current> = None;
+    let mut current_level: Option<usize> = None;
+
+    for line in lines {
+        let text = line.text.as_str();
+
+        if let Some(rest) = strip_keyword(text, "Trait") {
+            finish(&mut current, &mut file);
+            current_level = None;
+            current = Some(Current::Trait(Trait {
+                id: 0,
+                line_number: line.line_number,
+                name: rest.trim().to_string(),
+                characters: Vec::new(),
+                antitraits: Vec::new(),
+                levels: Vec::new(),
+            }));
+            continue;
+        }
+        if let Some(trigger) = trigger::try_start_trigger(text, line.line_number) {
+            finish(&mut current, &mut file);
+            current_level = None;
+            current = Some(Current::Trigger(trigger));
+            continue;
+        }
+
+        match &mut current {
+            Some(Current::Trait(t)) => {
+                if let Some(rest) = strip_keyword(text, "Level") {
+                    t.levels.push(TraitLevel { line_number: line.line_number, name: rest.trim().to_string(), ..TraitLevel::default() });
+                    current_level = Some(t.levels.len() - 1);
+                } else if let Some(rest) = strip_keyword(text, "Characters") {
+                    t.characters = split_list(rest);
+                } else if let Some(rest) = strip_keyword(text, "AntiTraits") {
+                    t.antitraits = split_list(rest);
+                } else if let Some(level) = current_level.and_then(|i| t.levels.get_mut(i)) {
+                    if let Some(rest) = strip_keyword(text, "Description") {
+                        level.description = Some(rest.trim().to_string());
+                    } else if let Some(rest) = strip_keyword(text, "EffectsDescription") {
+                        level.effects_description = Some(rest.trim().to_string());
+                    } else if let Some(rest) = strip_keyword(text, "Threshold") {
+                        match rest.trim().parse() {
+                            Ok(value) => level.threshold = Some(value),
+                            Err(_) => file.problems.push(TraitProblem {
+                                line_number: line.line_number,
+                                message: format!("{:?} is not a valid Threshold", rest.trim()),
+                            }),
+                        }
+                    } else if let Some(rest) = strip_keyword(text, "Effect") {
+                        level.effects.push(rest.trim().to_string());
+                    } else {
+                        file.problems.push(TraitProblem {
+                            line_number: line.line_number,
+                            message: format!("unrecognized line {text:?} in trait {:?}", t.name),
+                        });
+                    }
+                } else {
+                    file.problems.push(TraitProblem {
+                        line_number: line.line_number,
+                        message: format!("line {text:?} appears before any `Level` in trait {:?}", t.name),
+                    });
+                }
+            }
+            Some(Current::Trigger(trigger)) => {
+                if let Err(message) = trigger::parse_trigger_line(trigger, text, line.line_number) {
+                    file.problems.push(TraitProblem { line_number: line.line_number, message });
+                }
+            }
+            None => {
+                file.problems.push(TraitProblem { line_number: line.line_number, message: format!("line {text:?} appears before any `Trait` or `Trigger`") });
+            }
+        }
+    }
+    finish(&mut current, &mut file);
+
+    for (id, t) in file.traits.iter_mut().enumerate() {
+        t.id = id;
+    }
+    for (id, trigger) in file.triggers.iter_mut().enumerate() {
+        trigger.id = id;
+    }
+
+    file
+}
+
+fn finish(current: &mut Option<Current>, file: &mut TraitsFile) {
+    match current.take() {
+        Some(Current::Trait(t)) => file.traits.push(t),
+        Some(Current::Trigger(trigger)) => file.triggers.push(trigger),
+        None => {}
+    }
+}
+
+fn split_list(rest: &str) -> Vec<String> {
+    rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn parse_text(text: &str) -> TraitsFile {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("export_descr_character_traits_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        parse(&lines)
+    }
+
+    #[test]
+    fn parses_trait_levels_and_trigger_affects() {
+        let file = parse_text(
+            r#"
+Trait GoodCommander
+Characters family, general
+AntiTraits BadCommander
+Level GoodCommander1
+Description trait_desc
+EffectsDescription trait_effects_desc
+Threshold 1
+Effect Command 1
+Effect Influence 1
+
+Trigger WonBattleTrigger
+WhenToTest CharacterTurnEnd
+Condition WonBattle = 1
+Affects GoodCommander 1 Chance 50
+"#,
+        );
+
+        assert_eq!(file.problems, Vec::<TraitProblem>::new(), "{:?}", file.problems);
+        assert_eq!(file.traits.len(), 1);
+        let trait_ = &file.traits[0];
+        assert_eq!(trait_.name, "GoodCommander");
+        assert_eq!(trait_.characters, vec!["family", "general"]);
+        assert_eq!(trait_.antitraits, vec!["BadCommander"]);
+        assert_eq!(trait_.levels.len(), 1);
+        assert_eq!(trait_.levels[0].threshold, Some(1));
+        assert_eq!(trait_.levels[0].effects, vec!["Command 1", "Influence 1"]);
+
+        assert_eq!(file.triggers.len(), 1);
+        let trigger = &file.triggers[0];
+        assert_eq!(trigger.when.as_deref(), Some("CharacterTurnEnd"));
+        assert_eq!(trigger.conditions, vec!["WonBattle = 1"]);
+        assert_eq!(trigger.affects.len(), 1);
+        assert_eq!(trigger.affects[0].target, "GoodCommander");
+        assert_eq!(trigger.affects[0].level, "1");
+        assert_eq!(trigger.affects[0].chance, 50);
+    }
+
+    #[test]
+    fn missing_blank_line_between_traits_still_splits_them() {
+        let file = parse_text("Trait First\nLevel First1\nTrait Second\nLevel Second1\n");
+        assert_eq!(file.problems, Vec::<TraitProblem>::new(), "{:?}", file.problems);
+        assert_eq!(file.traits.len(), 2);
+        assert_eq!(file.traits[0].name, "First");
+        assert_eq!(file.traits[1].name, "Second");
+    }
+
+    #[test]
+    fn malformed_lines_become_problems_instead_of_failing_the_whole_file() {
+        let file = parse_text("Trait First\nLevel First1\nThreshold not_a_number\nEffect Command 1\n");
+        assert_eq!(file.traits.len(), 1);
+        assert_eq!(file.traits[0].levels[0].threshold, None);
+        assert_eq!(file.traits[0].levels[0].effects, vec!["Command 1"]);
+        assert_eq!(file.problems.len(), 1);
+        assert!(file.problems[0].message.contains("not a valid Threshold"), "{:?}", file.problems[0]);
+    }
+}
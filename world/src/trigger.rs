@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::text::strip_keyword;
+
+/// A `Trigger` record, the shape shared by `export_descr_character_traits.txt`
+/// and `export_descr_ancillaries.txt`: when to test its conditions, the
+/// conditions themselves, and which trait level or ancillary it grants (or
+/// removes) on success.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Trigger {
+    /// 0-based position among successfully parsed triggers, in file order.
+    pub id: usize,
+    /// 1-based line the `Trigger` header started on.
+    pub line_number: usize,
+    pub name: String,
+    pub when: Option<String>,
+    /// `Condition` lines, kept as raw expressions -- this parser doesn't
+    /// have a grammar for the condition language (yet).
+    pub conditions: Vec<String>,
+    pub affects: Vec<Affect>,
+}
+
+/// One `Affects TARGET LEVEL Chance N` line inside a [`Trigger`]. `target`
+/// names a trait or ancillary (whichever the owning file defines), `level`
+/// its level (or, for an ancillary, a nominal type name).
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Affect {
+    pub line_number: usize,
+    pub target: String,
+    pub level: String,
+    pub chance: u32,
+}
+
+/// If `text` is a `Trigger NAME` header, starts a new (empty) `Trigger`.
+/// `None` otherwise, so callers can fall through to their own record kinds.
+pub fn try_start_trigger(text: &str, line_number: usize) -> Option<Trigger> {
+    let rest = strip_keyword(text, "Trigger")?;
+    Some(Trigger { id: 0, line_number, name: rest.trim().to_string(), when: None, conditions: Vec::new(), affects: Vec::new() })
+}
+
+/// Interprets `text` as one line inside a `Trigger` block: `WhenToTest`,
+/// `Condition`, or `Affects TARGET LEVEL Chance N`. Returns an error
+/// message, not a hard failure, if `text` doesn't match any of those --
+/// callers log it and keep parsing, same recovery strategy as the rest of
+/// the record.
+pub fn parse_trigger_line(trigger: &mut Trigger, text: &str, line_number: usize) -> std::result::Result<(), String> {
+    if let Some(rest) = strip_keyword(text, "WhenToTest") {
+        trigger.when = Some(rest.trim().to_string());
+    } else if let Some(rest) = strip_keyword(text, "Condition") {
+        trigger.conditions.push(rest.trim().to_string());
+    } else if let Some(rest) = strip_keyword(text, "Affects") {
+        trigger.affects.push(parse_affect(rest, line_number)?);
+    } else {
+        return Err(format!("unrecognized line {text:?} in trigger {:?}", trigger.name));
+    }
+    Ok(())
+}
+
+fn parse_affect(rest: &str, line_number: usize) -> std::result::Result<Affect, String> {
+    let tokens: Vec<&str> = rest.split_whitespace().collect();
+    if tokens.len() != 4 || tokens[2] != "Chance" {
+        return Err(format!("expected \"Affects TARGET LEVEL Chance N\", found {rest:?}"));
+    }
+    let chance = tokens[3].parse().map_err(|_| format!("{:?} is not a valid Chance", tokens[3]))?;
+    Ok(Affect { line_number, target: tokens[0].to_string(), level: tokens[1].to_string(), chance })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_when_condition_and_affects() {
+        let mut trigger = try_start_trigger("Trigger WonBattleTrigger", 1).unwrap();
+        parse_trigger_line(&mut trigger, "WhenToTest CharacterTurnEnd", 2).unwrap();
+        parse_trigger_line(&mut trigger, "Condition WonBattle = 1", 3).unwrap();
+        parse_trigger_line(&mut trigger, "Affects GoodCommander 1 Chance 50", 4).unwrap();
+
+        assert_eq!(trigger.name, "WonBattleTrigger");
+        assert_eq!(trigger.when.as_deref(), Some("CharacterTurnEnd"));
+        assert_eq!(trigger.conditions, vec!["WonBattle = 1"]);
+        assert_eq!(trigger.affects[0].target, "GoodCommander");
+        assert_eq!(trigger.affects[0].level, "1");
+        assert_eq!(trigger.affects[0].chance, 50);
+    }
+
+    #[test]
+    fn malformed_affects_line_is_rejected() {
+        let mut trigger = try_start_trigger("Trigger T", 1).unwrap();
+        let err = parse_trigger_line(&mut trigger, "Affects GoodCommander Chance 50", 2).unwrap_err();
+        assert!(err.contains("expected \"Affects"), "{err}");
+    }
+
+    #[test]
+    fn non_trigger_header_returns_none() {
+        assert!(try_start_trigger("Trait GoodCommander", 1).is_none());
+    }
+}
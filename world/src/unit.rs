@@ -0,0 +1,573 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::lossless::LosslessDocument;
+use crate::text::{parse_descr_lines, strip_comment, strip_keyword, DescrLine, ReadOptions};
+
+/// A unit record from `export_descr_unit.txt`. Records are separated by
+/// blank lines, each starting with a `type NAME` line. Only the fields
+/// needed for cross-checking against other files are pulled out; everything
+/// else is kept verbatim in `extra` so a record round-trips.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Unit {
+    /// 0-based position in `export_descr_unit.txt`, stable for a given file
+    /// so other loaded data (and external tooling) can reference a unit by
+    /// id instead of by name.
+    pub id: usize,
+    /// 1-based line the `type NAME` line started on.
+    pub line_number: usize,
+    pub name: String,
+    /// Looked up from a `text/export_units.txt`-style localization file by
+    /// [`crate::localization::LocalizationFile::apply`]; `None` if no
+    /// locale directory was loaded or it has no matching key.
+    pub display_name: Option<String>,
+    /// Entries from the `attributes` line, kept verbatim (e.g.
+    /// `"hide_improved_forest -2"` keeps its parameter).
+    pub attributes: Vec<String>,
+    /// Factions from the `ownership` line, i.e. which factions may recruit
+    /// this unit.
+    pub ownership: Vec<String>,
+    /// `era N faction, faction, ...` lines, restricting which of the
+    /// `ownership` factions can recruit this unit in a given era. Kept as
+    /// its own list rather than folded into `extra` since a unit can carry
+    /// more than one `era` line and `extra` only keeps one value per key.
+    pub era_ownership: Vec<EraOwnership>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+    /// The line each recognized or `extra` key was set from, keyed by field
+    /// name, same convention as [`crate::faction::Faction::field_lines`].
+    pub field_lines: BTreeMap<String, usize>,
+}
+
+/// One `era N faction, faction, ...` line from a unit's record, paired with
+/// the line it was declared on.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct EraOwnership {
+    pub line_number: usize,
+    pub era: u8,
+    pub factions: Vec<String>,
+}
+
+impl Unit {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<Unit>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut units = Vec::new();
+        for block in split_blocks(&lines) {
+            units.push(parse_record(path, &block)?);
+        }
+
+        for (id, unit) in units.iter_mut().enumerate() {
+            unit.id = id;
+        }
+
+        Ok(units)
+    }
+
+    /// Whether this unit carries the `mercenary_unit` attribute, i.e. it's
+    /// legal to reference from a `descr_mercenaries.txt` pool.
+    pub fn is_mercenary(&self) -> bool {
+        self.attributes.iter().any(|attribute| attribute.split_whitespace().next() == Some("mercenary_unit"))
+    }
+
+    /// The projectile this unit's primary weapon fires, read from the third
+    /// comma-separated field of its `stat_pri` line (e.g.
+    /// `stat_pri  9, 0, arrow, 140, 12, ...`), paired with the line it was
+    /// declared on for pointing `world validate` findings at the right
+    /// place. `None` if the unit has no `stat_pri` line, the line doesn't
+    /// have a third field, or the weapon is melee-only (`no`).
+    pub fn missile_projectile(&self) -> Option<(&str, usize)> {
+        let stat_pri = self.extra.get("stat_pri")?;
+        let name = stat_pri.split(',').nth(2)?.trim();
+        if name.is_empty() || name.eq_ignore_ascii_case("no") {
+            return None;
+        }
+        let line_number = *self.field_lines.get("stat_pri")?;
+        Some((name, line_number))
+    }
+
+    /// The mount this unit rides, from its `mount NAME` line, paired with
+    /// the line it was declared on. `None` for units with no `mount` line
+    /// (most infantry).
+    pub fn mount(&self) -> Option<(&str, usize)> {
+        let name = self.extra.get("mount")?;
+        let line_number = *self.field_lines.get("mount")?;
+        Some((name.as_str(), line_number))
+    }
+
+    /// The battle model this unit's soldiers render as, from the first
+    /// comma-separated field of its `soldier` line (e.g.
+    /// `soldier  early_byzantine_infantry, 40, 0, 1`), paired with the line
+    /// it was declared on. `None` for units with no `soldier` line.
+    pub fn soldier_model(&self) -> Option<(&str, usize)> {
+        let soldier = self.extra.get("soldier")?;
+        let name = soldier.split(',').next()?.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let line_number = *self.field_lines.get("soldier")?;
+        Some((name, line_number))
+    }
+
+    /// This unit's `dictionary` tag, the name its UI card, unit info card,
+    /// and `export_units.txt` entries are all keyed on. `None` for units
+    /// with no `dictionary` line.
+    pub fn dictionary(&self) -> Option<(&str, usize)> {
+        let name = self.extra.get("dictionary")?;
+        let line_number = *self.field_lines.get("dictionary")?;
+        Some((name.as_str(), line_number))
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank (or
+/// comment-only, since [`read_descr_lines`] already stripped comments)
+/// lines.
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<Unit> {
+    let name_line = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let name = name_line
+        .text
+        .strip_prefix("type ")
+        .map(str::trim)
+        .ok_or_else(|| err(name_line, format!("expected a `type` line, found {:?}", name_line.text)))?
+        .to_string();
+
+    let mut attributes = Vec::new();
+    let mut ownership = Vec::new();
+    let mut era_ownership = Vec::new();
+    let mut extra = BTreeMap::new();
+    let mut field_lines = BTreeMap::new();
+    for line in &block[1..] {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let rest = line.text[key.len()..].trim().to_string();
+        field_lines.insert(key.to_string(), line.line_number);
+
+        match key {
+            "attributes" => attributes = rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect(),
+            "ownership" => ownership = rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect(),
+            "era" => match parse_era(&rest) {
+                Some((era, factions)) => era_ownership.push(EraOwnership { line_number: line.line_number, era, factions }),
+                None => {
+                    extra.insert(key.to_string(), rest);
+                }
+            },
+            _ => {
+                extra.insert(key.to_string(), rest);
+            }
+        }
+    }
+
+    Ok(Unit { id: 0, line_number: name_line.line_number, name, display_name: None, attributes, ownership, era_ownership, extra, field_lines })
+}
+
+/// Parses an `era` line's tail (everything after the `era` keyword itself,
+/// e.g. `"0 england, france"`) into the era number and its faction list.
+/// `None` if the era number isn't a plain integer, so a line this parser
+/// doesn't understand falls back to `extra` instead of being dropped.
+fn parse_era(rest: &str) -> Option<(u8, Vec<String>)> {
+    let mut parts = rest.splitn(2, char::is_whitespace);
+    let era = parts.next()?.trim().parse().ok()?;
+    let factions = parts.next().unwrap_or("").split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect();
+    Some((era, factions))
+}
+
+/// An `export_descr_unit.txt` document kept line-for-line (see
+/// [`LosslessDocument`]) so a balance pass can rewrite a handful of stat
+/// lines and leave everything else -- comments, spacing, unrelated units --
+/// byte-identical. Same template as [`crate::faction::FactionsDocument`],
+/// just keyed on a `type NAME` line instead of `faction NAME`.
+pub struct EduDocument {
+    doc: LosslessDocument,
+    /// Each unit's `[start, end)` line range, `start` being its `type NAME`
+    /// line, in file order.
+    spans: Vec<(String, Range<usize>)>,
+}
+
+impl EduDocument {
+    pub fn parse(text: &str) -> Self {
+        let doc = LosslessDocument::parse(text);
+
+        let mut spans = Vec::new();
+        let mut current: Option<(String, usize)> = None;
+        for i in 0..doc.line_count() {
+            let stripped = strip_comment(doc.line(i)).trim();
+            if let Some(name) = strip_keyword(stripped, "type") {
+                if let Some((name, start)) = current.take() {
+                    spans.push((name, start..i));
+                }
+                current = Some((name.to_string(), i));
+            }
+        }
+        if let Some((name, start)) = current {
+            let end = doc.line_count();
+            spans.push((name, start..end));
+        }
+
+        EduDocument { doc, spans }
+    }
+
+    /// Names of every unit in the document, in file order, for a caller
+    /// that wants to filter before editing (e.g. `world edu edit --filter`).
+    pub fn unit_names(&self) -> impl Iterator<Item = &str> {
+        self.spans.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns a raw `key`'s value within `name`'s record, for filtering on
+    /// a field this document doesn't have a typed accessor for (e.g.
+    /// `class`, `category`). `None` if there's no such unit or no such key.
+    pub fn field(&self, name: &str, key: &str) -> Option<&str> {
+        let (_, span) = self.spans.iter().find(|(unit_name, _)| unit_name == name)?;
+        span.clone().find_map(|i| strip_keyword(strip_comment(self.doc.line(i)).trim(), key))
+    }
+
+    /// Returns an editable handle to `name`'s record (matched exactly, like
+    /// [`Unit::load_all`]), or `None` if there's no such unit.
+    pub fn unit_mut(&mut self, name: &str) -> Option<UnitRecordMut<'_>> {
+        let index = self.spans.iter().position(|(unit_name, _)| unit_name == name)?;
+        Some(UnitRecordMut { document: self, index })
+    }
+
+    pub fn to_text(&self) -> String {
+        self.doc.to_text()
+    }
+}
+
+/// An editable handle to one unit's lines within an [`EduDocument`]. Holds
+/// the whole document rather than just its own line range for the same
+/// reason as [`crate::faction::FactionRecordMut`]: appending a line shifts
+/// every later unit's span.
+pub struct UnitRecordMut<'a> {
+    document: &'a mut EduDocument,
+    index: usize,
+}
+
+impl UnitRecordMut<'_> {
+    /// The unit's `stat_mental` value (morale), or `None` if it has no such
+    /// line.
+    pub fn morale(&self) -> Option<i64> {
+        self.field("stat_mental")?.trim().parse().ok()
+    }
+
+    pub fn set_morale(&mut self, value: i64) {
+        self.set_field("stat_mental", &value.to_string());
+    }
+
+    /// Multiplies every comma-separated number on the unit's `stat_cost`
+    /// line by `factor`, rounding each to the nearest integer -- recruit
+    /// turns, denari cost, upkeep, and the rest all scale together, since
+    /// none of them means anything as a standalone stat once the others
+    /// have moved.
+    pub fn scale_cost(&mut self, factor: f64) {
+        let Some(current) = self.field("stat_cost") else { return };
+        let scaled = current
+            .split(',')
+            .map(|field| match field.trim().parse::<f64>() {
+                Ok(n) => ((n * factor).round() as i64).to_string(),
+                Err(_) => field.trim().to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.set_field("stat_cost", &scaled);
+    }
+
+    /// Adds `faction` to the unit's `ownership` line, creating one if it
+    /// doesn't have one yet, unless `faction` is already listed. Returns
+    /// whether a change was made.
+    pub fn add_ownership(&mut self, faction: &str) -> bool {
+        let mut names: Vec<String> = match self.field("ownership") {
+            Some(value) => value.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect(),
+            None => Vec::new(),
+        };
+        if names.iter().any(|name| name == faction) {
+            return false;
+        }
+        names.push(faction.to_string());
+        self.set_field("ownership", &names.join(", "));
+        true
+    }
+
+    fn field(&self, key: &str) -> Option<&str> {
+        let mut span = self.document.spans[self.index].1.clone();
+        span.find_map(|i| strip_keyword(strip_comment(self.document.doc.line(i)).trim(), key))
+    }
+
+    /// Rewrites `key`'s existing `key value` line in place if the record
+    /// has one -- keeping its indentation and any trailing inline comment
+    /// -- or appends a new line just before the blank line(s) that end the
+    /// record's block if it doesn't. Every other line, in this record and
+    /// every other one, is left untouched.
+    fn set_field(&mut self, key: &str, value: &str) {
+        let span = self.document.spans[self.index].1.clone();
+        for i in span.clone() {
+            let line = self.document.doc.line(i);
+            let content = strip_comment(line);
+            let comment = &line[content.len()..];
+            let indent = &content[..content.len() - content.trim_start().len()];
+            if strip_keyword(content.trim(), key).is_some() {
+                let new_line = if comment.is_empty() { format!("{indent}{key} {value}") } else { format!("{indent}{key} {value} {comment}") };
+                self.document.doc.set_line(i, new_line);
+                return;
+            }
+        }
+
+        let mut insert_at = span.end;
+        while insert_at > span.start + 1 && self.document.doc.line(insert_at - 1).trim().is_empty() {
+            insert_at -= 1;
+        }
+        self.document.doc.insert_line(insert_at, format!("{key} {value}"));
+        for (_, other_span) in &mut self.document.spans {
+            if other_span.start >= insert_at {
+                other_span.start += 1;
+            }
+            if other_span.end >= insert_at {
+                other_span.end += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn units_from(text: &str) -> Vec<Unit> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("export_descr_unit_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut units = Vec::new();
+        for block in split_blocks(&lines) {
+            units.push(parse_record(&path, &block).unwrap());
+        }
+        units
+    }
+
+    #[test]
+    fn parses_name_and_attributes() {
+        let units = units_from(
+            r#"
+type Peasants
+dictionary Peasants_descr
+category infantry
+class light
+attributes sea_faring, can_sap
+
+type Steppe Cavalry
+dictionary Steppe_Cavalry_descr
+category cavalry
+class light cavalry
+attributes mercenary_unit, can_sap
+ownership slave, poland
+"#,
+        );
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].name, "Peasants");
+        assert_eq!(units[0].attributes, vec!["sea_faring", "can_sap"]);
+        assert!(units[0].ownership.is_empty());
+        assert!(!units[0].is_mercenary());
+        assert_eq!(units[1].name, "Steppe Cavalry");
+        assert!(units[1].is_mercenary());
+        assert_eq!(units[1].ownership, vec!["slave", "poland"]);
+        assert_eq!(units[1].extra.get("category"), Some(&"cavalry".to_string()));
+    }
+
+    #[test]
+    fn missile_projectile_reads_the_third_stat_pri_field() {
+        let units = units_from(
+            r#"
+type Peasant Archers
+category infantry
+class light
+stat_pri 4, 0, arrow, 140, 12, thrown, missile, none, arrow
+
+type Peasants
+category infantry
+class light
+stat_pri 3, 0, no, 0, 0, melee, simple, none, none
+"#,
+        );
+        assert_eq!(units[0].missile_projectile(), Some(("arrow", 5)));
+        assert_eq!(units[1].missile_projectile(), None);
+    }
+
+    #[test]
+    fn mount_reads_the_mount_line() {
+        let units = units_from(
+            r#"
+type Mailed Knights
+category cavalry
+class heavy cavalry
+mount mailed horse
+
+type Peasants
+category infantry
+class light
+"#,
+        );
+        assert_eq!(units[0].mount(), Some(("mailed horse", 5)));
+        assert_eq!(units[1].mount(), None);
+    }
+
+    #[test]
+    fn soldier_model_reads_the_first_soldier_field() {
+        let units = units_from(
+            r#"
+type Byzantine Infantry
+category infantry
+class heavy
+soldier early_byzantine_infantry, 40, 0, 1
+
+type Peasants
+category infantry
+class light
+"#,
+        );
+        assert_eq!(units[0].soldier_model(), Some(("early_byzantine_infantry", 5)));
+        assert_eq!(units[1].soldier_model(), None);
+    }
+
+    #[test]
+    fn dictionary_reads_the_dictionary_line() {
+        let units = units_from(
+            r#"
+type Peasants
+dictionary Peasants_descr
+category infantry
+class light
+
+type Swordsmen
+category infantry
+class heavy
+"#,
+        );
+        assert_eq!(units[0].dictionary(), Some(("Peasants_descr", 3)));
+        assert_eq!(units[1].dictionary(), None);
+    }
+
+    #[test]
+    fn missing_type_line_is_rejected() {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("export_descr_unit_test_{id}.txt"));
+        std::fs::write(&path, "category infantry\n").unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        let err = parse_record(&path, &blocks[0]).unwrap_err();
+        assert!(err.to_string().contains("expected a `type` line"), "{err}");
+    }
+
+    #[test]
+    fn era_line_is_kept_off_the_ownership_list() {
+        let units = units_from(
+            r#"
+type Peasants
+category infantry
+class light
+ownership england, france
+era 1 england
+era 2 england, france
+"#,
+        );
+        assert_eq!(units[0].ownership, vec!["england", "france"]);
+        assert_eq!(units[0].era_ownership.len(), 2);
+        assert_eq!(units[0].era_ownership[0].era, 1);
+        assert_eq!(units[0].era_ownership[0].factions, vec!["england"]);
+        assert_eq!(units[0].era_ownership[1].factions, vec!["england", "france"]);
+    }
+
+    #[test]
+    fn edu_document_unedited_round_trips_byte_identical() {
+        let text = "type Peasants\n\tstat_mental 3\n\tstat_cost 1, 100, 30, 100, 40, 30\n\ntype Swordsmen\n\tstat_mental 6\n";
+        assert_eq!(EduDocument::parse(text).to_text(), text);
+    }
+
+    #[test]
+    fn edu_document_set_morale_rewrites_only_that_line() {
+        let text = "type Peasants\n\tstat_mental 3 ; base morale\n\ntype Swordsmen\n\tstat_mental 6\n";
+        let mut doc = EduDocument::parse(text);
+        assert_eq!(doc.unit_mut("Peasants").unwrap().morale(), Some(3));
+        doc.unit_mut("Peasants").unwrap().set_morale(5);
+        assert_eq!(doc.to_text(), "type Peasants\n\tstat_mental 5 ; base morale\n\ntype Swordsmen\n\tstat_mental 6\n");
+    }
+
+    #[test]
+    fn edu_document_scale_cost_rounds_every_field() {
+        let text = "type Peasants\n\tstat_cost 1, 100, 30, 100, 40, 30\n";
+        let mut doc = EduDocument::parse(text);
+        doc.unit_mut("Peasants").unwrap().scale_cost(1.5);
+        assert_eq!(doc.to_text(), "type Peasants\n\tstat_cost 2, 150, 45, 150, 60, 45\n");
+    }
+
+    #[test]
+    fn edu_document_add_ownership_appends_to_an_existing_line() {
+        let text = "type Peasants\n\townership england, france\n";
+        let mut doc = EduDocument::parse(text);
+        assert!(doc.unit_mut("Peasants").unwrap().add_ownership("scotland"));
+        assert_eq!(doc.to_text(), "type Peasants\n\townership england, france, scotland\n");
+    }
+
+    #[test]
+    fn edu_document_add_ownership_creates_a_missing_line() {
+        let text = "type Peasants\n\tclass light\n";
+        let mut doc = EduDocument::parse(text);
+        assert!(doc.unit_mut("Peasants").unwrap().add_ownership("england"));
+        assert_eq!(doc.to_text(), "type Peasants\n\tclass light\nownership england\n");
+    }
+
+    #[test]
+    fn edu_document_add_ownership_is_a_no_op_if_already_present() {
+        let text = "type Peasants\n\townership england\n";
+        let mut doc = EduDocument::parse(text);
+        assert!(!doc.unit_mut("Peasants").unwrap().add_ownership("england"));
+        assert_eq!(doc.to_text(), text);
+    }
+
+    #[test]
+    fn edu_document_field_reads_a_raw_key_for_filtering() {
+        let text = "type Peasants\n\tclass light\n\ntype Swordsmen\n\tclass heavy\n";
+        let doc = EduDocument::parse(text);
+        assert_eq!(doc.field("Peasants", "class"), Some("light"));
+        assert_eq!(doc.field("Swordsmen", "class"), Some("heavy"));
+        assert_eq!(doc.field("Peasants", "category"), None);
+    }
+
+    #[test]
+    fn edu_document_unit_mut_returns_none_for_an_unknown_name() {
+        let text = "type Peasants\n\tstat_mental 3\n";
+        let mut doc = EduDocument::parse(text);
+        assert!(doc.unit_mut("Wales").is_none());
+    }
+}
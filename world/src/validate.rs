@@ -0,0 +1,1944 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::campaign_script::{self, ArgKind};
+use crate::character::Character;
+use crate::config::Config;
+use crate::heightmap::TileClass;
+use crate::requires::RequiresExpr;
+use crate::tga::TgaImage;
+use crate::trigger::Trigger;
+use crate::world::World;
+
+/// The largest `standard_index`/`logo_index`/`small_logo_index` value that's
+/// plausible for a faction: these index into a fixed-size texture atlas, and
+/// nothing shipped or modded to date has needed more than a few dozen
+/// entries. Not a hard limit enforced by the game, just a heuristic to catch
+/// the "typo'd an extra digit" class of mistake.
+const MAX_PLAUSIBLE_INDEX: u32 = 63;
+
+/// The faction name the engine reserves for unnamed garrisons/brigands. Its
+/// characters aren't drawn from a `descr_names.txt` pool this parser can
+/// check against, so they're exempt from the name-pool check below.
+pub(crate) const REBEL_FACTION: &str = "slave";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// One problem found while cross-checking a loaded `World`, precise enough
+/// that a modder can open `file` at `line_number` and see what's wrong.
+///
+/// `check` is a stable, kebab-case identifier for the rule that produced
+/// this finding (e.g. `"unit-projectile"`), independent of `message`'s
+/// wording -- it's what `world validate --allow <check>` matches against,
+/// so it must stay the same across releases even if the message text
+/// changes. `related` names the entities the finding is about (a faction,
+/// a unit, both sides of a broken reference, ...), in the same order
+/// `message` mentions them, for tooling that wants structured names
+/// instead of parsing them back out of the message text.
+#[derive(Debug, Clone, Serialize)]
+pub struct Finding {
+    pub severity: Severity,
+    pub file: PathBuf,
+    pub line_number: usize,
+    pub check: &'static str,
+    pub related: Vec<String>,
+    pub message: String,
+}
+
+impl Finding {
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+/// Cross-checks a loaded `World`'s internal references: every faction's
+/// `culture`/`religion` must name something that was actually loaded, index
+/// fields should stay within a plausible range, every culture/religion
+/// should be referenced by at least one faction, every playable or
+/// unlockable faction named in the campaign header must actually exist in
+/// `descr_sm_factions.txt`, every settlement with a map position must sit on
+/// land, if a heightmap was found, every character with a map position must
+/// sit on a tile its kind can actually occupy (sea for a naval kind, land
+/// for everything else, see [`NAVAL_CHARACTER_KINDS`]), every family member
+/// (see [`FAMILY_MEMBER_KINDS`]) must start inside a region their own
+/// faction controls at campaign start, if `map_regions.tga` was found, no
+/// two characters with an army start stacked on the same tile, every map
+/// image's dimensions must agree with `descr_terrain.txt`'s declared map
+/// size, every named
+/// `descr_strat.txt` character's first name and surname must come from its
+/// faction's `descr_names.txt` pool, every `descr_mercenaries.txt` pool
+/// must reference regions and units that actually exist (the latter with
+/// the `mercenary_unit` attribute), every region's rebel type must be
+/// defined in `descr_rebel_factions.txt`, every unit a rebel faction
+/// garrisons with must exist and be owned by the [`REBEL_FACTION`], every
+/// region/faction named in `descr_win_conditions.txt` must actually exist,
+/// every `descr_events.txt` event/disaster falls within the campaign's
+/// date range and triggers on the map, no trait name in
+/// `export_descr_character_traits.txt` is defined more than once, every
+/// `ExcludedAncillaries` reference in `export_descr_ancillaries.txt` names a
+/// defined ancillary, and (unless `check_assets` is `false`) every
+/// ancillary's `Image` points at a file that actually exists under
+/// `data/ui/ancillaries`, resolved through [`Config::resolve`] the same as
+/// every loader, every whitelisted `campaign_script.txt` command (see
+/// [`campaign_script::COMMAND_ARG_KINDS`]) references a faction, region,
+/// settlement, unit, or character type that actually exists, every unit's
+/// `stat_pri` missile (see [`crate::unit::Unit::missile_projectile`]) names
+/// a projectile actually defined in `descr_projectile.txt`, (unless
+/// `check_assets` is `false`) every projectile's model paths point at files
+/// that actually exist, every unit's `mount` (see
+/// [`crate::unit::Unit::mount`]) names a mount defined in
+/// `descr_mount.txt`, every defined mount is ridden by at least one unit,
+/// every unit's `soldier` (see [`crate::unit::Unit::soldier_model`]) names a
+/// battle model defined in `descr_model_battle.txt`, and (unless
+/// `check_assets` is `false`) every battle model's mesh and per-faction
+/// texture paths point at files that actually exist. Battle model skeleton
+/// names aren't cross-checked against the skeletons archive -- this
+/// codebase has no parser for that format yet. Every `descr_strat.txt`
+/// `resource` placement names a resource defined in
+/// `descr_sm_resources.txt`, no two placements occupy the same tile, every
+/// placement sits on land (if a heightmap was found), and every resource
+/// named in a region's `resource`/`hidden_resources` list is defined. Every
+/// `export_descr_buildings.txt` level, capability, and recruitment entry's
+/// `requires` clause (see [`crate::requires::RequiresExpr`]) references a
+/// real faction (or the `all` wildcard) and a real building family, and no
+/// culture is left unable to ever build a level its factions could
+/// otherwise reach (`descr_cultures.txt` itself carries no per-culture
+/// building list to check directly, so this is inferred from which
+/// cultures have factions at all). Every `recruit_pool` entry names a unit
+/// that actually exists in `export_descr_unit.txt` (a case/whitespace-only
+/// mismatch is reported separately from a true unknown) and is reachable
+/// by at least one faction that both owns the unit and can build the
+/// level, and every non-mercenary, non-rebel-only unit is recruitable from
+/// at least one `recruit_pool` somewhere. Every name on a unit's
+/// `ownership` or `era` line must be a real faction, the `slave` keyword,
+/// or a real culture name (the engine accepts a culture as shorthand for
+/// every faction of that culture), a unit owned by nobody is flagged, and
+/// so (as a warning) is a faction that owns zero units. Any two factions,
+/// regions, units, or cultures sharing a name (case-insensitively) are
+/// flagged too, since [`World::index`] can only ever resolve one of them.
+/// Every `hidden_resource` a requires clause names must be granted by at
+/// least one region (an error, naming every building level it makes
+/// unbuildable), and every hidden resource a region grants should be
+/// checked for by at least one requires clause somewhere (a warning). When
+/// `check_assets` is set, every unit with a `dictionary` tag must have a
+/// unit card and unit info card under `ui/units`/`ui/unit_info` for each
+/// faction that owns it (or `mercs` for a mercenary unit), findings grouped
+/// one per unit listing everything that's missing. Every unit's localization
+/// key (its `dictionary` tag, or its own name if it has none) must have
+/// `export_units.txt` entries for the plain key plus `_descr`/`_descr_short`,
+/// every EDB level name must have an `export_buildings.txt` entry, and a key
+/// in either file matching no current unit or level is flagged too (a
+/// warning, since it's dead weight rather than a visible bug). Every
+/// starting diplomatic stance (see [`crate::campaign::DiplomacyMatrix`])
+/// must name two factions that actually exist, and a faction pair declared
+/// more than once (an older `faction_relationships` line alongside a newer
+/// `relationship` block, say) must agree on the stance, since a mismatch
+/// means one of the two declarations is a one-sided war/alliance the engine
+/// will silently pick a side on. Every `Trigger`'s `Affects` line (see
+/// [`crate::trigger::Affect`]) must name a trait actually defined in
+/// `export_descr_character_traits.txt` at one of its defined levels, or an
+/// ancillary actually defined in `export_descr_ancillaries.txt` (whichever
+/// file the trigger came from), and a `Condition` line using
+/// `SettlementBuildingExists`, `UnitType`, or `RegionName` must name a
+/// building level, unit type, or region that actually exists -- any other
+/// condition keyword is left alone, since the engine supports hundreds this
+/// parser has no model for. Every region's and settlement's starting
+/// `religions { ... }` percentages must name a religion actually defined in
+/// `descr_religions.txt` and sum to exactly 100. When `check_assets` is set,
+/// every culture's portrait mapping (see [`crate::culture::Culture::portrait_dir`])
+/// must resolve, through the mod/pack/base override cascade, to a
+/// `young`/`old`/`dead` portrait subfolder that actually exists (an error)
+/// and has at least one file in it (a warning if the folder exists but is
+/// empty). Every `capability { wall_level N }` a building grants must name a
+/// level `descr_walls.txt` actually defines, and every turret/gate model a
+/// wall level references must name a battle model actually defined in
+/// `descr_model_battle.txt`. When `descr_missions.txt` is present, every
+/// mission's `faction`/`region` conditions and `unit`/`building` paybacks
+/// must resolve against the loaded `World`. When
+/// `export_descr_sounds_units_voice.txt` is present, every unit named under
+/// one of its voice classes must still exist (a warning if not) and every
+/// `export_descr_unit.txt` unit should be named under at least one class (a
+/// warning if it's silent in battle instead), and the text file being newer
+/// than its compiled `.dat` is a warning too, since the engine only reads
+/// the compiled form. Every recoverable problem `TraitsFile`/`AncillariesFile`
+/// already logged while skipping a malformed record (see
+/// [`crate::traits::TraitProblem`], [`crate::ancillary::AncillaryProblem`])
+/// is surfaced here too, as a warning, so a messy mod's broken records show
+/// up in the same report as everything else instead of only in `world dump`.
+pub fn validate(world: &World, config: &Config, check_assets: bool) -> Vec<Finding> {
+    let factions_path = source_path(world, config, "descr_sm_factions.txt");
+    let cultures_path = source_path(world, config, "descr_cultures.txt");
+    let religions_path = source_path(world, config, "descr_religions.txt");
+    let strat_path = source_path(world, config, "descr_strat.txt");
+
+    let mut findings = Vec::new();
+    let mut used_cultures = std::collections::BTreeSet::new();
+    let mut used_religions = std::collections::BTreeSet::new();
+
+    for faction in &world.factions {
+        let field_line = |field: &str| faction.field_lines.get(field).copied().unwrap_or(faction.line_number);
+
+        if let Some(culture) = &faction.culture {
+            if world.cultures.iter().any(|c| &c.name == culture) {
+                used_cultures.insert(culture.as_str());
+            } else {
+                findings.push(Finding {
+                    check: "faction-culture",
+                    severity: Severity::Error,
+                    file: factions_path.clone(),
+                    line_number: field_line("culture"),
+                    related: vec![faction.name.clone(), culture.clone()],
+                    message: format!("faction {:?} references unknown culture {culture:?}", faction.name),
+                });
+            }
+        }
+
+        if let Some(religion) = &faction.religion {
+            if world.religions.iter().any(|r| &r.name == religion) {
+                used_religions.insert(religion.as_str());
+            } else {
+                findings.push(Finding {
+                    check: "faction-religion",
+                    severity: Severity::Error,
+                    file: factions_path.clone(),
+                    line_number: field_line("religion"),
+                    related: vec![faction.name.clone(), religion.clone()],
+                    message: format!("faction {:?} references unknown religion {religion:?}", faction.name),
+                });
+            }
+        }
+
+        for (field, index) in [
+            ("standard_index", faction.standard_index),
+            ("logo_index", faction.logo_index),
+            ("small_logo_index", faction.small_logo_index),
+        ] {
+            if let Some(index) = index {
+                if index > MAX_PLAUSIBLE_INDEX {
+                    findings.push(Finding {
+                        check: "faction-index-range",
+                        severity: Severity::Warning,
+                        file: factions_path.clone(),
+                        line_number: field_line(field),
+                        related: vec![faction.name.clone()],
+                        message: format!(
+                            "faction {:?} has {field} {index}, outside the plausible range 0..={MAX_PLAUSIBLE_INDEX}",
+                            faction.name
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    for culture in &world.cultures {
+        if !used_cultures.contains(culture.name.as_str()) {
+            findings.push(Finding {
+                check: "unused-culture",
+                severity: Severity::Warning,
+                file: cultures_path.clone(),
+                line_number: culture.line_number,
+                related: vec![culture.name.clone()],
+                message: format!("culture {:?} is not used by any faction", culture.name),
+            });
+        }
+    }
+
+    if check_assets {
+        for culture in &world.cultures {
+            let portraits_dir = Path::new("ui").join(culture.portrait_dir()).join("portraits");
+            for category in ["young", "old", "dead"] {
+                match config.list_dir(&portraits_dir.join(category)).ok().flatten() {
+                    Some((files, _source)) if files.is_empty() => findings.push(Finding {
+                        check: "culture-portrait-empty",
+                        severity: Severity::Warning,
+                        file: cultures_path.clone(),
+                        line_number: culture.line_number,
+                        related: vec![culture.name.clone(), category.to_string()],
+                        message: format!(
+                            "culture {:?}'s {category} portrait directory {} exists but has no portraits",
+                            culture.name,
+                            portraits_dir.join(category).display()
+                        ),
+                    }),
+                    Some(_) => {}
+                    None => findings.push(Finding {
+                        check: "culture-portrait-missing",
+                        severity: Severity::Error,
+                        file: cultures_path.clone(),
+                        line_number: culture.line_number,
+                        related: vec![culture.name.clone(), category.to_string()],
+                        message: format!(
+                            "culture {:?}'s portrait mapping {:?} has no {category} portraits ({} does not exist)",
+                            culture.name,
+                            culture.portrait_dir(),
+                            portraits_dir.join(category).display()
+                        ),
+                    }),
+                }
+            }
+        }
+    }
+
+    for religion in &world.religions {
+        if !used_religions.contains(religion.name.as_str()) {
+            findings.push(Finding {
+                check: "unused-religion",
+                severity: Severity::Warning,
+                file: religions_path.clone(),
+                line_number: religion.line_number,
+                related: vec![religion.name.clone()],
+                message: format!("religion {:?} is not used by any faction", religion.name),
+            });
+        }
+    }
+
+    for (list, keyword) in [(&world.campaign.playable_factions, "playable"), (&world.campaign.unlockable_factions, "unlockable")] {
+        let line_number = world.campaign.list_lines.get(keyword).copied().unwrap_or(0);
+        for name in list {
+            if !world.factions.iter().any(|f| &f.name == name) {
+                findings.push(Finding {
+                    check: "campaign-faction-list",
+                    severity: Severity::Error,
+                    file: strat_path.clone(),
+                    line_number,
+                    related: vec![name.clone()],
+                    message: format!("{keyword} faction {name:?} is not a known faction"),
+                });
+            }
+        }
+    }
+
+    {
+        let mut seen_pairs: std::collections::BTreeMap<(String, String), &crate::campaign::DiplomaticStance> = std::collections::BTreeMap::new();
+        for stance in &world.diplomacy.stances {
+            for name in [&stance.faction_a, &stance.faction_b] {
+                if !world.factions.iter().any(|f| &f.name == name) {
+                    findings.push(Finding {
+                        check: "diplomacy-unknown-faction",
+                        severity: Severity::Error,
+                        file: strat_path.clone(),
+                        line_number: stance.line_number,
+                        related: vec![stance.faction_a.clone(), stance.faction_b.clone()],
+                        message: format!("starting diplomatic stance names unknown faction {name:?}"),
+                    });
+                }
+            }
+
+            let key = diplomacy_pair_key(&stance.faction_a, &stance.faction_b);
+            match seen_pairs.get(&key) {
+                Some(earlier) if earlier.stance != stance.stance => {
+                    findings.push(Finding {
+                        check: "diplomacy-conflicting-stance",
+                        severity: Severity::Error,
+                        file: strat_path.clone(),
+                        line_number: stance.line_number,
+                        related: vec![stance.faction_a.clone(), stance.faction_b.clone()],
+                        message: format!(
+                            "{:?}/{:?} is declared both {:?} (line {}) and {:?} (line {})",
+                            stance.faction_a, stance.faction_b, earlier.stance, earlier.line_number, stance.stance, stance.line_number
+                        ),
+                    });
+                }
+                _ => {
+                    seen_pairs.insert(key, stance);
+                }
+            }
+        }
+    }
+
+    if let Some(heightmap) = &world.heightmap {
+        for settlement in &world.settlements {
+            if let Some(position) = settlement.position {
+                if let Some(finding) = check_tile_placement(
+                    heightmap,
+                    position,
+                    &strat_path,
+                    settlement.line_number,
+                    "settlement-position",
+                    &format!("settlement in region {:?}", settlement.region),
+                ) {
+                    findings.push(finding);
+                }
+            }
+        }
+
+        for character in &world.characters {
+            if let Some(position) = character.position {
+                if let Some(finding) = check_character_tile_class(heightmap, character, position, &strat_path) {
+                    findings.push(finding);
+                }
+            }
+        }
+    }
+
+    if let Ok(image) = TgaImage::load(&config.resolve(Path::new("map_regions.tga")).path) {
+        for character in &world.characters {
+            if let Some(finding) = check_character_region_ownership(world, character, &image, &strat_path) {
+                findings.push(finding);
+            }
+        }
+    }
+
+    {
+        let mut armies_by_tile: std::collections::BTreeMap<(i32, i32), Vec<&Character>> = std::collections::BTreeMap::new();
+        for character in &world.characters {
+            if let (Some(position), false) = (character.position, character.army.is_empty()) {
+                armies_by_tile.entry((position.x, position.y)).or_default().push(character);
+            }
+        }
+        for ((x, y), characters) in &armies_by_tile {
+            if characters.len() > 1 {
+                let names: Vec<&str> = characters.iter().map(|c| c.name.as_str()).collect();
+                for character in characters {
+                    findings.push(Finding {
+                        check: "army-stacked-tile",
+                        severity: Severity::Error,
+                        file: strat_path.clone(),
+                        line_number: character.line_number,
+                        related: names.iter().map(|n| n.to_string()).collect(),
+                        message: format!("armies {} are all stacked on the same tile ({x}, {y})", names.join(", ")),
+                    });
+                }
+            }
+        }
+    }
+
+    let terrain_path = source_path(world, config, "descr_terrain.txt");
+    check_map_size(
+        &mut findings,
+        &terrain_path,
+        world.terrain.line_number,
+        "map_regions.tga",
+        TgaImage::read_dimensions(&config.resolve(Path::new("map_regions.tga")).path).ok(),
+        (world.terrain.width, world.terrain.height),
+    );
+    if let Some(heightmap) = &world.heightmap {
+        check_map_size(
+            &mut findings,
+            &terrain_path,
+            world.terrain.line_number,
+            "map_heights",
+            Some((heightmap.width, heightmap.height)),
+            (2 * world.terrain.width + 1, 2 * world.terrain.height + 1),
+        );
+    }
+
+    for character in &world.characters {
+        if let Some(finding) = check_character_name(world, character, &strat_path) {
+            findings.push(finding);
+        }
+    }
+
+    let mercenaries_path = source_path(world, config, "descr_mercenaries.txt");
+    let regions_path = source_path(world, config, "descr_regions.txt");
+    let units_path = source_path(world, config, "export_descr_unit.txt");
+    let mut regions_with_a_pool = std::collections::BTreeSet::new();
+
+    for pool in &world.merc_pools {
+        for region in &pool.regions {
+            if world.regions.iter().any(|r| &r.name == region) {
+                regions_with_a_pool.insert(region.as_str());
+            } else {
+                findings.push(Finding {
+                    check: "mercenary-pool-region",
+                    severity: Severity::Error,
+                    file: mercenaries_path.clone(),
+                    line_number: pool.regions_line,
+                    related: vec![pool.name.clone(), region.clone()],
+                    message: format!("mercenary pool {:?} references unknown region {region:?}", pool.name),
+                });
+            }
+        }
+
+        for unit in &pool.units {
+            match world.units.iter().find(|u| u.name == unit.name) {
+                None => findings.push(Finding {
+                    check: "mercenary-pool-unit",
+                    severity: Severity::Error,
+                    file: mercenaries_path.clone(),
+                    line_number: unit.line_number,
+                    related: vec![pool.name.clone(), unit.name.clone()],
+                    message: format!("mercenary pool {:?} references unknown unit {:?}", pool.name, unit.name),
+                }),
+                Some(found) if !found.is_mercenary() => findings.push(Finding {
+                    check: "mercenary-pool-unit",
+                    severity: Severity::Error,
+                    file: mercenaries_path.clone(),
+                    line_number: unit.line_number,
+                    related: vec![pool.name.clone(), unit.name.clone()],
+                    message: format!(
+                        "mercenary pool {:?} references unit {:?} ({}:{}), which is missing the `mercenary_unit` attribute",
+                        pool.name,
+                        unit.name,
+                        units_path.display(),
+                        found.line_number
+                    ),
+                }),
+                Some(_) => {}
+            }
+        }
+    }
+
+    for region in &world.regions {
+        if !regions_with_a_pool.contains(region.name.as_str()) {
+            findings.push(Finding {
+                check: "region-mercenary-coverage",
+                severity: Severity::Warning,
+                file: regions_path.clone(),
+                line_number: region.line_number,
+                related: vec![region.name.clone()],
+                message: format!("region {:?} is not covered by any mercenary pool, so it will never spawn mercenaries", region.name),
+            });
+        }
+
+        if !world.rebel_factions.iter().any(|r| r.identifier == region.rebel_type) {
+            findings.push(Finding {
+                check: "region-rebel-type",
+                severity: Severity::Error,
+                file: regions_path.clone(),
+                line_number: region.line_number,
+                related: vec![region.name.clone(), region.rebel_type.clone()],
+                message: format!("region {:?} has rebel type {:?}, which is not defined in descr_rebel_factions.txt", region.name, region.rebel_type),
+            });
+        }
+    }
+
+    let rebel_factions_path = source_path(world, config, "descr_rebel_factions.txt");
+    for rebel in &world.rebel_factions {
+        for unit in &rebel.units {
+            match world.units.iter().find(|u| u.name == unit.name) {
+                None => findings.push(Finding {
+                    check: "rebel-faction-unit",
+                    severity: Severity::Error,
+                    file: rebel_factions_path.clone(),
+                    line_number: unit.line_number,
+                    related: vec![rebel.identifier.clone(), unit.name.clone()],
+                    message: format!("rebel faction {:?} references unknown unit {:?}", rebel.identifier, unit.name),
+                }),
+                Some(found) if !found.ownership.iter().any(|f| f == REBEL_FACTION) => findings.push(Finding {
+                    check: "rebel-faction-unit",
+                    severity: Severity::Error,
+                    file: rebel_factions_path.clone(),
+                    line_number: unit.line_number,
+                    related: vec![rebel.identifier.clone(), unit.name.clone()],
+                    message: format!(
+                        "rebel faction {:?} references unit {:?} ({}:{}), which the {REBEL_FACTION:?} faction doesn't own",
+                        rebel.identifier,
+                        unit.name,
+                        units_path.display(),
+                        found.line_number
+                    ),
+                }),
+                Some(_) => {}
+            }
+        }
+    }
+
+    let win_conditions_path = source_path(world, config, "descr_win_conditions.txt");
+    for win_conditions in &world.win_conditions {
+        if !world.factions.iter().any(|f| f.name == win_conditions.faction) {
+            findings.push(Finding {
+                check: "win-condition-faction",
+                severity: Severity::Error,
+                file: win_conditions_path.clone(),
+                line_number: win_conditions.line_number,
+                related: vec![win_conditions.faction.clone()],
+                message: format!("descr_win_conditions.txt has a section for unknown faction {:?}", win_conditions.faction),
+            });
+        }
+
+        for set in [&win_conditions.short_campaign, &win_conditions.long_campaign] {
+            for region in &set.hold_regions {
+                if !world.regions.iter().any(|r| r.name == region.name) {
+                    findings.push(Finding {
+                        check: "win-condition-region",
+                        severity: Severity::Error,
+                        file: win_conditions_path.clone(),
+                        line_number: region.line_number,
+                        related: vec![win_conditions.faction.clone(), region.name.clone()],
+                        message: format!("faction {:?}'s win conditions reference unknown region {:?}", win_conditions.faction, region.name),
+                    });
+                }
+            }
+            for faction in set.eliminate_factions.iter().chain(&set.outlive_factions) {
+                if !world.factions.iter().any(|f| f.name == faction.name) {
+                    findings.push(Finding {
+                        check: "win-condition-faction",
+                        severity: Severity::Error,
+                        file: win_conditions_path.clone(),
+                        line_number: faction.line_number,
+                        related: vec![win_conditions.faction.clone(), faction.name.clone()],
+                        message: format!("faction {:?}'s win conditions reference unknown faction {:?}", win_conditions.faction, faction.name),
+                    });
+                }
+            }
+        }
+    }
+
+    let events_path = source_path(world, config, "descr_events.txt");
+    let start_year = world.campaign.start_year();
+    let end_year = world.campaign.end_year();
+    for event in &world.events {
+        if let (Some(start_year), Some(end_year)) = (start_year, end_year) {
+            if event.date_range.1 < start_year || event.date_range.0 > end_year {
+                findings.push(Finding {
+                    check: "event-date-range",
+                    severity: Severity::Error,
+                    file: events_path.clone(),
+                    line_number: event.line_number,
+                    related: vec![event.name.clone()],
+                    message: format!(
+                        "{:?} {:?} can only trigger in {}-{}, which is outside the campaign's {start_year}-{end_year} date range",
+                        event.kind, event.name, event.date_range.0, event.date_range.1
+                    ),
+                });
+            }
+        }
+
+        if let Some(heightmap) = &world.heightmap {
+            for position in &event.positions {
+                if heightmap.classify_tile(position.x, position.y) == TileClass::OffMap {
+                    findings.push(Finding {
+                        check: "event-position",
+                        severity: Severity::Error,
+                        file: events_path.clone(),
+                        line_number: event.line_number,
+                        related: vec![event.name.clone()],
+                        message: format!(
+                            "{:?} {:?} triggers at ({}, {}), which is off the edge of the map",
+                            event.kind, event.name, position.x, position.y
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    let traits_path = source_path(world, config, "export_descr_character_traits.txt");
+    let mut trait_lines: std::collections::BTreeMap<&str, Vec<usize>> = std::collections::BTreeMap::new();
+    for t in &world.traits.traits {
+        trait_lines.entry(t.name.as_str()).or_default().push(t.line_number);
+    }
+    for (name, lines) in &trait_lines {
+        if lines.len() > 1 {
+            for &line_number in lines {
+                findings.push(Finding {
+                    check: "trait-duplicate",
+                    severity: Severity::Warning,
+                    file: traits_path.clone(),
+                    line_number,
+                    related: vec![(*name).to_string()],
+                    message: format!("trait {name:?} is defined {} times", lines.len()),
+                });
+            }
+        }
+    }
+
+    for problem in &world.traits.problems {
+        findings.push(Finding {
+            check: "trait-parse-problem",
+            severity: Severity::Warning,
+            file: traits_path.clone(),
+            line_number: problem.line_number,
+            related: Vec::new(),
+            message: problem.message.clone(),
+        });
+    }
+
+    let ancillaries_path = source_path(world, config, "export_descr_ancillaries.txt");
+    for problem in &world.ancillaries.problems {
+        findings.push(Finding {
+            check: "ancillary-parse-problem",
+            severity: Severity::Warning,
+            file: ancillaries_path.clone(),
+            line_number: problem.line_number,
+            related: Vec::new(),
+            message: problem.message.clone(),
+        });
+    }
+    for ancillary in &world.ancillaries.ancillaries {
+        for excluded in &ancillary.excluded_ancillaries {
+            if !world.ancillaries.ancillaries.iter().any(|a| a.name == excluded.name) {
+                findings.push(Finding {
+                    check: "ancillary-exclusion",
+                    severity: Severity::Error,
+                    file: ancillaries_path.clone(),
+                    line_number: excluded.line_number,
+                    related: vec![ancillary.name.clone(), excluded.name.clone()],
+                    message: format!("ancillary {:?}'s ExcludedAncillaries references unknown ancillary {:?}", ancillary.name, excluded.name),
+                });
+            }
+        }
+
+        if check_assets {
+            if let Some(image) = &ancillary.image {
+                let relative = Path::new("data/ui/ancillaries").join(image);
+                if !config.resolve(&relative).path.is_file() {
+                    findings.push(Finding {
+                        check: "ancillary-image",
+                        severity: Severity::Error,
+                        file: ancillaries_path.clone(),
+                        line_number: ancillary.image_line.unwrap_or(ancillary.line_number),
+                        related: vec![ancillary.name.clone()],
+                        message: format!("ancillary {:?}'s image {} does not exist", ancillary.name, relative.display()),
+                    });
+                }
+            }
+        }
+    }
+
+    let known_building_names: std::collections::BTreeSet<&str> = world.buildings.iter().flat_map(|b| b.levels.iter().map(|l| l.name.as_str())).collect();
+    let check_trigger_conditions = |findings: &mut Vec<Finding>, trigger: &Trigger, path: &Path| {
+        for condition in &trigger.conditions {
+            let tokens: Vec<&str> = condition.split_whitespace().collect();
+            let Some(&keyword) = tokens.first() else { continue };
+            let Some(&operand) = tokens.last() else { continue };
+            let exists = match keyword {
+                "SettlementBuildingExists" => known_building_names.contains(operand),
+                "UnitType" => world.unit_by_type(operand).is_some(),
+                "RegionName" => world.regions.iter().any(|r| r.name == operand),
+                // Hundreds of other condition keywords exist that this
+                // parser has no model for; only the ones above reference
+                // something `validate` can actually check.
+                _ => continue,
+            };
+            if !exists {
+                findings.push(Finding {
+                    check: "trigger-condition-reference",
+                    severity: Severity::Error,
+                    file: path.to_path_buf(),
+                    line_number: trigger.line_number,
+                    related: vec![trigger.name.clone(), operand.to_string()],
+                    message: format!("trigger {:?}'s {keyword} condition references unknown {operand:?}", trigger.name),
+                });
+            }
+        }
+    };
+
+    for trigger in &world.traits.triggers {
+        check_trigger_conditions(&mut findings, trigger, &traits_path);
+        for affect in &trigger.affects {
+            match world.traits.traits.iter().find(|t| t.name == affect.target) {
+                None => findings.push(Finding {
+                    check: "trigger-affects-trait",
+                    severity: Severity::Error,
+                    file: traits_path.clone(),
+                    line_number: affect.line_number,
+                    related: vec![trigger.name.clone(), affect.target.clone()],
+                    message: format!("trigger {:?} affects unknown trait {:?}", trigger.name, affect.target),
+                }),
+                Some(t) => {
+                    let valid_level = affect.level.parse::<usize>().is_ok_and(|level| level >= 1 && level <= t.levels.len());
+                    if !valid_level {
+                        findings.push(Finding {
+                            check: "trigger-affects-trait-level",
+                            severity: Severity::Error,
+                            file: traits_path.clone(),
+                            line_number: affect.line_number,
+                            related: vec![trigger.name.clone(), affect.target.clone(), affect.level.clone()],
+                            message: format!(
+                                "trigger {:?} affects trait {:?} at level {:?}, which isn't one of its {} defined levels",
+                                trigger.name,
+                                affect.target,
+                                affect.level,
+                                t.levels.len()
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    for trigger in &world.ancillaries.triggers {
+        check_trigger_conditions(&mut findings, trigger, &ancillaries_path);
+        for affect in &trigger.affects {
+            if !world.ancillaries.ancillaries.iter().any(|a| a.name == affect.target) {
+                findings.push(Finding {
+                    check: "trigger-affects-ancillary",
+                    severity: Severity::Error,
+                    file: ancillaries_path.clone(),
+                    line_number: affect.line_number,
+                    related: vec![trigger.name.clone(), affect.target.clone()],
+                    message: format!("trigger {:?} affects unknown ancillary {:?}", trigger.name, affect.target),
+                });
+            }
+        }
+    }
+
+    let script_path = source_path(world, config, "campaign_script.txt");
+    let known_character_types: std::collections::BTreeSet<&str> = world.characters.iter().map(|c| c.kind.as_str()).collect();
+    for command in &world.script_commands {
+        let Some((kinds, offset)) = campaign_script::arg_kinds_for(command) else { continue };
+        for &(index, kind) in kinds {
+            let Some(value) = command.args.get(offset + index) else { continue };
+            let exists = match kind {
+                ArgKind::Faction => world.factions.iter().any(|f| &f.name == value),
+                ArgKind::Region => world.regions.iter().any(|r| &r.name == value),
+                ArgKind::Settlement => world.settlements.iter().any(|s| &s.region == value),
+                ArgKind::Unit => world.units.iter().any(|u| &u.name == value),
+                ArgKind::CharacterType => known_character_types.contains(value.as_str()),
+            };
+            if !exists {
+                findings.push(Finding {
+                    check: "campaign-script-reference",
+                    severity: Severity::Error,
+                    file: script_path.clone(),
+                    line_number: command.line_number,
+                    related: vec![command.name.clone(), value.clone()],
+                    message: format!("{:?} references unknown {kind:?} {value:?}", command.name),
+                });
+            }
+        }
+    }
+
+    let projectiles_path = source_path(world, config, "descr_projectile.txt");
+    for unit in &world.units {
+        if let Some((projectile, line_number)) = unit.missile_projectile() {
+            if !world.projectiles.iter().any(|p| p.name == projectile) {
+                let suggestion = closest_match(projectile, world.projectiles.iter().map(|p| &p.name));
+                findings.push(Finding {
+                    check: "unit-projectile",
+                    severity: Severity::Error,
+                    file: units_path.clone(),
+                    line_number,
+                    related: vec![unit.name.clone(), projectile.to_string()],
+                    message: match suggestion {
+                        Some(s) => format!("unit {:?} fires unknown projectile {projectile:?}; did you mean {s:?}?", unit.name),
+                        None => format!("unit {:?} fires unknown projectile {projectile:?}", unit.name),
+                    },
+                });
+            }
+        }
+    }
+
+    if check_assets {
+        for projectile in &world.projectiles {
+            for model in &projectile.model_paths {
+                if !config.resolve(Path::new(&model.path)).path.is_file() {
+                    findings.push(Finding {
+                        check: "projectile-asset",
+                        severity: Severity::Error,
+                        file: projectiles_path.clone(),
+                        line_number: model.line_number,
+                        related: vec![projectile.name.clone()],
+                        message: format!("projectile {:?}'s {} {} does not exist", projectile.name, model.key, model.path),
+                    });
+                }
+            }
+        }
+    }
+
+    let mounts_path = source_path(world, config, "descr_mount.txt");
+    let mut used_mounts = std::collections::BTreeSet::new();
+    for unit in &world.units {
+        if let Some((mount, line_number)) = unit.mount() {
+            if world.mounts.iter().any(|m| m.name == mount) {
+                used_mounts.insert(mount);
+            } else {
+                let suggestion = closest_match(mount, world.mounts.iter().map(|m| &m.name));
+                findings.push(Finding {
+                    check: "unit-mount",
+                    severity: Severity::Error,
+                    file: units_path.clone(),
+                    line_number,
+                    related: vec![unit.name.clone(), mount.to_string()],
+                    message: match suggestion {
+                        Some(s) => format!("unit {:?} rides unknown mount {mount:?}; did you mean {s:?}?", unit.name),
+                        None => format!("unit {:?} rides unknown mount {mount:?}", unit.name),
+                    },
+                });
+            }
+        }
+    }
+
+    for mount in &world.mounts {
+        if !used_mounts.contains(mount.name.as_str()) {
+            findings.push(Finding {
+                check: "unused-mount",
+                severity: Severity::Warning,
+                file: mounts_path.clone(),
+                line_number: mount.line_number,
+                related: vec![mount.name.clone()],
+                message: format!("mount {:?} is not used by any unit", mount.name),
+            });
+        }
+    }
+
+    let battle_models_path = source_path(world, config, "descr_model_battle.txt");
+    for unit in &world.units {
+        if let Some((model, line_number)) = unit.soldier_model() {
+            if !world.battle_models.iter().any(|m| m.name == model) {
+                let suggestion = closest_match(model, world.battle_models.iter().map(|m| &m.name));
+                findings.push(Finding {
+                    check: "unit-battle-model",
+                    severity: Severity::Error,
+                    file: units_path.clone(),
+                    line_number,
+                    related: vec![unit.name.clone(), model.to_string()],
+                    message: match suggestion {
+                        Some(s) => format!("unit {:?} uses unknown battle model {model:?}; did you mean {s:?}?", unit.name),
+                        None => format!("unit {:?} uses unknown battle model {model:?}", unit.name),
+                    },
+                });
+            }
+        }
+    }
+
+    if check_assets {
+        for model in &world.battle_models {
+            for mesh in &model.model_paths {
+                if !config.resolve(Path::new(&mesh.path)).path.is_file() {
+                    findings.push(Finding {
+                        check: "battle-model-asset",
+                        severity: Severity::Error,
+                        file: battle_models_path.clone(),
+                        line_number: mesh.line_number,
+                        related: vec![model.name.clone()],
+                        message: format!("battle model {:?}'s {} {} does not exist", model.name, mesh.key, mesh.path),
+                    });
+                }
+            }
+            for texture in &model.textures {
+                if !config.resolve(Path::new(&texture.path)).path.is_file() {
+                    let affected = if texture.factions.is_empty() { "all factions".to_string() } else { texture.factions.join(", ") };
+                    findings.push(Finding {
+                        check: "battle-model-asset",
+                        severity: Severity::Error,
+                        file: battle_models_path.clone(),
+                        line_number: texture.line_number,
+                        related: vec![model.name.clone()],
+                        message: format!("battle model {:?}'s texture {} does not exist, affecting {affected}", model.name, texture.path),
+                    });
+                }
+            }
+        }
+    }
+
+    if check_assets {
+        for unit in &world.units {
+            let Some((dictionary, line_number)) = unit.dictionary() else { continue };
+            let folders: Vec<&str> = if unit.is_mercenary() {
+                vec!["mercs"]
+            } else {
+                let mut folders: Vec<&str> = unit.ownership.iter().map(String::as_str).collect();
+                folders.sort_unstable();
+                folders.dedup();
+                folders
+            };
+
+            let mut missing = Vec::new();
+            for folder in folders {
+                for (kind, subdir) in [("unit card", "units"), ("unit info card", "unit_info")] {
+                    let relative = PathBuf::from("ui").join(subdir).join(folder).join(format!("#{dictionary}.tga"));
+                    if config.read_data(&relative).ok().flatten().is_none() {
+                        missing.push(format!("{kind} for {folder} ({})", relative.display()));
+                    }
+                }
+            }
+
+            if !missing.is_empty() {
+                findings.push(Finding {
+                    check: "unit-ui-asset-missing",
+                    severity: Severity::Error,
+                    file: units_path.clone(),
+                    line_number,
+                    related: vec![unit.name.clone()],
+                    message: format!("unit {:?} is missing {}: {}", unit.name, if missing.len() == 1 { "an asset" } else { "assets" }, missing.join("; ")),
+                });
+            }
+        }
+    }
+
+    for placement in &world.resource_placements {
+        if !world.resources.iter().any(|r| r.name == placement.name) {
+            let suggestion = closest_match(&placement.name, world.resources.iter().map(|r| &r.name));
+            findings.push(Finding {
+                check: "resource-placement-name",
+                severity: Severity::Error,
+                file: strat_path.clone(),
+                line_number: placement.line_number,
+                related: vec![placement.name.clone()],
+                message: match suggestion {
+                    Some(s) => format!("placed resource {:?} is not defined; did you mean {s:?}?", placement.name),
+                    None => format!("placed resource {:?} is not defined", placement.name),
+                },
+            });
+        }
+    }
+
+    let mut placements_by_tile: std::collections::BTreeMap<(i32, i32), Vec<&crate::resource::ResourcePlacement>> = std::collections::BTreeMap::new();
+    for placement in &world.resource_placements {
+        placements_by_tile.entry((placement.position.x, placement.position.y)).or_default().push(placement);
+    }
+    for ((x, y), placements) in &placements_by_tile {
+        if placements.len() > 1 {
+            let names: Vec<&str> = placements.iter().map(|p| p.name.as_str()).collect();
+            for placement in placements {
+                findings.push(Finding {
+                    check: "resource-duplicate-tile",
+                    severity: Severity::Error,
+                    file: strat_path.clone(),
+                    line_number: placement.line_number,
+                    related: names.iter().map(|n| n.to_string()).collect(),
+                    message: format!("resources {} all occupy the same tile ({x}, {y})", names.join(", ")),
+                });
+            }
+        }
+    }
+
+    if let Some(heightmap) = &world.heightmap {
+        for placement in &world.resource_placements {
+            if let Some(finding) = check_tile_placement(
+                heightmap,
+                placement.position,
+                &strat_path,
+                placement.line_number,
+                "resource-position",
+                &format!("resource {:?}", placement.name),
+            ) {
+                findings.push(finding);
+            }
+        }
+    }
+
+    for region in &world.regions {
+        for name in region.resources.iter().chain(&region.hidden_resources) {
+            if !world.resources.iter().any(|r| &r.name == name) {
+                let suggestion = closest_match(name, world.resources.iter().map(|r| &r.name));
+                findings.push(Finding {
+                    check: "region-resource",
+                    severity: Severity::Error,
+                    file: regions_path.clone(),
+                    line_number: region.line_number,
+                    related: vec![region.name.clone(), name.clone()],
+                    message: match suggestion {
+                        Some(s) => format!("region {:?} references unknown resource {name:?}; did you mean {s:?}?", region.name),
+                        None => format!("region {:?} references unknown resource {name:?}", region.name),
+                    },
+                });
+            }
+        }
+    }
+
+    let check_religion_percentages = |findings: &mut Vec<Finding>, file: &Path, line_number: usize, related_name: &str, kind: &str, percentages: &std::collections::BTreeMap<String, u32>| {
+        if percentages.is_empty() {
+            return;
+        }
+        for religion in percentages.keys() {
+            if !world.religions.iter().any(|r| &r.name == religion) {
+                let suggestion = closest_match(religion, world.religions.iter().map(|r| &r.name));
+                findings.push(Finding {
+                    check: "religion-percentage-unknown",
+                    severity: Severity::Error,
+                    file: file.to_path_buf(),
+                    line_number,
+                    related: vec![related_name.to_string(), religion.clone()],
+                    message: match suggestion {
+                        Some(s) => format!("{kind} {related_name:?} references unknown religion {religion:?}; did you mean {s:?}?"),
+                        None => format!("{kind} {related_name:?} references unknown religion {religion:?}"),
+                    },
+                });
+            }
+        }
+
+        let total: u32 = percentages.values().sum();
+        if total != 100 {
+            findings.push(Finding {
+                check: "religion-percentage-sum",
+                severity: Severity::Error,
+                file: file.to_path_buf(),
+                line_number,
+                related: vec![related_name.to_string()],
+                message: format!("{kind} {related_name:?}'s starting religion percentages sum to {total}, not 100"),
+            });
+        }
+    };
+
+    for region in &world.regions {
+        check_religion_percentages(&mut findings, &regions_path, region.line_number, &region.name, "region", &region.religion_percentages);
+    }
+    for settlement in &world.settlements {
+        check_religion_percentages(
+            &mut findings,
+            &strat_path,
+            settlement.line_number,
+            settlement.display_name.as_deref().unwrap_or(&settlement.region),
+            "settlement",
+            &settlement.religion_percentages,
+        );
+    }
+
+    let buildings_path = source_path(world, config, "export_descr_buildings.txt");
+    let building_names: std::collections::BTreeSet<&str> = world.buildings.iter().map(|b| b.name.as_str()).collect();
+    let mut recruited_units: std::collections::BTreeSet<&str> = std::collections::BTreeSet::new();
+    // `hidden_resource` name -> (first line it was required on, every
+    // "building/level" that requires it), for the used-vs-granted
+    // cross-check against `descr_regions.txt` below.
+    let mut hidden_resource_uses: std::collections::BTreeMap<String, (usize, std::collections::BTreeSet<String>)> = std::collections::BTreeMap::new();
+    for building in &world.buildings {
+        for level in &building.levels {
+            let mut requires_clauses = Vec::new();
+            if let Some(requires) = &level.requires {
+                requires_clauses.push((level.line_number, requires));
+            }
+            for capability in &level.capabilities {
+                if let Some(requires) = &capability.requires {
+                    requires_clauses.push((capability.line_number, requires));
+                }
+            }
+            for recruitment in &level.recruitment {
+                if let Some(requires) = &recruitment.requires {
+                    requires_clauses.push((recruitment.line_number, requires));
+                }
+            }
+
+            for (line_number, requires) in requires_clauses {
+                let expr = RequiresExpr::parse(requires);
+                for resource in expr.hidden_resource_names() {
+                    let entry = hidden_resource_uses.entry(resource.to_string()).or_insert_with(|| (line_number, std::collections::BTreeSet::new()));
+                    entry.1.insert(format!("{}/{}", building.name, level.name));
+                }
+                for faction in expr.faction_names() {
+                    if faction != "all" && !world.factions.iter().any(|f| f.name == faction) {
+                        findings.push(Finding {
+                            check: "building-requires-faction",
+                            severity: Severity::Error,
+                            file: buildings_path.clone(),
+                            line_number,
+                            related: vec![building.name.clone(), level.name.clone(), faction.to_string()],
+                            message: format!(
+                                "{:?} level {:?}'s requires clause references unknown faction {faction:?}",
+                                building.name, level.name
+                            ),
+                        });
+                    }
+                }
+                for required_building in expr.building_present_names() {
+                    if !building_names.contains(required_building) {
+                        findings.push(Finding {
+                            check: "building-requires-building",
+                            severity: Severity::Error,
+                            file: buildings_path.clone(),
+                            line_number,
+                            related: vec![building.name.clone(), level.name.clone(), required_building.to_string()],
+                            message: format!(
+                                "{:?} level {:?}'s requires clause references unknown building {required_building:?}",
+                                building.name, level.name
+                            ),
+                        });
+                    }
+                }
+            }
+
+            for recruitment in &level.recruitment {
+                match world.units.iter().find(|u| u.name == recruitment.unit) {
+                    Some(unit) => {
+                        recruited_units.insert(unit.name.as_str());
+
+                        // A pool entry needs a faction that both satisfies
+                        // the level's own `requires` (to build the level at
+                        // all) and the pool entry's own `requires`, and is
+                        // also in the unit's `ownership` list -- the engine
+                        // checks all three before it'll let a faction
+                        // recruit from this pool.
+                        let owners: Vec<&str> =
+                            if unit.ownership.is_empty() { world.factions.iter().map(|f| f.name.as_str()).collect() } else { unit.ownership.iter().map(String::as_str).collect() };
+                        let level_expr = level.requires.as_deref().map(RequiresExpr::parse);
+                        let recruitment_expr = recruitment.requires.as_deref().map(RequiresExpr::parse);
+                        let reachable = owners.iter().any(|owner| {
+                            level_expr.as_ref().is_none_or(|expr| expr.satisfiable_by_faction(owner))
+                                && recruitment_expr.as_ref().is_none_or(|expr| expr.satisfiable_by_faction(owner))
+                        });
+                        if !reachable {
+                            findings.push(Finding {
+                                check: "building-recruitment-unreachable",
+                                severity: Severity::Error,
+                                file: buildings_path.clone(),
+                                line_number: recruitment.line_number,
+                                related: vec![building.name.clone(), level.name.clone(), unit.name.clone()],
+                                message: format!(
+                                    "{:?} level {:?}'s recruit_pool for {:?} can never be recruited: no faction that owns the unit can also build the level",
+                                    building.name, level.name, unit.name
+                                ),
+                            });
+                        }
+                    }
+                    None => {
+                        let near_miss = world.units.iter().find(|u| u.name.trim().eq_ignore_ascii_case(recruitment.unit.trim()));
+                        match near_miss {
+                            Some(unit) => findings.push(Finding {
+                                check: "building-recruitment-unit-near-miss",
+                                severity: Severity::Error,
+                                file: buildings_path.clone(),
+                                line_number: recruitment.line_number,
+                                related: vec![building.name.clone(), level.name.clone(), recruitment.unit.clone(), unit.name.clone()],
+                                message: format!(
+                                    "{:?} level {:?}'s recruit_pool references {:?}, which only differs in case or whitespace from unit {:?}",
+                                    building.name, level.name, recruitment.unit, unit.name
+                                ),
+                            }),
+                            None => {
+                                let suggestion = closest_match(&recruitment.unit, world.units.iter().map(|u| &u.name));
+                                findings.push(Finding {
+                                    check: "building-recruitment-unit",
+                                    severity: Severity::Error,
+                                    file: buildings_path.clone(),
+                                    line_number: recruitment.line_number,
+                                    related: vec![building.name.clone(), level.name.clone(), recruitment.unit.clone()],
+                                    message: match suggestion {
+                                        Some(s) => format!(
+                                            "{:?} level {:?}'s recruit_pool references unknown unit {:?}; did you mean {s:?}?",
+                                            building.name, level.name, recruitment.unit
+                                        ),
+                                        None => format!(
+                                            "{:?} level {:?}'s recruit_pool references unknown unit {:?}",
+                                            building.name, level.name, recruitment.unit
+                                        ),
+                                    },
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Whether any faction of a culture could ever build this level
+            // is the closest thing this parser's flat, name-only
+            // `descr_cultures.txt` model (see `crate::culture::Culture`,
+            // which matches vanilla and carries no per-culture building
+            // list) has to the request's "culture default/core buildings"
+            // idea. `slave` (see `REBEL_FACTION`) is excluded since it
+            // doesn't belong to any culture.
+            if let Some(requires) = &level.requires {
+                let expr = RequiresExpr::parse(requires);
+                let mut cultures_with_factions = std::collections::BTreeSet::new();
+                for faction in &world.factions {
+                    if faction.name != REBEL_FACTION {
+                        if let Some(culture) = &faction.culture {
+                            cultures_with_factions.insert(culture.as_str());
+                        }
+                    }
+                }
+                for culture in cultures_with_factions {
+                    let buildable = world
+                        .factions
+                        .iter()
+                        .filter(|f| f.name != REBEL_FACTION && f.culture.as_deref() == Some(culture))
+                        .any(|f| expr.satisfiable_by_faction(&f.name));
+                    if !buildable {
+                        findings.push(Finding {
+                            check: "building-unbuildable-by-culture",
+                            severity: Severity::Error,
+                            file: buildings_path.clone(),
+                            line_number: level.line_number,
+                            related: vec![building.name.clone(), level.name.clone(), culture.to_string()],
+                            message: format!(
+                                "no {culture} faction can ever build {:?} level {:?} (requires {requires})",
+                                building.name, level.name
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    // Cross-check every `hidden_resource` a requires clause names against
+    // what `descr_regions.txt`'s `hidden_resources` block actually grants
+    // (the same names `region-resource` above already validates exist in
+    // descr_sm_resources.txt at all). A used-but-never-granted resource
+    // makes every level naming it permanently unbuildable; a
+    // granted-but-unused one is likely a stale leftover or a typo on the
+    // building side that
+    // this check's sibling doesn't catch because it only sees the
+    // resource's name, not what's supposed to reference it.
+    let mut granting_regions: std::collections::BTreeMap<&str, Vec<&crate::region::Region>> = std::collections::BTreeMap::new();
+    for region in &world.regions {
+        for name in &region.hidden_resources {
+            granting_regions.entry(name.as_str()).or_default().push(region);
+        }
+    }
+    for (resource, (line_number, levels)) in &hidden_resource_uses {
+        if !granting_regions.contains_key(resource.as_str()) {
+            let mut levels: Vec<&String> = levels.iter().collect();
+            levels.sort();
+            findings.push(Finding {
+                check: "hidden-resource-never-granted",
+                severity: Severity::Error,
+                file: buildings_path.clone(),
+                line_number: *line_number,
+                related: std::iter::once(resource.clone()).chain(levels.iter().map(|l| l.to_string())).collect(),
+                message: format!(
+                    "hidden_resource {resource:?} is required but no region grants it, making {} permanently unbuildable",
+                    levels.iter().map(|l| l.as_str()).collect::<Vec<_>>().join(", ")
+                ),
+            });
+        }
+    }
+    for (resource, regions) in &granting_regions {
+        if !hidden_resource_uses.contains_key(*resource) {
+            findings.push(Finding {
+                check: "hidden-resource-never-used",
+                severity: Severity::Warning,
+                file: regions_path.clone(),
+                line_number: regions[0].line_number,
+                related: std::iter::once(resource.to_string()).chain(regions.iter().map(|r| r.name.clone())).collect(),
+                message: format!(
+                    "hidden_resource {resource:?} is granted by {} region(s) ({}) but no requires clause ever checks for it",
+                    regions.len(),
+                    regions.iter().map(|r| r.name.as_str()).collect::<Vec<_>>().join(", ")
+                ),
+            });
+        }
+    }
+
+    // Every `capability { wall_level N }` in export_descr_buildings.txt has
+    // to name a level `descr_walls.txt` actually defines, or the settlement
+    // ends up with walls the engine doesn't know how to render and the
+    // siege crashes. `wall_level`'s value is validated as a bare integer
+    // rather than reused from `descr_walls.txt`'s own parsing so a
+    // non-numeric value here is reported at the capability, not silently
+    // treated as "level 0 not found".
+    let walls_path = source_path(world, config, "descr_walls.txt");
+    let defined_wall_levels: std::collections::BTreeSet<u32> = world.wall_levels.iter().map(|w| w.level).collect();
+    for building in &world.buildings {
+        for level in &building.levels {
+            for capability in &level.capabilities {
+                if capability.kind != "wall_level" {
+                    continue;
+                }
+                match capability.value.trim().parse::<u32>() {
+                    Ok(wall_level) if !defined_wall_levels.contains(&wall_level) => {
+                        findings.push(Finding {
+                            check: "building-wall-level-unknown",
+                            severity: Severity::Error,
+                            file: buildings_path.clone(),
+                            line_number: capability.line_number,
+                            related: vec![building.name.clone(), level.name.clone(), wall_level.to_string()],
+                            message: format!(
+                                "{:?} level {:?} grants wall_level {wall_level}, which descr_walls.txt does not define",
+                                building.name, level.name
+                            ),
+                        });
+                    }
+                    Ok(_) => {}
+                    Err(_) => {
+                        findings.push(Finding {
+                            check: "building-wall-level-unknown",
+                            severity: Severity::Error,
+                            file: buildings_path.clone(),
+                            line_number: capability.line_number,
+                            related: vec![building.name.clone(), level.name.clone(), capability.value.clone()],
+                            message: format!("{:?} level {:?}'s wall_level {:?} is not a number", building.name, level.name, capability.value),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    // A wall level's turret/gate model references have to name an actual
+    // `descr_model_battle.txt` record, the same way a unit's `soldier` model
+    // does above, or the battle map falls back to no model at all for that
+    // piece of the walls.
+    for wall_level in &world.wall_levels {
+        for model in wall_level.all_models() {
+            if !world.battle_models.iter().any(|m| m.name == model.model) {
+                let suggestion = closest_match(&model.model, world.battle_models.iter().map(|m| &m.name));
+                findings.push(Finding {
+                    check: "wall-model-unknown",
+                    severity: Severity::Error,
+                    file: walls_path.clone(),
+                    line_number: model.line_number,
+                    related: vec![wall_level.level.to_string(), model.key.clone(), model.model.clone()],
+                    message: match suggestion {
+                        Some(s) => format!("wall_level {}'s {} references unknown battle model {:?}; did you mean {s:?}?", wall_level.level, model.key, model.model),
+                        None => format!("wall_level {}'s {} references unknown battle model {:?}", wall_level.level, model.key, model.model),
+                    },
+                });
+            }
+        }
+    }
+
+    // A mission's `payback`/`payback_*` rewards and `condition` lines all
+    // name records elsewhere in the data; a rename on either side breaks the
+    // mission silently since nothing else in the game re-checks it.
+    if let Some(missions_path) = world.sources.get("descr_missions.txt").map(|r| r.path.clone()) {
+        for mission in &world.missions {
+            for condition in &mission.conditions {
+                if condition.kind == "faction" && !world.factions.iter().any(|f| f.name == condition.value) {
+                    let suggestion = closest_match(&condition.value, world.factions.iter().map(|f| &f.name));
+                    findings.push(Finding {
+                        check: "mission-condition-unknown",
+                        severity: Severity::Error,
+                        file: missions_path.clone(),
+                        line_number: condition.line_number,
+                        related: vec![mission.name.clone(), condition.value.clone()],
+                        message: match suggestion {
+                            Some(s) => format!("mission {:?}'s condition references unknown faction {:?}; did you mean {s:?}?", mission.name, condition.value),
+                            None => format!("mission {:?}'s condition references unknown faction {:?}", mission.name, condition.value),
+                        },
+                    });
+                } else if condition.kind == "region" && world.region_by_name(&condition.value).is_none() {
+                    let suggestion = closest_match(&condition.value, world.regions.iter().map(|r| &r.name));
+                    findings.push(Finding {
+                        check: "mission-condition-unknown",
+                        severity: Severity::Error,
+                        file: missions_path.clone(),
+                        line_number: condition.line_number,
+                        related: vec![mission.name.clone(), condition.value.clone()],
+                        message: match suggestion {
+                            Some(s) => format!("mission {:?}'s condition references unknown region {:?}; did you mean {s:?}?", mission.name, condition.value),
+                            None => format!("mission {:?}'s condition references unknown region {:?}", mission.name, condition.value),
+                        },
+                    });
+                }
+            }
+
+            for payback in &mission.paybacks {
+                match payback.kind.as_str() {
+                    "unit" => {
+                        let Some(unit_name) = crate::mission::Mission::reward_unit_name(payback) else { continue };
+                        if !world.units.iter().any(|u| u.name == unit_name) {
+                            let suggestion = closest_match(unit_name, world.units.iter().map(|u| &u.name));
+                            findings.push(Finding {
+                                check: "mission-payback-unit",
+                                severity: Severity::Error,
+                                file: missions_path.clone(),
+                                line_number: payback.line_number,
+                                related: vec![mission.name.clone(), unit_name.to_string()],
+                                message: match suggestion {
+                                    Some(s) => format!("mission {:?}'s unit payback references unknown unit {unit_name:?}; did you mean {s:?}?", mission.name),
+                                    None => format!("mission {:?}'s unit payback references unknown unit {unit_name:?}", mission.name),
+                                },
+                            });
+                        }
+                    }
+                    "building" if !building_names.contains(payback.value.as_str()) => {
+                        let suggestion = closest_match(&payback.value, world.buildings.iter().map(|b| &b.name));
+                        findings.push(Finding {
+                            check: "mission-payback-building",
+                            severity: Severity::Error,
+                            file: missions_path.clone(),
+                            line_number: payback.line_number,
+                            related: vec![mission.name.clone(), payback.value.clone()],
+                            message: match suggestion {
+                                Some(s) => format!("mission {:?}'s building payback references unknown building {:?}; did you mean {s:?}?", mission.name, payback.value),
+                                None => format!("mission {:?}'s building payback references unknown building {:?}", mission.name, payback.value),
+                            },
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Every unit needs an `export_units.txt` entry for its localization key
+    // (the `dictionary` tag if it has one, else its own name -- the same
+    // fallback `LocalizationFile::apply` uses) plus `_descr`/`_descr_short`
+    // variants, or the recruitment scroll shows the raw key instead of a
+    // name/description. `unit_keys`/`building_keys` are the per-file views
+    // `LocalizationFile::load` keeps separate from its merged `strings` map
+    // specifically so this check isn't fooled by an unrelated file
+    // supplying the same key.
+    let export_units_path = locale_text_path(config, "export_units.txt");
+    let mut unit_localization_keys: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+    for unit in &world.units {
+        let key = unit.extra.get("dictionary").map(String::as_str).unwrap_or(unit.name.as_str());
+        let expected = [key.to_string(), format!("{key}_descr"), format!("{key}_descr_short")];
+        let missing: Vec<&str> = expected.iter().filter(|k| !world.localization.unit_keys.contains_key(k.as_str())).map(String::as_str).collect();
+        if !missing.is_empty() {
+            findings.push(Finding {
+                check: "unit-localization-missing",
+                severity: Severity::Error,
+                file: units_path.clone(),
+                line_number: unit.line_number,
+                related: std::iter::once(unit.name.clone()).chain(missing.iter().map(|m| m.to_string())).collect(),
+                message: format!("unit {:?} is missing export_units.txt {}: {}", unit.name, if missing.len() == 1 { "key" } else { "keys" }, missing.join(", ")),
+            });
+        }
+        unit_localization_keys.extend(expected);
+    }
+    for key in world.localization.unit_keys.keys() {
+        if !unit_localization_keys.contains(key) {
+            findings.push(Finding {
+                check: "localization-orphaned",
+                severity: Severity::Warning,
+                file: export_units_path.clone(),
+                line_number: 1,
+                related: vec![key.clone()],
+                message: format!("export_units.txt key {key:?} matches no current unit"),
+            });
+        }
+    }
+
+    // Same idea for `export_buildings.txt`, keyed on a building level's own
+    // name rather than a `dictionary` tag -- EDB levels have no such field.
+    let export_buildings_path = locale_text_path(config, "export_buildings.txt");
+    let mut building_localization_keys: std::collections::BTreeSet<&str> = std::collections::BTreeSet::new();
+    for building in &world.buildings {
+        for level in &building.levels {
+            if !world.localization.building_keys.contains_key(level.name.as_str()) {
+                findings.push(Finding {
+                    check: "building-localization-missing",
+                    severity: Severity::Error,
+                    file: buildings_path.clone(),
+                    line_number: level.line_number,
+                    related: vec![format!("{}/{}", building.name, level.name)],
+                    message: format!("building level {:?} ({}) is missing its export_buildings.txt key", level.name, building.name),
+                });
+            }
+            building_localization_keys.insert(level.name.as_str());
+        }
+    }
+    for key in world.localization.building_keys.keys() {
+        if !building_localization_keys.contains(key.as_str()) {
+            findings.push(Finding {
+                check: "localization-orphaned",
+                severity: Severity::Warning,
+                file: export_buildings_path.clone(),
+                line_number: 1,
+                related: vec![key.clone()],
+                message: format!("export_buildings.txt key {key:?} matches no current building level"),
+            });
+        }
+    }
+
+    // A unit no `recruit_pool` anywhere ever names (by exact match) is dead
+    // weight: it'll sit in export_descr_unit.txt but never appear in a
+    // recruitment screen. Mercenaries and rebel-only units are exempt,
+    // since they're raised through descr_mercenaries.txt or ownership
+    // rather than a building's recruit_pool.
+    for unit in &world.units {
+        if unit.is_mercenary() || (!unit.ownership.is_empty() && unit.ownership.iter().all(|f| f == REBEL_FACTION)) {
+            continue;
+        }
+        if !recruited_units.contains(unit.name.as_str()) {
+            findings.push(Finding {
+                check: "unit-never-recruited",
+                severity: Severity::Warning,
+                file: units_path.clone(),
+                line_number: unit.line_number,
+                related: vec![unit.name.clone()],
+                message: format!("unit {:?} is not recruitable from any building's recruit_pool", unit.name),
+            });
+        }
+    }
+
+    // `ownership` accepts a real faction name, the `slave` keyword (see
+    // [`REBEL_FACTION`]), or a culture name as shorthand for every faction
+    // of that culture -- the engine expands the shorthand itself, this
+    // parser just keeps the raw string.
+    for unit in &world.units {
+        let ownership_line = || *unit.field_lines.get("ownership").unwrap_or(&unit.line_number);
+
+        for name in &unit.ownership {
+            if name == REBEL_FACTION {
+                continue;
+            }
+            let known = world.factions.iter().any(|f| &f.name == name) || world.cultures.iter().any(|c| &c.name == name);
+            if !known {
+                findings.push(Finding {
+                    check: "unit-ownership-faction",
+                    severity: Severity::Error,
+                    file: units_path.clone(),
+                    line_number: ownership_line(),
+                    related: vec![unit.name.clone(), name.clone()],
+                    message: format!("unit {:?}'s ownership references unknown faction or culture {name:?}", unit.name),
+                });
+            }
+        }
+
+        if unit.ownership.is_empty() && !unit.is_mercenary() {
+            findings.push(Finding {
+                check: "unit-owned-by-nobody",
+                severity: Severity::Error,
+                file: units_path.clone(),
+                line_number: unit.line_number,
+                related: vec![unit.name.clone()],
+                message: format!("unit {:?} has no ownership entries and isn't a mercenary, so no faction can ever recruit it", unit.name),
+            });
+        }
+
+        for era in &unit.era_ownership {
+            for name in &era.factions {
+                if !unit.ownership.iter().any(|owner| owner == name) {
+                    findings.push(Finding {
+                        check: "unit-era-ownership",
+                        severity: Severity::Error,
+                        file: units_path.clone(),
+                        line_number: era.line_number,
+                        related: vec![unit.name.clone(), name.clone()],
+                        message: format!("unit {:?}'s era {} line references {name:?}, which isn't in its ownership list", unit.name, era.era),
+                    });
+                }
+            }
+        }
+    }
+
+    // A faction with no units at all (directly, or via a culture-shorthand
+    // `ownership` entry) can still play, but it's a strong sign a rename
+    // slipped past every unit's ownership list. `slave` is excluded, same
+    // as the culture-coverage check above.
+    let mut factions_with_units = std::collections::BTreeSet::new();
+    for unit in &world.units {
+        for name in &unit.ownership {
+            if world.factions.iter().any(|f| &f.name == name) {
+                factions_with_units.insert(name.as_str());
+            } else if world.cultures.iter().any(|c| &c.name == name) {
+                factions_with_units.extend(world.factions.iter().filter(|f| f.culture.as_deref() == Some(name.as_str())).map(|f| f.name.as_str()));
+            }
+        }
+    }
+    for faction in &world.factions {
+        if faction.name != REBEL_FACTION && !factions_with_units.contains(faction.name.as_str()) {
+            findings.push(Finding {
+                check: "faction-no-units",
+                severity: Severity::Warning,
+                file: factions_path.clone(),
+                line_number: faction.line_number,
+                related: vec![faction.name.clone()],
+                message: format!("faction {:?} doesn't own any units", faction.name),
+            });
+        }
+    }
+
+    // Two factions/regions/units/cultures sharing a name (case-insensitively)
+    // would otherwise silently shadow each other in `World::index` and
+    // anything built on it -- surface it instead of leaving it to whichever
+    // lookup happens to notice the wrong record came back.
+    for duplicate in &world.index().duplicates {
+        let (file, line_number) = match duplicate.kind {
+            "faction" => (factions_path.clone(), world.factions[duplicate.second_id].line_number),
+            "region" => (regions_path.clone(), world.regions[duplicate.second_id].line_number),
+            "unit" => (units_path.clone(), world.units[duplicate.second_id].line_number),
+            "culture" => (cultures_path.clone(), world.cultures[duplicate.second_id].line_number),
+            other => unreachable!("WorldIndex only reports these four kinds, got {other:?}"),
+        };
+        findings.push(Finding {
+            check: "duplicate-name",
+            severity: Severity::Error,
+            file,
+            line_number,
+            related: vec![duplicate.name.clone()],
+            message: format!("{} {:?} is defined more than once (case-insensitively) -- only the first definition is reachable by name", duplicate.kind, duplicate.name),
+        });
+    }
+
+    // A unit missing from every voice class is silent in battle -- nothing
+    // else in the engine falls back to a default voice -- and a name in a
+    // voice class that no longer matches a unit is dead weight left behind
+    // by a rename, so it's a warning rather than an error either way.
+    // `export_descr_sounds_units_voice.txt` is optional, so both checks are
+    // skipped entirely when it wasn't found, same as the missions checks
+    // above.
+    if let Some(voice_path) = world.sources.get("export_descr_sounds_units_voice.txt").map(|r| r.path.clone()) {
+        let mut voiced_units = std::collections::BTreeSet::new();
+        for class in &world.voice_classes {
+            for unit_ref in &class.units {
+                if world.units.iter().any(|u| u.name == unit_ref.name) {
+                    voiced_units.insert(unit_ref.name.as_str());
+                } else {
+                    let suggestion = closest_match(&unit_ref.name, world.units.iter().map(|u| &u.name));
+                    findings.push(Finding {
+                        check: "voice-unknown-unit",
+                        severity: Severity::Warning,
+                        file: voice_path.clone(),
+                        line_number: unit_ref.line_number,
+                        related: vec![class.name.clone(), unit_ref.name.clone()],
+                        message: match suggestion {
+                            Some(s) => format!("voice class {:?} names unknown unit {:?}; did you mean {s:?}?", class.name, unit_ref.name),
+                            None => format!("voice class {:?} names unknown unit {:?}", class.name, unit_ref.name),
+                        },
+                    });
+                }
+            }
+        }
+        for unit in &world.units {
+            if !voiced_units.contains(unit.name.as_str()) {
+                findings.push(Finding {
+                    check: "unit-missing-voice",
+                    severity: Severity::Warning,
+                    file: units_path.clone(),
+                    line_number: unit.line_number,
+                    related: vec![unit.name.clone()],
+                    message: format!("unit {:?} isn't listed in any export_descr_sounds_units_voice.txt class, so it will be silent in battle", unit.name),
+                });
+            }
+        }
+
+        // The engine loads the compiled `.dat`/`.idx` pair, not the text
+        // source directly, so an edit here does nothing in-game until
+        // someone re-runs the compiler that produces them.
+        let compiled_dat = voice_path.with_extension("dat");
+        if let (Ok(source_modified), Ok(compiled_modified)) = (std::fs::metadata(&voice_path).and_then(|m| m.modified()), std::fs::metadata(&compiled_dat).and_then(|m| m.modified())) {
+            if source_modified > compiled_modified {
+                findings.push(Finding {
+                    check: "voice-stale-compiled-data",
+                    severity: Severity::Warning,
+                    file: voice_path.clone(),
+                    line_number: 1,
+                    related: vec![compiled_dat.display().to_string()],
+                    message: format!("export_descr_sounds_units_voice.txt is newer than {} -- recompile it or the game won't see this change", compiled_dat.display()),
+                });
+            }
+        }
+    }
+
+    findings
+}
+
+/// Looks up `name`'s resolved path from `world.sources`, falling back to
+/// `config.resolve` for a file the load skipped recording (an optional file
+/// that turned out not to exist, so nothing references it here anyway).
+fn source_path(world: &World, config: &Config, name: &str) -> PathBuf {
+    match world.sources.get(name) {
+        Some(resolved) => resolved.path.clone(),
+        None => config.resolve(Path::new(name)).path,
+    }
+}
+
+/// A case-insensitive, order-independent key for a faction pair, so a
+/// `relationship` block declaring `france`/`england` is recognized as the
+/// same pair as an older `faction_relationships` line declaring
+/// `england`/`france`.
+fn diplomacy_pair_key(a: &str, b: &str) -> (String, String) {
+    let (a, b) = (a.to_lowercase(), b.to_lowercase());
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Where `LocalizationFile::load` would have read `name` from -- `locale_dir`
+/// if set, else `data_dir`, joined with `text/`, mirroring the fallback
+/// `World::load` itself uses to pick a locale directory. Locale files never
+/// earn a `world.sources` entry (unlike the `descr_*.txt`/`export_descr_*.txt`
+/// family `source_path` looks up), since `LocalizationFile` doesn't track one.
+fn locale_text_path(config: &Config, name: &str) -> PathBuf {
+    let locale_dir = config.locale_dir.as_deref().unwrap_or(&config.data_dir);
+    locale_dir.join("text").join(name)
+}
+
+/// Checks a `descr_strat.txt` character's name against its faction's
+/// `descr_names.txt` pool: the first name must appear in either the
+/// `male_names` or `female_names` list, and a surname (if the character has
+/// one) must appear in `surnames`. A mismatch makes the engine fall back to
+/// a blank name, or crash outright when it tries to generate a family
+/// member for that character.
+///
+/// Rebel characters are exempt (see [`REBEL_FACTION`]). A `sub_faction NAME`
+/// flag, used by regional culture variants such as Sicily under the HRE,
+/// redirects the lookup to that faction's pool instead of the owning
+/// faction's, matching the engine's own rule for where it draws those
+/// characters' names from.
+fn check_character_name(world: &World, character: &Character, strat_path: &Path) -> Option<Finding> {
+    if character.owning_faction == REBEL_FACTION {
+        return None;
+    }
+
+    let faction = character
+        .flags
+        .iter()
+        .find_map(|flag| flag.strip_prefix("sub_faction "))
+        .map(str::trim)
+        .unwrap_or(character.owning_faction.as_str());
+    let pool = world.names_for(faction)?;
+
+    let mut parts = character.name.split_whitespace();
+    let first = parts.next()?;
+    let surname = parts.next();
+
+    if !pool.male_names.iter().any(|n| n == first) && !pool.female_names.iter().any(|n| n == first) {
+        let suggestion = closest_match(first, pool.male_names.iter().chain(&pool.female_names));
+        return Some(Finding {
+            check: "character-name",
+            severity: Severity::Error,
+            file: strat_path.to_path_buf(),
+            line_number: character.line_number,
+            related: vec![character.name.clone(), faction.to_string()],
+            message: match suggestion {
+                Some(s) => format!("character {:?} (faction {faction:?}) has first name {first:?}, which is not in its name pool; did you mean {s:?}?", character.name),
+                None => format!("character {:?} (faction {faction:?}) has first name {first:?}, which is not in its name pool", character.name),
+            },
+        });
+    }
+
+    if let Some(surname) = surname {
+        if !pool.surnames.iter().any(|n| n == surname) {
+            let suggestion = closest_match(surname, pool.surnames.iter());
+            return Some(Finding {
+                check: "character-name",
+                severity: Severity::Error,
+                file: strat_path.to_path_buf(),
+                line_number: character.line_number,
+                related: vec![character.name.clone(), faction.to_string()],
+                message: match suggestion {
+                    Some(s) => format!("character {:?} (faction {faction:?}) has surname {surname:?}, which is not in its name pool; did you mean {s:?}?", character.name),
+                    None => format!("character {:?} (faction {faction:?}) has surname {surname:?}, which is not in its name pool", character.name),
+                },
+            });
+        }
+    }
+
+    None
+}
+
+/// Returns the entry in `candidates` with the smallest Levenshtein distance
+/// to `target`, for suggesting a "did you mean" fix on a name mismatch.
+pub(crate) fn closest_match<'a>(target: &str, candidates: impl Iterator<Item = &'a String>) -> Option<&'a str> {
+    candidates.min_by_key(|candidate| levenshtein(target, candidate)).map(String::as_str)
+}
+
+/// Classic Levenshtein edit distance between two strings, by character
+/// (not byte), so it stays correct on the accented names `descr_names.txt`
+/// can contain.
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, &ac) in a.iter().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+        for (j, &bc) in b.iter().enumerate() {
+            let temp = row[j + 1];
+            row[j + 1] = if ac == bc { prev_diag } else { 1 + prev_diag.min(row[j]).min(row[j + 1]) };
+            prev_diag = temp;
+        }
+    }
+    row[b.len()]
+}
+
+/// Compares a map file's actual dimensions (if the file could be read at
+/// all) against what `descr_terrain.txt` implies they should be. A missing
+/// or unreadable file is left to whichever check actually needs to load it
+/// (e.g. `map check-regions`), rather than duplicated here.
+fn check_map_size(findings: &mut Vec<Finding>, terrain_path: &Path, line_number: usize, file_name: &str, actual: Option<(u32, u32)>, expected: (u32, u32)) {
+    if let Some(actual) = actual {
+        if actual != expected {
+            findings.push(Finding {
+                check: "map-size",
+                severity: Severity::Error,
+                file: terrain_path.to_path_buf(),
+                line_number,
+                related: vec![file_name.to_string()],
+                message: format!(
+                    "{file_name} is {}x{}, but descr_terrain.txt implies it should be {}x{}",
+                    actual.0, actual.1, expected.0, expected.1
+                ),
+            });
+        }
+    }
+}
+
+fn check_tile_placement(
+    heightmap: &crate::heightmap::HeightMap,
+    position: crate::character::Position,
+    strat_path: &Path,
+    line_number: usize,
+    check: &'static str,
+    who: &str,
+) -> Option<Finding> {
+    match heightmap.classify_tile(position.x, position.y) {
+        TileClass::Land => None,
+        TileClass::Sea => Some(Finding {
+            severity: Severity::Error,
+            file: strat_path.to_path_buf(),
+            line_number,
+            check,
+            related: vec![who.to_string()],
+            message: format!("{who} is placed at ({}, {}), which is sea", position.x, position.y),
+        }),
+        TileClass::OffMap => Some(Finding {
+            severity: Severity::Error,
+            file: strat_path.to_path_buf(),
+            line_number,
+            check,
+            related: vec![who.to_string()],
+            message: format!("{who} is placed at ({}, {}), which is off the edge of the map", position.x, position.y),
+        }),
+    }
+}
+
+/// Character kinds whose army travels by sea, so a sea tile is expected
+/// rather than flagged. Every other kind (see the `kind` values noted on
+/// [`crate::campaign_script::COMMAND_ARG_KINDS`]) is treated as land-based.
+const NAVAL_CHARACTER_KINDS: &[&str] = &["admiral"];
+
+/// Character kinds that represent the ruling family and its generals, which
+/// the engine requires to start inside territory their own faction actually
+/// controls. Agents (spies, assassins, diplomats, merchants, priests,
+/// heretics, witches) and admirals are exempt, since starting embedded in
+/// another faction's court or out at sea is exactly the point of them.
+const FAMILY_MEMBER_KINDS: &[&str] = &["king", "heir", "general", "princess", "named_character"];
+
+/// Classifies `character`'s tile and checks it against what its `kind`
+/// implies it should be able to stand on: naval kinds ([`NAVAL_CHARACTER_KINDS`])
+/// expect sea, everyone else expects land. Off-map is always wrong,
+/// regardless of kind.
+fn check_character_tile_class(
+    heightmap: &crate::heightmap::HeightMap,
+    character: &Character,
+    position: crate::character::Position,
+    strat_path: &Path,
+) -> Option<Finding> {
+    let expects_sea = NAVAL_CHARACTER_KINDS.contains(&character.kind.as_str());
+    let class = heightmap.classify_tile(position.x, position.y);
+    let reason = match class {
+        TileClass::Land if expects_sea => "which is land, but its army is naval and expects a sea tile",
+        TileClass::Sea if !expects_sea => "which is sea, but its army is land-based and expects a land tile",
+        TileClass::OffMap => "which is off the edge of the map",
+        _ => return None,
+    };
+    Some(Finding {
+        check: "character-tile-class",
+        severity: Severity::Error,
+        file: strat_path.to_path_buf(),
+        line_number: character.line_number,
+        related: vec![character.name.clone()],
+        message: format!("character {:?} (kind {:?}) is placed at ({}, {}), {reason}", character.name, character.kind, position.x, position.y),
+    })
+}
+
+/// Checks that a family member (see [`FAMILY_MEMBER_KINDS`]) starts inside a
+/// region their own faction controls at campaign start (`descr_regions.txt`'s
+/// `creator_faction`), matching the character's tile to a region by looking
+/// up its `map_regions.tga` pixel colour the same way [`crate::commands::resources::build_report`]
+/// attributes a resource placement to a region. Silently skipped if the tile
+/// doesn't land on any known region's colour (already reported by the
+/// tile-class check above if that's because it's off the map).
+fn check_character_region_ownership(world: &World, character: &Character, map_regions: &TgaImage, strat_path: &Path) -> Option<Finding> {
+    if !FAMILY_MEMBER_KINDS.contains(&character.kind.as_str()) {
+        return None;
+    }
+    let position = character.position?;
+    let x = u32::try_from(position.x).ok()?;
+    let y = u32::try_from(position.y).ok()?;
+    let colour = map_regions.pixel(x, y)?;
+    let region = world.regions.iter().find(|region| region.colour == colour)?;
+    if region.creator_faction == character.owning_faction {
+        return None;
+    }
+    Some(Finding {
+        check: "character-region-ownership",
+        severity: Severity::Error,
+        file: strat_path.to_path_buf(),
+        line_number: character.line_number,
+        related: vec![character.name.clone(), character.owning_faction.clone(), region.name.clone()],
+        message: format!(
+            "character {:?} (faction {:?}) starts in region {:?}, which faction {:?} controls at campaign start",
+            character.name, character.owning_faction, region.name, region.creator_faction
+        ),
+    })
+}
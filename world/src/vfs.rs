@@ -0,0 +1,191 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use pack::pack::Pack;
+
+use crate::error::Result;
+
+/// Bytes read through a [`Vfs`], plus a short label identifying where they
+/// came from (a directory role, or a `.pack` file name) for provenance
+/// reporting.
+pub struct VfsRead {
+    pub bytes: Vec<u8>,
+    pub origin: String,
+}
+
+/// A source of file bytes addressed by a path relative to a data directory
+/// (e.g. `descr_regions.txt`). Lets [`crate::config::Config`] read the same
+/// filenames from a plain directory or from a set of `.pack` files without
+/// the rest of the codebase caring which.
+pub trait Vfs {
+    fn read(&self, relative: &Path) -> Result<Option<VfsRead>>;
+
+    /// Lists the file names directly inside the directory `relative`, or
+    /// `None` if this source has no such directory at all -- as opposed to
+    /// `Some(vec![])`, which means the directory exists but is empty.
+    fn list_dir(&self, relative: &Path) -> Result<Option<Vec<String>>>;
+}
+
+/// Reads files straight off disk under `dir`, labelling every read with
+/// `label` (e.g. `"mod"`, `"base"`).
+pub struct DirVfs {
+    dir: PathBuf,
+    label: String,
+}
+
+impl DirVfs {
+    pub fn new(dir: PathBuf, label: impl Into<String>) -> Self {
+        DirVfs { dir, label: label.into() }
+    }
+}
+
+impl Vfs for DirVfs {
+    fn read(&self, relative: &Path) -> Result<Option<VfsRead>> {
+        let path = self.dir.join(relative);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let bytes = std::fs::read(&path).map_err(|e| crate::error::io_err(&path, e))?;
+        Ok(Some(VfsRead { bytes, origin: self.label.clone() }))
+    }
+
+    fn list_dir(&self, relative: &Path) -> Result<Option<Vec<String>>> {
+        let path = self.dir.join(relative);
+        if !path.is_dir() {
+            return Ok(None);
+        }
+        let mut names = Vec::new();
+        for entry in std::fs::read_dir(&path).map_err(|e| crate::error::io_err(&path, e))? {
+            let entry = entry.map_err(|e| crate::error::io_err(&path, e))?;
+            if entry.path().is_file() {
+                if let Some(name) = entry.file_name().to_str() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        Ok(Some(names))
+    }
+}
+
+/// Reads files out of a set of `.pack` files, opened once up front. Entries
+/// are matched by exact relative path, the same bare form
+/// [`crate::config::Config::resolve`] already uses. `Pack::read_entry_bytes`
+/// needs `&mut self` to seek the underlying file, so each opened pack is
+/// wrapped in a `Mutex` to let `read` take `&self` like `DirVfs` while
+/// staying safe to call from [`World::load`]'s parallel component reads --
+/// a plain `RefCell` would make `PackVfs` (and so `Config`) `!Sync`.
+pub struct PackVfs {
+    /// `(pack name, opened pack)`, in load order (later entries win).
+    packs: Vec<(String, Mutex<Pack<File>>)>,
+}
+
+impl PackVfs {
+    pub fn open(dir: &Path) -> Result<Self> {
+        let mut names = Vec::new();
+        for entry in std::fs::read_dir(dir).map_err(|e| crate::error::io_err(dir, e))? {
+            let entry = entry.map_err(|e| crate::error::io_err(dir, e))?;
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("pack") {
+                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        pack::loadorder::sort_by_load_order(&mut names);
+
+        let mut packs = Vec::with_capacity(names.len());
+        for name in names {
+            let pack = Pack::<File>::open(&dir.join(&name))?;
+            packs.push((name, Mutex::new(pack)));
+        }
+        Ok(PackVfs { packs })
+    }
+}
+
+impl Vfs for PackVfs {
+    fn read(&self, relative: &Path) -> Result<Option<VfsRead>> {
+        for (name, pack) in self.packs.iter().rev() {
+            let mut pack = pack.lock().expect("pack mutex poisoned by a prior panic");
+            let Some(entry) = pack.entries.iter().find(|entry| entry.path == relative).cloned() else {
+                continue;
+            };
+            let bytes = pack.read_entry_bytes(&entry)?;
+            return Ok(Some(VfsRead { bytes, origin: name.clone() }));
+        }
+        Ok(None)
+    }
+
+    /// A `.pack` archive has no directory nodes of its own, only a flat list
+    /// of entry paths -- so "the directory exists" is inferred from at
+    /// least one entry living under it, in the same later-pack-wins load
+    /// order `read` uses.
+    fn list_dir(&self, relative: &Path) -> Result<Option<Vec<String>>> {
+        Ok(self.list_dir_named(relative)?.map(|(names, _)| names))
+    }
+}
+
+impl PackVfs {
+    /// Same as [`Vfs::list_dir`], but also returns the name of the pack that
+    /// satisfied it, since `.pack` archives don't carry a fixed label like
+    /// [`DirVfs`]'s `"mod"`/`"base"` -- callers that report provenance (see
+    /// [`crate::config::Config::list_dir`]) need it.
+    pub fn list_dir_named(&self, relative: &Path) -> Result<Option<(Vec<String>, String)>> {
+        for (name, pack) in self.packs.iter().rev() {
+            let pack = pack.lock().expect("pack mutex poisoned by a prior panic");
+            let names: Vec<String> = pack
+                .entries
+                .iter()
+                .filter_map(|entry| entry.path.parent().filter(|parent| *parent == relative).and(entry.path.file_name()).and_then(|n| n.to_str()).map(str::to_string))
+                .collect();
+            if !names.is_empty() {
+                return Ok(Some((names, name.clone())));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir_vfs_reads_existing_files_and_reports_missing_ones_as_none() {
+        let dir = std::env::temp_dir().join("world_vfs_dir_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("descr_regions.txt"), b"england\n").unwrap();
+
+        let vfs = DirVfs::new(dir.clone(), "base");
+        let found = vfs.read(Path::new("descr_regions.txt")).unwrap().unwrap();
+        assert_eq!(found.bytes, b"england\n");
+        assert_eq!(found.origin, "base");
+
+        assert!(vfs.read(Path::new("does_not_exist.txt")).unwrap().is_none());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    fn write_pack(path: &Path, entries: &[(&str, &[u8])]) {
+        let prepared = entries.iter().map(|(name, data)| pack::writer::prepare_entry((*name).to_string(), data)).collect::<Vec<_>>();
+        pack::writer::write_pack(path, &prepared).unwrap();
+    }
+
+    #[test]
+    fn pack_vfs_reads_entries_and_later_load_order_packs_win() {
+        let dir = std::env::temp_dir().join("world_vfs_pack_test");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        write_pack(&dir.join("data.pack"), &[("descr_regions.txt", b"base england\n")]);
+        write_pack(&dir.join("patch.pack"), &[("descr_regions.txt", b"patched england\n")]);
+
+        let vfs = PackVfs::open(&dir).unwrap();
+        let found = vfs.read(Path::new("descr_regions.txt")).unwrap().unwrap();
+        assert_eq!(found.bytes, b"patched england\n");
+        assert_eq!(found.origin, "patch.pack");
+
+        assert!(vfs.read(Path::new("does_not_exist.txt")).unwrap().is_none());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
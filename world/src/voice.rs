@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A `CLASS_NAME, accent ACCENT { ... }` block from
+/// `export_descr_sounds_units_voice.txt`, assigning a voice/accent pair to
+/// every unit type listed in its body. A unit not named under any class is
+/// silent in battle; [`crate::validate::validate`] flags that (and the
+/// reverse, a name that doesn't match a real unit) as warnings rather than
+/// errors, since neither breaks the campaign the way a missing model or
+/// projectile would.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct VoiceClass {
+    /// 1-based line the class header started on.
+    pub line_number: usize,
+    pub name: String,
+    /// The `accent` named on the header line, if any -- some mods omit it
+    /// and let the engine fall back to a default.
+    pub accent: Option<String>,
+    pub units: Vec<VoiceUnitRef>,
+}
+
+/// One unit type name inside a [`VoiceClass`]'s body.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct VoiceUnitRef {
+    /// 1-based line this entry was declared on.
+    pub line_number: usize,
+    pub name: String,
+}
+
+impl VoiceClass {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<VoiceClass>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(path, &lines)
+    }
+}
+
+fn parse(path: &Path, lines: &[&DescrLine]) -> Result<Vec<VoiceClass>> {
+    let mut classes = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let header = lines[i];
+        if header.text.starts_with('{') || header.text.starts_with('}') {
+            i += 1;
+            continue;
+        }
+
+        let mut parts = header.text.splitn(2, ',');
+        let name = parts.next().unwrap_or_default().trim().to_string();
+        let accent = parts
+            .next()
+            .and_then(|rest| rest.trim().strip_prefix("accent"))
+            .map(|rest| rest.trim().to_string())
+            .filter(|accent| !accent.is_empty());
+
+        let (open, close) = find_block(path, lines, i + 1)?;
+        let units = lines[open + 1..close]
+            .iter()
+            .map(|line| VoiceUnitRef { line_number: line.line_number, name: line.text.clone() })
+            .collect();
+        classes.push(VoiceClass { line_number: header.line_number, name, accent, units });
+        i = close + 1;
+    }
+    Ok(classes)
+}
+
+/// Finds the `{ ... }` block starting at or after `lines[start]`, returning
+/// the index of the line holding the (first) unmatched opening brace and the
+/// index of the line holding its matching closing brace. Same approach as
+/// [`crate::wall::find_block`], duplicated here because nothing shared
+/// exists yet for this style of brace scan.
+fn find_block(path: &Path, lines: &[&DescrLine], start: usize) -> Result<(usize, usize)> {
+    let mut open_at = start;
+    loop {
+        if open_at >= lines.len() {
+            return Err(ParseError {
+                file: path.to_path_buf(),
+                line_number: lines.last().map(|l| l.line_number).unwrap_or(0),
+                line_text: String::new(),
+                message: "voice class is missing its opening `{`".to_string(),
+            }
+            .into());
+        }
+        let opens = lines[open_at].text.matches('{').count();
+        let closes = lines[open_at].text.matches('}').count();
+        if opens > closes {
+            break;
+        }
+        open_at += 1;
+    }
+
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(open_at) {
+        let opens = line.text.matches('{').count() as i32;
+        let closes = line.text.matches('}').count() as i32;
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((open_at, idx));
+        }
+    }
+
+    Err(ParseError {
+        file: path.to_path_buf(),
+        line_number: lines[open_at].line_number,
+        line_text: lines[open_at].text.clone(),
+        message: "voice class is missing its closing `}`".to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn classes_from(text: &str) -> Vec<VoiceClass> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("edsuv_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(Path::new("export_descr_sounds_units_voice.txt"), &lines).unwrap()
+    }
+
+    const EXCERPT: &str = r#"
+Peasants_and_Rebels, accent northern_european
+{
+    Peasant Archers
+    Peasants
+}
+Militia
+{
+    Militia Sergeants
+}
+"#;
+
+    #[test]
+    fn parses_classes_with_and_without_an_accent() {
+        let classes = classes_from(EXCERPT);
+        assert_eq!(classes.len(), 2);
+
+        let peasants = &classes[0];
+        assert_eq!(peasants.name, "Peasants_and_Rebels");
+        assert_eq!(peasants.accent.as_deref(), Some("northern_european"));
+        assert_eq!(peasants.units.len(), 2);
+        assert_eq!(peasants.units[0].name, "Peasant Archers");
+
+        let militia = &classes[1];
+        assert_eq!(militia.name, "Militia");
+        assert!(militia.accent.is_none());
+        assert_eq!(militia.units[0].name, "Militia Sergeants");
+    }
+
+    #[test]
+    fn missing_closing_brace_is_rejected() {
+        let text = "Militia\n{\n    Militia Sergeants\n";
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("edsuv_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        let message = parse(Path::new("export_descr_sounds_units_voice.txt"), &lines).unwrap_err().to_string();
+        assert!(message.contains("missing its closing `}`"), "{message}");
+    }
+}
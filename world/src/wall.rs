@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// A `wall_level N { ... }` record from `descr_walls.txt`. `export_descr_buildings.txt`
+/// grants a settlement a wall level through a building capability
+/// (`capability { wall_level N }`, see [`crate::building::Capability`]); this
+/// is what level `N` actually resolves to -- the turret and gate models used
+/// once a settlement's walls reach that grade.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct WallLevel {
+    /// 0-based position in `descr_walls.txt`, stable for a given file.
+    pub id: usize,
+    /// 1-based line the `wall_level N` header started on.
+    pub line_number: usize,
+    pub level: u32,
+    /// Model references (`turret_model`, `gate_model`, ...) declared
+    /// directly in the level block, applying to any culture with no more
+    /// specific [`WallLevel::variants`] entry of its own.
+    pub models: Vec<WallModelRef>,
+    /// Nested `culture LIST { ... }` blocks overriding the models for
+    /// specific cultures (e.g. eastern factions using different gate art).
+    pub variants: Vec<WallVariant>,
+    /// Any key this parser doesn't know about yet, kept verbatim so a
+    /// record round-trips instead of silently losing data.
+    pub extra: BTreeMap<String, String>,
+}
+
+/// One `culture LIST { ... }` block nested inside a [`WallLevel`].
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct WallVariant {
+    /// 1-based line the `culture LIST` header started on.
+    pub line_number: usize,
+    pub cultures: Vec<String>,
+    pub models: Vec<WallModelRef>,
+}
+
+/// A `turret_model`/`gate_model`/... line inside a [`WallLevel`] or
+/// [`WallVariant`], naming a battle model by identifier rather than a raw
+/// mesh path -- unlike [`crate::battle_model::ModelPath`], which points
+/// straight at a file, this points at a `descr_model_battle.txt` record by
+/// name so [`crate::validate::validate`] can cross-check it the same way it
+/// cross-checks a unit's `soldier` model.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct WallModelRef {
+    /// 1-based line this entry was declared on.
+    pub line_number: usize,
+    pub key: String,
+    pub model: String,
+}
+
+impl WallLevel {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<WallLevel>> {
+        let lines = parse_descr_lines(text, ReadOptions::default());
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        let mut levels = parse(path, &lines)?;
+        for (id, level) in levels.iter_mut().enumerate() {
+            level.id = id;
+        }
+        Ok(levels)
+    }
+
+    /// The models that apply for `culture`: its own [`WallVariant`] if one
+    /// is declared, otherwise the level's own direct models.
+    pub fn models_for(&self, culture: &str) -> &[WallModelRef] {
+        match self.variants.iter().find(|v| v.cultures.iter().any(|c| c.eq_ignore_ascii_case(culture))) {
+            Some(variant) => &variant.models,
+            None => &self.models,
+        }
+    }
+
+    /// Every model reference this level declares, direct or per-culture --
+    /// for cross-checks that don't care which culture a variant belongs to.
+    pub fn all_models(&self) -> impl Iterator<Item = &WallModelRef> {
+        self.models.iter().chain(self.variants.iter().flat_map(|v| v.models.iter()))
+    }
+}
+
+fn parse(path: &Path, lines: &[&DescrLine]) -> Result<Vec<WallLevel>> {
+    let mut levels = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some(rest) = lines[i].text.strip_prefix("wall_level ") {
+            let header_line = lines[i];
+            let level = rest.trim().parse().map_err(|_| {
+                WorldError::from(ParseError {
+                    file: path.to_path_buf(),
+                    line_number: header_line.line_number,
+                    line_text: header_line.text.clone(),
+                    message: format!("{:?} is not a valid wall level number", rest.trim()),
+                })
+            })?;
+            let (open, close) = find_block(path, lines, i)?;
+            levels.push(parse_level(path, level, header_line.line_number, &lines[open + 1..close])?);
+            i = close + 1;
+        } else {
+            i += 1;
+        }
+    }
+    Ok(levels)
+}
+
+fn parse_level(path: &Path, level: u32, line_number: usize, body: &[&DescrLine]) -> Result<WallLevel> {
+    let mut models = Vec::new();
+    let mut variants = Vec::new();
+    let mut extra = BTreeMap::new();
+
+    let mut i = 0;
+    while i < body.len() {
+        let line = body[i];
+        let text = line.text.as_str();
+
+        if let Some(rest) = text.strip_prefix("culture ") {
+            let cultures: Vec<String> = rest.split(',').map(str::trim).filter(|c| !c.is_empty()).map(str::to_string).collect();
+            let (open, close) = find_block(path, body, i)?;
+            variants.push(parse_variant(line.line_number, cultures, &body[open + 1..close]));
+            i = close + 1;
+            continue;
+        }
+
+        let mut parts = text.split_whitespace();
+        let Some(key) = parts.next() else {
+            i += 1;
+            continue;
+        };
+        let value = parts.collect::<Vec<_>>().join(" ");
+
+        if key.ends_with("_model") {
+            models.push(WallModelRef { line_number: line.line_number, key: key.to_string(), model: value });
+        } else {
+            extra.insert(key.to_string(), value);
+        }
+        i += 1;
+    }
+
+    Ok(WallLevel { id: 0, line_number, level, models, variants, extra })
+}
+
+fn parse_variant(line_number: usize, cultures: Vec<String>, body: &[&DescrLine]) -> WallVariant {
+    let mut models = Vec::new();
+    for line in body {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let value = parts.collect::<Vec<_>>().join(" ");
+        if key.ends_with("_model") {
+            models.push(WallModelRef { line_number: line.line_number, key: key.to_string(), model: value });
+        }
+    }
+    WallVariant { line_number, cultures, models }
+}
+
+/// Finds the `{ ... }` block starting at or after `lines[start]`, returning
+/// the index of the line holding the (first) unmatched opening brace and the
+/// index of the line holding its matching closing brace. Same approach as
+/// [`crate::building::find_block`], duplicated here because nothing shared
+/// exists yet for this style of brace scan.
+fn find_block(path: &Path, lines: &[&DescrLine], start: usize) -> Result<(usize, usize)> {
+    let mut open_at = start;
+    loop {
+        if open_at >= lines.len() {
+            return Err(ParseError {
+                file: path.to_path_buf(),
+                line_number: lines[start].line_number,
+                line_text: lines[start].text.clone(),
+                message: "block is missing its opening `{`".to_string(),
+            }
+            .into());
+        }
+        let opens = lines[open_at].text.matches('{').count();
+        let closes = lines[open_at].text.matches('}').count();
+        if opens > closes {
+            break;
+        }
+        open_at += 1;
+    }
+
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(open_at) {
+        let opens = line.text.matches('{').count() as i32;
+        let closes = line.text.matches('}').count() as i32;
+        depth += opens - closes;
+        if depth == 0 {
+            return Ok((open_at, idx));
+        }
+    }
+
+    Err(ParseError {
+        file: path.to_path_buf(),
+        line_number: lines[open_at].line_number,
+        line_text: lines[open_at].text.clone(),
+        message: "block is missing its closing `}`".to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn walls_from(text: &str) -> Vec<WallLevel> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_walls_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        parse(Path::new("descr_walls.txt"), &lines).unwrap()
+    }
+
+    const EXCERPT: &str = r#"
+wall_level 0
+{
+    turret_model turret_none
+    gate_model gate_none
+}
+
+wall_level 1
+{
+    culture default
+    {
+        turret_model turret_1
+        gate_model gate_1
+    }
+    culture eastern, middle_eastern
+    {
+        turret_model turret_1_eastern
+        gate_model gate_1_eastern
+    }
+}
+"#;
+
+    #[test]
+    fn parses_levels_and_culture_variants() {
+        let levels = walls_from(EXCERPT);
+        assert_eq!(levels.len(), 2);
+
+        let level0 = &levels[0];
+        assert_eq!(level0.level, 0);
+        assert_eq!(level0.models.len(), 2);
+        assert_eq!(level0.models[0].key, "turret_model");
+        assert_eq!(level0.models[0].model, "turret_none");
+        assert!(level0.variants.is_empty());
+
+        let level1 = &levels[1];
+        assert_eq!(level1.level, 1);
+        assert!(level1.models.is_empty());
+        assert_eq!(level1.variants.len(), 2);
+        assert_eq!(level1.variants[0].cultures, vec!["default".to_string()]);
+        assert_eq!(level1.variants[1].cultures, vec!["eastern".to_string(), "middle_eastern".to_string()]);
+        assert_eq!(level1.variants[1].models[1].model, "gate_1_eastern");
+
+        assert_eq!(level1.models_for("eastern")[0].model, "turret_1_eastern");
+        assert_eq!(level1.models_for("EASTERN")[0].model, "turret_1_eastern");
+        assert_eq!(level1.models_for("default")[0].model, "turret_1");
+        assert!(level1.models_for("byzantine").is_empty());
+        assert_eq!(level1.all_models().count(), 4);
+    }
+
+    #[test]
+    fn missing_closing_brace_is_rejected() {
+        let text = r#"
+wall_level 0
+{
+    turret_model turret_none
+"#;
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_walls_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+        let lines: Vec<&DescrLine> = lines.iter().collect();
+        let message = parse(Path::new("descr_walls.txt"), &lines).unwrap_err().to_string();
+        assert!(message.contains("missing its closing `}`"), "{message}");
+    }
+}
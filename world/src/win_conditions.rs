@@ -0,0 +1,205 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, Result, WorldError};
+use crate::text::{parse_descr_lines, DescrLine, ReadOptions};
+
+/// The condition keywords this parser recognizes, used to tell a faction
+/// header apart from a stray condition line at the start of a section (see
+/// [`parse_record`]).
+const CONDITION_KEYWORDS: &[&str] = &["short_campaign", "long_campaign", "take_regions", "hold_regions", "eliminate_faction", "outlive"];
+
+/// A faction's victory conditions from `descr_win_conditions.txt`. Records
+/// are separated by blank lines, each starting with a bare faction name,
+/// the same loosely keyword-based layout `descr_regions.txt` uses.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct WinConditions {
+    pub faction: String,
+    /// 1-based line the faction name started on.
+    pub line_number: usize,
+    pub short_campaign: ConditionSet,
+    pub long_campaign: ConditionSet,
+}
+
+/// One `short_campaign`/`long_campaign` block's conditions.
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct ConditionSet {
+    pub take_regions: Option<u32>,
+    pub hold_regions: Vec<Reference>,
+    pub eliminate_factions: Vec<Reference>,
+    pub outlive_factions: Vec<Reference>,
+}
+
+/// A named reference (region or faction) with the line it was written on,
+/// for pointing `validate` findings back at the source file.
+#[derive(Deserialize, Debug, Clone, Serialize)]
+pub struct Reference {
+    pub line_number: usize,
+    pub name: String,
+}
+
+impl WinConditions {
+    pub fn load_all(path: &Path, text: &str) -> Result<Vec<WinConditions>> {
+        let lines = parse_descr_lines(text, ReadOptions { drop_blank_lines: false });
+
+        let mut records = Vec::new();
+        for block in split_blocks(&lines) {
+            records.push(parse_record(path, &block)?);
+        }
+        Ok(records)
+    }
+}
+
+/// Groups `lines` into records separated by one or more blank (or
+/// comment-only, since [`read_descr_lines`] already stripped comments)
+/// lines.
+fn split_blocks(lines: &[DescrLine]) -> Vec<Vec<&DescrLine>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+fn parse_record(path: &Path, block: &[&DescrLine]) -> Result<WinConditions> {
+    let header = block[0];
+    let err = |line: &DescrLine, message: String| -> WorldError {
+        ParseError { file: path.to_path_buf(), line_number: line.line_number, line_text: line.text.clone(), message }.into()
+    };
+
+    let header_first_word = header.text.split_whitespace().next().unwrap_or_default();
+    if CONDITION_KEYWORDS.contains(&header_first_word) {
+        return Err(err(header, format!("expected a faction name to start a win-conditions section, found condition keyword {header_first_word:?}")));
+    }
+    let faction = header.text.clone();
+
+    let mut short_campaign = ConditionSet::default();
+    let mut long_campaign = ConditionSet::default();
+    let mut current: Option<&mut ConditionSet> = None;
+
+    for line in &block[1..] {
+        let mut parts = line.text.split_whitespace();
+        let Some(key) = parts.next() else { continue };
+        let rest = line.text[key.len()..].trim().to_string();
+
+        match key {
+            "short_campaign" => current = Some(&mut short_campaign),
+            "long_campaign" => current = Some(&mut long_campaign),
+            "take_regions" | "hold_regions" | "eliminate_faction" | "outlive" => {
+                let set = current.as_deref_mut().ok_or_else(|| {
+                    err(line, format!("`{key}` appears before a `short_campaign` or `long_campaign` line in faction {faction:?}'s section"))
+                })?;
+                match key {
+                    "take_regions" => {
+                        set.take_regions = Some(rest.parse().map_err(|_| err(line, format!("{rest:?} is not a valid region count")))?);
+                    }
+                    "hold_regions" => {
+                        set.hold_regions.extend(
+                            rest.split(',').map(str::trim).filter(|s| !s.is_empty()).map(|name| Reference { line_number: line.line_number, name: name.to_string() }),
+                        );
+                    }
+                    "eliminate_faction" => set.eliminate_factions.push(Reference { line_number: line.line_number, name: rest }),
+                    "outlive" => set.outlive_factions.push(Reference { line_number: line.line_number, name: rest }),
+                    _ => unreachable!(),
+                }
+            }
+            _ => {
+                return Err(err(line, format!("unexpected line {:?} in faction {faction:?}'s win-conditions section", line.text)));
+            }
+        }
+    }
+
+    Ok(WinConditions { faction, line_number: header.line_number, short_campaign, long_campaign })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::text::read_descr_lines;
+
+    static FIXTURE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn records_from(text: &str) -> Vec<WinConditions> {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_win_conditions_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut records = Vec::new();
+        for block in split_blocks(&lines) {
+            records.push(parse_record(&path, &block).unwrap());
+        }
+        records
+    }
+
+    fn parse_err(text: &str) -> String {
+        let id = FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("descr_win_conditions_test_{id}.txt"));
+        std::fs::write(&path, text).unwrap();
+        let lines = read_descr_lines(&path, ReadOptions { drop_blank_lines: false }).unwrap();
+        std::fs::remove_file(&path).ok();
+        let blocks = split_blocks(&lines);
+        parse_record(&path, &blocks[0]).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parses_both_campaign_lengths() {
+        let records = records_from(
+            r#"
+england
+short_campaign
+take_regions 5
+hold_regions Wessex, Normandy
+long_campaign
+eliminate_faction france
+outlive scotland
+
+france
+short_campaign
+take_regions 3
+hold_regions Ile_de_France
+"#,
+        );
+        assert_eq!(records.len(), 2);
+
+        let england = &records[0];
+        assert_eq!(england.faction, "england");
+        assert_eq!(england.short_campaign.take_regions, Some(5));
+        assert_eq!(england.short_campaign.hold_regions.len(), 2);
+        assert_eq!(england.short_campaign.hold_regions[0].name, "Wessex");
+        assert_eq!(england.short_campaign.hold_regions[1].name, "Normandy");
+        assert!(england.long_campaign.hold_regions.is_empty());
+        assert_eq!(england.long_campaign.eliminate_factions.len(), 1);
+        assert_eq!(england.long_campaign.eliminate_factions[0].name, "france");
+        assert_eq!(england.long_campaign.outlive_factions[0].name, "scotland");
+
+        let france = &records[1];
+        assert_eq!(france.faction, "france");
+        assert_eq!(france.short_campaign.hold_regions[0].name, "Ile_de_France");
+    }
+
+    #[test]
+    fn condition_keyword_is_not_mistaken_for_a_faction_header() {
+        let message = parse_err("short_campaign\ntake_regions 5\n");
+        assert!(message.contains("expected a faction name"), "{message}");
+    }
+
+    #[test]
+    fn condition_before_any_campaign_block_is_rejected() {
+        let message = parse_err("england\ntake_regions 5\n");
+        assert!(message.contains("appears before a `short_campaign` or `long_campaign` line"), "{message}");
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ancillary::AncillariesFile;
+use crate::battle_model::BattleModel;
+use crate::building::Building;
+use crate::campaign::{CampaignSettings, DiplomacyMatrix, FactionStart};
+use crate::campaign_db::CampaignDbSection;
+use crate::campaign_script::ScriptCommand;
+use crate::character::{Character, FamilyRecord};
+use crate::config::{Config, ResolvedPath};
+use crate::culture::Culture;
+use crate::error::{Result, WorldError};
+use crate::events::HistoricEvent;
+use crate::faction::Faction;
+use crate::heightmap::HeightMap;
+use crate::index::WorldIndex;
+use crate::localization::LocalizationFile;
+use crate::mercenary::MercPool;
+use crate::mission::Mission;
+use crate::mount::Mount;
+use crate::names::NamePool;
+use crate::projectile::Projectile;
+use crate::rebel_faction::RebelFaction;
+use crate::region::Region;
+use crate::religion::Religion;
+use crate::resource::{ResourceDef, ResourcePlacement};
+use crate::settlement::Settlement;
+use crate::terrain::TerrainInfo;
+use crate::traits::TraitsFile;
+use crate::unit::Unit;
+use crate::voice::VoiceClass;
+use crate::wall::WallLevel;
+use crate::win_conditions::WinConditions;
+
+#[derive(Deserialize, Debug, Serialize)]
+pub struct World {
+    pub cultures: Vec<Culture>,
+    pub religions: Vec<Religion>,
+    pub factions: Vec<Faction>,
+    pub regions: Vec<Region>,
+    pub settlements: Vec<Settlement>,
+    pub characters: Vec<Character>,
+    pub families: Vec<FamilyRecord>,
+    pub campaign: CampaignSettings,
+    pub campaign_db: CampaignDbSection,
+    pub faction_starts: Vec<FactionStart>,
+    pub diplomacy: DiplomacyMatrix,
+    /// `None` if neither `map_heights.hgt` nor `map_heights.tga` exists;
+    /// the land/sea check in `validate` is skipped in that case.
+    pub heightmap: Option<HeightMap>,
+    pub terrain: TerrainInfo,
+    pub buildings: Vec<Building>,
+    pub wall_levels: Vec<WallLevel>,
+    pub name_pools: Vec<NamePool>,
+    pub units: Vec<Unit>,
+    pub projectiles: Vec<Projectile>,
+    pub mounts: Vec<Mount>,
+    pub battle_models: Vec<BattleModel>,
+    pub resources: Vec<ResourceDef>,
+    pub resource_placements: Vec<ResourcePlacement>,
+    pub merc_pools: Vec<MercPool>,
+    pub missions: Vec<Mission>,
+    pub rebel_factions: Vec<RebelFaction>,
+    pub win_conditions: Vec<WinConditions>,
+    pub events: Vec<HistoricEvent>,
+    pub traits: TraitsFile,
+    pub ancillaries: AncillariesFile,
+    pub script_commands: Vec<ScriptCommand>,
+    pub voice_classes: Vec<VoiceClass>,
+    pub localization: LocalizationFile,
+    /// Which directory (base `data` or a mod overlay) actually supplied
+    /// each data file this `World` loaded from, keyed by filename. Lets
+    /// `dump`/`validate` report e.g. "descr_regions.txt: mod,
+    /// descr_sm_factions.txt: base" so a mod author can see at a glance
+    /// what they've actually overridden.
+    pub sources: BTreeMap<String, ResolvedPath>,
+    /// Cross-reference index over the fields above, built the first time
+    /// something calls [`World::index`] and reused after that. Skipped by
+    /// (de)serialization, same as [`HeightMap::heights`] -- it's rebuilt
+    /// lazily rather than persisted.
+    #[serde(skip)]
+    pub(crate) index: OnceLock<WorldIndex>,
+}
+
+/// Every required-file group `World::load` reads to build its "campaign
+/// header" batch, in load order. Shared with [`crate::cache`] so its cache
+/// invalidation checks the same file universe `load` actually reads from,
+/// instead of a second, driftable copy of this list.
+pub(crate) const CAMPAIGN_HEADER_GROUPS: &[&[&str]] =
+    &[&["descr_sm_factions.txt"], &["descr_regions.txt"], &["descr_strat.txt"], &["export_descr_unit.txt"]];
+
+/// Every required-file group `World::load` reads to build its "campaign
+/// data" batch, in load order. Shared with [`crate::cache`] for the same
+/// reason as [`CAMPAIGN_HEADER_GROUPS`].
+pub(crate) const CAMPAIGN_DATA_GROUPS: &[&[&str]] = &[
+    &["descr_cultures.txt"],
+    &["descr_religions.txt"],
+    &["descr_terrain.txt"],
+    &["export_descr_buildings.txt"],
+    &["descr_walls.txt"],
+    &["descr_names.txt"],
+    &["descr_mercenaries.txt"],
+    &["descr_rebel_factions.txt"],
+    &["descr_win_conditions.txt"],
+    &["descr_events.txt"],
+    &["export_descr_character_traits.txt"],
+    &["export_descr_ancillaries.txt"],
+    // Legacy mods sometimes still ship this file under RTW's old name.
+    &["descr_projectile.txt", "descr_projectile_new.txt"],
+    &["descr_mount.txt"],
+    &["descr_model_battle.txt"],
+    &["descr_sm_resources.txt"],
+    &["descr_campaign_db.xml"],
+];
+
+/// Names of the optional `read_data`-based files `World::load` checks for,
+/// each only earning a `sources` entry if actually present. Shared with
+/// [`crate::cache`] so a file that newly appears (or disappears) is caught
+/// by cache invalidation even though it never showed up in a previous
+/// `sources` map.
+pub(crate) const OPTIONAL_FILE_NAMES: &[&str] =
+    &["campaign_script.txt", "descr_missions.txt", "export_descr_sounds_units_voice.txt"];
+
+/// Names of the optional `resolve`-based (directory-only) binary assets
+/// `World::load` checks for. Shared with [`crate::cache`] for the same
+/// reason as [`OPTIONAL_FILE_NAMES`].
+pub(crate) const OPTIONAL_ASSET_NAMES: &[&str] = &["map_heights.hgt", "map_heights.tga"];
+
+impl World {
+    pub fn load(config: &Config) -> Result<World> {
+        let mut sources: BTreeMap<String, ResolvedPath> = BTreeMap::new();
+
+        // Reads the first of `names` whose bytes can be found via
+        // `mod_dir`/`packs`/`data_dir` (in that override order) and decodes
+        // them, without touching `sources` -- so it can run on any thread.
+        // Every required `descr_*.txt`/`export_descr_*.txt` file goes
+        // through this instead of `Config::resolve`, so it can come out of
+        // a `.pack` as easily as a directory. Most files only have one
+        // accepted name; a few (like `descr_projectile.txt`) also accept a
+        // legacy name some mods still ship instead.
+        let read_source = |names: &[&str]| -> Result<(PathBuf, String, ResolvedPath)> {
+            for &name in names {
+                if let Some((bytes, resolved)) = config.read_data(Path::new(name))? {
+                    let text = crate::text::decode_text(&bytes, &resolved.path)?;
+                    let path = resolved.path.clone();
+                    return Ok((path, text, resolved));
+                }
+            }
+            Err(WorldError::MissingFile(config.resolve(Path::new(names[0])).path))
+        };
+
+        // Reads every group in `groups` in parallel via rayon -- each is an
+        // independent `read_source` call, so there's no reason to wait for
+        // one file's disk I/O and decode before starting the next -- then
+        // records who supplied each one under its canonical name
+        // (`names[0]`) back on this thread, in file order, so `world.sources`
+        // and `validate`'s findings still point at the right file
+        // regardless of which thread happened to finish first.
+        let mut read_required = |label: &str, groups: &[&[&str]]| -> Result<Vec<(PathBuf, String)>> {
+            let started = Instant::now();
+            let results: Vec<Result<(PathBuf, String, ResolvedPath)>> = groups.par_iter().map(|names| read_source(names)).collect();
+            let mut texts = Vec::with_capacity(results.len());
+            for (names, result) in groups.iter().zip(results) {
+                let (path, text, resolved) = result?;
+                sources.insert(names[0].to_string(), resolved);
+                texts.push((path, text));
+            }
+            if config.debug_timing {
+                eprintln!("world::load: read {label} ({} files) in {:?}", groups.len(), started.elapsed());
+            }
+            Ok(texts)
+        };
+
+        let mut first_batch = read_required("campaign header", CAMPAIGN_HEADER_GROUPS)?.into_iter();
+        let (factions_path, factions_text) = first_batch.next().expect("read_required returns one entry per group");
+        let (regions_path, regions_text) = first_batch.next().expect("read_required returns one entry per group");
+        let (strat_path, strat_text) = first_batch.next().expect("read_required returns one entry per group");
+        let (units_path, units_text) = first_batch.next().expect("read_required returns one entry per group");
+
+        let parse_started = Instant::now();
+        let mut factions = Faction::load_all(&factions_path, &factions_text)?;
+        let mut regions = Region::load_all(&regions_path, &regions_text)?;
+        let mut settlements = Settlement::load_all(&strat_path, &strat_text)?;
+        let mut units = Unit::load_all(&units_path, &units_text)?;
+
+        let locale_dir = config.locale_dir.as_deref().unwrap_or(&config.data_dir);
+        let mut localization = LocalizationFile::load(locale_dir)?;
+        localization.apply(&mut factions, &mut units, &mut regions, &mut settlements);
+        if config.debug_timing {
+            eprintln!("world::load: parse campaign header in {:?}", parse_started.elapsed());
+        }
+
+        let mut second_batch = read_required("campaign data", CAMPAIGN_DATA_GROUPS)?.into_iter();
+        let (cultures_path, cultures_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (religions_path, religions_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (terrain_path, terrain_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (buildings_path, buildings_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (walls_path, walls_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (names_path, names_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (merc_path, merc_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (rebel_path, rebel_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (win_conditions_path, win_conditions_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (events_path, events_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (traits_path, traits_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (ancillaries_path, ancillaries_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (projectiles_path, projectiles_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (mounts_path, mounts_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (battle_models_path, battle_models_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (resources_path, resources_text) = second_batch.next().expect("read_required returns one entry per group");
+        let (campaign_db_path, campaign_db_text) = second_batch.next().expect("read_required returns one entry per group");
+
+        let parse_started = Instant::now();
+        let cultures = Culture::load_all(&cultures_path, &cultures_text)?;
+        let religions = Religion::load_all(&religions_path, &religions_text)?;
+        let characters = Character::load_all(&strat_path, &strat_text)?;
+        let families = FamilyRecord::load_all(&strat_path, &strat_text)?;
+        let campaign = CampaignSettings::load(&strat_path, &strat_text)?;
+        let faction_starts = FactionStart::load_all(&strat_path, &strat_text)?;
+        let diplomacy = DiplomacyMatrix::load(&strat_path, &strat_text)?;
+        let terrain = TerrainInfo::load(&terrain_path, &terrain_text)?;
+        let buildings = Building::load_all(&buildings_path, &buildings_text)?;
+        let wall_levels = WallLevel::load_all(&walls_path, &walls_text)?;
+        let name_pools = NamePool::load_all(&names_path, &names_text)?;
+        let merc_pools = MercPool::load_all(&merc_path, &merc_text)?;
+        let rebel_factions = RebelFaction::load_all(&rebel_path, &rebel_text)?;
+        let win_conditions = WinConditions::load_all(&win_conditions_path, &win_conditions_text)?;
+        let events = HistoricEvent::load_all(&events_path, &events_text)?;
+        let traits = TraitsFile::load(&traits_path, &traits_text)?;
+        let ancillaries = AncillariesFile::load(&ancillaries_path, &ancillaries_text)?;
+        let projectiles = Projectile::load_all(&projectiles_path, &projectiles_text)?;
+        let mounts = Mount::load_all(&mounts_path, &mounts_text)?;
+        let battle_models = BattleModel::load_all(&battle_models_path, &battle_models_text)?;
+        let resources = ResourceDef::load_all(&resources_path, &resources_text)?;
+        let resource_placements = ResourcePlacement::load_all(&strat_path, &strat_text)?;
+        let campaign_db = CampaignDbSection::load(&campaign_db_path, &campaign_db_text)?;
+        if config.debug_timing {
+            eprintln!("world::load: parse campaign data in {:?}", parse_started.elapsed());
+        }
+
+        // `map_heights.*` is a binary asset, so it stays on the
+        // directory-only `resolve` path rather than `read_data`. It's also
+        // optional, so it only earns a `sources` entry if it's actually
+        // present -- an absent file isn't "supplied by" either directory.
+        let hgt = config.resolve(Path::new("map_heights.hgt"));
+        let tga = config.resolve(Path::new("map_heights.tga"));
+        let heightmap = HeightMap::try_load(&hgt.path, &tga.path)?;
+        if hgt.path.is_file() {
+            sources.insert("map_heights.hgt".to_string(), hgt);
+        } else if tga.path.is_file() {
+            sources.insert("map_heights.tga".to_string(), tga);
+        }
+
+        // `campaign_script.txt` is optional -- most mods don't use the
+        // scripting engine at all -- so a missing file just means no
+        // commands were found, not a load failure.
+        let script_commands = match config.read_data(Path::new("campaign_script.txt"))? {
+            Some((bytes, resolved)) => {
+                let text = crate::text::decode_text(&bytes, &resolved.path)?;
+                let commands = ScriptCommand::load_all(&resolved.path, &text)?;
+                sources.insert("campaign_script.txt".to_string(), resolved);
+                commands
+            }
+            None => Vec::new(),
+        };
+
+        // `descr_missions.txt` is optional -- only mods with council/guild
+        // missions ship it -- so a missing file just means no missions were
+        // found, not a load failure.
+        let missions = match config.read_data(Path::new("descr_missions.txt"))? {
+            Some((bytes, resolved)) => {
+                let text = crate::text::decode_text(&bytes, &resolved.path)?;
+                let missions = Mission::load_all(&resolved.path, &text)?;
+                sources.insert("descr_missions.txt".to_string(), resolved);
+                missions
+            }
+            None => Vec::new(),
+        };
+
+        // `export_descr_sounds_units_voice.txt` is optional -- only mods
+        // that ship a custom sound package touch it -- so a missing file
+        // just means the voice/silent-unit checks below are skipped rather
+        // than a load failure.
+        let voice_classes = match config.read_data(Path::new("export_descr_sounds_units_voice.txt"))? {
+            Some((bytes, resolved)) => {
+                let text = crate::text::decode_text(&bytes, &resolved.path)?;
+                let classes = VoiceClass::load_all(&resolved.path, &text)?;
+                sources.insert("export_descr_sounds_units_voice.txt".to_string(), resolved);
+                classes
+            }
+            None => Vec::new(),
+        };
+
+        Ok(World {
+            cultures,
+            religions,
+            factions,
+            regions,
+            settlements,
+            characters,
+            families,
+            campaign,
+            campaign_db,
+            faction_starts,
+            diplomacy,
+            heightmap,
+            terrain,
+            buildings,
+            wall_levels,
+            name_pools,
+            units,
+            projectiles,
+            mounts,
+            battle_models,
+            resources,
+            resource_placements,
+            merc_pools,
+            missions,
+            rebel_factions,
+            win_conditions,
+            events,
+            traits,
+            ancillaries,
+            script_commands,
+            voice_classes,
+            localization,
+            sources,
+            index: OnceLock::new(),
+        })
+    }
+
+    /// Looks up a faction's name pool by name, for validating that a
+    /// character's given/family name actually comes from its faction's
+    /// pool.
+    pub fn names_for(&self, faction: &str) -> Option<&NamePool> {
+        self.name_pools.iter().find(|pool| pool.faction == faction)
+    }
+
+    /// The cross-reference index over this `World`'s factions, regions,
+    /// units, and cultures, built on first use and cached from then on.
+    pub fn index(&self) -> &WorldIndex {
+        self.index.get_or_init(|| WorldIndex::build(self))
+    }
+
+    /// Case-insensitive faction lookup by name, backed by [`World::index`]
+    /// instead of a linear scan.
+    pub fn faction_by_name(&self, name: &str) -> Option<&Faction> {
+        self.index().faction_id(name).and_then(|id| self.factions.get(id))
+    }
+
+    /// Case-insensitive region lookup by name, backed by [`World::index`]
+    /// instead of a linear scan.
+    pub fn region_by_name(&self, name: &str) -> Option<&Region> {
+        self.index().region_id(name).and_then(|id| self.regions.get(id))
+    }
+
+    /// Case-insensitive unit lookup by its EDU `type`, backed by
+    /// [`World::index`] instead of a linear scan.
+    pub fn unit_by_type(&self, unit_type: &str) -> Option<&Unit> {
+        self.index().unit_id(unit_type).and_then(|id| self.units.get(id))
+    }
+
+    /// Case-insensitive culture lookup by name, backed by [`World::index`]
+    /// instead of a linear scan.
+    pub fn culture_by_name(&self, name: &str) -> Option<&Culture> {
+        self.index().culture_id(name).and_then(|id| self.cultures.get(id))
+    }
+}